@@ -0,0 +1,12 @@
+//! Snake in the terminal: `cargo run --example snake --features minigames`.
+//! Arrows or WASD turn the snake, Q or Esc quits.
+#![allow(clippy::needless_return)]
+use std::time::{SystemTime, UNIX_EPOCH};
+use char_tilemap::Vector2;
+
+fn main() -> std::io::Result<()> {
+    let seed = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_secs()).unwrap_or(0);
+    let score = char_tilemap::minigames::play_snake(Vector2::new(30, 15), seed)?;
+    println!("Score: {score}");
+    return Ok(());
+}
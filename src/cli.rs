@@ -0,0 +1,565 @@
+use std::io::Read;
+use std::path::PathBuf;
+use char_tilemap::{commands, Color, Rng, Rotated, Rotation, TileChange, Tilemap, Vector2};
+use std::collections::HashMap;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Command line interface of the `char-tilemap` binary.
+///
+/// Use "-" as a file to read it from the standard input. Commands that produce tilemaps print
+/// them in the save format, unless `--output` is given.
+#[derive(Parser, Debug)]
+#[command(name = "char-tilemap", version, args_conflicts_with_subcommands = true,
+          about = "Create, edit and transform char tilemaps", long_about = None)]
+pub struct Cli {
+    /// # Description
+    /// Command to run. Interactive editor is opened if no command is specified.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    /// File to open in the interactive editor when no command is specified
+    pub file: Option<PathBuf>,
+}
+
+/// # Description
+/// Commands of the command line interface.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Open interactive editor
+    Edit {
+        /// File to edit
+        file: Option<PathBuf>,
+    },
+    /// Explore tilemap with interactive queries and mutations
+    Repl {
+        /// Tilemap file to load
+        file: Option<PathBuf>,
+    },
+    /// Step through a journal exported as JSON lines, rendering the tilemap at each step
+    Replay {
+        /// Journal file
+        journal: PathBuf,
+        /// Tilemap file in the state when the journal was started, new tilemap is used if not specified
+        #[arg(long)]
+        map: Option<PathBuf>,
+        /// Value of the empty tile of the new tilemap
+        #[arg(long, default_value_t = '.')]
+        empty: char,
+    },
+    /// Re-render file every time it is modified, highlighting changes
+    Watch {
+        /// Tilemap file, or script of commands with --script
+        file: PathBuf,
+        /// Treat file as a script of commands, see "exec"
+        #[arg(long)]
+        script: bool,
+        /// Value of the empty tile of the tilemap built by the script
+        #[arg(long, default_value_t = '.')]
+        empty: char,
+        /// Delay between checks of the file in milliseconds
+        #[arg(long, default_value_t = 200)]
+        interval: u64,
+    },
+    /// Print built tilemap
+    Render {
+        /// Tilemap file
+        file: String,
+    },
+    /// Create new tilemap
+    New {
+        /// Width of the tilemap
+        width: usize,
+        /// Height of the tilemap
+        height: usize,
+        /// Value of the empty tile
+        #[arg(long, default_value_t = '.')]
+        empty: char,
+        #[command(flatten)]
+        output: Output,
+    },
+    /// Set tile
+    Set {
+        /// Tilemap file
+        file: String,
+        /// X position of the tile
+        x: usize,
+        /// Y position of the tile
+        y: usize,
+        /// Value of the tile
+        value: char,
+        #[command(flatten)]
+        output: Output,
+    },
+    /// Cut rectangular region
+    Crop {
+        /// Tilemap file
+        file: String,
+        /// X position of the top left corner
+        x: usize,
+        /// Y position of the top left corner
+        y: usize,
+        /// Width of the region
+        width: usize,
+        /// Height of the region
+        height: usize,
+        #[command(flatten)]
+        output: Output,
+    },
+    /// Rotate clockwise
+    Rotate {
+        /// Tilemap file
+        file: String,
+        /// Angle of the rotation: 90, 180 or 270
+        #[arg(value_parser = parse_rotation)]
+        rotation: Rotation,
+        #[command(flatten)]
+        output: Output,
+    },
+    /// Copy tiles of the second file over the first one
+    Merge {
+        /// Tilemap file that is merged into
+        first: String,
+        /// Tilemap file which tiles are copied
+        second: String,
+        #[command(flatten)]
+        output: Output,
+    },
+    /// Print changes from the first file to the second one
+    Diff {
+        /// Original tilemap file
+        first: String,
+        /// Changed tilemap file
+        second: String,
+        /// Format of the printed changes
+        #[arg(long, value_enum, default_value_t = DiffFormat::Overlay)]
+        format: DiffFormat,
+    },
+    /// Generate procedural tilemap
+    Generate {
+        #[command(subcommand)]
+        generator: Generator,
+    },
+    /// Execute commands read from the standard input and print output of every "build"
+    Exec {
+        /// Tilemap file to start from, new tilemap is created if not specified
+        #[arg(long)]
+        map: Option<String>,
+        /// Value of the empty tile of the new tilemap
+        #[arg(long, default_value_t = '.')]
+        empty: char,
+        /// Save resulting tilemap to the file
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// # Description
+/// Generators available through [`Command::Generate`].
+#[derive(Subcommand, Debug)]
+pub enum Generator {
+    /// Perfect maze with passages at odd positions
+    Maze {
+        /// Size of the tilemap in format WIDTHxHEIGHT
+        #[arg(value_parser = parse_size)]
+        size: Vector2,
+        #[command(flatten)]
+        options: GenerateOptions,
+    },
+    /// Caves made by cellular automaton
+    Caves {
+        /// Size of the tilemap in format WIDTHxHEIGHT
+        #[arg(value_parser = parse_size)]
+        size: Vector2,
+        /// Probability of a wall before smoothing
+        #[arg(long, default_value_t = 0.45)]
+        fill: f64,
+        /// Number of smoothing iterations
+        #[arg(long, default_value_t = 4)]
+        smooth: usize,
+        #[command(flatten)]
+        options: GenerateOptions,
+    },
+}
+
+/// # Description
+/// Options shared by all generators.
+#[derive(Args, Debug)]
+pub struct GenerateOptions {
+    /// Seed of the generator, random if not specified
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Value of the wall tiles
+    #[arg(long, default_value_t = '#')]
+    wall: char,
+    /// Value of the empty tile
+    #[arg(long, default_value_t = '.')]
+    empty: char,
+    #[command(flatten)]
+    output: Output,
+}
+
+/// # Description
+/// Format in which [`Command::Diff`] prints changes.
+#[derive(ValueEnum, Debug, Eq, PartialEq, Copy, Clone)]
+pub enum DiffFormat {
+    /// Changed tilemap with colored additions, removals and changes, followed by summary counts
+    Overlay,
+    /// One change per line: "+ x y new", "- x y old" or "~ x y old new"
+    List,
+    /// JSON object with summary counts and list of changes
+    Json,
+}
+
+/// # Description
+/// Destination of the tilemap produced by a command.
+#[derive(Args, Debug)]
+pub struct Output {
+    /// Save result to the file instead of printing it
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Commands
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Runs a non-interactive command.
+///
+/// # Arguments
+/// * `command: Command` - Command to run. Interactive [`Command::Edit`], [`Command::Repl`],
+///   [`Command::Replay`] and [`Command::Watch`] are not handled here.
+///
+/// # Return
+/// * [`Ok`] with text that should be printed to the standard output.
+/// * [`Err`] if command failed. Contains error message.
+pub fn run(command: Command) -> Result<String, String> {
+    return match command {
+        Command::Edit { .. } | Command::Repl { .. } | Command::Replay { .. } | Command::Watch { .. } => {
+            Err(String::from("Interactive command can not be run as a non-interactive one"))
+        },
+        Command::Render { file } => Ok(load(&file)?.build()),
+        Command::New { width, height, empty, output } => {
+            let mut tilemap = Tilemap::new(empty);
+            tilemap.resize(Vector2::new(width, height), Default::default())?;
+            output.write(&tilemap)
+        },
+        Command::Set { file, x, y, value, output } => {
+            let mut tilemap = load(&file)?;
+            tilemap.set(Vector2::new(x, y), value);
+            output.write(&tilemap)
+        },
+        Command::Crop { file, x, y, width, height, output } => {
+            let tilemap = load(&file)?;
+            output.write(&tilemap.crop(Vector2::new(x, y), Vector2::new(width, height))?)
+        },
+        Command::Rotate { file, rotation, output } => {
+            let tilemap = load(&file)?;
+            output.write(&Tilemap::from_source(&Rotated::new(&tilemap, rotation)))
+        },
+        Command::Merge { first, second, output } => {
+            let mut tilemap = load(&first)?;
+            let skipped = tilemap.merge(&load(&second)?);
+            if !skipped.is_empty() {
+                let errors: Vec<String> = skipped.into_iter().map(|(position, error)| format!("{position}: {error}")).collect();
+                return Err(format!("Failed to merge tiles:\n{}", errors.join("\n")));
+            }
+            output.write(&tilemap)
+        },
+        Command::Diff { first, second, format } => {
+            let (first, second) = (load(&first)?, load(&second)?);
+            let changes = first.diff(&second);
+            Ok(match format {
+                DiffFormat::Overlay => diff_overlay(&first, &second, &changes),
+                DiffFormat::List => diff_list(&changes),
+                DiffFormat::Json => diff_json(&changes),
+            })
+        },
+        Command::Generate { generator } => {
+            let (tilemap, options) = match generator {
+                Generator::Maze { size, options } => {
+                    (Tilemap::maze(size, options.wall, options.empty, &mut options.rng()), options)
+                },
+                Generator::Caves { size, fill, smooth, options } => {
+                    (Tilemap::caves(size, options.wall, options.empty, fill, smooth, &mut options.rng()), options)
+                },
+            };
+            options.output.write(&tilemap)
+        },
+        Command::Exec { map, empty, output } => {
+            let mut tilemap = match map {
+                Some(path) => load(&path)?,
+                None => Tilemap::new(empty),
+            };
+            let mut script = String::new();
+            std::io::stdin().read_to_string(&mut script).map_err(|error| format!("Failed to read input: {error}"))?;
+            let text = commands::execute(&mut tilemap, &script)?.join("\n\n");
+            if let Some(path) = output {
+                tilemap.save_to_file(path)?;
+            }
+            Ok(text)
+        },
+    };
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Output {
+    /// # Description
+    /// Saves tilemap to the `--output` file if it was specified, otherwise returns it in the save
+    /// format so it can be printed.
+    fn write(&self, tilemap: &Tilemap) -> Result<String, String> {
+        return match &self.output {
+            Some(path) => tilemap.save_to_file(path).map(|_| String::new()),
+            None => Ok(tilemap.to_file_string()),
+        };
+    }
+}
+
+impl GenerateOptions {
+    /// # Description
+    /// Creates generator from the `--seed`, or from the current time if seed was not specified.
+    fn rng(&self) -> Rng {
+        let seed = self.seed.unwrap_or_else(|| {
+            let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default();
+            now.as_nanos() as u64
+        });
+        return Rng::new(seed);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Loads tilemap from the file, or from the standard input if `path` is `-`. Trailing newline of
+/// the standard input is ignored, so output of other commands can be piped in.
+fn load(path: &str) -> Result<Tilemap, String> {
+    if path != "-" {
+        return Tilemap::load_from_file(path);
+    }
+
+    let mut text = String::new();
+    std::io::stdin().read_to_string(&mut text).map_err(|error| format!("Failed to read input: {error}"))?;
+    return Tilemap::from_file_string(text.strip_suffix('\n').unwrap_or(&text));
+}
+
+/// # Description
+/// Builds the union of both tilemaps where added tiles are green, removed tiles are red and
+/// changed tiles are yellow, followed by the summary counts.
+pub fn diff_overlay(first: &Tilemap, second: &Tilemap, changes: &[TileChange]) -> String {
+    let changes: HashMap<Vector2, &TileChange> = changes.iter().map(|change| (change.position, change)).collect();
+    let width = std::cmp::max(first.size().x, second.size().x);
+    let height = std::cmp::max(first.size().y, second.size().y);
+    let mut rows = Vec::with_capacity(height + 1);
+
+    for y in 0..height {
+        let mut row = String::new();
+        for x in 0..width {
+            let position = Vector2::new(x, y);
+            let (value, color) = match changes.get(&position) {
+                Some(TileChange { old: Some(old), new: None, .. }) => (*old, Some(Color::Red)),
+                Some(TileChange { old: None, new: Some(new), .. }) => (*new, Some(Color::Green)),
+                Some(TileChange { new: Some(new), .. }) => (*new, Some(Color::Yellow)),
+                _ => (second.get(position).unwrap_or(second.empty_tile), None),
+            };
+            match color {
+                Some(color) => row.push_str(&format!("{}{value}\x1b[0m", color.ansi_foreground())),
+                None => row.push(value),
+            }
+        }
+        rows.push(row);
+    }
+
+    let (added, removed, changed) = diff_counts(changes.values().copied());
+    rows.push(format!("{added} added, {removed} removed, {changed} changed"));
+    return rows.join("\n");
+}
+
+/// # Description
+/// Lists changes one per line.
+fn diff_list(changes: &[TileChange]) -> String {
+    let lines: Vec<String> = changes.iter().map(|change| match (change.old, change.new) {
+        (None, Some(new)) => format!("+ {} {} {new}", change.position.x, change.position.y),
+        (Some(old), None) => format!("- {} {} {old}", change.position.x, change.position.y),
+        (Some(old), Some(new)) => format!("~ {} {} {old} {new}", change.position.x, change.position.y),
+        (None, None) => String::new(),
+    }).collect();
+    return lines.join("\n");
+}
+
+/// # Description
+/// Describes summary counts and changes as a JSON object. Missing values are `null`.
+fn diff_json(changes: &[TileChange]) -> String {
+    let value = |value: Option<char>| match value {
+        Some(value) => json_string(value),
+        None => String::from("null"),
+    };
+    let entries: Vec<String> = changes.iter()
+        .map(|change| format!("{{\"x\":{},\"y\":{},\"old\":{},\"new\":{}}}",
+                              change.position.x, change.position.y, value(change.old), value(change.new)))
+        .collect();
+
+    let (added, removed, changed) = diff_counts(changes.iter());
+    return format!("{{\"added\":{added},\"removed\":{removed},\"changed\":{changed},\"changes\":[{}]}}",
+                   entries.join(","));
+}
+
+/// # Description
+/// Counts additions, removals and modifications.
+fn diff_counts<'a, I: Iterator<Item = &'a TileChange>>(changes: I) -> (usize, usize, usize) {
+    return changes.fold((0, 0, 0), |(added, removed, changed), change| {
+        (added + change.is_addition() as usize,
+         removed + change.is_removal() as usize,
+         changed + change.is_modification() as usize)
+    });
+}
+
+/// # Description
+/// Encodes char as a JSON string.
+fn json_string(value: char) -> String {
+    return match value {
+        '"' => String::from("\"\\\"\""),
+        '\\' => String::from("\"\\\\\""),
+        value if value.is_control() => format!("\"\\u{:04x}\"", value as u32),
+        value => format!("\"{value}\""),
+    };
+}
+
+/// # Description
+/// Parses size in format `WIDTHxHEIGHT`.
+fn parse_size(value: &str) -> Result<Vector2, String> {
+    let error = || String::from("expected size in format WIDTHxHEIGHT");
+    let (width, height) = value.split_once('x').ok_or_else(error)?;
+    return Ok(Vector2::new(width.parse().map_err(|_| error())?, height.parse().map_err(|_| error())?));
+}
+
+/// # Description
+/// Parses angle of the clockwise rotation in degrees.
+fn parse_rotation(value: &str) -> Result<Rotation, String> {
+    return match value {
+        "90" => Ok(Rotation::Clockwise90),
+        "180" => Ok(Rotation::Clockwise180),
+        "270" => Ok(Rotation::Clockwise270),
+        _ => Err(String::from("expected 90, 180 or 270")),
+    };
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use char_tilemap::Tilemap;
+    use clap::Parser;
+    use crate::cli::{run, Cli};
+
+    fn execute(values: &[&str]) -> Result<String, String> {
+        let cli = Cli::try_parse_from(std::iter::once("char-tilemap").chain(values.iter().copied()))
+            .map_err(|error| error.to_string())?;
+        return run(cli.command.unwrap());
+    }
+
+    fn temp_file(name: &str, tilemap: &Tilemap) -> String {
+        let path = std::env::temp_dir().join(name);
+        tilemap.save_to_file(&path).unwrap();
+        return path.to_string_lossy().to_string();
+    }
+
+    #[test]
+    fn new_and_render() {
+        let created = execute(&["new", "3", "2", "--empty", "-"]).unwrap();
+        assert_eq!(created, "version=3\nempty_tile=-\nsize=3x2\n\n---\n---");
+
+        let path = temp_file("char_tilemap_cli_render.tilemap", &Tilemap::from_file_string(&created).unwrap());
+        assert_eq!(execute(&["render", &path]).unwrap(), "---\n---");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn transforms() {
+        let first = temp_file("char_tilemap_cli_first.tilemap", &Tilemap::from_string("A-\n--\n-B", '-'));
+        let second = temp_file("char_tilemap_cli_second.tilemap", &Tilemap::from_string("C", '-'));
+
+        let set = execute(&["set", &first, "1", "0", "X"]).unwrap();
+        assert!(set.ends_with("AX\n--\n-B"));
+
+        let cropped = execute(&["crop", &first, "0", "1", "2", "2"]).unwrap();
+        assert!(cropped.ends_with("--\n-B"));
+
+        let rotated = execute(&["rotate", &first, "90"]).unwrap();
+        assert!(rotated.ends_with("--A\nB--"));
+
+        let merged = execute(&["merge", &first, &second]).unwrap();
+        assert!(merged.ends_with("C-\n--\n-B"));
+
+        assert_eq!(execute(&["diff", &first, &second, "--format", "list"]).unwrap(), "~ 0 0 A C\n- 1 2 B");
+
+        for file in [first, second] {
+            std::fs::remove_file(file).unwrap();
+        }
+    }
+
+    #[test]
+    fn diff_formats() {
+        let first = temp_file("char_tilemap_cli_diff_first.tilemap", &Tilemap::from_string("AB\n\"-", '-'));
+        let second = temp_file("char_tilemap_cli_diff_second.tilemap", &Tilemap::from_string("AC\n-D", '-'));
+
+        assert_eq!(execute(&["diff", &first, &second]).unwrap(),
+                   "A\x1b[33mC\x1b[0m\n\x1b[31m\"\x1b[0m\x1b[32mD\x1b[0m\n1 added, 1 removed, 1 changed");
+        assert_eq!(execute(&["diff", &first, &second, "--format", "json"]).unwrap(),
+                   "{\"added\":1,\"removed\":1,\"changed\":1,\"changes\":[\
+                   {\"x\":1,\"y\":0,\"old\":\"B\",\"new\":\"C\"},\
+                   {\"x\":0,\"y\":1,\"old\":\"\\\"\",\"new\":null},\
+                   {\"x\":1,\"y\":1,\"old\":null,\"new\":\"D\"}]}");
+
+        for file in [first, second] {
+            std::fs::remove_file(file).unwrap();
+        }
+    }
+
+    #[test]
+    fn generate() {
+        let maze = execute(&["generate", "maze", "7x5", "--seed", "7"]).unwrap();
+        assert_eq!(maze, execute(&["generate", "maze", "7x5", "--seed", "7"]).unwrap());
+        assert!(maze.starts_with("version=3\nempty_tile=.\nsize=7x5\n\n#######\n#"));
+
+        let caves = execute(&["generate", "caves", "10x4", "--seed", "7", "--smooth", "0", "--fill", "1"]).unwrap();
+        assert!(caves.ends_with("##########\n##########\n##########\n##########"));
+
+        match execute(&["generate", "maze", "7by5"]) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+
+    #[test]
+    fn output_option() {
+        let path = std::env::temp_dir().join("char_tilemap_cli_output.tilemap");
+        let path = path.to_string_lossy().to_string();
+
+        assert_eq!(execute(&["new", "2", "2", "-o", &path]).unwrap(), "");
+        assert_eq!(Tilemap::load_from_file(&path).unwrap().build(), "..\n..");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn errors() {
+        for values in [vec!["unknown", "file"], vec!["new", "1"], vec!["new", "a", "1"],
+                       vec!["new", "1", "1", "--empty"], vec!["rotate", "-", "45"],
+                       vec!["set", "missing.tilemap", "0", "0", "X"]] {
+            match execute(&values) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! # Commands
+//!
+//! Small text language that describes edits of a [`Tilemap`]. Every non-empty line of a script is
+//! a single command, arguments are separated by whitespaces:
+//! * `add <x> <y> <char>` - adds new tile, see [`Tilemap::add_tile()`].
+//! * `set <x> <y> <char>` - adds or updates tile, see [`Tilemap::set()`].
+//! * `remove <x> <y>` - removes existing tile, see [`Tilemap::remove_tile()`].
+//! * `rect <x> <y> <width> <height> <char>` - sets all tiles of the rectangular region.
+//! * `build` - outputs built tilemap, see [`Tilemap::build()`].
+use crate::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Commands
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Executes all commands of the script on the specified [`Tilemap`]. Execution stops at the first
+/// command that fails, changes made by previous commands are kept.
+///
+/// # Arguments
+/// * `tilemap: &mut Tilemap` - [`Tilemap`] on which commands are executed.
+/// * `script: &str` - Commands separated by new lines.
+///
+/// # Return
+/// * [`Ok`] with outputs of all `build` commands in order.
+/// * [`Err`] if any command could not be parsed or failed. Contains error message with the
+///   number of the line.
+///
+/// # Example
+/// ```rust
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// let output = char_tilemap::commands::execute(&mut tilemap, "rect 0 0 3 2 #\nremove 1 1\nbuild").unwrap();
+/// assert_eq!(output, vec!["###\n#-#"]);
+/// ```
+pub fn execute(tilemap: &mut Tilemap, script: &str) -> Result<Vec<String>, String> {
+    let mut output = Vec::new();
+
+    for (index, line) in script.lines().enumerate() {
+        let result = execute_line(tilemap, line)
+            .map_err(|error| format!("Line {}: {error}", index + 1))?;
+        if let Some(text) = result {
+            output.push(text);
+        }
+    }
+
+    return Ok(output);
+}
+
+/// # Description
+/// Executes single command.
+///
+/// # Return
+/// * [`Ok`] with output of the command if it has one.
+/// * [`Err`] if command could not be parsed or failed. Contains error message.
+fn execute_line(tilemap: &mut Tilemap, line: &str) -> Result<Option<String>, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let Some((&command, args)) = words.split_first() else {
+        return Ok(None);
+    };
+
+    match command {
+        "add" => {
+            expect(command, args, 3)?;
+            tilemap.add_tile(parse_position(args)?, parse_char(args[2])?)?;
+        },
+        "set" => {
+            expect(command, args, 3)?;
+            tilemap.set(parse_position(args)?, parse_char(args[2])?);
+        },
+        "remove" => {
+            expect(command, args, 2)?;
+            tilemap.remove_tile(parse_position(args)?)?;
+        },
+        "rect" => {
+            expect(command, args, 5)?;
+            let position = parse_position(args)?;
+            let size = parse_position(&args[2..])?;
+            let value = parse_char(args[4])?;
+            for y in position.y..position.y + size.y {
+                for x in position.x..position.x + size.x {
+                    tilemap.set(Vector2::new(x, y), value);
+                }
+            }
+        },
+        "build" => {
+            expect(command, args, 0)?;
+            return Ok(Some(tilemap.build()));
+        },
+        _ => return Err(format!("Unknown command \"{command}\"")),
+    }
+
+    return Ok(None);
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Checks that command received exactly `count` arguments.
+fn expect(command: &str, args: &[&str], count: usize) -> Result<(), String> {
+    if args.len() != count {
+        return Err(format!("Command \"{command}\" expects {count} arguments, but got {}", args.len()));
+    }
+
+    return Ok(());
+}
+
+/// # Description
+/// Parses first two arguments as a [`Vector2`].
+fn parse_position(args: &[&str]) -> Result<Vector2, String> {
+    let parse = |value: &str| value.parse::<usize>().map_err(|_| format!("Invalid number \"{value}\""));
+    return Ok(Vector2::new(parse(args[0])?, parse(args[1])?));
+}
+
+/// # Description
+/// Parses argument that should contain exactly one char.
+fn parse_char(value: &str) -> Result<char, String> {
+    let mut chars = value.chars();
+    return match (chars.next(), chars.next()) {
+        (Some(value), None) => Ok(value),
+        _ => Err(format!("Expected single char, but got \"{value}\"")),
+    };
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::execute;
+    use crate::{Tilemap, Vector2};
+
+    #[test]
+    fn execute_script() {
+        let mut tilemap = Tilemap::new('-');
+        let script = "add 3 1 X\n\n  rect 0 0 2 2 #  \nbuild\nremove 0 0\nset 1 1 O\nbuild";
+
+        let output = execute(&mut tilemap, script).unwrap();
+        assert_eq!(output, vec!["##--\n##-X", "-#--\n#O-X"]);
+        assert_eq!(tilemap.get(Vector2::new(3, 1)), Some('X'));
+    }
+
+    #[test]
+    fn errors() {
+        for script in ["unknown", "add 1 X", "add a 1 X", "add 1 1 XY", "remove 0 0", "build now",
+                       "add 0 0 X\nadd 0 0 Y"] {
+            match execute(&mut Tilemap::new('-'), script) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+
+        let mut tilemap = Tilemap::new('-');
+        assert_eq!(execute(&mut tilemap, "add 0 0 X\nremove 1 1"),
+                   Err(String::from("Line 2: There is no tile at the position { x: 1, y: 1 }")));
+        assert_eq!(tilemap.get(Vector2::ZERO), Some('X'));
+    }
+}
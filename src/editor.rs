@@ -0,0 +1,372 @@
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use char_tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Value of the empty tile that is used for new tilemaps created by the editor.
+const DEFAULT_EMPTY_TILE: char = '.';
+
+/// # Description
+/// Key pressed by the user, decoded from the raw terminal input.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Key {
+    /// # Description
+    /// Arrow up.
+    Up,
+    /// # Description
+    /// Arrow down.
+    Down,
+    /// # Description
+    /// Arrow left.
+    Left,
+    /// # Description
+    /// Arrow right.
+    Right,
+    /// # Description
+    /// Enter.
+    Enter,
+    /// # Description
+    /// Backspace.
+    Backspace,
+    /// # Description
+    /// Delete.
+    Delete,
+    /// # Description
+    /// Ctrl-S.
+    Save,
+    /// # Description
+    /// Ctrl-Q or Ctrl-C.
+    Quit,
+    /// # Description
+    /// Printable character.
+    /// # Value
+    /// * `char` - Typed character.
+    Char(char),
+}
+
+/// # Description
+/// Interactive terminal editor of the [`Tilemap`].
+///
+/// # Controls
+/// * Arrows - move cursor.
+/// * Any printable character - place tile under cursor and move cursor right.
+/// * Backspace - move cursor left and remove tile there.
+/// * Delete - remove tile under cursor.
+/// * Enter - move cursor to the start of the next row.
+/// * Ctrl-S - save tilemap.
+/// * Ctrl-Q or Ctrl-C - quit.
+pub struct Editor {
+    /// # Description
+    /// Edited tilemap.
+    tilemap: Tilemap,
+    /// # Description
+    /// Position of the cursor.
+    cursor: Vector2,
+    /// # Description
+    /// Path to which tilemap is saved.
+    path: PathBuf,
+    /// # Description
+    /// Message shown in the status bar.
+    status: String,
+    /// # Description
+    /// Is editor still running.
+    running: bool,
+}
+
+/// # Description
+/// Guard that switches terminal into the raw mode using `stty` and restores previous mode
+/// when dropped.
+struct RawMode {
+    /// # Description
+    /// Terminal settings before the switch, as reported by `stty -g`.
+    saved: String,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Editor {
+    /// # Description
+    /// Creates new [`Editor`] for the specified file. Tilemap is loaded from the file if it
+    /// exists, otherwise new tilemap is created.
+    ///
+    /// # Arguments
+    /// * `path: PathBuf` - Path to the edited file.
+    ///
+    /// # Return
+    /// * [`Ok`] with new [`Editor`].
+    /// * [`Err`] if file exists but could not be loaded. Contains error message.
+    pub fn new(path: PathBuf) -> Result<Editor, String> {
+        let (tilemap, status) = if path.exists() {
+            (Tilemap::load_from_file(&path)?, format!("Loaded {}", path.display()))
+        } else {
+            (Tilemap::new(DEFAULT_EMPTY_TILE), format!("New file {}", path.display()))
+        };
+
+        return Ok(Editor { tilemap, cursor: Vector2::ZERO, path, status, running: true });
+    }
+
+    /// # Description
+    /// Runs editor until user quits. Switches terminal into the raw mode for the duration of
+    /// the editing.
+    ///
+    /// # Return
+    /// * [`Ok`] when user quits.
+    /// * [`Err`] if terminal input or output failed.
+    pub fn run(&mut self) -> std::io::Result<()> {
+        let _raw_mode = RawMode::enable()?;
+        let mut input = std::io::stdin().lock();
+        let mut output = std::io::stdout().lock();
+
+        while self.running {
+            self.draw(&mut output)?;
+            match read_key(&mut input)? {
+                Some(key) => self.handle_key(key),
+                None => self.running = false,
+            }
+        }
+
+        write!(output, "\x1b[2J\x1b[H")?;
+        return output.flush();
+    }
+
+    /// # Description
+    /// Applies pressed key to the editor.
+    ///
+    /// # Arguments
+    /// * `key: Key` - Pressed key.
+    pub fn handle_key(&mut self, key: Key) {
+        match key {
+            Key::Up => self.cursor.y = self.cursor.y.saturating_sub(1),
+            Key::Down => self.cursor.y += 1,
+            Key::Left => self.cursor.x = self.cursor.x.saturating_sub(1),
+            Key::Right => self.cursor.x += 1,
+            Key::Enter => self.cursor = Vector2::new(0, self.cursor.y + 1),
+            Key::Backspace => {
+                self.cursor.x = self.cursor.x.saturating_sub(1);
+                let _ = self.tilemap.remove_tile(self.cursor);
+            },
+            Key::Delete => {
+                let _ = self.tilemap.remove_tile(self.cursor);
+            },
+            Key::Save => {
+                self.status = match self.tilemap.save_to_file(&self.path) {
+                    Ok(_) => format!("Saved {}", self.path.display()),
+                    Err(msg) => msg,
+                };
+            },
+            Key::Quit => self.running = false,
+            Key::Char(value) => {
+                self.tilemap.set(self.cursor, value);
+                self.cursor.x += 1;
+            },
+        }
+    }
+
+    /// # Description
+    /// Draws tilemap, cursor and status bar.
+    ///
+    /// # Arguments
+    /// * `output: &mut W` - Output to which editor is drawn.
+    ///
+    /// # Return
+    /// * [`Ok`] if editor was drawn.
+    /// * [`Err`] if writing to the output failed.
+    pub fn draw<W: Write>(&self, output: &mut W) -> std::io::Result<()> {
+        let size = self.tilemap.size();
+        let width = std::cmp::max(size.x, self.cursor.x + 1);
+        let height = std::cmp::max(size.y, self.cursor.y + 1);
+
+        write!(output, "\x1b[2J\x1b[H")?;
+        for y in 0..height {
+            let row: String = (0..width)
+                .map(|x| self.tilemap.get(Vector2::new(x, y)).unwrap_or(self.tilemap.empty_tile))
+                .collect();
+            write!(output, "{row}\r\n")?;
+        }
+
+        write!(output, "\x1b[7m {} | cursor: {} | size: {} | ^S save, ^Q quit \x1b[0m",
+               self.status, self.cursor, size)?;
+        write!(output, "\x1b[{};{}H", self.cursor.y + 1, self.cursor.x + 1)?;
+        return output.flush();
+    }
+}
+
+impl RawMode {
+    /// # Description
+    /// Switches terminal into the raw mode without echo.
+    ///
+    /// # Return
+    /// * [`Ok`] with guard that restores previous mode.
+    /// * [`Err`] if `stty` could not be executed.
+    fn enable() -> std::io::Result<RawMode> {
+        let saved = std::process::Command::new("stty")
+            .arg("-g")
+            .stdin(std::process::Stdio::inherit())
+            .output()?;
+        let saved = String::from_utf8_lossy(&saved.stdout).trim().to_string();
+
+        std::process::Command::new("stty")
+            .args(["raw", "-echo"])
+            .stdin(std::process::Stdio::inherit())
+            .status()?;
+
+        return Ok(RawMode { saved });
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl Drop for RawMode {
+    /// # Description
+    /// Restores terminal mode that was active before [`RawMode::enable()`].
+    fn drop(&mut self) {
+        let _ = std::process::Command::new("stty")
+            .arg(&self.saved)
+            .stdin(std::process::Stdio::inherit())
+            .status();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Reads a single byte from the input.
+fn read_byte<R: Read>(input: &mut R) -> std::io::Result<Option<u8>> {
+    let mut buffer = [0u8; 1];
+    return match input.read(&mut buffer)? {
+        0 => Ok(None),
+        _ => Ok(Some(buffer[0])),
+    };
+}
+
+/// # Description
+/// Reads and decodes a single key from the raw terminal input.
+///
+/// # Return
+/// * [`Ok`] with [`Some`] key, or [`None`] if input has ended.
+/// * [`Err`] if reading failed.
+pub fn read_key<R: Read>(input: &mut R) -> std::io::Result<Option<Key>> {
+    loop {
+        let byte = match read_byte(input)? {
+            Some(byte) => byte,
+            None => return Ok(None),
+        };
+
+        let key = match byte {
+            0x03 | 0x11 => Some(Key::Quit),
+            0x13 => Some(Key::Save),
+            0x08 | 0x7f => Some(Key::Backspace),
+            b'\r' | b'\n' => Some(Key::Enter),
+            0x1b => read_escape_sequence(input)?,
+            byte if byte < 0x20 => None,
+            byte => read_utf8_char(input, byte)?.map(Key::Char),
+        };
+
+        if key.is_some() {
+            return Ok(key);
+        }
+    }
+}
+
+/// # Description
+/// Decodes escape sequence that follows the escape byte.
+fn read_escape_sequence<R: Read>(input: &mut R) -> std::io::Result<Option<Key>> {
+    if read_byte(input)? != Some(b'[') {
+        return Ok(None);
+    }
+
+    return Ok(match read_byte(input)? {
+        Some(b'A') => Some(Key::Up),
+        Some(b'B') => Some(Key::Down),
+        Some(b'C') => Some(Key::Right),
+        Some(b'D') => Some(Key::Left),
+        Some(b'3') => match read_byte(input)? {
+            Some(b'~') => Some(Key::Delete),
+            _ => None,
+        },
+        _ => None,
+    });
+}
+
+/// # Description
+/// Decodes UTF-8 character which first byte is `first`.
+fn read_utf8_char<R: Read>(input: &mut R, first: u8) -> std::io::Result<Option<char>> {
+    let length = match first {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => return Ok(None),
+    };
+
+    let mut bytes = vec![first];
+    for _ in 1..length {
+        match read_byte(input)? {
+            Some(byte) => bytes.push(byte),
+            None => return Ok(None),
+        }
+    }
+
+    return Ok(std::str::from_utf8(&bytes).ok().and_then(|text| text.chars().next()));
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use char_tilemap::Vector2;
+    use crate::editor::{read_key, Editor, Key};
+
+    #[test]
+    fn read_keys() {
+        let mut input: &[u8] = b"\x1b[A\x1b[B\x1b[C\x1b[D\x1b[3~\x7f\x13\x11\ra\x01\xc3\xa9";
+        let mut keys = Vec::new();
+        while let Some(key) = read_key(&mut input).unwrap() {
+            keys.push(key);
+        }
+
+        assert_eq!(keys, vec![Key::Up, Key::Down, Key::Right, Key::Left, Key::Delete, Key::Backspace,
+                              Key::Save, Key::Quit, Key::Enter, Key::Char('a'), Key::Char('é')]);
+    }
+
+    #[test]
+    fn handle_keys() {
+        let mut editor = Editor::new(PathBuf::from("char_tilemap_editor_missing.tilemap")).unwrap();
+        for key in [Key::Char('a'), Key::Char('b'), Key::Enter, Key::Right, Key::Char('c'),
+                    Key::Up, Key::Backspace, Key::Left, Key::Delete] {
+            editor.handle_key(key);
+        }
+
+        assert_eq!(editor.cursor, Vector2::ZERO);
+        assert_eq!(editor.tilemap.build(), "..\n.c");
+
+        editor.handle_key(Key::Quit);
+        assert!(!editor.running);
+    }
+
+    #[test]
+    fn draw() {
+        let mut editor = Editor::new(PathBuf::from("char_tilemap_editor_missing.tilemap")).unwrap();
+        editor.handle_key(Key::Char('#'));
+
+        let mut output = Vec::new();
+        editor.draw(&mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.starts_with("\x1b[2J\x1b[H#.\r\n"));
+        assert!(output.ends_with("\x1b[1;2H"));
+    }
+}
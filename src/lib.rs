@@ -7,8 +7,19 @@
 //!
 //! Check documentation of public objects to understand more:\
 //! * [`Tilemap`]
+//! * [`Tilemap3`]
 //! * [`Tile`]
+//! * [`TokenTilemap`]
 //! * [`Vector2`]
+//! * [`World`]
+//! * [`commands`]
+//! * [`minigames`] (with the `minigames` feature)
+#![allow(clippy::needless_return)]
+#![cfg_attr(test, allow(clippy::assertions_on_constants))]
+
+pub mod commands;
+#[cfg(feature = "minigames")]
+pub mod minigames;
 mod tilemap;
 
 pub use tilemap::*;
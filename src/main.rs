@@ -1,19 +1,70 @@
-fn add_tile_to_tilemap(tilemap: &mut char_tilemap::Tilemap, position: char_tilemap::Vector2, value: char) {
-    match tilemap.add_tile(position, value) {
-        Ok(_) => (),
-        Err(msg) => println!("{msg}")
+#![allow(clippy::needless_return)]
+#![cfg_attr(test, allow(clippy::assertions_on_constants))]
+
+mod cli;
+mod editor;
+mod repl;
+mod replay;
+mod watch;
+
+use clap::Parser;
+
+/// # Description
+/// File that is edited when no file is specified.
+const DEFAULT_FILE: &str = "untitled.tilemap";
+
+fn main() {
+    let cli = cli::Cli::parse();
+
+    let result = match cli.command {
+        None => edit(cli.file),
+        Some(cli::Command::Edit { file }) => edit(file),
+        Some(cli::Command::Repl { file }) => repl(file),
+        Some(cli::Command::Replay { journal, map, empty }) => replay(journal, map, empty),
+        Some(cli::Command::Watch { file, script, empty, interval }) => {
+            let mut watcher = watch::Watcher::new(file, script, empty);
+            watcher.run(&mut std::io::stdout().lock(), std::time::Duration::from_millis(interval))
+                .map_err(|error| error.to_string())
+        },
+        Some(command) => cli::run(command).map(|text| {
+            if !text.is_empty() {
+                println!("{text}");
+            }
+        }),
+    };
+
+    if let Err(error) = result {
+        eprintln!("{error}");
+        std::process::exit(1);
     }
 }
 
-fn main() {
-    let mut tilemap = char_tilemap::Tilemap::new('-');
+/// # Description
+/// Runs interactive editor for the specified file, or for the [`DEFAULT_FILE`] if none is given.
+fn edit(file: Option<std::path::PathBuf>) -> Result<(), String> {
+    let path = file.unwrap_or(std::path::PathBuf::from(DEFAULT_FILE));
+    let mut editor = editor::Editor::new(path)?;
+    return editor.run().map_err(|error| error.to_string());
+}
+
+/// # Description
+/// Runs interactive REPL on the standard input and output.
+fn repl(file: Option<std::path::PathBuf>) -> Result<(), String> {
+    let mut repl = repl::Repl::new(file)?;
+    return repl.run(&mut std::io::stdin().lock(), &mut std::io::stdout().lock()).map_err(|error| error.to_string());
+}
 
-    add_tile_to_tilemap(&mut tilemap, char_tilemap::Vector2::new(0, 0), 'O');
-    add_tile_to_tilemap(&mut tilemap, char_tilemap::Vector2::new(3, 0), 'A');
-    add_tile_to_tilemap(&mut tilemap, char_tilemap::Vector2::new(5, 2), 'X');
-    add_tile_to_tilemap(&mut tilemap, char_tilemap::Vector2::new(2, 1), 'U');
-    add_tile_to_tilemap(&mut tilemap, char_tilemap::Vector2::new(3, 5), 'V');
-    add_tile_to_tilemap(&mut tilemap, char_tilemap::Vector2::new(1, 4), 'H');
+/// # Description
+/// Runs interactive replay of the journal on the standard input and output. Replay starts from
+/// the tilemap file if it is specified, otherwise from a new tilemap.
+fn replay(journal: std::path::PathBuf, map: Option<std::path::PathBuf>, empty: char) -> Result<(), String> {
+    let text = std::fs::read_to_string(&journal)
+        .map_err(|error| format!("Failed to read {}: {error}", journal.display()))?;
+    let tilemap = match map {
+        Some(path) => char_tilemap::Tilemap::load_from_file(path)?,
+        None => char_tilemap::Tilemap::new(empty),
+    };
 
-    println!("{}", tilemap.build());
+    let mut replay = replay::Replay::new(tilemap, char_tilemap::Journal::from_json_lines(&text)?);
+    return replay.run(&mut std::io::stdin().lock(), &mut std::io::stdout().lock()).map_err(|error| error.to_string());
 }
@@ -0,0 +1,425 @@
+//! # Minigames
+//!
+//! Small terminal games built entirely on the crate API. They show how movement, collisions,
+//! random placement and incremental rendering fit together, and can be played with the
+//! `snake` example: `cargo run --example snake --features minigames`.
+use std::collections::VecDeque;
+use std::io::Write;
+use std::time::{Duration, Instant};
+use crossterm::{cursor, event, execute, queue, terminal};
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use crate::{Direction, Rng, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Value of wall tiles around the field.
+pub const WALL: char = '#';
+/// # Description
+/// Value of the snake head.
+pub const HEAD: char = '@';
+/// # Description
+/// Value of the snake body.
+pub const BODY: char = 'o';
+/// # Description
+/// Value of the food.
+pub const FOOD: char = '*';
+
+/// # Description
+/// Minimal size of the [`Snake`] field including walls.
+const MIN_SIZE: Vector2 = Vector2 { x: 6, y: 5 };
+/// # Description
+/// Initial length of the snake.
+const INITIAL_LENGTH: usize = 3;
+/// # Description
+/// Duration of a single step of [`play_snake()`].
+const STEP_DURATION: Duration = Duration::from_millis(150);
+
+/// # Description
+/// Result of a single step of the [`Snake`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum SnakeStatus {
+    /// # Description
+    /// Snake moved to an empty tile.
+    Moved,
+    /// # Description
+    /// Snake ate the food and grew.
+    Ate,
+    /// # Description
+    /// Snake hit a wall or itself. Game is over.
+    Crashed,
+    /// # Description
+    /// Snake fills the whole field. Game is over.
+    Won,
+}
+
+/// # Description
+/// Game of snake, where all state is kept in the [`Tilemap`]: collisions are found by values of
+/// tiles, and the field is rendered by building the [`Tilemap`].
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::Direction;
+/// use char_tilemap::minigames::{Snake, SnakeStatus};
+///
+/// let mut snake = Snake::new(char_tilemap::Vector2::new(10, 7), 42).unwrap();
+/// snake.turn(Direction::Up);
+/// assert_ne!(snake.step(), SnakeStatus::Crashed);
+/// assert_eq!(snake.tilemap().size(), char_tilemap::Vector2::new(10, 7));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Snake {
+    /// # Description
+    /// Field with walls, snake and food.
+    tilemap: Tilemap,
+    /// # Description
+    /// Positions of the snake from the head to the tail.
+    body: VecDeque<Vector2>,
+    /// # Description
+    /// Direction of the last step.
+    direction: Direction,
+    /// # Description
+    /// Direction of the next step.
+    next_direction: Direction,
+    /// # Description
+    /// Generator of food positions.
+    rng: Rng,
+    /// # Description
+    /// Number of eaten food.
+    score: usize,
+    /// # Description
+    /// Status of the last step, if game is over.
+    finished: Option<SnakeStatus>,
+}
+
+/// # Description
+/// Guard that switches terminal into the raw mode and the alternate screen, and restores it
+/// when dropped.
+struct TerminalGuard;
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Snake {
+    /// # Description
+    /// Creates new game with the field surrounded by walls. Snake starts in the middle of the
+    /// field and moves right.
+    ///
+    /// # Arguments
+    /// * `size: Vector2` - Size of the field including walls. Should be at least 6x5.
+    /// * `seed: u64` - Seed of food positions. The same seed and moves produce the same game.
+    ///
+    /// # Return
+    /// * [`Ok`] with new [`Snake`].
+    /// * [`Err`] if field is too small. Contains error message.
+    pub fn new(size: Vector2, seed: u64) -> Result<Snake, String> {
+        if size.x < MIN_SIZE.x || size.y < MIN_SIZE.y {
+            return Err(format!("Field {size} is smaller than {MIN_SIZE}"));
+        }
+
+        let mut tilemap = Tilemap::new(' ');
+        for x in 0..size.x {
+            tilemap.set(Vector2::new(x, 0), WALL);
+            tilemap.set(Vector2::new(x, size.y - 1), WALL);
+        }
+        for y in 1..size.y - 1 {
+            tilemap.set(Vector2::new(0, y), WALL);
+            tilemap.set(Vector2::new(size.x - 1, y), WALL);
+        }
+
+        let head = Vector2::new(size.x / 2, size.y / 2);
+        let body: VecDeque<Vector2> = (0..INITIAL_LENGTH).map(|offset| Vector2::new(head.x - offset, head.y)).collect();
+        for position in body.iter().skip(1) {
+            tilemap.set(*position, BODY);
+        }
+        tilemap.set(head, HEAD);
+
+        let mut snake = Snake {
+            tilemap,
+            body,
+            direction: Direction::Right,
+            next_direction: Direction::Right,
+            rng: Rng::new(seed),
+            score: 0,
+            finished: None,
+        };
+        snake.place_food();
+        return Ok(snake);
+    }
+
+    /// # Description
+    /// Returns field with walls, snake and food.
+    pub fn tilemap(&self) -> &Tilemap {
+        return &self.tilemap;
+    }
+
+    /// # Description
+    /// Returns number of eaten food.
+    pub fn score(&self) -> usize {
+        return self.score;
+    }
+
+    /// # Description
+    /// Checks if snake crashed or filled the whole field.
+    pub fn is_over(&self) -> bool {
+        return self.finished.is_some();
+    }
+
+    /// # Description
+    /// Sets direction of the next step. Turning back into the body is ignored.
+    ///
+    /// # Arguments
+    /// * `direction: Direction` - Direction of the next step.
+    pub fn turn(&mut self, direction: Direction) {
+        if direction != self.direction.opposite() {
+            self.next_direction = direction;
+        }
+    }
+
+    /// # Description
+    /// Moves snake by a single tile.
+    ///
+    /// # Return
+    /// Status of the step. If game is already over, the final status is returned and nothing
+    /// is moved.
+    pub fn step(&mut self) -> SnakeStatus {
+        if let Some(status) = self.finished {
+            return status;
+        }
+
+        self.direction = self.next_direction;
+        let head = self.body[0];
+        let Some(next) = self.direction.step(head) else {
+            return self.finish(SnakeStatus::Crashed);
+        };
+
+        let ate = self.tilemap.get(next) == Some(FOOD);
+        let tail = *self.body.back().expect("Snake is never empty");
+        let blocked = match self.tilemap.get(next) {
+            Some(WALL) => true,
+            Some(BODY) => next != tail,
+            _ => false,
+        };
+        if blocked {
+            return self.finish(SnakeStatus::Crashed);
+        }
+
+        if !ate {
+            self.body.pop_back();
+            self.tilemap.remove_tile(tail).expect("Tail is always on the field");
+        }
+        self.tilemap.set(head, BODY);
+        self.tilemap.set(next, HEAD);
+        self.body.push_front(next);
+
+        if !ate {
+            return SnakeStatus::Moved;
+        }
+        self.score += 1;
+        return match self.place_food() {
+            true => SnakeStatus::Ate,
+            false => self.finish(SnakeStatus::Won),
+        };
+    }
+
+    /// # Description
+    /// Places food at a random free tile.
+    ///
+    /// # Return
+    /// `true` if food was placed, `false` if there are no free tiles.
+    fn place_food(&mut self) -> bool {
+        let size = self.tilemap.size();
+        let free: Vec<Vector2> = (1..size.y - 1)
+            .flat_map(|y| (1..size.x - 1).map(move |x| Vector2::new(x, y)))
+            .filter(|position| self.tilemap.get(*position).is_none())
+            .collect();
+        if free.is_empty() {
+            return false;
+        }
+
+        let food = free[self.rng.next_below(free.len())];
+        self.tilemap.set(food, FOOD);
+        return true;
+    }
+
+    /// # Description
+    /// Ends the game with the status.
+    fn finish(&mut self, status: SnakeStatus) -> SnakeStatus {
+        self.finished = Some(status);
+        return status;
+    }
+}
+
+impl TerminalGuard {
+    /// # Description
+    /// Switches terminal into the raw mode and the alternate screen, and hides cursor.
+    fn enable() -> std::io::Result<TerminalGuard> {
+        terminal::enable_raw_mode()?;
+        execute!(std::io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        return Ok(TerminalGuard);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl Drop for TerminalGuard {
+    /// # Description
+    /// Restores the terminal. Errors are ignored, since there is no way to report them.
+    fn drop(&mut self) {
+        let _ = execute!(std::io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Functions
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Plays [`Snake`] in the terminal until the game is over or user quits. Arrows or WASD turn
+/// the snake, Q or Esc quits. Only rows that were changed are redrawn, they are found by
+/// [`Tilemap::row_hashes()`].
+///
+/// # Arguments
+/// * `size: Vector2` - Size of the field including walls.
+/// * `seed: u64` - Seed of food positions.
+///
+/// # Return
+/// * [`Ok`] with the final score.
+/// * [`Err`] if field is too small, or terminal input or output failed.
+pub fn play_snake(size: Vector2, seed: u64) -> std::io::Result<usize> {
+    let mut snake = Snake::new(size, seed).map_err(std::io::Error::other)?;
+    let _guard = TerminalGuard::enable()?;
+    let mut output = std::io::stdout().lock();
+    let mut drawn: Vec<u64> = Vec::new();
+
+    loop {
+        let hashes = snake.tilemap.row_hashes();
+        for (y, hash) in hashes.iter().enumerate() {
+            if drawn.get(y) != Some(hash) {
+                let row = snake.tilemap.row(y).unwrap_or_default();
+                queue!(output, cursor::MoveTo(0, y as u16))?;
+                write!(output, "{row}")?;
+            }
+        }
+        queue!(output, cursor::MoveTo(0, hashes.len() as u16), terminal::Clear(terminal::ClearType::CurrentLine))?;
+        write!(output, "Score: {}", snake.score)?;
+        output.flush()?;
+        drawn = hashes;
+
+        if snake.is_over() {
+            break;
+        }
+
+        let deadline = Instant::now() + STEP_DURATION;
+        while let Some(timeout) = deadline.checked_duration_since(Instant::now()) {
+            if !event::poll(timeout)? {
+                break;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            match key.code {
+                KeyCode::Up | KeyCode::Char('w') => snake.turn(Direction::Up),
+                KeyCode::Right | KeyCode::Char('d') => snake.turn(Direction::Right),
+                KeyCode::Down | KeyCode::Char('s') => snake.turn(Direction::Down),
+                KeyCode::Left | KeyCode::Char('a') => snake.turn(Direction::Left),
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(snake.score),
+                _ => (),
+            }
+        }
+        snake.step();
+    }
+
+    std::thread::sleep(Duration::from_secs(1));
+    return Ok(snake.score);
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, Vector2};
+    use super::{Snake, SnakeStatus, BODY, FOOD, HEAD};
+
+    #[test]
+    fn new() {
+        let snake = Snake::new(Vector2::new(8, 5), 1).unwrap();
+        let field = snake.tilemap().build();
+
+        assert_eq!(field.matches(HEAD).count(), 1);
+        assert_eq!(field.matches(BODY).count(), 2);
+        assert_eq!(field.matches(FOOD).count(), 1);
+        assert_eq!(snake.tilemap().get(Vector2::new(4, 2)), Some(HEAD));
+        match Snake::new(Vector2::new(5, 5), 1) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true),
+        }
+    }
+
+    #[test]
+    fn crash() {
+        let mut snake = Snake::new(Vector2::new(8, 5), 1).unwrap();
+        snake.turn(Direction::Left);
+        snake.turn(Direction::Up);
+
+        let mut status = SnakeStatus::Moved;
+        for _ in 0..3 {
+            status = snake.step();
+        }
+        assert_eq!(status, SnakeStatus::Crashed);
+        assert!(snake.is_over());
+        assert_eq!(snake.step(), SnakeStatus::Crashed);
+    }
+
+    #[test]
+    fn same_seed_same_game() {
+        let play = |seed| {
+            let mut snake = Snake::new(Vector2::new(12, 8), seed).unwrap();
+            let turns = [Direction::Up, Direction::Left, Direction::Down, Direction::Right];
+            for step in 0..40 {
+                snake.turn(turns[(step / 3) % 4]);
+                snake.step();
+            }
+            return snake.tilemap().build();
+        };
+
+        assert_eq!(play(7), play(7));
+    }
+
+    #[test]
+    fn eat_and_win() {
+        // Cycle through all free tiles of the field, following it always fills the field
+        let cycle = [(1, 1), (1, 2), (1, 3), (2, 3), (2, 2), (3, 2), (3, 3), (4, 3), (4, 2), (4, 1), (3, 1), (2, 1)];
+        let next = |position: Vector2| {
+            let index = cycle.iter().position(|&(x, y)| Vector2::new(x, y) == position).unwrap();
+            let (x, y) = cycle[(index + 1) % cycle.len()];
+            return Vector2::new(x, y);
+        };
+
+        let mut snake = Snake::new(Vector2::new(6, 5), 3).unwrap();
+        let mut statuses = Vec::new();
+        while !snake.is_over() && statuses.len() < 500 {
+            let head = snake.body[0];
+            let direction = Direction::ALL.into_iter().find(|direction| direction.step(head) == Some(next(head))).unwrap();
+            snake.turn(direction);
+            statuses.push(snake.step());
+        }
+
+        assert_eq!(statuses.last(), Some(&SnakeStatus::Won));
+        assert_eq!(statuses.iter().filter(|status| **status == SnakeStatus::Ate).count(), 8);
+        assert_eq!(snake.score(), 9);
+        assert_eq!(snake.tilemap().build().matches(FOOD).count(), 0);
+    }
+}
@@ -0,0 +1,280 @@
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use char_tilemap::{commands, Overlay, Tilemap, TileSource, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Value of the empty tile that is used for new tilemaps created by the REPL.
+const DEFAULT_EMPTY_TILE: char = '.';
+
+/// # Description
+/// Value that marks positions of the found path.
+const PATH_TILE: char = '*';
+
+/// # Description
+/// Help message of the REPL.
+const HELP: &str = "\
+Queries:
+  get <x> <y>                         Print value of the tile
+  count <char>                        Print number of tiles with the value
+  path <x1> <y1> <x2> <y2> [chars]    Show shortest path through empty tiles and tiles with chars
+  size                                Print size of the tilemap
+  show                                Print the tilemap
+Mutations (tilemap is printed after each one):
+  add <x> <y> <char>, set <x> <y> <char>, remove <x> <y>, rect <x> <y> <width> <height> <char>
+Session:
+  load <file>, save [file]            Load or save the tilemap
+  history                             Print entered commands
+  !<number>                           Repeat command from the history
+  help, quit";
+
+/// # Description
+/// Interactive read-eval-print loop that runs queries and mutations on a [`Tilemap`].
+pub struct Repl {
+    /// # Description
+    /// Explored tilemap.
+    tilemap: Tilemap,
+    /// # Description
+    /// File from which tilemap was loaded or to which it was saved last time.
+    path: Option<PathBuf>,
+    /// # Description
+    /// Entered commands in order.
+    history: Vec<String>,
+    /// # Description
+    /// Is REPL still running.
+    running: bool,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Repl {
+    /// # Description
+    /// Creates new [`Repl`]. Tilemap is loaded from the file if it is specified, otherwise new
+    /// tilemap is created.
+    ///
+    /// # Arguments
+    /// * `path: Option<PathBuf>` - Path to the explored file.
+    ///
+    /// # Return
+    /// * [`Ok`] with new [`Repl`].
+    /// * [`Err`] if file could not be loaded. Contains error message.
+    pub fn new(path: Option<PathBuf>) -> Result<Repl, String> {
+        let tilemap = match &path {
+            Some(path) => Tilemap::load_from_file(path)?,
+            None => Tilemap::new(DEFAULT_EMPTY_TILE),
+        };
+
+        return Ok(Repl { tilemap, path, history: Vec::new(), running: true });
+    }
+
+    /// # Description
+    /// Reads commands from the input line by line and writes their results to the output until
+    /// user quits or input ends.
+    ///
+    /// # Arguments
+    /// * `input: &mut R` - Input from which commands are read.
+    /// * `output: &mut W` - Output to which results are written.
+    ///
+    /// # Return
+    /// * [`Ok`] when user quits or input ends.
+    /// * [`Err`] if reading the input or writing the output failed.
+    pub fn run<R: BufRead, W: Write>(&mut self, input: &mut R, output: &mut W) -> std::io::Result<()> {
+        writeln!(output, "{}\n", self.tilemap.build())?;
+
+        let mut line = String::new();
+        while self.running {
+            write!(output, "> ")?;
+            output.flush()?;
+
+            line.clear();
+            if input.read_line(&mut line)? == 0 {
+                break;
+            }
+
+            match self.execute(line.trim()) {
+                Ok(text) if text.is_empty() => (),
+                Ok(text) => writeln!(output, "{text}")?,
+                Err(error) => writeln!(output, "Error: {error}")?,
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// # Description
+    /// Executes single command and records it in the history.
+    ///
+    /// # Arguments
+    /// * `line: &str` - Entered command.
+    ///
+    /// # Return
+    /// * [`Ok`] with text that should be shown to the user.
+    /// * [`Err`] if command failed. Contains error message.
+    pub fn execute(&mut self, line: &str) -> Result<String, String> {
+        let line = match line.strip_prefix('!') {
+            Some(number) => {
+                let index = number.parse::<usize>().map_err(|_| format!("Invalid history number \"{number}\""))?;
+                match index.checked_sub(1).and_then(|index| self.history.get(index)) {
+                    Some(line) => line.clone(),
+                    None => return Err(format!("There is no command {index} in the history")),
+                }
+            },
+            None => line.to_string(),
+        };
+
+        if line.is_empty() {
+            return Ok(String::new());
+        }
+
+        self.history.push(line.clone());
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        return match (words[0], &words[1..]) {
+            ("get", [x, y]) => {
+                let position = Vector2::new(parse_number(x)?, parse_number(y)?);
+                Ok(match self.tilemap.get(position) {
+                    Some(value) => value.to_string(),
+                    None => String::from("empty"),
+                })
+            },
+            ("count", [value]) => {
+                let value = parse_char(value)?;
+                let size = self.tilemap.size();
+                let count = (0..size.y)
+                    .flat_map(|y| (0..size.x).map(move |x| Vector2::new(x, y)))
+                    .filter(|position| self.tilemap.get(*position) == Some(value))
+                    .count();
+                Ok(count.to_string())
+            },
+            ("path", [x1, y1, x2, y2, walkable @ ..]) if walkable.len() <= 1 => {
+                let from = Vector2::new(parse_number(x1)?, parse_number(y1)?);
+                let to = Vector2::new(parse_number(x2)?, parse_number(y2)?);
+                let walkable: Vec<char> = walkable.iter().flat_map(|chars| chars.chars()).collect();
+                let path = self.tilemap.find_path(from, to, |value| match value {
+                    Some(value) => walkable.contains(&value),
+                    None => true,
+                });
+
+                Ok(match path {
+                    Some(path) => {
+                        let mut overlay = Overlay::new(&self.tilemap);
+                        overlay.add_path(&path, PATH_TILE);
+                        format!("{}\nLength: {}", overlay.build(), path.len() - 1)
+                    },
+                    None => String::from("No path"),
+                })
+            },
+            ("size", []) => Ok(self.tilemap.size().to_string()),
+            ("show", []) | ("build", []) => Ok(self.tilemap.build()),
+            ("load", [file]) => {
+                self.tilemap = Tilemap::load_from_file(file)?;
+                self.path = Some(PathBuf::from(file));
+                Ok(self.tilemap.build())
+            },
+            ("save", args) if args.len() <= 1 => {
+                if let Some(file) = args.first() {
+                    self.path = Some(PathBuf::from(file));
+                }
+                let Some(path) = &self.path else {
+                    return Err(String::from("File is not specified"));
+                };
+                self.tilemap.save_to_file(path)?;
+                Ok(format!("Saved {}", path.display()))
+            },
+            ("history", []) => {
+                let lines: Vec<String> = self.history.iter().enumerate()
+                    .map(|(index, line)| format!("{:>4}  {line}", index + 1))
+                    .collect();
+                Ok(lines.join("\n"))
+            },
+            ("help", []) => Ok(HELP.to_string()),
+            ("quit", []) | ("exit", []) => {
+                self.running = false;
+                Ok(String::new())
+            },
+            _ => {
+                commands::execute(&mut self.tilemap, &line)?;
+                Ok(self.tilemap.build())
+            },
+        };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Parses argument that should contain a coordinate.
+fn parse_number(value: &str) -> Result<usize, String> {
+    return value.parse().map_err(|_| format!("Invalid number \"{value}\""));
+}
+
+/// # Description
+/// Parses argument that should contain exactly one char.
+fn parse_char(value: &str) -> Result<char, String> {
+    let mut chars = value.chars();
+    return match (chars.next(), chars.next()) {
+        (Some(value), None) => Ok(value),
+        _ => Err(format!("Expected single char, but got \"{value}\"")),
+    };
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::repl::Repl;
+
+    #[test]
+    fn queries_and_mutations() {
+        let mut repl = Repl::new(None).unwrap();
+
+        assert_eq!(repl.execute("rect 0 0 3 3 #").unwrap(), "###\n###\n###");
+        assert_eq!(repl.execute("remove 1 1").unwrap(), "###\n#.#\n###");
+        assert_eq!(repl.execute("get 1 1").unwrap(), "empty");
+        assert_eq!(repl.execute("get 0 0").unwrap(), "#");
+        assert_eq!(repl.execute("count #").unwrap(), "8");
+        assert_eq!(repl.execute("size").unwrap(), "{ x: 3, y: 3 }");
+
+        assert_eq!(repl.execute("path 0 0 2 0").unwrap(), "No path");
+        assert_eq!(repl.execute("path 0 0 2 0 #").unwrap(), "***\n#.#\n###\nLength: 2");
+
+        match repl.execute("unknown") {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+
+    #[test]
+    fn history() {
+        let mut repl = Repl::new(None).unwrap();
+
+        repl.execute("set 0 0 A").unwrap();
+        repl.execute("count A").unwrap();
+        assert_eq!(repl.execute("!2").unwrap(), "1");
+        assert_eq!(repl.execute("history").unwrap(), "   1  set 0 0 A\n   2  count A\n   3  count A\n   4  history");
+
+        match repl.execute("!10") {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+
+    #[test]
+    fn run() {
+        let mut repl = Repl::new(None).unwrap();
+        let mut input = "set 1 0 X\nget 1 0\nquit\nget 0 0\n".as_bytes();
+        let mut output = Vec::new();
+
+        repl.run(&mut input, &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "\n\n> .X\n> X\n> ");
+    }
+}
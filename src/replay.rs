@@ -0,0 +1,219 @@
+use std::io::{BufRead, Write};
+use char_tilemap::{Journal, JournalEntry, Tilemap};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Help message of the journal replay.
+const HELP: &str = "\
+  next, n, <enter>     Apply the next entry
+  prev, p              Undo the last applied entry
+  goto <sequence>      Show the state after the entry with the sequence number
+  first, last          Show the starting or the final state
+  help, quit";
+
+/// # Description
+/// Interactive stepper through a [`Journal`] that renders the tilemap after every step.
+pub struct Replay {
+    /// # Description
+    /// Tilemap in the current state.
+    tilemap: Tilemap,
+    /// # Description
+    /// Replayed journal.
+    journal: Journal,
+    /// # Description
+    /// Number of applied entries.
+    applied: usize,
+    /// # Description
+    /// Is replay still running.
+    running: bool,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Replay {
+    /// # Description
+    /// Creates new [`Replay`] that starts from the specified tilemap without applied entries.
+    ///
+    /// # Arguments
+    /// * `tilemap: Tilemap` - State of the tilemap when the journal was started.
+    /// * `journal: Journal` - Replayed journal.
+    ///
+    /// # Return
+    /// New instance of the [`Replay`].
+    pub fn new(tilemap: Tilemap, journal: Journal) -> Replay {
+        return Replay { tilemap, journal, applied: 0, running: true };
+    }
+
+    /// # Description
+    /// Reads commands from the input line by line and writes the current state to the output
+    /// after each of them, until user quits or input ends.
+    ///
+    /// # Arguments
+    /// * `input: &mut R` - Input from which commands are read.
+    /// * `output: &mut W` - Output to which states are written.
+    ///
+    /// # Return
+    /// * [`Ok`] when user quits or input ends.
+    /// * [`Err`] if reading the input or writing the output failed.
+    pub fn run<R: BufRead, W: Write>(&mut self, input: &mut R, output: &mut W) -> std::io::Result<()> {
+        writeln!(output, "{}", self.render())?;
+
+        let mut line = String::new();
+        while self.running {
+            write!(output, "> ")?;
+            output.flush()?;
+
+            line.clear();
+            if input.read_line(&mut line)? == 0 {
+                break;
+            }
+
+            match self.execute(line.trim()) {
+                Ok(text) if text.is_empty() => (),
+                Ok(text) => writeln!(output, "{text}")?,
+                Err(error) => writeln!(output, "Error: {error}")?,
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// # Description
+    /// Executes single command.
+    ///
+    /// # Arguments
+    /// * `line: &str` - Entered command.
+    ///
+    /// # Return
+    /// * [`Ok`] with text that should be shown to the user.
+    /// * [`Err`] if command failed. Contains error message.
+    pub fn execute(&mut self, line: &str) -> Result<String, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match words.as_slice() {
+            [] | ["next"] | ["n"] => {
+                if self.applied == self.journal.entries().len() {
+                    return Err(String::from("There are no more entries"));
+                }
+                self.step_to(self.applied + 1);
+            },
+            ["prev"] | ["p"] => {
+                if self.applied == 0 {
+                    return Err(String::from("There are no applied entries"));
+                }
+                self.step_to(self.applied - 1);
+            },
+            ["goto", sequence] => {
+                let sequence: u64 = sequence.parse().map_err(|_| format!("Invalid sequence number \"{sequence}\""))?;
+                let Some(index) = self.journal.entries().iter().position(|entry| entry.sequence == sequence) else {
+                    return Err(format!("There is no entry {sequence}"));
+                };
+                self.step_to(index + 1);
+            },
+            ["first"] => self.step_to(0),
+            ["last"] => self.step_to(self.journal.entries().len()),
+            ["help"] => return Ok(HELP.to_string()),
+            ["quit"] | ["exit"] | ["q"] => {
+                self.running = false;
+                return Ok(String::new());
+            },
+            _ => return Err(format!("Unknown command \"{line}\", see \"help\"")),
+        }
+
+        return Ok(self.render());
+    }
+
+    /// # Description
+    /// Applies or undoes entries until the specified number of them is applied.
+    fn step_to(&mut self, applied: usize) {
+        let entries = self.journal.entries();
+        let changes = if applied >= self.applied {
+            entries[self.applied..applied].iter().map(|entry| entry.change).collect::<Vec<_>>()
+        } else {
+            entries[applied..self.applied].iter().rev().map(|entry| entry.change.inverted()).collect()
+        };
+
+        self.tilemap.apply_changes(&changes);
+        self.applied = applied;
+    }
+
+    /// # Description
+    /// Renders status line with the last applied entry, followed by the tilemap.
+    fn render(&self) -> String {
+        let total = self.journal.entries().len();
+        let status = match self.applied.checked_sub(1).map(|index| &self.journal.entries()[index]) {
+            Some(entry) => format!("Step {}/{total}: {}", self.applied, describe(entry)),
+            None => format!("Step 0/{total}: start"),
+        };
+
+        return format!("{status}\n{}", self.tilemap.build());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Describes entry as `#<sequence> <x> <y> <old> -> <new>`, where missing values are `empty`.
+fn describe(entry: &JournalEntry) -> String {
+    let value = |value: Option<char>| value.map(String::from).unwrap_or(String::from("empty"));
+    let change = entry.change;
+    return format!("#{} {} {} {} -> {}", entry.sequence, change.position.x, change.position.y,
+                   value(change.old), value(change.new));
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use char_tilemap::{Tilemap, Vector2};
+    use crate::replay::Replay;
+
+    fn build_test_replay() -> Replay {
+        let mut tilemap = Tilemap::from_string("...", '.');
+        let start = tilemap.clone();
+        tilemap.start_journal();
+        tilemap.set(Vector2::ZERO, 'a');
+        tilemap.set(Vector2::new(1, 0), 'b');
+        tilemap.set(Vector2::ZERO, 'c');
+
+        return Replay::new(start, tilemap.stop_journal().unwrap());
+    }
+
+    #[test]
+    fn steps() {
+        let mut replay = build_test_replay();
+
+        assert_eq!(replay.execute("").unwrap(), "Step 1/3: #0 0 0 empty -> a\na..");
+        assert_eq!(replay.execute("n").unwrap(), "Step 2/3: #1 1 0 empty -> b\nab.");
+        assert_eq!(replay.execute("last").unwrap(), "Step 3/3: #2 0 0 a -> c\ncb.");
+        assert_eq!(replay.execute("prev").unwrap(), "Step 2/3: #1 1 0 empty -> b\nab.");
+        assert_eq!(replay.execute("goto 0").unwrap(), "Step 1/3: #0 0 0 empty -> a\na..");
+        assert_eq!(replay.execute("first").unwrap(), "Step 0/3: start\n...");
+
+        for line in ["prev", "goto 3", "goto x", "jump"] {
+            match replay.execute(line) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+
+    #[test]
+    fn run() {
+        let mut replay = build_test_replay();
+        let mut input = "next\nquit\nnext\n".as_bytes();
+        let mut output = Vec::new();
+
+        replay.run(&mut input, &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "Step 0/3: start\n...\n> Step 1/3: #0 0 0 empty -> a\na..\n> ");
+    }
+}
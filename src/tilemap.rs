@@ -1,8 +1,158 @@
+pub use animation::{Animation, AnimationFrame, AnimationStates};
+pub use automaton::LifeRule;
+pub use blink::Blink;
+pub use build_order::BuildOrder;
+pub use camera::Camera;
+pub use chunk_streaming::{ChunkProvider, ChunkedTilemap};
+pub use chunk_views::{ChunkView, ChunksMut};
+pub use color::Color;
 pub use common_types::Vector2;
+pub use constraint_placement::{PlacementPredicate, PlacementRule};
+pub use control_char_policy::{ControlCharPolicy, DEFAULT_CONTROL_CHAR_PLACEHOLDER};
+pub use dependencies::{DependencyId, Derived};
+pub use difference_map::DifferenceChars;
+pub use direction::Direction;
+pub use distance_map::DistanceMap;
+pub use downsampled_renderer::{BrailleRenderer, QuadrantRenderer};
+pub use effects::{EffectLayer, Particle};
+pub use entity_layer::{EntityId, EntityLayer};
+pub use file_parser::FileParser;
+pub use heatmap::DEFAULT_HEATMAP_RAMP;
+pub use hierarchical_pathfinding::HierarchicalPathfinder;
+pub use journal::{Journal, JournalEntry};
+pub use limits::TilemapLimits;
+pub use lint::{LintFinding, LintRule, MapRule};
+pub use map_cache::MapCache;
+pub use map_report::MapReport;
+pub use metadata::MapMetadata;
+pub use migration::FILE_FORMAT_VERSION;
+pub use nav_graph::NavGraph;
+pub use orientation::Orientation;
+pub use overlay::Overlay;
+pub use path_drawing::PathChars;
+pub use pipeline::{Pass, Pipeline};
+pub use prefab::Prefab;
+pub use renderer::{AnsiRenderer, HtmlRenderer, PlainRenderer, Renderer};
+pub use quadtree::QuadtreeTilemap;
+pub use resize_policy::{Anchor, OverflowPolicy, ResizePolicy};
+pub use rng::Rng;
+pub use row_runs::RowRuns;
+pub use scheduler::{Scheduler, ACTION_COST};
+pub use selection::Selection;
+pub use sync_session::{ChangeBatch, SyncSession};
+pub use symmetry::SymmetryAxis;
+pub use text_block::TextAlignment;
+pub use theme::Theme;
 pub use tile::Tile;
+pub use tile_change::TileChange;
+pub use tile_error::TileError;
+pub use tile_group::TileGroup;
+pub use tile_iter::{OrderedTiles, UnorderedTiles};
+pub use tile_op::TileOp;
+pub use tile_source::{BoxedTileSource, TileSource};
+pub use tile_source_adapters::{FlipAxis, Flipped, Mapped, Offset, Rotated, Rotation};
+pub use tilemap3::{Tilemap3, TopDown};
+pub use tilemap_builder::TilemapBuilder;
+pub use tilemap_view::{TilemapView, TilemapViewMut};
+pub use token_tilemap::TokenTilemap;
+pub use turmite::{Turmite, TurmiteRule, Turn};
+pub use world::{Link, LinkKind, Location, World};
 
+mod animation;
+mod annotations;
+#[cfg(any(feature = "ndarray", feature = "grid"))]
+mod array_conversions;
+#[cfg(feature = "tokio")]
+mod async_file;
+mod automaton;
+mod blink;
+mod bookmarks;
+mod build_cache;
+mod build_order;
+mod camera;
+mod cell_width;
+mod checksum;
+mod chunk_streaming;
+mod chunk_views;
+mod clusters;
+mod color;
 mod common_types;
+mod constraint_placement;
+mod control_char_policy;
+mod decay;
+mod dependencies;
+mod difference_map;
+mod direction;
+mod distance_map;
+mod downsampled_renderer;
+mod effects;
+mod entity_layer;
+mod file_format;
+mod file_parser;
+mod generators;
+mod heatmap;
+mod hierarchical_pathfinding;
+mod journal;
+mod level_analysis;
+mod limits;
+mod lint;
+mod map_cache;
+mod map_report;
+mod metadata;
+mod migration;
+mod morph;
+mod nav_graph;
+mod orientation;
+mod output_index;
+mod overlay;
+mod path_drawing;
+mod pipeline;
+mod pathfinding;
+mod placement;
+mod prefab;
+mod push;
+mod quadtree;
+mod random_placement;
+mod renderer;
+mod resize_policy;
+mod rng;
+mod row_hashes;
+mod row_runs;
+mod rows;
+mod scheduler;
+#[cfg(feature = "rhai")]
+mod scripting;
+mod selection;
+mod settle;
+mod similarity;
+mod spatial_index;
+mod state_hash;
+mod symmetry;
+mod tags;
+mod targeting;
+mod sync_session;
+#[cfg(feature = "terminal")]
+mod terminal_playback;
+mod text_block;
+mod theme;
 mod tile;
+mod tile_age;
+mod tile_change;
+mod tile_colors;
+mod tile_error;
+mod tile_group;
+mod tile_history;
+mod tile_iter;
+mod tile_op;
+mod tile_source;
+mod tile_source_adapters;
+mod tilemap3;
+mod tilemap_builder;
+mod tilemap_view;
+mod token_tilemap;
+mod turmite;
+mod world;
+mod zobrist;
 
 // -------------------------------------------------------------------------------------------------
 // Definition
@@ -19,7 +169,7 @@ enum DrawLineState {
     /// tp the same line.
     /// # Value
     /// * `usize` - Index at which [`Tilemap::build_row()`] stopped. Build process should be continued
-    ///             from this position.
+    ///   from this position.
     SameLine(usize)
 }
 
@@ -53,20 +203,90 @@ enum DrawLineState {
 ///     Err(msg) => println!("{msg}")
 /// }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Tilemap {
     /// # Description
     /// Value of the empty tile that will be used during the build process of the tile map in
     /// [`Tilemap::build()`] method.
     pub empty_tile: char,
     /// # Description
+    /// What happens to control chars that are used as tile values by [`Tilemap::add_tile()`],
+    /// [`Tilemap::update_tile()`] and [`Tilemap::set()`].
+    pub control_chars: ControlCharPolicy,
+    /// # Description
+    /// Number of chars that every tile takes in [`Tilemap::build()`]. Values are centered and
+    /// empty tiles are repeated, see [`Tilemap::build()`]. Values less than 2 mean a single char.
+    pub cell_width: usize,
+    /// # Description
+    /// Limits of the number of tiles and of the size, see [`TilemapLimits`]. There are no limits
+    /// by default.
+    pub limits: TilemapLimits,
+    /// # Description
     /// Size of the tilemap. Depends on the positions of tile that are stored within.
-    /// Always equals to the furthest coordinates of tiles on X and Y axes.
+    /// Always at least equals to the furthest coordinates of tiles on X and Y axes.
+    /// Can be explicitly changed by [`Tilemap::resize()`].
     size: Vector2,
     /// # Description
     /// Stores all tiles of this tilemap. They are sorted so it would be much easier to build
     /// tilemap to a string representation.
-    tiles: sorted_vec::SortedSet<Tile>
+    tiles: sorted_vec::SortedSet<Tile>,
+    /// # Description
+    /// Text notes attached to positions, see [`Tilemap::annotate()`].
+    annotations: std::collections::BTreeMap<Vector2, String>,
+    /// # Description
+    /// Grapheme clusters by chars of the Private Use Area that were allocated to represent them,
+    /// see [`Tilemap::set_cluster()`].
+    clusters: std::collections::BTreeMap<char, String>,
+    /// # Description
+    /// Colors assigned to positions, see [`Tilemap::set_tile_color()`].
+    colors: std::collections::BTreeMap<Vector2, Color>,
+    /// # Description
+    /// Blinks assigned to positions, see [`Tilemap::set_blink()`].
+    blinks: std::collections::BTreeMap<Vector2, Blink>,
+    /// # Description
+    /// Named groups of positions, see [`Tilemap::add_group()`].
+    groups: std::collections::BTreeMap<String, TileGroup>,
+    /// # Description
+    /// Named positions, see [`Tilemap::set_bookmark()`].
+    bookmarks: std::collections::BTreeMap<String, Vector2>,
+    /// # Description
+    /// Description of the map that is saved with it, see [`Tilemap::metadata()`].
+    metadata: MapMetadata,
+    /// # Description
+    /// Tagged positions by names of tags, see [`Tilemap::tag()`].
+    tags: std::collections::BTreeMap<String, std::collections::BTreeSet<Vector2>>,
+    /// # Description
+    /// Record of changes of tiles, see [`Tilemap::start_journal()`].
+    journal: Option<Journal>,
+    /// # Description
+    /// Positions of tiles by their values, see [`Tilemap::enable_index()`].
+    index: Option<spatial_index::SpatialIndex>,
+    /// # Description
+    /// Rows of the last build, see [`Tilemap::build_cached()`].
+    build_cache: Option<build_cache::BuildCache>,
+    /// # Description
+    /// Hashes of rows that are updated on every change, see [`Tilemap::enable_row_hashes()`].
+    row_hashes: Option<Vec<u64>>,
+    /// # Description
+    /// Previous values of changed positions, see [`Tilemap::enable_history()`].
+    history: Option<tile_history::TileHistory>,
+    /// # Description
+    /// Current time of the clock in ticks, see [`Tilemap::tick()`].
+    clock: u64,
+    /// # Description
+    /// Times when tiles were added or updated for the last time, see [`Tilemap::enable_ages()`].
+    stamps: Option<std::collections::HashMap<Vector2, u64>>,
+    /// # Description
+    /// Values that replace tiles after they have the specified age, see
+    /// [`Tilemap::add_decay_rule()`].
+    decay_rules: std::collections::BTreeMap<char, (Option<char>, u64)>,
+    /// # Description
+    /// Zobrist hash of tiles that is updated on every change, see
+    /// [`Tilemap::enable_incremental_hash()`].
+    zobrist: Option<u64>,
+    /// # Description
+    /// Regions on which derived data depends, see [`Tilemap::add_dependency()`].
+    dependencies: dependencies::Dependencies,
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -90,8 +310,29 @@ impl Tilemap {
     pub fn new(empty_tile: char) -> Tilemap {
         return Tilemap {
             empty_tile,
+            control_chars: ControlCharPolicy::default(),
+            cell_width: 1,
+            limits: TilemapLimits::default(),
             size: Vector2::new(0, 0),
             tiles: sorted_vec::SortedSet::new(),
+            annotations: std::collections::BTreeMap::new(),
+            clusters: std::collections::BTreeMap::new(),
+            colors: std::collections::BTreeMap::new(),
+            blinks: std::collections::BTreeMap::new(),
+            groups: std::collections::BTreeMap::new(),
+            bookmarks: std::collections::BTreeMap::new(),
+            metadata: MapMetadata::default(),
+            tags: std::collections::BTreeMap::new(),
+            journal: None,
+            index: None,
+            build_cache: None,
+            row_hashes: None,
+            history: None,
+            clock: 0,
+            stamps: None,
+            decay_rules: std::collections::BTreeMap::new(),
+            zobrist: None,
+            dependencies: Default::default(),
         }
     }
 
@@ -119,7 +360,10 @@ impl Tilemap {
     ///
     /// # Return
     /// * [`Ok`] will be returned in case of successful addition of a new [`Tile`].
-    /// * [`Err`] will be returned if tile at the specified position already exists. Contains error message.
+    /// * [`Err`] with [`TileError::Occupied`] if tile at the specified position already exists,
+    ///   with [`TileError::ControlChar`] if value is rejected by [`Tilemap::control_chars`], or
+    ///   with [`TileError::TooManyTiles`] or [`TileError::OutOfLimits`] if the new tile exceeds
+    ///   [`Tilemap::limits`].
     ///
     /// # Example
     /// ```rust
@@ -129,17 +373,21 @@ impl Tilemap {
     ///     Err(msg) => println!("{msg}")
     /// }
     /// ```
-    pub fn add_tile(&mut self, position: Vector2, value: char) -> Result<(), String> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self), err))]
+    pub fn add_tile(&mut self, position: Vector2, value: char) -> Result<(), TileError> {
+        let value = self.control_chars.apply(value)?;
         let new_tile = Tile { position, value };
         if !self.tiles.contains(&new_tile)
         {
+            self.check_limits(position)?;
             self.tiles.push(new_tile);
             self.size.x = std::cmp::max(self.size.x, position.x + 1);
             self.size.y = std::cmp::max(self.size.y, position.y + 1);
+            self.record(position, None, Some(value));
             return Ok(());
         }
 
-        return Err(format!("Failed to add new tile at {position} with value \'{value}\'"));
+        return Err(TileError::Occupied(position));
     }
 
     /// # Description
@@ -150,7 +398,7 @@ impl Tilemap {
     ///
     /// # Return
     /// * [`Ok`] if at the specified position [`Tile`] did exist and was removed.
-    /// * [`Err`] if at the specified position [`Tile`] did not exist. Contains error message.
+    /// * [`Err`] with [`TileError::Missing`] if at the specified position [`Tile`] did not exist.
     ///
     /// # Example
     /// ```rust
@@ -160,13 +408,26 @@ impl Tilemap {
     ///     Err(msg) => println!("{msg}")
     /// }
     /// ```
-    pub fn remove_tile(&mut self, position: Vector2) -> Result<(), String> {
-        if let Some(index) = self.tiles.iter().position(|tile| tile.position == position) {
-            self.tiles.remove_index(index);
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self), err))]
+    pub fn remove_tile(&mut self, position: Vector2) -> Result<(), TileError> {
+        if self.take_tile(position).is_some() {
             return Ok(());
         }
 
-        return Err(format!("There is no tile at the position {position}"));
+        return Err(TileError::Missing(position));
+    }
+
+    /// # Description
+    /// Removes [`Tile`] at the specified position if it exists, without an error otherwise.
+    ///
+    /// # Return
+    /// * [`Some`] with value of the removed [`Tile`].
+    /// * [`None`] if there was no [`Tile`] at the specified position.
+    pub(crate) fn take_tile(&mut self, position: Vector2) -> Option<char> {
+        let index = self.tiles.binary_search_by(|tile| tile.position.cmp(&position)).ok()?;
+        let value = self.tiles.remove_index(index).value;
+        self.record(position, Some(value), None);
+        return Some(value);
     }
 
     /// # Description
@@ -178,7 +439,8 @@ impl Tilemap {
     ///
     /// # Return
     /// * [`Ok`] if at the specified position [`Tile`] did exist and was updated.
-    /// * [`Err`] if at the specified position [`Tile`] did not exist. Contains error message.
+    /// * [`Err`] with [`TileError::Missing`] if at the specified position [`Tile`] did not exist,
+    ///   or with [`TileError::ControlChar`] if new value is rejected by [`Tilemap::control_chars`].
     ///
     /// # Example
     /// ```rust
@@ -188,19 +450,319 @@ impl Tilemap {
     ///     Err(msg) => println!("{msg}")
     /// }
     /// ```
-    pub fn update_tile(&mut self, position: Vector2, new_value: char) -> Result<(), String> {
-        if let Some(_) = self.tiles.iter().find(|tile| tile.position == position) {
-            self.tiles.replace(Tile { position, value: new_value });
-
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self), err))]
+    pub fn update_tile(&mut self, position: Vector2, new_value: char) -> Result<(), TileError> {
+        let new_value = self.control_chars.apply(new_value)?;
+        if self.tiles.iter().any(|tile| tile.position == position) {
+            let (_, old) = self.tiles.replace(Tile { position, value: new_value });
+            self.record(position, old.map(|tile| tile.value), Some(new_value));
             return Ok(());
         }
 
-        return Err(format!("There is no tile at the position {position}"));
+        return Err(TileError::Missing(position));
+    }
+
+    /// # Description
+    /// Returns value of the [`Tile`] at the specified position if it exists.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position represented as [`Vector2`] of the requested [`Tile`].
+    ///
+    /// # Return
+    /// * [`Some`] with value of the [`Tile`] if it exists at the specified position.
+    /// * [`None`] if there is no [`Tile`] at the specified position.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('-');
+    /// assert_eq!(tilemap.get(char_tilemap::Vector2::ZERO), None);
+    /// ```
+    pub fn get(&self, position: Vector2) -> Option<char> {
+        return match self.tiles.binary_search_by(|tile| tile.position.cmp(&position)) {
+            Ok(index) => Some(self.tiles[index].value),
+            Err(_) => None,
+        };
+    }
+
+    /// # Description
+    /// Sets value of the [`Tile`] at the specified position. Tile will be added if it does not
+    /// exist, or updated otherwise. If value is a control char rejected by
+    /// [`Tilemap::control_chars`], or new tile exceeds [`Tilemap::limits`], [`Tilemap`] stays
+    /// unchanged, see [`Tilemap::try_set()`] to know when it happens.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position represented as [`Vector2`] of the [`Tile`].
+    /// * `value: char` - New value of the [`Tile`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('-');
+    /// tilemap.set(char_tilemap::Vector2::ZERO, 'O');
+    /// assert_eq!(tilemap.get(char_tilemap::Vector2::ZERO), Some('O'));
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
+    pub fn set(&mut self, position: Vector2, value: char) {
+        let _ = self.try_set(position, value);
+    }
+
+    /// # Description
+    /// Sets value of the [`Tile`] at the specified position, see [`Tilemap::set()`].
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position represented as [`Vector2`] of the [`Tile`].
+    /// * `value: char` - New value of the [`Tile`].
+    ///
+    /// # Return
+    /// * [`Ok`] if tile was set.
+    /// * [`Err`] if value is a control char rejected by [`Tilemap::control_chars`], or new tile
+    ///   exceeds [`Tilemap::limits`]. Contains [`TileError`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::{TileError, Vector2};
+    ///
+    /// let mut tilemap = char_tilemap::Tilemap::new('-');
+    /// tilemap.limits.max_tiles = Some(1);
+    /// assert_eq!(tilemap.try_set(Vector2::ZERO, 'O'), Ok(()));
+    /// assert_eq!(tilemap.try_set(Vector2::ONE, 'O'), Err(TileError::TooManyTiles(1)));
+    /// ```
+    pub fn try_set(&mut self, position: Vector2, value: char) -> Result<(), TileError> {
+        let value = self.control_chars.apply(value)?;
+        self.check_limits(position)?;
+
+        let (_, old) = self.tiles.replace(Tile { position, value });
+        self.size.x = std::cmp::max(self.size.x, position.x + 1);
+        self.size.y = std::cmp::max(self.size.y, position.y + 1);
+        self.record(position, old.map(|tile| tile.value), Some(value));
+        return Ok(());
+    }
+
+    /// # Description
+    /// Creates read-only [`TilemapView`] into the rectangular region of the [`Tilemap`].
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Top left corner of the region.
+    /// * `size: Vector2` - Size of the region.
+    ///
+    /// # Return
+    /// * [`Ok`] with new [`TilemapView`].
+    /// * [`Err`] if region does not fit into the [`Tilemap`]. Contains error message.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('-');
+    /// tilemap.set(char_tilemap::Vector2::new(3, 3), 'O');
+    /// let view = tilemap.view(char_tilemap::Vector2::ONE, char_tilemap::Vector2::new(3, 3)).unwrap();
+    /// assert_eq!(view.get(char_tilemap::Vector2::new(2, 2)), Some('O'));
+    /// ```
+    pub fn view(&self, position: Vector2, size: Vector2) -> Result<TilemapView<'_>, String> {
+        return TilemapView::new(self, position, size);
+    }
+
+    /// # Description
+    /// Creates mutable [`TilemapViewMut`] into the rectangular region of the [`Tilemap`].
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Top left corner of the region.
+    /// * `size: Vector2` - Size of the region.
+    ///
+    /// # Return
+    /// * [`Ok`] with new [`TilemapViewMut`].
+    /// * [`Err`] if region does not fit into the [`Tilemap`]. Contains error message.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('-');
+    /// tilemap.set(char_tilemap::Vector2::new(3, 3), '-');
+    /// let mut view = tilemap.view_mut(char_tilemap::Vector2::ONE, char_tilemap::Vector2::new(3, 3)).unwrap();
+    /// view.set(char_tilemap::Vector2::ZERO, 'O').unwrap();
+    /// ```
+    pub fn view_mut(&mut self, position: Vector2, size: Vector2) -> Result<TilemapViewMut<'_>, String> {
+        return TilemapViewMut::new(self, position, size);
+    }
+
+    /// # Description
+    /// Creates new [`Tilemap`] by copying all tiles of any [`TileSource`], such as a view or
+    /// a lazy adapter.
+    ///
+    /// # Arguments
+    /// * `source: &S` - Source of tiles.
+    ///
+    /// # Return
+    /// New instance of the [`Tilemap`] with the same size, empty tile, tiles and colors as the `source`.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('-');
+    /// tilemap.set(char_tilemap::Vector2::new(1, 0), 'O');
+    ///
+    /// let rotated = char_tilemap::Rotated::new(&tilemap, char_tilemap::Rotation::Clockwise90);
+    /// assert_eq!(char_tilemap::Tilemap::from_source(&rotated).build(), "-\nO");
+    /// ```
+    pub fn from_source<S: TileSource + ?Sized>(source: &S) -> Tilemap {
+        let mut tilemap = Tilemap::new(source.empty_tile());
+        let size = source.size();
+
+        for y in 0..size.y {
+            for x in 0..size.x {
+                let position = Vector2::new(x, y);
+                if let Some(value) = source.get(position) {
+                    tilemap.tiles.push(Tile { position, value });
+                }
+                if let Some(color) = source.color(position) {
+                    tilemap.colors.insert(position, color);
+                }
+            }
+        }
+
+        tilemap.size = size;
+        return tilemap;
+    }
+
+    /// # Description
+    /// Creates new [`Tilemap`] from the rectangular region of this one.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Top left corner of the region.
+    /// * `size: Vector2` - Size of the region.
+    ///
+    /// # Return
+    /// * [`Ok`] with new [`Tilemap`] of the specified size.
+    /// * [`Err`] if region does not fit into the [`Tilemap`]. Contains error message.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('-');
+    /// tilemap.set(char_tilemap::Vector2::new(2, 2), 'O');
+    ///
+    /// let cropped = tilemap.crop(char_tilemap::Vector2::ONE, char_tilemap::Vector2::new(2, 2)).unwrap();
+    /// assert_eq!(cropped.build(), "--\n-O");
+    /// ```
+    pub fn crop(&self, position: Vector2, size: Vector2) -> Result<Tilemap, String> {
+        let mut tilemap = Tilemap::from_source(&self.view(position, size)?);
+        tilemap.clusters = self.clusters.clone();
+        return Ok(tilemap);
+    }
+
+    /// # Description
+    /// Copies all tiles of the `other` [`Tilemap`] into this one. Existing tiles at the same
+    /// positions are overwritten. Grapheme clusters of the `other` [`Tilemap`] are kept.
+    /// Tiles that exceed [`Tilemap::limits`], or whose clusters could not be added, are skipped.
+    ///
+    /// # Arguments
+    /// * `other: &Tilemap` - [`Tilemap`] which tiles are copied.
+    ///
+    /// # Return
+    /// Positions of skipped tiles with their errors. Empty if all tiles were copied.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut first = char_tilemap::Tilemap::new('-');
+    /// first.set(char_tilemap::Vector2::ZERO, 'A');
+    /// let mut second = char_tilemap::Tilemap::new('-');
+    /// second.set(char_tilemap::Vector2::new(1, 0), 'B');
+    ///
+    /// assert!(first.merge(&second).is_empty());
+    /// assert_eq!(first.build(), "AB");
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(tiles = other.tiles.len())))]
+    pub fn merge(&mut self, other: &Tilemap) -> Vec<(Vector2, String)> {
+        let mut skipped = Vec::new();
+        for tile in other.tiles.iter() {
+            let result = match other.clusters.get(&tile.value) {
+                Some(cluster) => self.set_cluster(tile.position, cluster).map(|_| ()),
+                None => self.check_limits(tile.position).map_err(String::from).map(|_| {
+                    let (_, old) = self.tiles.replace(*tile);
+                    self.record(tile.position, old.map(|tile| tile.value), Some(tile.value));
+                }),
+            };
+            if let Err(error) = result {
+                skipped.push((tile.position, error));
+            }
+        }
+
+        let max_size = self.limits.max_size.unwrap_or(Vector2::MAX);
+        self.size.x = std::cmp::max(self.size.x, std::cmp::min(other.size.x, max_size.x));
+        self.size.y = std::cmp::max(self.size.y, std::cmp::min(other.size.y, max_size.y));
+        return skipped;
+    }
+
+    /// # Description
+    /// Changes size of the [`Tilemap`] to the specified one. Tiles are moved according to the
+    /// [`ResizePolicy::anchor`], and tiles that end up out of new bounds are treated according to
+    /// the [`ResizePolicy::overflow`].
+    ///
+    /// # Arguments
+    /// * `new_size: Vector2` - New size of the [`Tilemap`].
+    /// * `policy: ResizePolicy` - Policy that describes how tiles should be moved and treated.
+    ///
+    /// # Return
+    /// * [`Ok`] if [`Tilemap`] was resized.
+    /// * [`Err`] if [`OverflowPolicy::Error`] was used and at least one tile is out of new bounds,
+    ///   if [`OverflowPolicy::Wrap`] was used with new size equal to 0 on any axis, or if new size
+    ///   exceeds [`Tilemap::limits`]. [`Tilemap`] stays unchanged in that case. Contains error
+    ///   message.
+    ///
+    /// # Notes
+    /// If several tiles are wrapped to the same position, the one that goes last in row-major
+    /// order is kept.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('-');
+    /// let policy = char_tilemap::ResizePolicy::new(char_tilemap::OverflowPolicy::Truncate,
+    ///                                              char_tilemap::Anchor::Center);
+    /// match tilemap.resize(char_tilemap::Vector2::new(5, 5), policy) {
+    ///     Ok(_) => (),
+    ///     Err(msg) => println!("{msg}")
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub fn resize(&mut self, new_size: Vector2, policy: ResizePolicy) -> Result<(), String> {
+        self.limits.check_size(new_size)?;
+        let (offset_x, offset_y) = policy.anchor.offset(self.size, new_size);
+        let mut resized_tiles = sorted_vec::SortedSet::new();
+
+        for tile in self.tiles.iter() {
+            let x = tile.position.x as isize + offset_x;
+            let y = tile.position.y as isize + offset_y;
+            let in_bounds = x >= 0 && y >= 0 && (x as usize) < new_size.x && (y as usize) < new_size.y;
+
+            if in_bounds {
+                resized_tiles.replace(Tile { position: Vector2::new(x as usize, y as usize), value: tile.value });
+                continue;
+            }
+
+            match policy.overflow {
+                OverflowPolicy::Truncate => (),
+                OverflowPolicy::Error => {
+                    return Err(format!("Tile at {} is out of bounds of the new size {new_size}", tile.position));
+                },
+                OverflowPolicy::Wrap => {
+                    if new_size.x == 0 || new_size.y == 0 {
+                        return Err(format!("Failed to wrap tiles into the new size {new_size}"));
+                    }
+
+                    let position = Vector2::new(x.rem_euclid(new_size.x as isize) as usize,
+                                                y.rem_euclid(new_size.y as isize) as usize);
+                    resized_tiles.replace(Tile { position, value: tile.value });
+                },
+            }
+        }
+
+        let old_tiles = std::mem::replace(&mut self.tiles, resized_tiles);
+        self.size = new_size;
+        self.record_replaced(&old_tiles);
+        return Ok(());
     }
 
     /// # Description
     /// Builds [`Tilemap`] into the string representation. X = 0 is a top row, Y = 0 is a left column.
     ///
+    /// If [`Tilemap::cell_width`] is greater than 1, every tile takes that many chars: values,
+    /// including grapheme clusters set by [`Tilemap::set_cluster()`], are centered with spaces,
+    /// and empty tiles are repeated.
+    ///
     /// # Return
     /// A new [`String`] that contains representation of a [`Tilemap`].
     ///
@@ -209,13 +771,32 @@ impl Tilemap {
     /// let mut tilemap = char_tilemap::Tilemap::new('-');
     /// let tilemap_as_string = tilemap.build();
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(size = %self.size, tiles = self.tiles.len())))]
     pub fn build(&self) -> String {
+        if self.cell_width > 1 {
+            return self.build_cells();
+        }
+
+        return self.build_tiles();
+    }
+
+    /// # Description
+    /// Builds [`Tilemap`] into the string representation where every tile takes a single char,
+    /// regardless of [`Tilemap::cell_width`].
+    ///
+    /// # Return
+    /// A new [`String`] that contains representation of a [`Tilemap`].
+    pub(crate) fn build_tiles(&self) -> String {
         let mut result = String::new();
         let mut x = 0;
         let mut y = 0;
 
+        if self.size.x == 0 || self.size.y == 0 {
+            return result;
+        }
+
         // Draw all stored tile
-        for (_, tile) in self.tiles.iter().enumerate() {
+        for tile in self.tiles.iter() {
             // If current tile is on different row, draw empty rows until we reach required one
             while y < tile.position.y {
                 match self.build_row(x, self.size.x, &mut result) {
@@ -244,6 +825,13 @@ impl Tilemap {
             self.build_row(x, self.size.x, &mut result);
         }
 
+        // Draw empty rows that are left after the last tile
+        while y + 1 < self.size.y {
+            y += 1;
+            result.push('\n');
+            self.build_row(0, self.size.x, &mut result);
+        }
+
         return result;
     }
 
@@ -253,7 +841,7 @@ impl Tilemap {
     ///
     /// # Arguments
     /// * `mut start_position: usize` - Start position of the row.
-    ///    It is mutable since it will be used to determine current position of the built row.
+    ///   It is mutable since it will be used to determine current position of the built row.
     /// * `end_position: usize` - Final position of the row. Tile at this position will not be built.
     /// * `result: &mut String` - [`String`], to which row will be built.
     ///
@@ -272,13 +860,49 @@ impl Tilemap {
     }
 }
 
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl TileSource for Tilemap {
+    /// # Description
+    /// Returns size of the [`Tilemap`]. Same as [`Tilemap::size()`].
+    fn size(&self) -> Vector2 {
+        return self.size;
+    }
+
+    /// # Description
+    /// Returns value of the [`Tile`] at the specified position. Same as [`Tilemap::get()`].
+    fn get(&self, position: Vector2) -> Option<char> {
+        return Tilemap::get(self, position);
+    }
+
+    /// # Description
+    /// Returns [`Tilemap::empty_tile`].
+    fn empty_tile(&self) -> char {
+        return self.empty_tile;
+    }
+
+    /// # Description
+    /// Returns color of the position. Same as [`Tilemap::tile_color()`].
+    fn color(&self, position: Vector2) -> Option<Color> {
+        return self.tile_color(position);
+    }
+
+    /// # Description
+    /// Builds [`Tilemap`] into the string representation. Same as [`Tilemap::build()`].
+    fn build(&self) -> String {
+        return Tilemap::build(self);
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 // Tests
 // -------------------------------------------------------------------------------------------------
 
 #[cfg(test)]
 mod tests {
-    use crate::{Tilemap, Vector2};
+    use crate::{Anchor, OverflowPolicy, ResizePolicy, Tilemap, Vector2};
 
     const EMPTY_TILE_CHAR: char = '-';
     const NUMBER_OF_TILES: usize = 5;
@@ -401,5 +1025,141 @@ mod tests {
         }
 
         assert_eq!(tilemap.build(), ideal_result);
+        assert_eq!(crate::TileSource::build(&tilemap), ideal_result);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn resize() {
+        let mut tilemap = Tilemap::new(EMPTY_TILE_CHAR);
+        build_test_tilemap(&mut tilemap);
+
+        match tilemap.resize(Vector2::new(2, 2), ResizePolicy::new(OverflowPolicy::Error, Anchor::TopLeft)) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+
+        assert_eq!(tilemap.size(), Vector2::new(NUMBER_OF_TILES, NUMBER_OF_TILES));
+
+        match tilemap.resize(Vector2::new(2, 3), ResizePolicy::new(OverflowPolicy::Truncate, Anchor::TopLeft)) {
+            Ok(_) => assert!(true),
+            Err(_) => assert!(false)
+        }
+
+        assert_eq!(tilemap.size(), Vector2::new(2, 3));
+        assert_eq!(tilemap.build(), "O-\n-O\n--");
+
+        match tilemap.resize(Vector2::new(4, 4), ResizePolicy::new(OverflowPolicy::Error, Anchor::BottomRight)) {
+            Ok(_) => assert!(true),
+            Err(_) => assert!(false)
+        }
+
+        assert_eq!(tilemap.build(), "----\n--O-\n---O\n----");
+    }
+
+    #[test]
+    fn resize_with_kept_hashes() {
+        for anchor in [Anchor::TopLeft, Anchor::TopRight, Anchor::BottomLeft, Anchor::BottomRight, Anchor::Center] {
+            let mut tilemap = Tilemap::from_string("#", '.');
+            tilemap.enable_row_hashes();
+            tilemap.enable_index();
+            tilemap.enable_incremental_hash();
+
+            match tilemap.resize(Vector2::new(3, 3), ResizePolicy::new(OverflowPolicy::Truncate, anchor)) {
+                Ok(_) => assert!(true),
+                Err(_) => assert!(false)
+            }
+
+            let mut untracked = tilemap.clone();
+            untracked.disable_row_hashes();
+            untracked.disable_index();
+            untracked.disable_incremental_hash();
+            assert_eq!(tilemap.row_hashes(), untracked.row_hashes());
+            assert_eq!(tilemap.positions_of('#'), untracked.positions_of('#'));
+            assert_eq!(tilemap.incremental_hash(), untracked.incremental_hash());
+        }
+    }
+
+    #[test]
+    fn resize_wrap() {
+        let mut tilemap = Tilemap::new(EMPTY_TILE_CHAR);
+        build_test_tilemap(&mut tilemap);
+
+        match tilemap.resize(Vector2::new(3, 3), ResizePolicy::new(OverflowPolicy::Wrap, Anchor::TopLeft)) {
+            Ok(_) => assert!(true),
+            Err(_) => assert!(false)
+        }
+
+        assert_eq!(tilemap.build(), "O--\n-O-\n--O");
+
+        match tilemap.resize(Vector2::new(0, 3), ResizePolicy::new(OverflowPolicy::Wrap, Anchor::TopLeft)) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+
+    #[test]
+    fn get_set() {
+        let mut tilemap = Tilemap::new(EMPTY_TILE_CHAR);
+        assert_eq!(tilemap.get(Vector2::ONE), None);
+
+        tilemap.set(Vector2::ONE, TILE_VALUE);
+        assert_eq!(tilemap.get(Vector2::ONE), Some(TILE_VALUE));
+        assert_eq!(tilemap.size(), Vector2::new(2, 2));
+
+        tilemap.set(Vector2::ONE, '>');
+        assert_eq!(tilemap.get(Vector2::ONE), Some('>'));
+        assert_eq!(tilemap.get(Vector2::ZERO), None);
+    }
+
+    #[test]
+    fn from_source() {
+        let mut tilemap = Tilemap::new(EMPTY_TILE_CHAR);
+        build_test_tilemap(&mut tilemap);
+
+        let copy = Tilemap::from_source(&tilemap);
+        assert_eq!(copy.size(), tilemap.size());
+        assert_eq!(copy.build(), tilemap.build());
+    }
+
+    #[test]
+    fn crop() {
+        let mut tilemap = Tilemap::new(EMPTY_TILE_CHAR);
+        build_test_tilemap(&mut tilemap);
+
+        let cropped = tilemap.crop(Vector2::new(1, 0), Vector2::new(2, 3)).unwrap();
+        assert_eq!(cropped.build(), "--\nO-\n-O");
+
+        match tilemap.crop(Vector2::ONE, tilemap.size()) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+
+    #[test]
+    fn merge() {
+        let mut tilemap = Tilemap::new(EMPTY_TILE_CHAR);
+        tilemap.set(Vector2::ZERO, 'A');
+        tilemap.set(Vector2::new(1, 0), 'B');
+
+        let mut other = Tilemap::new(EMPTY_TILE_CHAR);
+        other.set(Vector2::new(1, 0), 'C');
+        other.set(Vector2::new(0, 1), 'D');
+
+        assert!(tilemap.merge(&other).is_empty());
+        assert_eq!(tilemap.build(), "AC\nD-");
+
+        let mut clusters = Tilemap::new(EMPTY_TILE_CHAR);
+        clusters.set_cluster(Vector2::ZERO, "a\u{301}").unwrap();
+        other.set_cluster(Vector2::ZERO, "o\u{301}").unwrap();
+        assert!(clusters.merge(&other).is_empty());
+        assert_eq!(clusters.build_clusters(), "o\u{301}C\nD-");
+
+        // Other clusters take all chars, so the cluster can not be added
+        let mut full = Tilemap::new(EMPTY_TILE_CHAR);
+        full.clusters = ('\u{E000}'..='\u{F8FF}').map(|value| (value, format!("{value}\u{301}"))).collect();
+        let skipped = full.merge(&other);
+        assert_eq!(skipped.iter().map(|(position, _)| *position).collect::<Vec<_>>(), vec![Vector2::ZERO]);
+        assert_eq!(full.get(Vector2::ZERO), None);
+        assert_eq!(full.build(), "-C\nD-");
+    }
+}
@@ -0,0 +1,267 @@
+use std::time::Duration;
+use crate::tilemap::{TileChange, Tilemap};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Single frame of the [`Animation`].
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum AnimationFrame {
+    /// # Description
+    /// Frame that stores the whole [`Tilemap`].
+    Full(Tilemap),
+    /// # Description
+    /// Frame that stores only changes relative to the previous frame.
+    Diff(Vec<TileChange>),
+}
+
+/// # Description
+/// Timeline of [`Tilemap`] frames, where every frame has its own duration.
+/// Frames can be stored either as full tilemaps or as diffs against the previous frame.
+///
+/// # Example
+/// ```rust
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::ONE, '-');
+///
+/// let mut animation = char_tilemap::Animation::new();
+/// animation.push_frame(tilemap.clone(), std::time::Duration::from_millis(100));
+///
+/// tilemap.set(char_tilemap::Vector2::ZERO, 'O');
+/// animation.push_frame_as_diff(&tilemap, std::time::Duration::from_millis(100)).unwrap();
+///
+/// let frames: Vec<String> = animation.rendered().map(|(frame, _)| frame).collect();
+/// assert_eq!(frames, vec!["--\n--", "O-\n--"]);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Animation {
+    /// # Description
+    /// Frames with their durations.
+    frames: Vec<(AnimationFrame, Duration)>,
+    /// # Description
+    /// State of the tilemap after the last frame. Used to calculate diffs of new frames.
+    last_state: Option<Tilemap>,
+}
+
+/// # Description
+/// Iterator over the states of the [`Animation`]. Reconstructs [`Tilemap`] of every frame.
+/// Created by [`Animation::states()`].
+#[derive(Debug)]
+pub struct AnimationStates<'a> {
+    /// # Description
+    /// Iterated animation.
+    animation: &'a Animation,
+    /// # Description
+    /// Index of the next frame.
+    index: usize,
+    /// # Description
+    /// State of the tilemap at the previous frame.
+    current: Option<Tilemap>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Animation {
+    /// # Description
+    /// Creates new empty [`Animation`].
+    ///
+    /// # Return
+    /// New instance of the [`Animation`].
+    pub fn new() -> Animation {
+        return Animation { frames: Vec::new(), last_state: None };
+    }
+
+    /// # Description
+    /// Returns number of frames.
+    ///
+    /// # Return
+    /// Number of frames in the [`Animation`].
+    pub fn len(&self) -> usize {
+        return self.frames.len();
+    }
+
+    /// # Description
+    /// Checks if [`Animation`] has no frames.
+    ///
+    /// # Return
+    /// `true` if there are no frames. Otherwise - `false`.
+    pub fn is_empty(&self) -> bool {
+        return self.frames.is_empty();
+    }
+
+    /// # Description
+    /// Returns sum of durations of all frames.
+    ///
+    /// # Return
+    /// Total [`Duration`] of the [`Animation`].
+    pub fn total_duration(&self) -> Duration {
+        return self.frames.iter().map(|(_, duration)| *duration).sum();
+    }
+
+    /// # Description
+    /// Returns stored frames with their durations.
+    ///
+    /// # Return
+    /// Slice of frames.
+    pub fn frames(&self) -> &[(AnimationFrame, Duration)] {
+        return &self.frames;
+    }
+
+    /// # Description
+    /// Adds new frame that stores the whole [`Tilemap`].
+    ///
+    /// # Arguments
+    /// * `tilemap: Tilemap` - State of the new frame.
+    /// * `duration: Duration` - How long frame should be shown.
+    pub fn push_frame(&mut self, tilemap: Tilemap, duration: Duration) {
+        self.last_state = Some(tilemap.clone());
+        self.frames.push((AnimationFrame::Full(tilemap), duration));
+    }
+
+    /// # Description
+    /// Adds new frame that stores only changes relative to the previous frame.
+    ///
+    /// # Arguments
+    /// * `changes: Vec<TileChange>` - Changes relative to the previous frame.
+    /// * `duration: Duration` - How long frame should be shown.
+    ///
+    /// # Return
+    /// * [`Ok`] if frame was added.
+    /// * [`Err`] if there are no frames yet. Contains error message.
+    pub fn push_diff(&mut self, changes: Vec<TileChange>, duration: Duration) -> Result<(), String> {
+        match &mut self.last_state {
+            Some(state) => state.apply_changes(&changes),
+            None => return Err(String::from("Failed to add diff frame to the animation without frames")),
+        }
+
+        self.frames.push((AnimationFrame::Diff(changes), duration));
+        return Ok(());
+    }
+
+    /// # Description
+    /// Adds new frame by calculating diff between the previous frame and the specified [`Tilemap`].
+    ///
+    /// # Arguments
+    /// * `tilemap: &Tilemap` - State of the new frame.
+    /// * `duration: Duration` - How long frame should be shown.
+    ///
+    /// # Return
+    /// * [`Ok`] if frame was added.
+    /// * [`Err`] if there are no frames yet. Contains error message.
+    pub fn push_frame_as_diff(&mut self, tilemap: &Tilemap, duration: Duration) -> Result<(), String> {
+        let changes = match &self.last_state {
+            Some(state) => state.diff(tilemap),
+            None => return Err(String::from("Failed to add diff frame to the animation without frames")),
+        };
+
+        return self.push_diff(changes, duration);
+    }
+
+    /// # Description
+    /// Creates iterator over the reconstructed states of all frames.
+    ///
+    /// # Return
+    /// [`AnimationStates`] iterator.
+    pub fn states(&self) -> AnimationStates<'_> {
+        return AnimationStates { animation: self, index: 0, current: None };
+    }
+
+    /// # Description
+    /// Creates iterator over the built frames.
+    ///
+    /// # Return
+    /// Iterator that yields built frames with their durations.
+    pub fn rendered(&self) -> impl Iterator<Item = (String, Duration)> + '_ {
+        return self.states().map(|(state, duration)| (state.build(), duration));
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl Iterator for AnimationStates<'_> {
+    type Item = (Tilemap, Duration);
+
+    /// # Description
+    /// Reconstructs state of the next frame.
+    ///
+    /// # Return
+    /// * [`Some`] with state of the frame and its duration.
+    /// * [`None`] if there are no more frames.
+    fn next(&mut self) -> Option<Self::Item> {
+        let (frame, duration) = self.animation.frames.get(self.index)?;
+        self.index += 1;
+
+        match frame {
+            AnimationFrame::Full(tilemap) => self.current = Some(tilemap.clone()),
+            AnimationFrame::Diff(changes) => {
+                if let Some(current) = &mut self.current {
+                    current.apply_changes(changes);
+                }
+            },
+        }
+
+        return self.current.clone().map(|state| (state, *duration));
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::tilemap::{Animation, AnimationFrame, TileChange, Tilemap, Vector2};
+
+    const FRAME_DURATION: Duration = Duration::from_millis(50);
+
+    #[test]
+    fn push_frame() {
+        let mut animation = Animation::new();
+        assert!(animation.is_empty());
+
+        match animation.push_diff(Vec::new(), FRAME_DURATION) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+
+        animation.push_frame(Tilemap::new('-'), FRAME_DURATION);
+        match animation.push_diff(vec![TileChange::new(Vector2::ZERO, None, Some('O'))], FRAME_DURATION) {
+            Ok(_) => assert!(true),
+            Err(_) => assert!(false)
+        }
+
+        assert_eq!(animation.len(), 2);
+        assert_eq!(animation.total_duration(), FRAME_DURATION * 2);
+    }
+
+    #[test]
+    fn rendered() {
+        let mut tilemap = Tilemap::new('-');
+        tilemap.set(Vector2::ONE, 'X');
+
+        let mut animation = Animation::new();
+        animation.push_frame(tilemap.clone(), FRAME_DURATION);
+
+        tilemap.set(Vector2::ZERO, 'O');
+        animation.push_frame_as_diff(&tilemap, FRAME_DURATION).unwrap();
+
+        tilemap.remove_tile(Vector2::ONE).unwrap();
+        animation.push_frame_as_diff(&tilemap, FRAME_DURATION).unwrap();
+
+        match &animation.frames()[2].0 {
+            AnimationFrame::Diff(changes) => assert_eq!(changes.len(), 1),
+            AnimationFrame::Full(_) => assert!(false)
+        }
+
+        let frames: Vec<String> = animation.rendered().map(|(frame, _)| frame).collect();
+        assert_eq!(frames, vec!["--\n-X", "O-\n-X", "O-\n--"]);
+    }
+}
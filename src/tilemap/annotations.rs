@@ -0,0 +1,145 @@
+use crate::tilemap::{Overlay, TileSource, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Values of the markers that are used by [`Tilemap::build_annotated()`], in order.
+/// Annotations beyond the last marker use [`OVERFLOW_MARKER`].
+const MARKERS: &str = "123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// # Description
+/// Value of the marker for annotations that do not have their own marker.
+const OVERFLOW_MARKER: char = '*';
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Attaches text note to the specified position. Previous note at the same position is
+    /// replaced. Notes do not affect tiles, size or [`Tilemap::build()`].
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the note.
+    /// * `text: &str` - Text of the note.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('-');
+    /// tilemap.annotate(char_tilemap::Vector2::ZERO, "Entrance");
+    /// assert_eq!(tilemap.annotation(char_tilemap::Vector2::ZERO), Some("Entrance"));
+    /// ```
+    pub fn annotate(&mut self, position: Vector2, text: &str) {
+        self.annotations.insert(position, text.to_string());
+    }
+
+    /// # Description
+    /// Returns note attached to the specified position.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the note.
+    ///
+    /// # Return
+    /// * [`Some`] with text of the note.
+    /// * [`None`] if there is no note at the specified position.
+    pub fn annotation(&self, position: Vector2) -> Option<&str> {
+        return self.annotations.get(&position).map(|text| text.as_str());
+    }
+
+    /// # Description
+    /// Removes note attached to the specified position.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the note.
+    ///
+    /// # Return
+    /// * [`Some`] with text of the removed note.
+    /// * [`None`] if there was no note at the specified position.
+    pub fn remove_annotation(&mut self, position: Vector2) -> Option<String> {
+        return self.annotations.remove(&position);
+    }
+
+    /// # Description
+    /// Returns all notes with their positions, ordered by rows and then by columns.
+    pub fn annotations(&self) -> impl Iterator<Item = (Vector2, &str)> + '_ {
+        return self.annotations.iter().map(|(position, text)| (*position, text.as_str()));
+    }
+
+    /// # Description
+    /// Builds [`Tilemap`] with markers placed at annotated positions, followed by an empty line and
+    /// a list of notes. Markers are `1`-`9`, then `a`-`z` and `A`-`Z` in order of
+    /// [`Tilemap::annotations()`], remaining notes are marked with `*`. Markers out of bounds
+    /// are not drawn, but their notes are still listed.
+    ///
+    /// # Return
+    /// A new [`String`] with the tilemap and notes. Same as [`Tilemap::build()`] if there are no notes.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("#..\n..#", '.');
+    /// tilemap.annotate(char_tilemap::Vector2::new(1, 0), "Door");
+    /// tilemap.annotate(char_tilemap::Vector2::new(0, 1), "Chest");
+    /// assert_eq!(tilemap.build_annotated(), "#1.\n2.#\n\n1: Door\n2: Chest");
+    /// ```
+    pub fn build_annotated(&self) -> String {
+        if self.annotations.is_empty() {
+            return self.build();
+        }
+
+        let mut overlay = Overlay::new(self);
+        let mut notes = Vec::with_capacity(self.annotations.len());
+        let mut markers = MARKERS.chars();
+
+        for (position, text) in self.annotations() {
+            let marker = markers.next().unwrap_or(OVERFLOW_MARKER);
+            overlay.add_label(position, &marker.to_string());
+            notes.push(format!("{marker}: {text}"));
+        }
+
+        return format!("{}\n\n{}", overlay.build(), notes.join("\n"));
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[test]
+    fn annotations() {
+        let mut tilemap = Tilemap::from_string("...", '.');
+        assert_eq!(tilemap.build_annotated(), "...");
+
+        tilemap.annotate(Vector2::new(2, 0), "Exit");
+        tilemap.annotate(Vector2::ZERO, "Start");
+        tilemap.annotate(Vector2::new(5, 5), "Outside");
+        tilemap.annotate(Vector2::new(2, 0), "Locked exit");
+        assert_eq!(tilemap.build(), "...");
+        assert_eq!(tilemap.build_annotated(), "1.2\n\n1: Start\n2: Locked exit\n3: Outside");
+
+        assert_eq!(tilemap.remove_annotation(Vector2::ZERO), Some(String::from("Start")));
+        assert_eq!(tilemap.remove_annotation(Vector2::ZERO), None);
+        assert_eq!(tilemap.annotations().collect::<Vec<(Vector2, &str)>>(),
+                   vec![(Vector2::new(2, 0), "Locked exit"), (Vector2::new(5, 5), "Outside")]);
+    }
+
+    #[test]
+    fn overflow_markers() {
+        let mut tilemap = Tilemap::new('.');
+        tilemap.resize(Vector2::new(70, 1), Default::default()).unwrap();
+        for x in 0..70 {
+            tilemap.annotate(Vector2::new(x, 0), "Note");
+        }
+
+        let built = tilemap.build_annotated();
+        assert!(built.starts_with("123456789abc"));
+        assert!(built.contains("YZ*********\n"));
+        assert!(built.ends_with("*: Note"));
+    }
+}
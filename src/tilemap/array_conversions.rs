@@ -0,0 +1,141 @@
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Converts [`Tilemap`] into the 2D array of the `ndarray` crate. First axis of the array is
+    /// Y, second is X, so `array[[y, x]]` is the tile at `Vector2::new(x, y)`.
+    ///
+    /// # Return
+    /// New array of the [`Tilemap`] size with [`None`] for positions without tiles.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("#.\n..", '.');
+    /// let array = tilemap.to_ndarray();
+    /// assert_eq!(array.dim(), (2, 2));
+    /// assert_eq!(array[[0, 0]], Some('#'));
+    /// ```
+    #[cfg(feature = "ndarray")]
+    pub fn to_ndarray(&self) -> ndarray::Array2<Option<char>> {
+        return ndarray::Array2::from_shape_fn((self.size.y, self.size.x), |(y, x)| self.get(Vector2::new(x, y)));
+    }
+
+    /// # Description
+    /// Creates [`Tilemap`] from the 2D array of the `ndarray` crate, see [`Tilemap::to_ndarray()`].
+    ///
+    /// # Arguments
+    /// * `array: &ndarray::Array2<Option<char>>` - Tiles, [`None`] for positions without tiles.
+    /// * `empty_tile: char` - Value that will be used for empty tiles.
+    ///
+    /// # Return
+    /// New [`Tilemap`] of the array size.
+    #[cfg(feature = "ndarray")]
+    pub fn from_ndarray(array: &ndarray::Array2<Option<char>>, empty_tile: char) -> Tilemap {
+        let (height, width) = array.dim();
+        return Tilemap::from_cells(Vector2::new(width, height), empty_tile, |position| array[[position.y, position.x]]);
+    }
+
+    /// # Description
+    /// Converts [`Tilemap`] into the grid of the `grid` crate. Rows of the grid are rows of the
+    /// [`Tilemap`], so `grid[(y, x)]` is the tile at `Vector2::new(x, y)`.
+    ///
+    /// # Return
+    /// New grid of the [`Tilemap`] size with [`None`] for positions without tiles.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("#.\n..", '.');
+    /// let grid = tilemap.to_grid();
+    /// assert_eq!((grid.rows(), grid.cols()), (2, 2));
+    /// assert_eq!(grid[(0, 0)], Some('#'));
+    /// ```
+    #[cfg(feature = "grid")]
+    pub fn to_grid(&self) -> grid::Grid<Option<char>> {
+        let mut grid = grid::Grid::new(self.size.y, self.size.x);
+        for tile in self.tiles.iter() {
+            grid[(tile.position.y, tile.position.x)] = Some(tile.value);
+        }
+
+        return grid;
+    }
+
+    /// # Description
+    /// Creates [`Tilemap`] from the grid of the `grid` crate, see [`Tilemap::to_grid()`].
+    ///
+    /// # Arguments
+    /// * `grid: &grid::Grid<Option<char>>` - Tiles, [`None`] for positions without tiles.
+    /// * `empty_tile: char` - Value that will be used for empty tiles.
+    ///
+    /// # Return
+    /// New [`Tilemap`] of the grid size.
+    #[cfg(feature = "grid")]
+    pub fn from_grid(grid: &grid::Grid<Option<char>>, empty_tile: char) -> Tilemap {
+        return Tilemap::from_cells(Vector2::new(grid.cols(), grid.rows()), empty_tile, |position| grid[(position.y, position.x)]);
+    }
+
+    /// # Description
+    /// Creates [`Tilemap`] of the specified size, taking tiles from the function.
+    fn from_cells<F>(size: Vector2, empty_tile: char, cell: F) -> Tilemap
+        where F: Fn(Vector2) -> Option<char> {
+        let mut tilemap = Tilemap::new(empty_tile);
+        for y in 0..size.y {
+            for x in 0..size.x {
+                if let Some(value) = cell(Vector2::new(x, y)) {
+                    tilemap.set(Vector2::new(x, y), value);
+                }
+            }
+        }
+
+        tilemap.size = size;
+        return tilemap;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    fn build_test_tilemap() -> Tilemap {
+        let mut tilemap = Tilemap::from_string("#..\n.@.", '.');
+        tilemap.set(Vector2::new(3, 2), '.');
+        tilemap.remove_tile(Vector2::new(3, 2)).unwrap();
+
+        return tilemap;
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn ndarray() {
+        let tilemap = build_test_tilemap();
+        let mut array = tilemap.to_ndarray();
+        assert_eq!(array.dim(), (3, 4));
+        assert_eq!(array[[1, 1]], Some('@'));
+        assert_eq!(array[[2, 3]], None);
+
+        array[[2, 3]] = Some('$');
+        let converted = Tilemap::from_ndarray(&array, '.');
+        assert_eq!(converted.build(), "#...\n.@..\n...$");
+    }
+
+    #[cfg(feature = "grid")]
+    #[test]
+    fn grid() {
+        let tilemap = build_test_tilemap();
+        let mut grid = tilemap.to_grid();
+        assert_eq!((grid.rows(), grid.cols()), (3, 4));
+        assert_eq!(grid[(1, 1)], Some('@'));
+
+        grid[(2, 3)] = Some('$');
+        let converted = Tilemap::from_grid(&grid, '.');
+        assert_eq!(converted.build(), "#...\n.@..\n...$");
+        assert_eq!(Tilemap::from_grid(&Tilemap::new('.').to_grid(), '.').size(), Vector2::ZERO);
+    }
+}
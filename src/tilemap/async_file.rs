@@ -0,0 +1,103 @@
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt};
+use crate::tilemap::{FileParser, Tilemap};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Reads [`Tilemap`] in the text save format from the asynchronous reader line by line,
+    /// see [`FileParser`].
+    ///
+    /// # Arguments
+    /// * `reader: R` - Buffered reader, such as a file or a network stream.
+    ///
+    /// # Return
+    /// * [`Ok`] with read [`Tilemap`].
+    /// * [`Err`] if reading failed or data is not in the save format. Contains error message.
+    pub async fn read_async<R: AsyncBufRead + Unpin>(reader: R) -> Result<Tilemap, String> {
+        let mut lines = reader.lines();
+        let mut parser = FileParser::new();
+
+        while let Some(line) = lines.next_line().await.map_err(|error| format!("Failed to read tilemap: {error}"))? {
+            parser.push_line(&line)?;
+        }
+
+        return parser.finish();
+    }
+
+    /// # Description
+    /// Loads [`Tilemap`] from the file in the text save format without blocking the runtime.
+    /// File is parsed while it is read, so it is never kept in memory as a whole.
+    ///
+    /// # Arguments
+    /// * `path: P` - Path to the file.
+    ///
+    /// # Return
+    /// * [`Ok`] with loaded [`Tilemap`].
+    /// * [`Err`] if file could not be read or is not in the save format. Contains error message.
+    pub async fn load_from_file_async<P: AsRef<std::path::Path>>(path: P) -> Result<Tilemap, String> {
+        let error = |error| format!("Failed to load tilemap from {}: {error}", path.as_ref().display());
+        let file = tokio::fs::File::open(&path).await.map_err(error)?;
+        return Tilemap::read_async(tokio::io::BufReader::new(file)).await;
+    }
+
+    /// # Description
+    /// Saves [`Tilemap`] to the file in the text save format without blocking the runtime.
+    ///
+    /// # Arguments
+    /// * `path: P` - Path to the file. File will be created or overwritten.
+    ///
+    /// # Return
+    /// * [`Ok`] if [`Tilemap`] was saved.
+    /// * [`Err`] if file could not be written. Contains error message.
+    pub async fn save_to_file_async<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), String> {
+        let error = |error| format!("Failed to save tilemap to {}: {error}", path.as_ref().display());
+        let mut file = tokio::fs::File::create(&path).await.map_err(error)?;
+        file.write_all(self.to_file_string().as_bytes()).await.map_err(error)?;
+        return file.flush().await.map_err(error);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[tokio::test]
+    async fn save_load() {
+        let path = std::env::temp_dir().join("char_tilemap_async_file_save_load.tilemap");
+        let mut tilemap = Tilemap::from_string("#...\n..@.\n....", '.');
+        tilemap.set_cluster(Vector2::new(3, 2), "e\u{301}").unwrap();
+
+        tilemap.save_to_file_async(&path).await.unwrap();
+        let loaded = Tilemap::load_from_file_async(&path).await.unwrap();
+        assert_eq!(loaded.to_file_string(), tilemap.to_file_string());
+
+        tokio::fs::remove_file(&path).await.unwrap();
+        match Tilemap::load_from_file_async(&path).await {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+
+    #[tokio::test]
+    async fn read_async() {
+        let tilemap = Tilemap::read_async("empty_tile=-\r\nsize=3x2\r\n\r\n-O\r\n---\r\n".as_bytes()).await.unwrap();
+        assert_eq!(tilemap.build(), "-O-\n---");
+
+        let v2 = "version=2\nempty_tile=.\nsize=2x1\ncluster=e\u{301}\n\n\u{E000}.";
+        assert_eq!(Tilemap::read_async(v2.as_bytes()).await.unwrap().build_clusters(), "e\u{301}.");
+
+        for text in ["empty_tile=-\nsize=1x1\n\n-O", "empty_tile=-\r\nsize=3x2\r\n\r\n-O\r\n"] {
+            match Tilemap::read_async(text.as_bytes()).await {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Rule of a Life-like cellular automaton, written as `B<digits>/S<digits>`, where digits are
+/// numbers of alive neighbors for which a dead cell becomes alive (birth) and an alive cell
+/// stays alive (survival). For example, Conway's Game of Life is `B3/S23`.
+///
+/// # Example
+/// ```rust
+/// let life = char_tilemap::LifeRule::parse("B3/S23").unwrap();
+/// assert!(life.next_state(false, 3));
+/// assert!(!life.next_state(true, 4));
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct LifeRule {
+    /// # Description
+    /// Numbers of alive neighbors, for which a dead cell becomes alive.
+    birth: [bool; 9],
+    /// # Description
+    /// Numbers of alive neighbors, for which an alive cell stays alive.
+    survival: [bool; 9],
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl LifeRule {
+    /// # Description
+    /// Parses rule in the `B<digits>/S<digits>` notation. Letters are case-insensitive and parts
+    /// may go in any order. Digits must be from 0 to 8.
+    ///
+    /// # Arguments
+    /// * `rule: &str` - Rule string, such as `B36/S23`.
+    ///
+    /// # Return
+    /// * [`Ok`] with the parsed [`LifeRule`].
+    /// * [`Err`] if rule is malformed. Contains error message.
+    pub fn parse(rule: &str) -> Result<LifeRule, String> {
+        let mut birth = None;
+        let mut survival = None;
+
+        for part in rule.trim().split('/') {
+            let mut chars = part.chars();
+            let target = match chars.next().map(|letter| letter.to_ascii_uppercase()) {
+                Some('B') => &mut birth,
+                Some('S') => &mut survival,
+                _ => return Err(format!("Invalid part \"{part}\" of the rule \"{rule}\", expected B or S")),
+            };
+            if target.is_some() {
+                return Err(format!("Rule \"{rule}\" has repeated part \"{part}\""));
+            }
+
+            let mut counts = [false; 9];
+            for digit in chars {
+                match digit.to_digit(10).filter(|digit| *digit <= 8) {
+                    Some(count) => counts[count as usize] = true,
+                    None => return Err(format!("Invalid number of neighbors '{digit}' in the rule \"{rule}\"")),
+                }
+            }
+            *target = Some(counts);
+        }
+
+        return match (birth, survival) {
+            (Some(birth), Some(survival)) => Ok(LifeRule { birth, survival }),
+            _ => Err(format!("Rule \"{rule}\" must have both B and S parts")),
+        };
+    }
+
+    /// # Description
+    /// Returns next state of the cell.
+    ///
+    /// # Arguments
+    /// * `alive: bool` - Is the cell alive now.
+    /// * `neighbors: usize` - Number of alive neighbors of the cell.
+    ///
+    /// # Return
+    /// `true` if the cell is alive in the next generation, `false` otherwise.
+    pub fn next_state(&self, alive: bool, neighbors: usize) -> bool {
+        let counts = if alive { &self.survival } else { &self.birth };
+        return counts.get(neighbors).copied().unwrap_or(false);
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Advances the [`Tilemap`] by one generation of the cellular automaton. Cells are alive if
+    /// their value is `alive`. Empty cells and cells with `dead` value are dead, other cells are
+    /// obstacles: they count as dead neighbors and never change. Cells out of bounds count as
+    /// dead neighbors too. Dead cells become empty if `dead` is [`Tilemap::empty_tile`].
+    ///
+    /// # Arguments
+    /// * `alive: char` - Value of alive cells.
+    /// * `dead: char` - Value of dead cells.
+    /// * `rule: F` - Function that receives whether cell is alive and number of its alive
+    ///   neighbors among 8 surrounding cells, and returns whether the cell is alive next.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string(".....\n..#..\n..#..\n..#..\n.....", '.');
+    ///
+    /// tilemap.step_automaton('#', '.', |alive, neighbors| neighbors == 3 || (alive && neighbors == 2));
+    /// assert_eq!(tilemap.build(), ".....\n.....\n.###.\n.....\n.....");
+    /// ```
+    pub fn step_automaton<F>(&mut self, alive: char, dead: char, rule: F)
+        where F: Fn(bool, usize) -> bool {
+        let size = self.size;
+        let mut cells = vec![false; size.x * size.y];
+        for tile in self.tiles.iter().filter(|tile| tile.value == alive) {
+            cells[tile.position.y * size.x + tile.position.x] = true;
+        }
+
+        for y in 0..size.y {
+            for x in 0..size.x {
+                let position = Vector2::new(x, y);
+                let value = self.get(position);
+                if value.is_some_and(|value| value != alive && value != dead) {
+                    continue;
+                }
+
+                let mut neighbors = 0;
+                for (dx, dy) in [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)] {
+                    let neighbor_x = x.checked_add_signed(dx).filter(|x| *x < size.x);
+                    let neighbor_y = y.checked_add_signed(dy).filter(|y| *y < size.y);
+                    if let (Some(neighbor_x), Some(neighbor_y)) = (neighbor_x, neighbor_y) {
+                        neighbors += cells[neighbor_y * size.x + neighbor_x] as usize;
+                    }
+                }
+
+                let next = if rule(cells[y * size.x + x], neighbors) { alive } else { dead };
+                if next != self.empty_tile {
+                    self.set(position, next);
+                } else {
+                    self.take_tile(position);
+                }
+            }
+        }
+    }
+
+    /// # Description
+    /// Advances the [`Tilemap`] by one generation of the Life-like cellular automaton described
+    /// by the rule string, see [`LifeRule`] and [`Tilemap::step_automaton()`].
+    ///
+    /// # Arguments
+    /// * `rule: &str` - Rule string, such as `B3/S23`.
+    /// * `alive: char` - Value of alive cells.
+    /// * `dead: char` - Value of dead cells.
+    ///
+    /// # Return
+    /// * [`Ok`] if generation was advanced.
+    /// * [`Err`] if rule is malformed. [`Tilemap`] stays unchanged. Contains error message.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("....\n.##.\n.##.\n....", '.');
+    /// tilemap.step_rule("B3/S23", '#', '.').unwrap();
+    /// assert_eq!(tilemap.build(), "....\n.##.\n.##.\n....");
+    /// ```
+    pub fn step_rule(&mut self, rule: &str, alive: char, dead: char) -> Result<(), String> {
+        let rule = LifeRule::parse(rule)?;
+        self.step_automaton(alive, dead, |is_alive, neighbors| rule.next_state(is_alive, neighbors));
+        return Ok(());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{LifeRule, Tilemap};
+
+    #[test]
+    fn parse() {
+        let highlife = LifeRule::parse("B36/S23").unwrap();
+        assert!(highlife.next_state(false, 6));
+        assert!(highlife.next_state(true, 2));
+        assert!(!highlife.next_state(true, 6));
+        assert!(!highlife.next_state(true, 9));
+        assert_eq!(LifeRule::parse("s23/b3"), LifeRule::parse("B3/S23"));
+        assert!(LifeRule::parse("B/S").is_ok());
+
+        for rule in ["", "B3", "B3/S23/S1", "B9/S23", "X3/S23", "B3/Sx"] {
+            match LifeRule::parse(rule) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+
+    #[test]
+    fn step_rule() {
+        let mut tilemap = Tilemap::from_string(".#...\n..#..\n###..\n.....\n.....", '.');
+
+        for _ in 0..4 {
+            tilemap.step_rule("B3/S23", '#', '.').unwrap();
+        }
+        assert_eq!(tilemap.build(), ".....\n..#..\n...#.\n.###.\n.....");
+
+        match tilemap.step_rule("B3", '#', '.') {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+
+    #[test]
+    fn obstacles_and_dead_value() {
+        let mut tilemap = Tilemap::from_string("##X\n#..", '.');
+
+        tilemap.step_rule("B3/S", '#', '-').unwrap();
+        assert_eq!(tilemap.build(), "--X\n-#-");
+    }
+}
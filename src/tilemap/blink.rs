@@ -0,0 +1,219 @@
+use std::time::Duration;
+use crate::tilemap::{Animation, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Small animation of a single tile: glyphs that are shown in turn, each for the same number of
+/// frames. Used for torches, water, cursors and other tiles that change on their own.
+///
+/// # Example
+/// ```rust
+/// let torch = char_tilemap::Blink::new(&['*', '+'], 2).unwrap();
+/// assert_eq!(torch.glyph(0), '*');
+/// assert_eq!(torch.glyph(2), '+');
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Blink {
+    /// # Description
+    /// Glyphs that are shown in turn.
+    glyphs: Vec<char>,
+    /// # Description
+    /// Number of frames during which every glyph is shown.
+    period: u32,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Blink {
+    /// # Description
+    /// Creates new [`Blink`].
+    ///
+    /// # Arguments
+    /// * `glyphs: &[char]` - Glyphs that are shown in turn, starting from the first one.
+    /// * `period: u32` - Number of frames during which every glyph is shown.
+    ///
+    /// # Return
+    /// * [`Ok`] with new [`Blink`].
+    /// * [`Err`] if there are no glyphs or period is 0. Contains error message.
+    pub fn new(glyphs: &[char], period: u32) -> Result<Blink, String> {
+        if glyphs.is_empty() || period == 0 {
+            return Err(String::from("Blink requires at least one glyph and non-zero period"));
+        }
+
+        return Ok(Blink { glyphs: glyphs.to_vec(), period });
+    }
+
+    /// # Description
+    /// Returns number of frames after which the blink repeats.
+    pub fn cycle(&self) -> u64 {
+        return self.glyphs.len() as u64 * self.period as u64;
+    }
+
+    /// # Description
+    /// Returns glyph that is shown at the specified frame.
+    ///
+    /// # Arguments
+    /// * `frame: u64` - Index of the frame.
+    pub fn glyph(&self, frame: u64) -> char {
+        return self.glyphs[((frame / self.period as u64) % self.glyphs.len() as u64) as usize];
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Assigns blink to the position. Blinks are stored in a separate layer, the tile itself is
+    /// not changed, and blinks are applied only by [`Tilemap::blink_frame()`] and by the
+    /// animations created from it.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    /// * `blink: Option<Blink>` - New blink, or [`None`] to remove the blink.
+    pub fn set_blink(&mut self, position: Vector2, blink: Option<Blink>) {
+        match blink {
+            Some(blink) => self.blinks.insert(position, blink),
+            None => self.blinks.remove(&position),
+        };
+    }
+
+    /// # Description
+    /// Returns blink assigned to the position.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with the blink set by [`Tilemap::set_blink()`].
+    /// * [`None`] if position has no blink.
+    pub fn blink(&self, position: Vector2) -> Option<&Blink> {
+        return self.blinks.get(&position);
+    }
+
+    /// # Description
+    /// Returns number of frames after which all blinks repeat together.
+    ///
+    /// # Return
+    /// Least common multiple of cycles of all blinks, or 1 if there are no blinks.
+    pub fn blink_cycle(&self) -> u64 {
+        return self.blinks.values().fold(1, |cycle, blink| lcm(cycle, blink.cycle()));
+    }
+
+    /// # Description
+    /// Creates copy of the [`Tilemap`] where every position with a blink is set to the glyph of
+    /// the specified frame.
+    ///
+    /// # Arguments
+    /// * `frame: u64` - Index of the frame.
+    ///
+    /// # Return
+    /// New [`Tilemap`] with the state of the frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("#..", '.');
+    /// let cursor = char_tilemap::Blink::new(&['_', '.'], 1).unwrap();
+    /// tilemap.set_blink(char_tilemap::Vector2::new(2, 0), Some(cursor));
+    ///
+    /// assert_eq!(tilemap.blink_frame(0).build(), "#._");
+    /// assert_eq!(tilemap.blink_frame(1).build(), "#..");
+    /// ```
+    pub fn blink_frame(&self, frame: u64) -> Tilemap {
+        let mut result = self.clone();
+        for (position, blink) in self.blinks.iter() {
+            result.set(*position, blink.glyph(frame));
+        }
+
+        return result;
+    }
+
+    /// # Description
+    /// Creates [`Animation`] of all blinks, which can be played by the terminal playback.
+    /// The first frame is stored fully, others are stored as diffs.
+    ///
+    /// # Arguments
+    /// * `cycles: usize` - How many times all blinks should be repeated, see
+    ///   [`Tilemap::blink_cycle()`].
+    /// * `frame_duration: Duration` - Duration of every frame.
+    ///
+    /// # Return
+    /// New [`Animation`] with `cycles * blink_cycle()` frames.
+    pub fn blink_animation(&self, cycles: usize, frame_duration: Duration) -> Animation {
+        let mut animation = Animation::new();
+        let frames = cycles as u64 * self.blink_cycle();
+
+        for frame in 0..frames {
+            let state = self.blink_frame(frame);
+            if animation.is_empty() {
+                animation.push_frame(state, frame_duration);
+            }
+            else {
+                animation.push_frame_as_diff(&state, frame_duration)
+                    .expect("Animation already has the first frame");
+            }
+        }
+
+        return animation;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Returns least common multiple of two positive numbers.
+fn lcm(first: u64, second: u64) -> u64 {
+    let (mut a, mut b) = (first, second);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    return first / a * second;
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::tilemap::{Blink, Tilemap, Vector2};
+
+    #[test]
+    fn new_blink() {
+        for (glyphs, period) in [(vec![], 1), (vec!['a'], 0)] {
+            match Blink::new(&glyphs, period) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+
+        let blink = Blink::new(&['a', 'b', 'c'], 2).unwrap();
+        assert_eq!(blink.cycle(), 6);
+        let glyphs: String = (0..8).map(|frame| blink.glyph(frame)).collect();
+        assert_eq!(glyphs, "aabbccaa");
+    }
+
+    #[test]
+    fn blink_animation() {
+        let mut tilemap = Tilemap::from_string("...", '.');
+        tilemap.set_blink(Vector2::ZERO, Some(Blink::new(&['~', '-'], 1).unwrap()));
+        tilemap.set_blink(Vector2::new(2, 0), Some(Blink::new(&['*', '+', 'x'], 1).unwrap()));
+        assert_eq!(tilemap.blink_cycle(), 6);
+        assert_eq!(tilemap.blink(Vector2::new(1, 0)), None);
+
+        let frames: Vec<String> = tilemap.blink_animation(1, Duration::ZERO).rendered().map(|(frame, _)| frame).collect();
+        assert_eq!(frames, vec!["~.*", "-.+", "~.x", "-.*", "~.+", "-.x"]);
+        assert_eq!(tilemap.build(), "...");
+
+        tilemap.set_blink(Vector2::ZERO, None);
+        tilemap.set_blink(Vector2::new(2, 0), None);
+        assert_eq!(tilemap.blink_cycle(), 1);
+        assert_eq!(tilemap.blink_animation(2, Duration::ZERO).len(), 2);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::tilemap::{Tilemap, Vector2};
+use crate::tilemap::file_format::parse_size;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Key of the header lines that store bookmarks in format `{name}@{x}x{y}`.
+pub(super) const BOOKMARK_KEY: &str = "bookmark";
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Sets named bookmark to the position, such as a spawn point or an exit. Bookmarks are saved
+    /// with the map by [`Tilemap::to_file_string()`] and are moved by [`Tilemap::oriented()`].
+    /// Tiles are not changed.
+    ///
+    /// # Arguments
+    /// * `name: &str` - Name of the bookmark. It replaces previous bookmark with the same name.
+    /// * `position: Vector2` - Position of the bookmark.
+    ///
+    /// # Return
+    /// * [`Ok`] if bookmark was set.
+    /// * [`Err`] if name is empty or contains line breaks, or position is out of bounds. Contains
+    ///   error message.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::Vector2;
+    ///
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("@..\n..>", '.');
+    /// tilemap.set_bookmark("exit", Vector2::new(2, 1)).unwrap();
+    ///
+    /// let loaded = char_tilemap::Tilemap::from_file_string(&tilemap.to_file_string()).unwrap();
+    /// assert_eq!(loaded.bookmark("exit"), Some(Vector2::new(2, 1)));
+    /// ```
+    pub fn set_bookmark(&mut self, name: &str, position: Vector2) -> Result<(), String> {
+        if name.is_empty() || name.contains(['\n', '\r']) {
+            return Err(format!("Invalid bookmark name \"{}\"", name.escape_debug()));
+        }
+        if !self.is_in_bounds(position) {
+            return Err(format!("Position {position} of the bookmark \"{name}\" is out of bounds"));
+        }
+
+        self.bookmarks.insert(name.to_string(), position);
+        return Ok(());
+    }
+
+    /// # Description
+    /// Returns position of the bookmark.
+    ///
+    /// # Return
+    /// * [`Some`] with the position.
+    /// * [`None`] if there is no bookmark with the specified name.
+    pub fn bookmark(&self, name: &str) -> Option<Vector2> {
+        return self.bookmarks.get(name).copied();
+    }
+
+    /// # Description
+    /// Returns names and positions of all bookmarks in alphabetical order of names.
+    pub fn bookmarks(&self) -> impl Iterator<Item = (&str, Vector2)> + '_ {
+        return self.bookmarks.iter().map(|(name, position)| (name.as_str(), *position));
+    }
+
+    /// # Description
+    /// Removes bookmark, tiles are not changed.
+    ///
+    /// # Return
+    /// * [`Some`] with position of the removed bookmark.
+    /// * [`None`] if there was no bookmark with the specified name.
+    pub fn remove_bookmark(&mut self, name: &str) -> Option<Vector2> {
+        return self.bookmarks.remove(name);
+    }
+
+    /// # Description
+    /// Returns header lines of the save format that store bookmarks.
+    pub(super) fn bookmark_lines(&self) -> String {
+        return self.bookmarks.iter()
+            .map(|(name, position)| format!("{BOOKMARK_KEY}={name}@{}x{}\n", position.x, position.y))
+            .collect();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Parses header value that should contain bookmark in format `{name}@{x}x{y}`. Name may
+/// contain `@` itself, since position follows the last one.
+pub(super) fn parse_bookmark(value: &str) -> Result<(String, Vector2), String> {
+    let (name, position) = value.rsplit_once('@')
+        .ok_or_else(|| format!("Expected bookmark in format {{name}}@{{x}}x{{y}}, but got \"{value}\""))?;
+    return Ok((name.to_string(), parse_size(position)?));
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Orientation, Tilemap, Vector2};
+
+    #[test]
+    fn bookmarks() {
+        let mut tilemap = Tilemap::from_string("...\n...", '.');
+        tilemap.set_bookmark("spawn", Vector2::ZERO).unwrap();
+        tilemap.set_bookmark("exit", Vector2::new(2, 0)).unwrap();
+        tilemap.set_bookmark("spawn", Vector2::ONE).unwrap();
+
+        assert_eq!(tilemap.bookmark("spawn"), Some(Vector2::ONE));
+        assert_eq!(tilemap.bookmarks().collect::<Vec<_>>(), vec![("exit", Vector2::new(2, 0)), ("spawn", Vector2::ONE)]);
+        assert_eq!(tilemap.oriented(Orientation::Rotate90).bookmark("exit"), Some(Vector2::new(1, 2)));
+
+        assert_eq!(tilemap.remove_bookmark("exit"), Some(Vector2::new(2, 0)));
+        assert_eq!(tilemap.remove_bookmark("exit"), None);
+        assert_eq!(tilemap.bookmark("exit"), None);
+
+        for (name, position) in [("", Vector2::ZERO), ("a\nb", Vector2::ZERO), ("far", Vector2::new(3, 0))] {
+            match tilemap.set_bookmark(name, position) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true),
+            }
+        }
+    }
+
+    #[test]
+    fn file_string() {
+        let mut tilemap = Tilemap::from_string("#..\n..#", '.');
+        tilemap.set_bookmark("boss@room", Vector2::new(2, 1)).unwrap();
+        tilemap.set_bookmark("spawn", Vector2::new(1, 0)).unwrap();
+        let text = tilemap.to_file_string();
+        assert_eq!(text, "version=3\nempty_tile=.\nsize=3x2\nbookmark=boss@room@2x1\nbookmark=spawn@1x0\n\n#..\n..#");
+
+        let loaded = Tilemap::from_file_string(&text).unwrap();
+        assert_eq!(loaded.bookmarks().collect::<Vec<_>>(), tilemap.bookmarks().collect::<Vec<_>>());
+
+        for text in ["empty_tile=.\nsize=1x1\nbookmark=spawn\n\n.", "empty_tile=.\nsize=1x1\nbookmark=spawn@1x1\n\n.",
+                     "empty_tile=.\nsize=1x1\nbookmark=@0x0\n\n."] {
+            match Tilemap::from_file_string(text) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true),
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::BTreeSet;
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Rows of the last build of the [`Tilemap`], see [`Tilemap::build_cached()`].
+#[derive(Debug, Clone)]
+pub(crate) struct BuildCache {
+    /// # Description
+    /// Built rows.
+    rows: Vec<String>,
+    /// # Description
+    /// Indices of rows that were changed since the last build.
+    dirty: BTreeSet<usize>,
+    /// # Description
+    /// Size of the [`Tilemap`] at the moment of the last build.
+    size: Vector2,
+    /// # Description
+    /// Empty tile of the [`Tilemap`] at the moment of the last build.
+    empty_tile: char,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Builds [`Tilemap`] same as [`Tilemap::build()`], but keeps built rows, so the next call
+    /// rebuilds only rows that were changed since then. It suits maps that are built every frame,
+    /// while only a few tiles change between frames.
+    ///
+    /// # Return
+    /// A new [`String`] that contains representation of the [`Tilemap`].
+    ///
+    /// # Notes
+    /// All rows are rebuilt after the size or the empty tile is changed, or after operations that
+    /// replace all tiles, such as [`Tilemap::resize()`]. Tilemaps with [`Tilemap::cell_width`]
+    /// greater than 1 are always built fully.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("#..\n...\n..#", '.');
+    /// assert_eq!(tilemap.build_cached(), "#..\n...\n..#");
+    ///
+    /// tilemap.set(char_tilemap::Vector2::ONE, '@');
+    /// assert_eq!(tilemap.build_cached(), "#..\n.@.\n..#");
+    /// ```
+    pub fn build_cached(&mut self) -> String {
+        if self.cell_width > 1 || self.size.x == 0 || self.size.y == 0 {
+            return self.build();
+        }
+
+        let valid = matches!(&self.build_cache,
+                             Some(cache) if cache.size == self.size && cache.empty_tile == self.empty_tile);
+        if !valid {
+            let rows = self.rows_as_strings();
+            self.build_cache = Some(BuildCache { rows, dirty: BTreeSet::new(), size: self.size, empty_tile: self.empty_tile });
+        }
+
+        let dirty = self.build_cache.as_mut().map(|cache| std::mem::take(&mut cache.dirty)).unwrap_or_default();
+        let rebuilt: Vec<(usize, String)> = dirty.into_iter().filter_map(|y| self.row(y).map(|row| (y, row))).collect();
+
+        let cache = self.build_cache.as_mut().expect("Build cache was just created");
+        for (y, row) in rebuilt {
+            cache.rows[y] = row;
+        }
+        return cache.rows.join("\n");
+    }
+
+    /// # Description
+    /// Drops rows that are kept by [`Tilemap::build_cached()`], so their memory is freed.
+    pub fn clear_build_cache(&mut self) {
+        self.build_cache = None;
+    }
+
+    /// # Description
+    /// Marks the row as changed for [`Tilemap::build_cached()`].
+    pub(crate) fn invalidate_row(&mut self, y: usize) {
+        if let Some(cache) = &mut self.build_cache {
+            cache.dirty.insert(y);
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Orientation, SymmetryAxis, Tilemap, Vector2};
+
+    #[test]
+    fn build_cached() {
+        let mut tilemap = Tilemap::from_string("#...\n....\n...#", '.');
+        assert_eq!(tilemap.build_cached(), tilemap.build());
+
+        tilemap.set(Vector2::new(1, 1), '@');
+        tilemap.remove_tile(Vector2::ZERO).unwrap();
+        assert_eq!(tilemap.build_cached(), "....\n.@..\n...#");
+
+        tilemap.set(Vector2::new(4, 3), '>');
+        assert_eq!(tilemap.build_cached(), tilemap.build());
+
+        tilemap.empty_tile = ' ';
+        assert_eq!(tilemap.build_cached(), tilemap.build());
+
+        tilemap.symmetrize(SymmetryAxis::Vertical);
+        assert_eq!(tilemap.build_cached(), tilemap.build());
+
+        tilemap.clear_build_cache();
+        tilemap.update_tile(Vector2::new(1, 1), '+').unwrap();
+        assert_eq!(tilemap.build_cached(), tilemap.build());
+
+        let mut oriented = tilemap.oriented(Orientation::Rotate90);
+        oriented.set(Vector2::ZERO, '*');
+        assert_eq!(oriented.build_cached(), oriented.build());
+        assert_eq!(Tilemap::new('.').build_cached(), "");
+    }
+}
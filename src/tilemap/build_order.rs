@@ -0,0 +1,103 @@
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Order in which [`Tilemap::build_ordered()`] emits tiles.
+#[derive(Debug, Default, Eq, PartialEq, Copy, Clone)]
+pub enum BuildOrder {
+    /// # Description
+    /// Rows are lines from top to bottom, and every row goes from left to right. Same as
+    /// [`Tilemap::build()`].
+    #[default]
+    RowMajor,
+    /// # Description
+    /// Rows are lines from top to bottom, and every row goes from right to left.
+    RightToLeft,
+    /// # Description
+    /// Columns are lines from left to right, and every column goes from top to bottom, which
+    /// is the transposed [`BuildOrder::RowMajor`].
+    ColumnMajor,
+    /// # Description
+    /// Columns are lines from right to left, and every column goes from top to bottom, as in
+    /// vertical writing.
+    ColumnMajorRightToLeft,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Builds [`Tilemap`] into the string representation with the specified order of lines and
+    /// tiles inside of them. Tiles are read in place, no transposed copy is created. Every
+    /// tile takes a single char, regardless of [`Tilemap::cell_width`].
+    ///
+    /// # Arguments
+    /// * `order: BuildOrder` - Order of lines and tiles.
+    ///
+    /// # Return
+    /// A new [`String`] that contains representation of the [`Tilemap`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("ab\ncd", '.');
+    /// assert_eq!(tilemap.build_ordered(char_tilemap::BuildOrder::ColumnMajor), "ac\nbd");
+    /// assert_eq!(tilemap.build_ordered(char_tilemap::BuildOrder::RightToLeft), "ba\ndc");
+    /// ```
+    pub fn build_ordered(&self, order: BuildOrder) -> String {
+        if order == BuildOrder::RowMajor {
+            return self.build_tiles();
+        }
+
+        let (lines, length) = match order {
+            BuildOrder::RowMajor | BuildOrder::RightToLeft => (self.size.y, self.size.x),
+            BuildOrder::ColumnMajor | BuildOrder::ColumnMajorRightToLeft => (self.size.x, self.size.y),
+        };
+        if lines == 0 || length == 0 {
+            return String::new();
+        }
+
+        let mut result = String::with_capacity(lines * (length + 1));
+        for line in 0..lines {
+            if line > 0 {
+                result.push('\n');
+            }
+
+            for index in 0..length {
+                let position = match order {
+                    BuildOrder::RowMajor => Vector2::new(index, line),
+                    BuildOrder::RightToLeft => Vector2::new(length - 1 - index, line),
+                    BuildOrder::ColumnMajor => Vector2::new(line, index),
+                    BuildOrder::ColumnMajorRightToLeft => Vector2::new(lines - 1 - line, index),
+                };
+                result.push(self.get(position).unwrap_or(self.empty_tile));
+            }
+        }
+
+        return result;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{BuildOrder, Orientation, Tilemap};
+
+    #[test]
+    fn build_ordered() {
+        let tilemap = Tilemap::from_string("ab.\n..c", '.');
+
+        assert_eq!(tilemap.build_ordered(BuildOrder::RowMajor), tilemap.build());
+        assert_eq!(tilemap.build_ordered(BuildOrder::RightToLeft), ".ba\nc..");
+        assert_eq!(tilemap.build_ordered(BuildOrder::ColumnMajor), tilemap.oriented(Orientation::Transpose).build());
+        assert_eq!(tilemap.build_ordered(BuildOrder::ColumnMajorRightToLeft), ".c\nb.\na.");
+        assert_eq!(Tilemap::new('.').build_ordered(BuildOrder::ColumnMajor), "");
+    }
+}
@@ -0,0 +1,118 @@
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Describes which part of the [`Tilemap`] is drawn in the terminal and where. Used to translate
+/// terminal coordinates back to positions of tiles, see [`Tilemap::hit_test()`].
+///
+/// # Example
+/// ```rust
+/// let camera = char_tilemap::Camera::new(char_tilemap::Vector2::new(10, 5), char_tilemap::Vector2::new(20, 10));
+/// assert_eq!(camera.screen_origin, char_tilemap::Vector2::ZERO);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Camera {
+    /// # Description
+    /// Position of the tile that is drawn in the top left corner of the viewport.
+    pub position: Vector2,
+    /// # Description
+    /// Size of the viewport in tiles.
+    pub size: Vector2,
+    /// # Description
+    /// Terminal column and row of the top left corner of the viewport.
+    pub screen_origin: Vector2,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Camera {
+    /// # Description
+    /// Creates new [`Camera`] whose viewport starts in the top left corner of the terminal.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile in the top left corner of the viewport.
+    /// * `size: Vector2` - Size of the viewport in tiles.
+    ///
+    /// # Return
+    /// New instance of the [`Camera`].
+    pub fn new(position: Vector2, size: Vector2) -> Camera {
+        return Camera { position, size, screen_origin: Vector2::ZERO };
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Translates terminal coordinates, such as coordinates of a mouse click, to the position of
+    /// the tile drawn there by the camera. Every tile takes [`Tilemap::cell_width`] columns.
+    ///
+    /// # Arguments
+    /// * `screen_x: usize` - Terminal column, starting from 0.
+    /// * `screen_y: usize` - Terminal row, starting from 0.
+    /// * `camera: &Camera` - Camera that was used to draw the [`Tilemap`].
+    ///
+    /// # Return
+    /// * [`Some`] with the position of the tile.
+    /// * [`None`] if coordinates are outside of the viewport or of the [`Tilemap`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("....\n....\n....", '.');
+    /// tilemap.cell_width = 2;
+    ///
+    /// let mut camera = char_tilemap::Camera::new(char_tilemap::Vector2::ONE, char_tilemap::Vector2::new(2, 2));
+    /// camera.screen_origin = char_tilemap::Vector2::new(0, 1);
+    /// assert_eq!(tilemap.hit_test(3, 1, &camera), Some(char_tilemap::Vector2::new(2, 1)));
+    /// assert_eq!(tilemap.hit_test(3, 0, &camera), None);
+    /// ```
+    pub fn hit_test(&self, screen_x: usize, screen_y: usize, camera: &Camera) -> Option<Vector2> {
+        let column = screen_x.checked_sub(camera.screen_origin.x)?;
+        let row = screen_y.checked_sub(camera.screen_origin.y)?;
+        let local = Vector2::new(column / self.cell_width.max(1), row);
+        if local.x >= camera.size.x || local.y >= camera.size.y {
+            return None;
+        }
+
+        let position = camera.position + local;
+        if position.x >= self.size.x || position.y >= self.size.y {
+            return None;
+        }
+
+        return Some(position);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Camera, Tilemap, Vector2};
+
+    #[test]
+    fn hit_test() {
+        let mut tilemap = Tilemap::from_string("....\n....\n....", '.');
+        let mut camera = Camera::new(Vector2::new(1, 0), Vector2::new(2, 2));
+        camera.screen_origin = Vector2::new(2, 3);
+
+        assert_eq!(tilemap.hit_test(2, 3, &camera), Some(Vector2::new(1, 0)));
+        assert_eq!(tilemap.hit_test(3, 4, &camera), Some(Vector2::new(2, 1)));
+        assert_eq!(tilemap.hit_test(1, 3, &camera), None);
+        assert_eq!(tilemap.hit_test(4, 3, &camera), None);
+        assert_eq!(tilemap.hit_test(2, 5, &camera), None);
+
+        tilemap.cell_width = 3;
+        assert_eq!(tilemap.hit_test(7, 3, &camera), Some(Vector2::new(2, 0)));
+        assert_eq!(tilemap.hit_test(8, 3, &camera), None);
+
+        camera.position = Vector2::new(3, 2);
+        assert_eq!(tilemap.hit_test(2, 3, &camera), Some(Vector2::new(3, 2)));
+        assert_eq!(tilemap.hit_test(5, 3, &camera), None);
+        assert_eq!(tilemap.hit_test(2, 4, &camera), None);
+    }
+}
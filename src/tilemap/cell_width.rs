@@ -0,0 +1,69 @@
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Builds [`Tilemap`] into the string representation where every tile takes
+    /// [`Tilemap::cell_width`] chars. Values are centered with spaces, extra space goes to the
+    /// right. Values that are longer than the cell width are written as is.
+    ///
+    /// # Return
+    /// A new [`String`] that contains representation of a [`Tilemap`].
+    pub(crate) fn build_cells(&self) -> String {
+        let mut result = String::new();
+
+        for y in 0..self.size.y {
+            if y > 0 {
+                result.push('\n');
+            }
+
+            for x in 0..self.size.x {
+                let Some(value) = self.get(Vector2::new(x, y)) else {
+                    result.extend(std::iter::repeat_n(self.empty_tile, self.cell_width));
+                    continue;
+                };
+
+                let cluster = self.cluster_of(value);
+                let padding = self.cell_width.saturating_sub(cluster.chars().count());
+                result.extend(std::iter::repeat_n(' ', padding / 2));
+                result.push_str(&cluster);
+                result.extend(std::iter::repeat_n(' ', padding - padding / 2));
+            }
+        }
+
+        return result;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[test]
+    fn build_cells() {
+        let mut tilemap = Tilemap::from_string("#..\n...", '.');
+        tilemap.set_cluster(Vector2::new(1, 1), "GR").unwrap();
+        tilemap.set_cluster(Vector2::new(2, 1), "TOWN").unwrap();
+
+        tilemap.cell_width = 2;
+        assert_eq!(tilemap.build(), "# ....\n..GRTOWN");
+
+        tilemap.cell_width = 3;
+        assert_eq!(tilemap.build(), " # ......\n...GR TOWN");
+    }
+
+    #[test]
+    fn single_char_cells() {
+        let tilemap = Tilemap::from_string("#.\n.#", '.');
+        let mut narrow = tilemap.clone();
+        narrow.cell_width = 0;
+        assert_eq!(narrow.build(), tilemap.build());
+    }
+}
@@ -0,0 +1,244 @@
+use crate::tilemap::{FileParser, Tilemap};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Key of the footer line that stores CRC32 of all previous lines of the save format.
+pub(super) const CHECKSUM_KEY: &str = "crc32";
+
+/// # Description
+/// Lookup table of the CRC32 (IEEE 802.3) polynomial.
+const CRC32_TABLE: [u32; 256] = build_crc32_table();
+
+/// # Description
+/// Running CRC32 of lines of the save format. Every line is followed by `'\n'`.
+#[derive(Debug, Copy, Clone)]
+pub(super) struct Crc32 {
+    /// # Description
+    /// Current state of the checksum, which is inverted at the start and at the end.
+    state: u32,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Crc32 {
+    /// # Description
+    /// Updates checksum with the line and the following `'\n'`.
+    pub(super) fn update_line(&mut self, line: &str) {
+        for byte in line.bytes().chain(std::iter::once(b'\n')) {
+            self.state = CRC32_TABLE[((self.state ^ byte as u32) & 0xFF) as usize] ^ (self.state >> 8);
+        }
+    }
+
+    /// # Description
+    /// Returns checksum of all lines.
+    pub(super) fn value(&self) -> u32 {
+        return !self.state;
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Serializes [`Tilemap`] into the text save format, same as [`Tilemap::to_file_string()`],
+    /// and adds `crc32` footer line with CRC32 of all previous lines. Footer is verified by
+    /// [`Tilemap::from_file_string()`] and [`Tilemap::load_from_file()`], so corrupted files
+    /// are detected.
+    ///
+    /// # Return
+    /// A new [`String`] with serialized [`Tilemap`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("-O", '-');
+    /// let text = tilemap.to_file_string_with_checksum();
+    /// assert!(text.starts_with(&tilemap.to_file_string()));
+    /// assert!(text.ends_with("\ncrc32=8b06b11b"));
+    ///
+    /// match char_tilemap::Tilemap::from_file_string(&text.replace("-O", "OO")) {
+    ///     Ok(_) => assert!(false),
+    ///     Err(_) => assert!(true)
+    /// }
+    /// ```
+    pub fn to_file_string_with_checksum(&self) -> String {
+        let mut text = self.to_file_string();
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+
+        let mut crc = Crc32::default();
+        text.lines().for_each(|line| crc.update_line(line));
+        text.push_str(&format!("{CHECKSUM_KEY}={:08x}", crc.value()));
+        return text;
+    }
+
+    /// # Description
+    /// Saves [`Tilemap`] to the file in the text save format with the checksum footer, see
+    /// [`Tilemap::to_file_string_with_checksum()`].
+    ///
+    /// # Arguments
+    /// * `path: P` - Path to the file. File will be created or overwritten.
+    ///
+    /// # Return
+    /// * [`Ok`] if [`Tilemap`] was saved.
+    /// * [`Err`] if file could not be written. Contains error message.
+    pub fn save_to_file_with_checksum<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), String> {
+        return std::fs::write(&path, self.to_file_string_with_checksum())
+            .map_err(|error| format!("Failed to save tilemap to {}: {error}", path.as_ref().display()));
+    }
+
+    /// # Description
+    /// Verifies integrity of the file saved by [`Tilemap::save_to_file_with_checksum()`].
+    ///
+    /// # Arguments
+    /// * `path: P` - Path to the file.
+    ///
+    /// # Return
+    /// * [`Ok`] if file is in the save format and its checksum matches its content.
+    /// * [`Err`] if file could not be read, is not in the save format, has no checksum footer
+    ///   (for example, because it is truncated), or checksum does not match. Contains error
+    ///   message.
+    pub fn verify<P: AsRef<std::path::Path>>(path: P) -> Result<(), String> {
+        let text = std::fs::read_to_string(&path)
+            .map_err(|error| format!("Failed to verify tilemap {}: {error}", path.as_ref().display()))?;
+
+        let mut parser = FileParser::new();
+        for line in text.lines() {
+            parser.push_line(line)?;
+        }
+
+        if !parser.is_verified() {
+            return Err(format!("Tilemap {} has no checksum, it could be truncated", path.as_ref().display()));
+        }
+
+        return parser.finish().map(|_| ());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl Default for Crc32 {
+    /// # Description
+    /// Creates checksum of no lines.
+    fn default() -> Crc32 {
+        return Crc32 { state: u32::MAX };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Builds lookup table of the reversed CRC32 polynomial `0xEDB88320`.
+const fn build_crc32_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut value = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            value = if value & 1 == 1 { (value >> 1) ^ 0xEDB88320 } else { value >> 1 };
+            bit += 1;
+        }
+        table[index] = value;
+        index += 1;
+    }
+
+    return table;
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+    use crate::tilemap::checksum::Crc32;
+
+    #[test]
+    fn crc32() {
+        let mut crc = Crc32::default();
+        crc.update_line("123456789");
+        assert_eq!(crc.value(), 0xe0117757);
+    }
+
+    #[test]
+    fn file_string_with_checksum() {
+        for text in ["#..\n.@.", ""] {
+            let tilemap = Tilemap::from_string(text, '.');
+            let loaded = Tilemap::from_file_string(&tilemap.to_file_string_with_checksum()).unwrap();
+            assert_eq!(loaded.to_file_string(), tilemap.to_file_string());
+        }
+
+        let text = Tilemap::from_string("#..\n.@.", '.').to_file_string_with_checksum();
+        for corrupted in [text.replace('@', "#"), text.replace("crc32=", "crc32=0"), format!("{text}\n#"), text.replace("crc32=", "crc32=x")] {
+            match Tilemap::from_file_string(&corrupted) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+
+    #[test]
+    fn verify() {
+        let path = std::env::temp_dir().join("char_tilemap_checksum_verify.tilemap");
+        let mut tilemap = Tilemap::from_string("#..\n.@.", '.');
+        tilemap.set_cluster(Vector2::ZERO, "e\u{301}").unwrap();
+
+        tilemap.save_to_file_with_checksum(&path).unwrap();
+        match Tilemap::verify(&path) {
+            Ok(_) => assert!(true),
+            Err(_) => assert!(false)
+        }
+        assert_eq!(Tilemap::load_from_file(&path).unwrap().build_clusters(), tilemap.build_clusters());
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        for corrupted in [text[..text.len() - 12].to_string(), text.replace('@', "."), tilemap.to_file_string()] {
+            std::fs::write(&path, corrupted).unwrap();
+            match Tilemap::verify(&path) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+
+        std::fs::remove_file(&path).unwrap();
+        match Tilemap::verify(&path) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+
+    #[test]
+    fn older_version() {
+        let path = std::env::temp_dir().join("char_tilemap_checksum_older_version.tilemap");
+        let checksum = |text: &str| {
+            let mut crc = Crc32::default();
+            text.split('\n').for_each(|line| crc.update_line(line));
+            return format!("{text}\ncrc32={:08x}", crc.value());
+        };
+        let text = "version=2\nempty_tile=.\nsize=2x1\n\n#.";
+
+        std::fs::write(&path, checksum(text)).unwrap();
+        match Tilemap::verify(&path) {
+            Ok(_) => assert!(true),
+            Err(_) => assert!(false)
+        }
+        assert_eq!(Tilemap::load_from_file(&path).unwrap().build(), "#.");
+
+        let migrated = checksum(&Tilemap::migrate(text).unwrap());
+        std::fs::write(&path, format!("{text}{}", &migrated[migrated.rfind('\n').unwrap()..])).unwrap();
+        match Tilemap::load_from_file(&path) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::HashMap;
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Source of chunks of the [`ChunkedTilemap`]. Chunks are requested when they are accessed for
+/// the first time or after they were evicted, so they can be generated or loaded on demand.
+/// Any `FnMut(Vector2) -> Tilemap` closure is a provider that discards evicted chunks.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::{ChunkProvider, Tilemap, Vector2};
+///
+/// struct Storage {
+///     saved: std::collections::HashMap<Vector2, Tilemap>,
+/// }
+///
+/// impl ChunkProvider for Storage {
+///     fn load_chunk(&mut self, chunk: Vector2) -> Tilemap {
+///         return self.saved.remove(&chunk).unwrap_or_else(|| Tilemap::new('.'));
+///     }
+///
+///     fn unload_chunk(&mut self, chunk: Vector2, tilemap: Tilemap) {
+///         self.saved.insert(chunk, tilemap);
+///     }
+/// }
+/// ```
+pub trait ChunkProvider {
+    /// # Description
+    /// Generates or loads the chunk.
+    ///
+    /// # Arguments
+    /// * `chunk: Vector2` - Coordinates of the chunk. Its top left corner is at the position
+    ///   `chunk.x * chunk_size.x, chunk.y * chunk_size.y` of the [`ChunkedTilemap`].
+    ///
+    /// # Return
+    /// [`Tilemap`] with tiles of the chunk at local positions. Tiles out of the chunk size are
+    /// never accessed.
+    fn load_chunk(&mut self, chunk: Vector2) -> Tilemap;
+
+    /// # Description
+    /// Takes the chunk that is evicted or unloaded, so it can be saved. Providers that regenerate
+    /// chunks do not need to implement it.
+    ///
+    /// # Arguments
+    /// * `chunk: Vector2` - Coordinates of the chunk.
+    /// * `tilemap: Tilemap` - Tiles of the chunk, including all changes.
+    fn unload_chunk(&mut self, _chunk: Vector2, _tilemap: Tilemap) {}
+}
+
+/// # Description
+/// Unbounded tilemap that consists of chunks of the same size, where only recently used chunks
+/// are kept in memory. Missing chunks are requested from the [`ChunkProvider`] when they are
+/// accessed, and the least recently used chunk is evicted when there are too many loaded chunks.
+///
+/// # Example
+/// ```rust
+/// let provider = |chunk: char_tilemap::Vector2| {
+///     let mut tilemap = char_tilemap::Tilemap::new('.');
+///     tilemap.set(char_tilemap::Vector2::ZERO, if chunk.x % 2 == 0 { 'A' } else { 'B' });
+///     return tilemap;
+/// };
+///
+/// let mut tilemap = char_tilemap::ChunkedTilemap::new(provider, char_tilemap::Vector2::new(16, 16), 4).unwrap();
+/// assert_eq!(tilemap.get(char_tilemap::Vector2::new(1_000_016, 32)), Some('B'));
+/// assert_eq!(tilemap.loaded_chunks().count(), 1);
+/// ```
+#[derive(Debug)]
+pub struct ChunkedTilemap<P: ChunkProvider> {
+    /// # Description
+    /// Source of chunks.
+    provider: P,
+    /// # Description
+    /// Size of every chunk.
+    chunk_size: Vector2,
+    /// # Description
+    /// Maximum number of chunks that are kept in memory.
+    max_loaded: usize,
+    /// # Description
+    /// Loaded chunks by their coordinates, with the time of the last access.
+    chunks: HashMap<Vector2, (Tilemap, u64)>,
+    /// # Description
+    /// Counter of accesses, which is used as time to find the least recently used chunk.
+    time: u64,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl<P: ChunkProvider> ChunkedTilemap<P> {
+    /// # Description
+    /// Creates new [`ChunkedTilemap`] without loaded chunks.
+    ///
+    /// # Arguments
+    /// * `provider: P` - Source of chunks.
+    /// * `chunk_size: Vector2` - Size of every chunk.
+    /// * `max_loaded: usize` - Maximum number of chunks that are kept in memory.
+    ///
+    /// # Return
+    /// * [`Ok`] with new instance of the [`ChunkedTilemap`].
+    /// * [`Err`] if chunk size is 0 on any axis or maximum number of chunks is 0. Contains error
+    ///   message.
+    pub fn new(provider: P, chunk_size: Vector2, max_loaded: usize) -> Result<ChunkedTilemap<P>, String> {
+        if chunk_size.x == 0 || chunk_size.y == 0 {
+            return Err(format!("Chunk size {chunk_size} must not be 0 on any axis"));
+        }
+        if max_loaded == 0 {
+            return Err(String::from("At least one chunk must be kept in memory"));
+        }
+
+        return Ok(ChunkedTilemap { provider, chunk_size, max_loaded, chunks: HashMap::new(), time: 0 });
+    }
+
+    /// # Description
+    /// Returns size of every chunk.
+    pub fn chunk_size(&self) -> Vector2 {
+        return self.chunk_size;
+    }
+
+    /// # Description
+    /// Returns source of chunks.
+    pub fn provider(&self) -> &P {
+        return &self.provider;
+    }
+
+    /// # Description
+    /// Returns mutable source of chunks.
+    pub fn provider_mut(&mut self) -> &mut P {
+        return &mut self.provider;
+    }
+
+    /// # Description
+    /// Returns coordinates of the chunk that contains the position.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position in the [`ChunkedTilemap`].
+    pub fn chunk_of(&self, position: Vector2) -> Vector2 {
+        return Vector2::new(position.x / self.chunk_size.x, position.y / self.chunk_size.y);
+    }
+
+    /// # Description
+    /// Returns value of the tile at the specified position. Chunk is loaded if needed.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with value of the tile if it exists.
+    /// * [`None`] if there is no tile at the specified position.
+    pub fn get(&mut self, position: Vector2) -> Option<char> {
+        let local = self.local_position(position);
+        let chunk = self.chunk_of(position);
+        return self.chunk(chunk).get(local);
+    }
+
+    /// # Description
+    /// Sets value of the tile at the specified position. Chunk is loaded if needed.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    /// * `value: char` - Value of the tile.
+    pub fn set(&mut self, position: Vector2, value: char) {
+        let local = self.local_position(position);
+        let chunk = self.chunk_of(position);
+        self.chunk_mut(chunk).set(local, value);
+    }
+
+    /// # Description
+    /// Removes tile at the specified position. Chunk is loaded if needed.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with value of the removed tile.
+    /// * [`None`] if there was no tile at the specified position.
+    pub fn remove_tile(&mut self, position: Vector2) -> Option<char> {
+        let local = self.local_position(position);
+        let chunk = self.chunk_of(position);
+        return self.chunk_mut(chunk).take_tile(local);
+    }
+
+    /// # Description
+    /// Returns the chunk. It is loaded if needed.
+    ///
+    /// # Arguments
+    /// * `chunk: Vector2` - Coordinates of the chunk.
+    pub fn chunk(&mut self, chunk: Vector2) -> &Tilemap {
+        return self.chunk_mut(chunk);
+    }
+
+    /// # Description
+    /// Returns mutable chunk, so all functions of [`Tilemap`] can be used on it. It is loaded
+    /// if needed. Tiles that are set out of the chunk size are ignored by other functions.
+    ///
+    /// # Arguments
+    /// * `chunk: Vector2` - Coordinates of the chunk.
+    pub fn chunk_mut(&mut self, chunk: Vector2) -> &mut Tilemap {
+        self.time += 1;
+        if !self.chunks.contains_key(&chunk) {
+            if self.chunks.len() >= self.max_loaded {
+                self.evict();
+            }
+            let tilemap = self.provider.load_chunk(chunk);
+            self.chunks.insert(chunk, (tilemap, self.time));
+        }
+
+        let (tilemap, time) = self.chunks.get_mut(&chunk).expect("Chunk was just loaded");
+        *time = self.time;
+        return tilemap;
+    }
+
+    /// # Description
+    /// Checks if the chunk is in memory.
+    ///
+    /// # Arguments
+    /// * `chunk: Vector2` - Coordinates of the chunk.
+    pub fn is_loaded(&self, chunk: Vector2) -> bool {
+        return self.chunks.contains_key(&chunk);
+    }
+
+    /// # Description
+    /// Returns coordinates of all chunks that are in memory, in arbitrary order.
+    pub fn loaded_chunks(&self) -> impl Iterator<Item = Vector2> + '_ {
+        return self.chunks.keys().copied();
+    }
+
+    /// # Description
+    /// Gives the chunk back to the [`ChunkProvider`] and removes it from memory.
+    ///
+    /// # Arguments
+    /// * `chunk: Vector2` - Coordinates of the chunk.
+    ///
+    /// # Return
+    /// `true` if chunk was loaded, `false` otherwise.
+    pub fn unload(&mut self, chunk: Vector2) -> bool {
+        let Some((tilemap, _)) = self.chunks.remove(&chunk) else {
+            return false;
+        };
+
+        self.provider.unload_chunk(chunk, tilemap);
+        return true;
+    }
+
+    /// # Description
+    /// Gives all chunks back to the [`ChunkProvider`], for example before saving the world.
+    pub fn unload_all(&mut self) {
+        for (chunk, (tilemap, _)) in self.chunks.drain() {
+            self.provider.unload_chunk(chunk, tilemap);
+        }
+    }
+
+    /// # Description
+    /// Returns position inside of the chunk that contains the position.
+    fn local_position(&self, position: Vector2) -> Vector2 {
+        return Vector2::new(position.x % self.chunk_size.x, position.y % self.chunk_size.y);
+    }
+
+    /// # Description
+    /// Unloads the least recently used chunk.
+    fn evict(&mut self) {
+        let oldest = self.chunks.iter().min_by_key(|(_, (_, time))| *time).map(|(chunk, _)| *chunk);
+        if let Some(chunk) = oldest {
+            self.unload(chunk);
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl<F: FnMut(Vector2) -> Tilemap> ChunkProvider for F {
+    /// # Description
+    /// Generates the chunk by calling the closure.
+    fn load_chunk(&mut self, chunk: Vector2) -> Tilemap {
+        return self(chunk);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use crate::tilemap::{ChunkProvider, ChunkedTilemap, Tilemap, Vector2};
+
+    #[derive(Default)]
+    struct Storage {
+        saved: HashMap<Vector2, Tilemap>,
+        loads: usize,
+    }
+
+    impl ChunkProvider for Storage {
+        fn load_chunk(&mut self, chunk: Vector2) -> Tilemap {
+            self.loads += 1;
+            return self.saved.remove(&chunk).unwrap_or_else(|| Tilemap::new('.'));
+        }
+
+        fn unload_chunk(&mut self, chunk: Vector2, tilemap: Tilemap) {
+            self.saved.insert(chunk, tilemap);
+        }
+    }
+
+    #[test]
+    fn new() {
+        for (chunk_size, max_loaded) in [(Vector2::new(0, 4), 1), (Vector2::new(4, 4), 0)] {
+            match ChunkedTilemap::new(Storage::default(), chunk_size, max_loaded) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+
+    #[test]
+    fn streaming() {
+        let mut tilemap = ChunkedTilemap::new(Storage::default(), Vector2::new(4, 4), 2).unwrap();
+        assert_eq!(tilemap.chunk_of(Vector2::new(9, 3)), Vector2::new(2, 0));
+
+        tilemap.set(Vector2::new(1, 1), 'A');
+        tilemap.set(Vector2::new(5, 1), 'B');
+        assert_eq!(tilemap.get(Vector2::new(1, 1)), Some('A'));
+        assert_eq!(tilemap.provider().loads, 2);
+
+        // Chunk (1, 0) is the least recently used one
+        tilemap.set(Vector2::new(9, 9), 'C');
+        assert!(tilemap.is_loaded(Vector2::ZERO));
+        assert!(!tilemap.is_loaded(Vector2::new(1, 0)));
+        assert_eq!(tilemap.loaded_chunks().count(), 2);
+
+        assert_eq!(tilemap.get(Vector2::new(5, 1)), Some('B'));
+        assert_eq!(tilemap.remove_tile(Vector2::new(5, 1)), Some('B'));
+        assert_eq!(tilemap.get(Vector2::new(5, 1)), None);
+        assert_eq!(tilemap.provider().loads, 4);
+
+        assert!(tilemap.unload(Vector2::new(1, 0)));
+        assert!(!tilemap.unload(Vector2::new(1, 0)));
+        tilemap.unload_all();
+        assert_eq!(tilemap.loaded_chunks().count(), 0);
+        assert_eq!(tilemap.provider().saved.len(), 3);
+        assert_eq!(tilemap.provider().saved[&Vector2::new(2, 2)].get(Vector2::ONE), Some('C'));
+    }
+
+    #[test]
+    fn closure_provider() {
+        let mut tilemap = ChunkedTilemap::new(|chunk: Vector2| Tilemap::from_string(&chunk.x.to_string(), '.'),
+                                              Vector2::new(8, 8), 1).unwrap();
+        assert_eq!(tilemap.get(Vector2::new(24, 96)), Some('3'));
+        assert_eq!(tilemap.get(Vector2::new(1, 0)), None);
+    }
+}
@@ -0,0 +1,331 @@
+use std::ops::{Deref, DerefMut};
+use crate::tilemap::{Tile, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Mutable view into a rectangular part of the [`Tilemap`], created by [`Tilemap::chunks_mut()`].
+/// It holds a copy of the tiles of its part, so several views can be changed at the same time,
+/// for example from different threads. View can not grow, all positions are local to its top
+/// left corner. It is not a storage mode: the [`Tilemap`] keeps all tiles in one set, see
+/// [`crate::ChunkedTilemap`] for storage that consists of chunks.
+#[derive(Debug, Clone)]
+pub struct ChunkView {
+    /// # Description
+    /// Position of the top left corner of the chunk in the [`Tilemap`].
+    origin: Vector2,
+    /// # Description
+    /// Tiles of the chunk.
+    tilemap: Tilemap,
+}
+
+/// # Description
+/// Guard that copies tiles of the [`Tilemap`] into disjoint [`ChunkView`]s, gives mutable access
+/// to all of them as a slice, and replaces all tiles of the [`Tilemap`] with tiles of the views
+/// when dropped. Since views are disjoint, the slice can be processed in parallel by any means,
+/// such as `rayon` or scoped threads. Splitting and writing back take time proportional to the
+/// number of all tiles.
+///
+/// # Example
+/// ```rust
+/// let mut tilemap = char_tilemap::Tilemap::from_string("....\n....", '.');
+///
+/// let mut chunks = tilemap.chunks_mut(char_tilemap::Vector2::new(2, 2)).unwrap();
+/// std::thread::scope(|scope| {
+///     for chunk in chunks.iter_mut() {
+///         scope.spawn(move || chunk.set(char_tilemap::Vector2::ZERO, '#').unwrap());
+///     }
+/// });
+/// drop(chunks);
+///
+/// assert_eq!(tilemap.build(), "#.#.\n....");
+/// ```
+#[derive(Debug)]
+pub struct ChunksMut<'a> {
+    /// # Description
+    /// [`Tilemap`] into which views are written back.
+    tilemap: &'a mut Tilemap,
+    /// # Description
+    /// Views of all chunks of the [`Tilemap`] in row order.
+    chunks: Vec<ChunkView>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl ChunkView {
+    /// # Description
+    /// Returns position of the top left corner of the chunk in the [`Tilemap`].
+    pub fn origin(&self) -> Vector2 {
+        return self.origin;
+    }
+
+    /// # Description
+    /// Returns size of the chunk. Views at the right and bottom edges can be smaller than others.
+    pub fn size(&self) -> Vector2 {
+        return self.tilemap.size();
+    }
+
+    /// # Description
+    /// Returns value of the tile at the local position if it exists.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position relative to the top left corner of the chunk.
+    pub fn get(&self, position: Vector2) -> Option<char> {
+        return self.tilemap.get(position);
+    }
+
+    /// # Description
+    /// Sets value of the tile at the local position.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position relative to the top left corner of the chunk.
+    /// * `value: char` - New value of the tile.
+    ///
+    /// # Return
+    /// * [`Ok`] if tile was set.
+    /// * [`Err`] if position is out of the chunk, or tile was not set, see
+    ///   [`Tilemap::try_set()`]. Contains error message.
+    pub fn set(&mut self, position: Vector2, value: char) -> Result<(), String> {
+        self.check_bounds(position)?;
+        return self.tilemap.try_set(position, value).map_err(String::from);
+    }
+
+    /// # Description
+    /// Removes tile at the local position if it exists.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position relative to the top left corner of the chunk.
+    ///
+    /// # Return
+    /// * [`Ok`] if position is inside of the chunk.
+    /// * [`Err`] if position is out of the chunk. Contains error message.
+    pub fn remove(&mut self, position: Vector2) -> Result<(), String> {
+        self.check_bounds(position)?;
+        let _ = self.tilemap.remove_tile(position);
+        return Ok(());
+    }
+
+    /// # Description
+    /// Returns read-only [`Tilemap`] with tiles of the chunk, so read-only algorithms can be used.
+    pub fn tilemap(&self) -> &Tilemap {
+        return &self.tilemap;
+    }
+
+    /// # Description
+    /// Checks that local position is inside of the chunk.
+    fn check_bounds(&self, position: Vector2) -> Result<(), String> {
+        let size = self.tilemap.size();
+        if position.x >= size.x || position.y >= size.y {
+            return Err(format!("Position {position} is out of the chunk of size {size}"));
+        }
+
+        return Ok(());
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Copies tiles of the [`Tilemap`] into views of chunks of the specified size that can be
+    /// changed independently. Changes are written back when returned [`ChunksMut`] is dropped.
+    /// Storage of the [`Tilemap`] is not chunked, see [`ChunkView`].
+    ///
+    /// Views reject tiles out of [`crate::TilemapLimits::max_size`]. Since views do not know about each
+    /// other, [`crate::TilemapLimits::max_tiles`] is enforced on write-back: new tiles that exceed it are
+    /// dropped in row-major order.
+    ///
+    /// # Arguments
+    /// * `chunk_size: Vector2` - Size of every chunk. Chunks at the right and bottom edges are
+    ///   clipped by the [`Tilemap`] bounds.
+    ///
+    /// # Return
+    /// * [`Ok`] with [`ChunksMut`] guard.
+    /// * [`Err`] if any component of the chunk size is 0. Contains error message.
+    pub fn chunks_mut(&mut self, chunk_size: Vector2) -> Result<ChunksMut<'_>, String> {
+        if chunk_size.x == 0 || chunk_size.y == 0 {
+            return Err(format!("Chunk size {chunk_size} must not be zero"));
+        }
+
+        let mut chunks = Vec::new();
+        for y in (0..self.size.y).step_by(chunk_size.y) {
+            for x in (0..self.size.x).step_by(chunk_size.x) {
+                let origin = Vector2::new(x, y);
+                let size = Vector2::new(std::cmp::min(chunk_size.x, self.size.x - x),
+                                        std::cmp::min(chunk_size.y, self.size.y - y));
+                let mut tilemap = Tilemap::new(self.empty_tile);
+                tilemap.control_chars = self.control_chars;
+                tilemap.limits.max_size = self.limits.max_size
+                    .map(|max_size| Vector2::new(max_size.x.saturating_sub(x), max_size.y.saturating_sub(y)));
+                tilemap.size = size;
+                chunks.push(ChunkView { origin, tilemap });
+            }
+        }
+
+        let columns = self.size.x.div_ceil(chunk_size.x);
+        for tile in self.tiles.iter() {
+            let index = tile.position.y / chunk_size.y * columns + tile.position.x / chunk_size.x;
+            let chunk = &mut chunks[index];
+            chunk.tilemap.tiles.push(Tile { position: tile.position - chunk.origin, value: tile.value });
+        }
+
+        return Ok(ChunksMut { tilemap: self, chunks });
+    }
+
+    /// # Description
+    /// Applies the function to views of all chunks of the [`Tilemap`] in parallel using `rayon`,
+    /// see [`Tilemap::chunks_mut()`].
+    ///
+    /// # Arguments
+    /// * `chunk_size: Vector2` - Size of every chunk, see [`Tilemap::chunks_mut()`].
+    /// * `function: F` - Function that is applied to every chunk.
+    ///
+    /// # Return
+    /// * [`Ok`] when all chunks were processed and written back.
+    /// * [`Err`] if any component of the chunk size is 0. Contains error message.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("....\n....", '.');
+    /// tilemap.par_chunks_mut(char_tilemap::Vector2::new(1, 2), |chunk| {
+    ///     if chunk.origin().x % 2 == 1 {
+    ///         chunk.set(char_tilemap::Vector2::new(0, 1), '#').unwrap();
+    ///     }
+    /// }).unwrap();
+    /// assert_eq!(tilemap.build(), "....\n.#.#");
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_chunks_mut<F>(&mut self, chunk_size: Vector2, function: F) -> Result<(), String>
+        where F: Fn(&mut ChunkView) + Sync + Send {
+        use rayon::prelude::*;
+
+        self.chunks_mut(chunk_size)?.par_iter_mut().for_each(function);
+        return Ok(());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl Deref for ChunksMut<'_> {
+    type Target = [ChunkView];
+
+    /// # Description
+    /// Returns all chunks.
+    fn deref(&self) -> &[ChunkView] {
+        return &self.chunks;
+    }
+}
+
+impl DerefMut for ChunksMut<'_> {
+    /// # Description
+    /// Returns all chunks for mutation.
+    fn deref_mut(&mut self) -> &mut [ChunkView] {
+        return &mut self.chunks;
+    }
+}
+
+impl Drop for ChunksMut<'_> {
+    /// # Description
+    /// Replaces tiles of the [`Tilemap`] with tiles of all views. New tiles that exceed
+    /// [`crate::TilemapLimits::max_tiles`] are dropped in row-major order.
+    fn drop(&mut self) {
+        let mut tiles: Vec<Tile> = self.chunks.iter().flat_map(|chunk| {
+            return chunk.tilemap.tiles.iter().map(|tile| Tile { position: tile.position + chunk.origin, value: tile.value });
+        }).collect();
+
+        if let Some(max_tiles) = self.tilemap.limits.max_tiles {
+            tiles.sort();
+            let existing = tiles.iter().filter(|tile| self.tilemap.get(tile.position).is_some()).count();
+            let mut free = max_tiles.saturating_sub(existing);
+            tiles.retain(|tile| {
+                if self.tilemap.get(tile.position).is_some() {
+                    return true;
+                }
+                if free == 0 {
+                    return false;
+                }
+                free -= 1;
+                return true;
+            });
+        }
+
+        let old_tiles = std::mem::replace(&mut self.tilemap.tiles, sorted_vec::SortedSet::from_unsorted(tiles));
+        self.tilemap.record_replaced(&old_tiles);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, TilemapLimits, Vector2};
+
+    #[test]
+    fn chunks_mut() {
+        let mut tilemap = Tilemap::from_string("#....\n.....\n....#", '.');
+
+        {
+            let mut chunks = tilemap.chunks_mut(Vector2::new(2, 2)).unwrap();
+            assert_eq!(chunks.len(), 6);
+            assert_eq!(chunks[2].origin(), Vector2::new(4, 0));
+            assert_eq!(chunks[2].size(), Vector2::new(1, 2));
+            assert_eq!(chunks[5].get(Vector2::ZERO), Some('#'));
+
+            chunks[0].remove(Vector2::ZERO).unwrap();
+            chunks[4].set(Vector2::new(1, 0), '@').unwrap();
+            for (chunk, position) in [(1, Vector2::new(2, 0)), (5, Vector2::ONE)] {
+                match chunks[chunk].set(position, 'X') {
+                    Ok(_) => assert!(false),
+                    Err(_) => assert!(true)
+                }
+            }
+        }
+
+        assert_eq!(tilemap.build(), ".....\n.....\n...@#");
+        assert_eq!(tilemap.size(), Vector2::new(5, 3));
+        match tilemap.chunks_mut(Vector2::new(0, 1)) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        };
+    }
+
+    #[test]
+    fn chunks_in_threads() {
+        let mut tilemap = Tilemap::from_string("......\n......\n......", '.');
+
+        let mut chunks = tilemap.chunks_mut(Vector2::new(3, 1)).unwrap();
+        std::thread::scope(|scope| {
+            for chunk in chunks.iter_mut() {
+                scope.spawn(move || {
+                    let value = char::from_digit((chunk.origin().y * 2 + chunk.origin().x / 3) as u32, 10).unwrap();
+                    chunk.set(Vector2::new(1, 0), value).unwrap();
+                });
+            }
+        });
+        drop(chunks);
+
+        assert_eq!(tilemap.build(), ".0..1.\n.2..3.\n.4..5.");
+    }
+
+    #[test]
+    fn limits() {
+        let mut tilemap = Tilemap::from_string("#...\n....", '.');
+        tilemap.limits = TilemapLimits { max_tiles: Some(3), max_size: Some(Vector2::new(3, 2)) };
+
+        {
+            let mut chunks = tilemap.chunks_mut(Vector2::ONE).unwrap();
+            for chunk in chunks.iter_mut() {
+                let result = chunk.set(Vector2::ZERO, '@');
+                assert_eq!(result.is_ok(), chunk.origin().x < 3);
+            }
+        }
+
+        assert_eq!(tilemap.build(), "@@@.\n....");
+    }
+}
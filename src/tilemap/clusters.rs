@@ -0,0 +1,365 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use crate::tilemap::{Tilemap, Vector2};
+use crate::tilemap::file_format::{parse_char, CLUSTER_KEY};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// First char of the Unicode Private Use Area, which is used to represent grapheme clusters
+/// in tiles.
+pub(super) const FIRST_CLUSTER_CHAR: u32 = 0xE000;
+
+/// # Description
+/// Last char of the Unicode Private Use Area.
+const LAST_CLUSTER_CHAR: u32 = 0xF8FF;
+
+/// # Description
+/// Ranges of chars that are displayed two columns wide by most terminals: Hangul Jamo,
+/// CJK symbols and ideographs, Hangul syllables, CJK compatibility, full-width forms,
+/// regional indicators (flags), emoji and supplementary ideographs.
+#[cfg(not(feature = "unicode-width"))]
+const WIDE_RANGES: [(u32, u32); 12] = [
+    (0x1100, 0x115F), (0x2E80, 0x303E), (0x3041, 0xA4CF), (0xAC00, 0xD7A3),
+    (0xF900, 0xFAFF), (0xFE30, 0xFE4F), (0xFF00, 0xFF60), (0xFFE0, 0xFFE6),
+    (0x1F1E6, 0x1F1FF), (0x1F300, 0x1F64F), (0x1F900, 0x1F9FF), (0x20000, 0x3FFFD),
+];
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Sets tile at the specified position to the grapheme cluster, such as an emoji sequence or
+    /// a letter with combining characters. Clusters of a single char are stored as regular tiles.
+    /// Longer clusters are represented by a char of the Unicode Private Use Area, so they work
+    /// with everything that works with tiles, and are turned back into clusters by
+    /// [`Tilemap::cluster()`] and [`Tilemap::build_clusters()`]. Same clusters share the same char.
+    /// Chars of the Private Use Area that are already used by tiles, such as icon glyphs, are
+    /// never allocated for clusters, so they are not mistaken for clusters.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    /// * `cluster: &str` - Value of the tile. It is not checked to be a single grapheme cluster.
+    ///
+    /// # Return
+    /// * [`Ok`] with the char that represents the cluster.
+    /// * [`Err`] if cluster is empty, contains whitespaces or control chars, there are no
+    ///   more free chars to represent new clusters, or tile was not set, see
+    ///   [`Tilemap::try_set()`]. Contains error message.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('.');
+    /// tilemap.set_cluster(char_tilemap::Vector2::ZERO, "e\u{301}").unwrap();
+    /// assert_eq!(tilemap.cluster(char_tilemap::Vector2::ZERO).unwrap(), "e\u{301}");
+    /// ```
+    pub fn set_cluster(&mut self, position: Vector2, cluster: &str) -> Result<char, String> {
+        if cluster.is_empty() || cluster.chars().any(|value| value.is_whitespace() || value.is_control()) {
+            return Err(format!("Invalid grapheme cluster \"{}\"", cluster.escape_debug()));
+        }
+
+        let mut chars = cluster.chars();
+        let (value, is_new) = match (chars.next(), chars.next()) {
+            (Some(value), None) => (value, false),
+            _ => self.cluster_char(cluster)?,
+        };
+
+        if let Err(error) = self.try_set(position, value) {
+            if is_new {
+                self.clusters.remove(&value);
+            }
+            return Err(error.into());
+        }
+
+        return Ok(value);
+    }
+
+    /// # Description
+    /// Returns value of the tile at the specified position as a grapheme cluster.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with the cluster set by [`Tilemap::set_cluster()`], or with the char of the
+    ///   tile if it does not represent a cluster.
+    /// * [`None`] if there is no tile at the specified position.
+    pub fn cluster(&self, position: Vector2) -> Option<Cow<'_, str>> {
+        return self.get(position).map(|value| self.cluster_of(value));
+    }
+
+    /// # Description
+    /// Builds [`Tilemap`] where all tiles are turned into their grapheme clusters. Wide clusters,
+    /// such as emoji and CJK characters, cover the next column if it is empty, so the following
+    /// columns stay aligned.
+    ///
+    /// # Return
+    /// A new [`String`] that contains representation of the [`Tilemap`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('.');
+    /// tilemap.set_cluster(char_tilemap::Vector2::ZERO, "\u{1F3F3}\u{FE0F}").unwrap();
+    /// tilemap.set(char_tilemap::Vector2::new(2, 0), '#');
+    /// assert_eq!(tilemap.build_clusters(), "\u{1F3F3}\u{FE0F}#");
+    /// ```
+    pub fn build_clusters(&self) -> String {
+        let mut result = String::new();
+
+        for y in 0..self.size.y {
+            if y > 0 {
+                result.push('\n');
+            }
+
+            let mut x = 0;
+            while x < self.size.x {
+                let Some(value) = self.get(Vector2::new(x, y)) else {
+                    result.push(self.empty_tile);
+                    x += 1;
+                    continue;
+                };
+
+                let cluster = self.cluster_of(value);
+                result.push_str(&cluster);
+                let covers_next = x + 1 < self.size.x && self.get(Vector2::new(x + 1, y)).is_none();
+                x += if cluster_width(&cluster) == 2 && covers_next { 2 } else { 1 };
+            }
+        }
+
+        return result;
+    }
+
+    /// # Description
+    /// Builds [`Tilemap`] where all tiles are turned into their grapheme clusters, and every
+    /// cell takes two columns if at least one tile is wide. Narrow tiles are followed by a space
+    /// and empty tiles are repeated, so all columns stay aligned regardless of neighbours. If
+    /// there are no wide tiles, result is the same as of [`Tilemap::build_clusters()`].
+    ///
+    /// Width is approximated with built-in ranges of wide chars, or taken from the Unicode tables
+    /// if `unicode-width` feature is enabled.
+    ///
+    /// # Return
+    /// A new [`String`] that contains representation of the [`Tilemap`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("..\n.#", '.');
+    /// tilemap.set_cluster(char_tilemap::Vector2::ZERO, "\u{1F332}").unwrap();
+    /// assert_eq!(tilemap.build_aligned(), "\u{1F332}..\n..# ");
+    /// ```
+    pub fn build_aligned(&self) -> String {
+        let wide = self.tiles.iter().any(|tile| cluster_width(&self.cluster_of(tile.value)) == 2);
+        if !wide {
+            return self.build_clusters();
+        }
+
+        let mut result = String::new();
+        for y in 0..self.size.y {
+            if y > 0 {
+                result.push('\n');
+            }
+
+            for x in 0..self.size.x {
+                match self.get(Vector2::new(x, y)) {
+                    Some(value) => {
+                        let cluster = self.cluster_of(value);
+                        result.push_str(&cluster);
+                        if cluster_width(&cluster) < 2 {
+                            result.push(' ');
+                        }
+                    },
+                    None => {
+                        result.push(self.empty_tile);
+                        result.push(self.empty_tile);
+                    },
+                }
+            }
+        }
+
+        return result;
+    }
+
+    /// # Description
+    /// Returns cluster that is represented by the char, or the char itself. Only chars allocated
+    /// by [`Tilemap::set_cluster()`] represent clusters.
+    pub(crate) fn cluster_of(&self, value: char) -> Cow<'_, str> {
+        return match self.clusters.get(&value) {
+            Some(cluster) => Cow::Borrowed(cluster),
+            None => Cow::Owned(value.to_string()),
+        };
+    }
+
+    /// # Description
+    /// Returns char that represents the cluster, and whether it was allocated for the cluster
+    /// now. New clusters get the first char of the Private Use Area that is not used by tiles or
+    /// other clusters.
+    fn cluster_char(&mut self, cluster: &str) -> Result<(char, bool), String> {
+        if let Some((value, _)) = self.clusters.iter().find(|(_, known)| *known == cluster) {
+            return Ok((*value, false));
+        }
+
+        let used: BTreeSet<char> = self.tiles.iter()
+            .map(|tile| tile.value)
+            .chain([self.empty_tile])
+            .filter(|value| is_cluster_char(*value))
+            .collect();
+        let value = (FIRST_CLUSTER_CHAR..=LAST_CLUSTER_CHAR)
+            .filter_map(char::from_u32)
+            .find(|value| !used.contains(value) && !self.clusters.contains_key(value))
+            .ok_or_else(|| String::from("There are no more free chars to represent grapheme clusters"))?;
+
+        self.clusters.insert(value, cluster.to_string());
+        return Ok((value, true));
+    }
+
+    /// # Description
+    /// Returns header lines of the save format that store grapheme clusters.
+    pub(super) fn cluster_lines(&self) -> String {
+        return self.clusters.iter()
+            .map(|(value, cluster)| format!("{CLUSTER_KEY}={cluster}@{value}\n"))
+            .collect();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Checks if the char belongs to the Private Use Area, which chars represent clusters.
+fn is_cluster_char(value: char) -> bool {
+    return (FIRST_CLUSTER_CHAR..=LAST_CLUSTER_CHAR).contains(&(value as u32));
+}
+
+/// # Description
+/// Parses header value that should contain grapheme cluster and its char in format
+/// `{cluster}@{char}`. Cluster may contain `@` itself, since the char follows the last one.
+pub(super) fn parse_cluster(value: &str) -> Result<(char, String), String> {
+    let (cluster, value) = value.rsplit_once('@')
+        .ok_or_else(|| format!("Expected grapheme cluster in format {{cluster}}@{{char}}, but got \"{value}\""))?;
+    let value = parse_char(value)?;
+    if cluster.is_empty() || !is_cluster_char(value) {
+        return Err(format!("Invalid grapheme cluster \"{cluster}\" of the char '{}'", value.escape_unicode()));
+    }
+
+    return Ok((value, cluster.to_string()));
+}
+
+/// # Description
+/// Returns number of terminal columns that the cluster takes, which is either 1 or 2 for
+/// non-empty clusters. Width is taken from the Unicode tables.
+#[cfg(feature = "unicode-width")]
+pub(crate) fn cluster_width(cluster: &str) -> usize {
+    if cluster.is_empty() {
+        return 0;
+    }
+
+    return unicode_width::UnicodeWidthStr::width(cluster).clamp(1, 2);
+}
+
+/// # Description
+/// Returns number of terminal columns that the cluster takes. Cluster is considered wide if its
+/// first char is wide, or if it is followed by the emoji presentation selector.
+#[cfg(not(feature = "unicode-width"))]
+pub(crate) fn cluster_width(cluster: &str) -> usize {
+    let Some(first) = cluster.chars().next() else {
+        return 0;
+    };
+
+    let code = first as u32;
+    let wide = WIDE_RANGES.iter().any(|(start, end)| (*start..=*end).contains(&code)) || cluster.contains('\u{FE0F}');
+    return if wide { 2 } else { 1 };
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, TilemapLimits, Vector2};
+    use crate::tilemap::clusters::cluster_width;
+
+    #[test]
+    fn set_cluster() {
+        let mut tilemap = Tilemap::new('.');
+
+        let first = tilemap.set_cluster(Vector2::ZERO, "\u{1F469}\u{200D}\u{1F680}").unwrap();
+        let second = tilemap.set_cluster(Vector2::new(1, 0), "e\u{301}").unwrap();
+        assert_ne!(first, second);
+        assert_eq!(tilemap.set_cluster(Vector2::new(2, 0), "\u{1F469}\u{200D}\u{1F680}"), Ok(first));
+        assert_eq!(tilemap.set_cluster(Vector2::new(3, 0), "#"), Ok('#'));
+
+        assert_eq!(tilemap.cluster(Vector2::ZERO).unwrap(), "\u{1F469}\u{200D}\u{1F680}");
+        assert_eq!(tilemap.cluster(Vector2::new(1, 0)).unwrap(), "e\u{301}");
+        assert_eq!(tilemap.cluster(Vector2::new(3, 0)).unwrap(), "#");
+        assert_eq!(tilemap.cluster(Vector2::new(4, 0)), None);
+
+        for cluster in ["", "a b", "a\n"] {
+            match tilemap.set_cluster(Vector2::ZERO, cluster) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+
+    #[test]
+    fn private_use_glyphs() {
+        let mut tilemap = Tilemap::from_string("\u{E000}\u{E002}.", '.');
+        let first = tilemap.set_cluster(Vector2::new(2, 0), "e\u{301}").unwrap();
+        let second = tilemap.set_cluster(Vector2::new(3, 0), "a\u{301}").unwrap();
+        assert_eq!((first, second), ('\u{E001}', '\u{E003}'));
+
+        assert_eq!(tilemap.cluster(Vector2::ZERO).unwrap(), "\u{E000}");
+        assert_eq!(tilemap.cluster(Vector2::new(1, 0)).unwrap(), "\u{E002}");
+        assert_eq!(tilemap.build_clusters(), "\u{E000}\u{E002}e\u{301}a\u{301}");
+
+        let loaded = Tilemap::from_file_string(&tilemap.to_file_string()).unwrap();
+        assert_eq!(loaded.build_clusters(), tilemap.build_clusters());
+    }
+
+    #[test]
+    fn set_cluster_over_limits() {
+        let mut tilemap = Tilemap::from_string("#", '.');
+        tilemap.limits = TilemapLimits { max_tiles: Some(1), max_size: None };
+
+        match tilemap.set_cluster(Vector2::new(1, 0), "e\u{301}") {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+        assert_eq!(tilemap.build_clusters(), "#");
+        assert_eq!(tilemap.set_cluster(Vector2::ZERO, "a\u{301}"), Ok('\u{E000}'));
+    }
+
+    #[test]
+    fn build_clusters() {
+        let mut tilemap = Tilemap::from_string("....\n....", '.');
+        tilemap.set_cluster(Vector2::ZERO, "\u{1F47E}").unwrap();
+        tilemap.set_cluster(Vector2::new(1, 1), "\u{1F1FA}\u{1F1E6}").unwrap();
+        tilemap.set(Vector2::new(2, 1), '#');
+
+        assert_eq!(tilemap.build_clusters(), "\u{1F47E}..\n.\u{1F1FA}\u{1F1E6}#.");
+    }
+
+    #[test]
+    fn build_aligned() {
+        let mut tilemap = Tilemap::from_string("#.\n.#", '.');
+        assert_eq!(tilemap.build_aligned(), "#.\n.#");
+
+        tilemap.set_cluster(Vector2::new(1, 0), "\u{4E2D}").unwrap();
+        assert_eq!(tilemap.build_aligned(), "# \u{4E2D}\n..# ");
+    }
+
+    #[test]
+    fn width() {
+        assert_eq!(cluster_width(""), 0);
+        assert_eq!(cluster_width("a"), 1);
+        assert_eq!(cluster_width("e\u{301}"), 1);
+        assert_eq!(cluster_width("\u{4E2D}"), 2);
+        assert_eq!(cluster_width("\u{2764}\u{FE0F}"), 2);
+    }
+}
@@ -0,0 +1,136 @@
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Color that can be used by colored renderers, such as [`crate::AnsiRenderer`] and
+/// [`crate::HtmlRenderer`].
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Color {
+    /// # Description
+    /// Basic black color.
+    Black,
+    /// # Description
+    /// Basic red color.
+    Red,
+    /// # Description
+    /// Basic green color.
+    Green,
+    /// # Description
+    /// Basic yellow color.
+    Yellow,
+    /// # Description
+    /// Basic blue color.
+    Blue,
+    /// # Description
+    /// Basic magenta color.
+    Magenta,
+    /// # Description
+    /// Basic cyan color.
+    Cyan,
+    /// # Description
+    /// Basic white color.
+    White,
+    /// # Description
+    /// True color described by its components.
+    /// # Value
+    /// * `u8` - Red component.
+    /// * `u8` - Green component.
+    /// * `u8` - Blue component.
+    Rgb(u8, u8, u8),
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Color {
+    /// # Description
+    /// Returns ANSI escape sequence that sets this color as a foreground color of the terminal.
+    ///
+    /// # Return
+    /// [`String`] with ANSI escape sequence.
+    ///
+    /// # Example
+    /// ```rust
+    /// assert_eq!(char_tilemap::Color::Red.ansi_foreground(), "\x1b[31m");
+    /// ```
+    pub fn ansi_foreground(&self) -> String {
+        return match self {
+            Color::Rgb(red, green, blue) => format!("\x1b[38;2;{red};{green};{blue}m"),
+            _ => format!("\x1b[{}m", 30 + self.ansi_index()),
+        };
+    }
+
+    /// # Description
+    /// Returns HTML hex representation of this color.
+    ///
+    /// # Return
+    /// [`String`] in format `#rrggbb`.
+    ///
+    /// # Example
+    /// ```rust
+    /// assert_eq!(char_tilemap::Color::Rgb(255, 0, 16).html(), "#ff0010");
+    /// ```
+    pub fn html(&self) -> String {
+        let (red, green, blue) = self.rgb();
+        return format!("#{red:02x}{green:02x}{blue:02x}");
+    }
+
+    /// # Description
+    /// Returns RGB components of this color. Basic colors are converted to their common
+    /// terminal values.
+    ///
+    /// # Return
+    /// Tuple of red, green and blue components.
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        return match self {
+            Color::Black => (0, 0, 0),
+            Color::Red => (205, 49, 49),
+            Color::Green => (13, 188, 121),
+            Color::Yellow => (229, 229, 16),
+            Color::Blue => (36, 114, 200),
+            Color::Magenta => (188, 63, 188),
+            Color::Cyan => (17, 168, 205),
+            Color::White => (229, 229, 229),
+            Color::Rgb(red, green, blue) => (*red, *green, *blue),
+        };
+    }
+
+    /// # Description
+    /// Returns index of the basic color in the ANSI color table.
+    fn ansi_index(&self) -> u8 {
+        return match self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White | Color::Rgb(_, _, _) => 7,
+        };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::Color;
+
+    #[test]
+    fn ansi_foreground() {
+        assert_eq!(Color::Black.ansi_foreground(), "\x1b[30m");
+        assert_eq!(Color::White.ansi_foreground(), "\x1b[37m");
+        assert_eq!(Color::Rgb(1, 2, 3).ansi_foreground(), "\x1b[38;2;1;2;3m");
+    }
+
+    #[test]
+    fn html() {
+        assert_eq!(Color::Black.html(), "#000000");
+        assert_eq!(Color::Rgb(1, 2, 255).html(), "#0102ff");
+    }
+}
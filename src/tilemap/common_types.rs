@@ -11,7 +11,8 @@
 /// By 'correctly' means that when comparing two [`Vector2`] objects,
 /// the bigger one is the one with bigger `y` field. If `y` fields are equal,
 /// then `x` field should be compared.
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector2 {
     /// # Description
     /// `Y` field of the [`Vector2`]. Represents vertical value of a vector.
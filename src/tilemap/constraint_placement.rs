@@ -0,0 +1,301 @@
+use crate::tilemap::{Rng, Tilemap, Vector2};
+use crate::tilemap::overlay::line;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Project-specific constraint of [`PlacementRule::Custom`] that returns `true` for valid
+/// positions of the map.
+pub type PlacementPredicate = Box<dyn Fn(&Tilemap, Vector2) -> bool>;
+
+/// # Description
+/// Constraint on positions of items or monsters, see [`Tilemap::valid_positions()`] and
+/// [`Tilemap::place_constrained()`]. Distances are Euclidean.
+pub enum PlacementRule {
+    /// # Description
+    /// Position has the tile with the value, or no tile if it is [`None`].
+    OnValue(Option<char>),
+    /// # Description
+    /// No tile with any of the values is closer than the distance, such as "at least 3 cells
+    /// from any wall".
+    AwayFrom {
+        /// # Description
+        /// Values of avoided tiles.
+        values: Vec<char>,
+        /// # Description
+        /// Minimum distance to avoided tiles.
+        distance: usize,
+    },
+    /// # Description
+    /// Position is not farther from the target than the distance.
+    Near {
+        /// # Description
+        /// Target position.
+        position: Vector2,
+        /// # Description
+        /// Maximum distance to the target.
+        distance: usize,
+    },
+    /// # Description
+    /// Straight line from the observer to the position is blocked, such as "not visible from
+    /// spawn". The observer itself is never hidden.
+    HiddenFrom {
+        /// # Description
+        /// Position of the observer.
+        observer: Vector2,
+        /// # Description
+        /// Values of tiles that block the line of sight.
+        blocking: Vec<char>,
+    },
+    /// # Description
+    /// Straight line from the observer to the position is not blocked.
+    VisibleFrom {
+        /// # Description
+        /// Position of the observer.
+        observer: Vector2,
+        /// # Description
+        /// Values of tiles that block the line of sight.
+        blocking: Vec<char>,
+    },
+    /// # Description
+    /// Positions placed by one call of [`Tilemap::place_constrained()`] are not closer to each
+    /// other than the distance. It does not restrict [`Tilemap::valid_positions()`].
+    Spacing(usize),
+    /// # Description
+    /// Project-specific constraint.
+    Custom(PlacementPredicate),
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl PlacementRule {
+    /// # Description
+    /// Checks if the position of the map satisfies the rule.
+    ///
+    /// # Arguments
+    /// * `tilemap: &Tilemap` - Checked map.
+    /// * `position: Vector2` - Checked position.
+    ///
+    /// # Return
+    /// `true` if position is valid. [`PlacementRule::Spacing`] is always satisfied.
+    pub fn is_satisfied(&self, tilemap: &Tilemap, position: Vector2) -> bool {
+        return match self {
+            PlacementRule::OnValue(value) => tilemap.get(position) == *value,
+            PlacementRule::AwayFrom { values, distance } => {
+                !values.iter().any(|value| tilemap.has_tile_closer(position, *value, *distance))
+            },
+            PlacementRule::Near { position: target, distance } => distance_squared(position, *target) <= squared(*distance),
+            PlacementRule::HiddenFrom { observer, blocking } => {
+                position != *observer && !is_visible(tilemap, *observer, position, blocking)
+            },
+            PlacementRule::VisibleFrom { observer, blocking } => is_visible(tilemap, *observer, position, blocking),
+            PlacementRule::Spacing(_) => true,
+            PlacementRule::Custom(predicate) => predicate(tilemap, position),
+        };
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Returns positions inside of the [`Tilemap`] that satisfy all rules.
+    ///
+    /// # Arguments
+    /// * `rules: &[PlacementRule]` - Constraints on positions.
+    ///
+    /// # Return
+    /// [`Vec`] of valid positions in row-major order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::{PlacementRule, Vector2};
+    ///
+    /// let tilemap = char_tilemap::Tilemap::from_string("#####\n#...#\n#.#.#\n#...#\n#####", ' ');
+    /// let rules = [
+    ///     PlacementRule::OnValue(Some('.')),
+    ///     PlacementRule::HiddenFrom { observer: Vector2::new(1, 1), blocking: vec!['#'] },
+    /// ];
+    /// let hidden = tilemap.valid_positions(&rules);
+    /// assert_eq!(hidden, vec![Vector2::new(3, 2), Vector2::new(2, 3), Vector2::new(3, 3)]);
+    /// ```
+    pub fn valid_positions(&self, rules: &[PlacementRule]) -> Vec<Vector2> {
+        let mut positions = Vec::new();
+        for y in 0..self.size.y {
+            for position in (0..self.size.x).map(|x| Vector2::new(x, y)) {
+                if rules.iter().all(|rule| rule.is_satisfied(self, position)) {
+                    positions.push(position);
+                }
+            }
+        }
+
+        return positions;
+    }
+
+    /// # Description
+    /// Places tiles with the value at random positions that satisfy all rules, such as items or
+    /// monsters. Rules are checked against the map before placement, and
+    /// [`PlacementRule::Spacing`] keeps placed tiles apart from each other.
+    ///
+    /// # Arguments
+    /// * `value: char` - Value of placed tiles.
+    /// * `count: usize` - Maximum number of placed tiles.
+    /// * `rules: &[PlacementRule]` - Constraints on positions.
+    /// * `rng: &mut Rng` - Generator that picks positions.
+    ///
+    /// # Return
+    /// [`Vec`] of positions of placed tiles in order of placement. There are fewer than `count`
+    /// of them if there are not enough valid positions.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::PlacementRule;
+    ///
+    /// let mut tilemap = char_tilemap::Tilemap::from_string(&".".repeat(10), '.');
+    /// let mut rng = char_tilemap::Rng::new(7);
+    /// let rules = [PlacementRule::OnValue(None), PlacementRule::Spacing(4)];
+    ///
+    /// let placed = tilemap.place_constrained('$', 5, &rules, &mut rng);
+    /// assert!(placed.len() >= 2 && placed.len() <= 3);
+    /// ```
+    pub fn place_constrained(&mut self, value: char, count: usize, rules: &[PlacementRule], rng: &mut Rng) -> Vec<Vector2> {
+        let spacing = rules.iter()
+            .filter_map(|rule| match rule {
+                PlacementRule::Spacing(distance) => Some(*distance),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+
+        let mut candidates = self.valid_positions(rules);
+        rng.shuffle(&mut candidates);
+
+        let mut placed: Vec<Vector2> = Vec::new();
+        for candidate in candidates {
+            if placed.len() >= count {
+                break;
+            }
+            if placed.iter().all(|position| !is_closer(*position, candidate, spacing)) {
+                placed.push(candidate);
+            }
+        }
+
+        for position in placed.iter() {
+            self.set(*position, value);
+        }
+
+        return placed;
+    }
+
+    /// # Description
+    /// Checks if there is a tile with the value closer to the position than the distance.
+    fn has_tile_closer(&self, position: Vector2, value: char, distance: usize) -> bool {
+        let radius = distance.saturating_sub(1);
+        let end = Vector2::new(std::cmp::min(position.x.saturating_add(radius), self.size.x.saturating_sub(1)),
+                               std::cmp::min(position.y.saturating_add(radius), self.size.y.saturating_sub(1)));
+        for y in position.y.saturating_sub(radius)..=end.y {
+            for x in position.x.saturating_sub(radius)..=end.x {
+                let other = Vector2::new(x, y);
+                if is_closer(position, other, distance) && self.get(other) == Some(value) {
+                    return true;
+                }
+            }
+        }
+
+        return false;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Checks if Euclidean distance between positions is less than the distance.
+fn is_closer(first: Vector2, second: Vector2, distance: usize) -> bool {
+    return distance_squared(first, second) < squared(distance);
+}
+
+/// # Description
+/// Returns squared Euclidean distance between positions, which does not overflow.
+fn distance_squared(first: Vector2, second: Vector2) -> u128 {
+    return squared(first.x.abs_diff(second.x)) + squared(first.y.abs_diff(second.y));
+}
+
+/// # Description
+/// Returns square of the value, which does not overflow.
+fn squared(value: usize) -> u128 {
+    return (value as u128) * (value as u128);
+}
+
+/// # Description
+/// Checks if there are no blocking tiles on the straight line between the observer and the
+/// position, not counting both of them.
+fn is_visible(tilemap: &Tilemap, observer: Vector2, position: Vector2, blocking: &[char]) -> bool {
+    let points = line(observer, position);
+    return points.iter()
+        .skip(1)
+        .take(points.len().saturating_sub(2))
+        .all(|point| !tilemap.get(*point).is_some_and(|value| blocking.contains(&value)));
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{PlacementRule, Rng, Tilemap, Vector2};
+
+    #[test]
+    fn valid_positions() {
+        let tilemap = Tilemap::from_string("#######\n#.....#\n#.....#\n#.....#\n#######", ' ');
+        let away = PlacementRule::AwayFrom { values: vec!['#'], distance: 2 };
+        assert_eq!(tilemap.valid_positions(&[PlacementRule::OnValue(Some('.')), away]),
+                   vec![Vector2::new(2, 2), Vector2::new(3, 2), Vector2::new(4, 2)]);
+
+        let near = PlacementRule::Near { position: Vector2::new(1, 1), distance: 1 };
+        assert_eq!(tilemap.valid_positions(&[PlacementRule::OnValue(Some('.')), near]),
+                   vec![Vector2::new(1, 1), Vector2::new(2, 1), Vector2::new(1, 2)]);
+
+        let custom = PlacementRule::Custom(Box::new(|_, position| position.x == 6 && position.y == 0));
+        assert_eq!(tilemap.valid_positions(&[custom]), vec![Vector2::new(6, 0)]);
+        assert_eq!(tilemap.valid_positions(&[PlacementRule::Spacing(10)]).len(), 35);
+    }
+
+    #[test]
+    fn visibility() {
+        let tilemap = Tilemap::from_string(".....\n..#..\n.....", '.');
+        let observer = Vector2::new(2, 0);
+        let hidden = tilemap.valid_positions(&[PlacementRule::HiddenFrom { observer, blocking: vec!['#'] }]);
+        assert_eq!(hidden, vec![Vector2::new(2, 2)]);
+
+        let visible = tilemap.valid_positions(&[PlacementRule::VisibleFrom { observer, blocking: vec!['#'] }]);
+        assert_eq!(visible.len(), 14);
+        assert!(visible.contains(&observer));
+        assert!(visible.contains(&Vector2::new(2, 1)));
+    }
+
+    #[test]
+    fn place_constrained() {
+        let mut tilemap = Tilemap::from_string("#.........\n..........", '.');
+        let rules = [PlacementRule::OnValue(None), PlacementRule::Spacing(3),
+                     PlacementRule::AwayFrom { values: vec!['#'], distance: 2 }];
+        let placed = tilemap.place_constrained('M', 10, &rules, &mut Rng::new(7));
+
+        assert!(!placed.is_empty());
+        for (index, position) in placed.iter().enumerate() {
+            assert_eq!(tilemap.get(*position), Some('M'));
+            assert!(position.x + position.y >= 2);
+            for other in placed[index + 1..].iter() {
+                assert!(position.x.abs_diff(other.x).pow(2) + position.y.abs_diff(other.y).pow(2) >= 9);
+            }
+        }
+
+        let placed = tilemap.place_constrained('$', 2, &[PlacementRule::OnValue(None)], &mut Rng::new(7));
+        assert_eq!(placed.len(), 2);
+        assert!(tilemap.place_constrained('$', 2, &[PlacementRule::OnValue(Some('?'))], &mut Rng::new(7)).is_empty());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::tilemap::TileError;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Describes what should happen when a control char, such as `'\n'`, `'\t'` or `'\r'`, is used as
+/// a value of a [`crate::Tile`]. Such values break the row structure of the built
+/// [`crate::Tilemap`], so they are replaced by default.
+///
+/// # Example
+/// ```rust
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.control_chars = char_tilemap::ControlCharPolicy::Reject;
+/// assert!(tilemap.add_tile(char_tilemap::Vector2::ZERO, '\n').is_err());
+/// ```
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ControlCharPolicy {
+    /// # Description
+    /// Control chars are stored as is.
+    Allow,
+    /// # Description
+    /// Methods that return [`Result`] fail with an error, and [`crate::Tilemap::set()`] leaves
+    /// the tilemap unchanged.
+    Reject,
+    /// # Description
+    /// Control chars are replaced with the specified placeholder.
+    Replace(char),
+}
+
+/// # Description
+/// Placeholder that is used by the default [`ControlCharPolicy`].
+pub const DEFAULT_CONTROL_CHAR_PLACEHOLDER: char = '?';
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl ControlCharPolicy {
+    /// # Description
+    /// Applies the policy to the value of a tile.
+    ///
+    /// # Arguments
+    /// * `value: char` - Value of a tile.
+    ///
+    /// # Return
+    /// * [`Ok`] with the value that should be stored.
+    /// * [`Err`] with [`TileError::ControlChar`] if value is a control char and policy is
+    ///   [`ControlCharPolicy::Reject`].
+    pub(crate) fn apply(self, value: char) -> Result<char, TileError> {
+        if !value.is_control() {
+            return Ok(value);
+        }
+
+        return match self {
+            ControlCharPolicy::Allow => Ok(value),
+            ControlCharPolicy::Reject => Err(TileError::ControlChar(value)),
+            ControlCharPolicy::Replace(placeholder) => Ok(placeholder),
+        };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl Default for ControlCharPolicy {
+    /// # Description
+    /// Returns policy that replaces control chars with [`DEFAULT_CONTROL_CHAR_PLACEHOLDER`].
+    fn default() -> ControlCharPolicy {
+        return ControlCharPolicy::Replace(DEFAULT_CONTROL_CHAR_PLACEHOLDER);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{ControlCharPolicy, Tilemap, Vector2};
+
+    #[test]
+    fn apply() {
+        for value in ['\n', '\t', '\r', '\u{7F}'] {
+            assert_eq!(ControlCharPolicy::Allow.apply(value), Ok(value));
+            assert_eq!(ControlCharPolicy::Replace('~').apply(value), Ok('~'));
+            match ControlCharPolicy::Reject.apply(value) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+
+        assert_eq!(ControlCharPolicy::Reject.apply('#'), Ok('#'));
+    }
+
+    #[test]
+    fn tilemap_policy() {
+        let mut tilemap = Tilemap::new('-');
+        tilemap.set(Vector2::ZERO, '\t');
+        assert_eq!(tilemap.build(), "?");
+
+        tilemap.control_chars = ControlCharPolicy::Reject;
+        tilemap.set(Vector2::new(1, 0), '\n');
+        assert_eq!(tilemap.build(), "?");
+        match tilemap.add_tile(Vector2::new(1, 0), '\r') {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+        match tilemap.update_tile(Vector2::ZERO, '\r') {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+        assert_eq!(tilemap.build(), "?");
+
+        tilemap.control_chars = ControlCharPolicy::Allow;
+        tilemap.set(Vector2::new(1, 0), '\n');
+        assert_eq!(tilemap.build(), "?\n");
+    }
+}
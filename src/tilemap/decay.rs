@@ -0,0 +1,193 @@
+use crate::tilemap::{TileOp, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Adds rule that replaces tiles with the value after they have the specified age, see
+    /// [`Tilemap::advance()`]. Rules can be chained, for example fire becomes ash, and ash
+    /// disappears later. Existing rule for the same value is replaced. Ages of tiles are enabled,
+    /// if they were not.
+    ///
+    /// # Arguments
+    /// * `from: char` - Value of tiles that decay.
+    /// * `to: Option<char>` - Value that replaces decayed tiles. [`None`] removes them.
+    /// * `after: u64` - Age in ticks at which tiles decay.
+    ///
+    /// # Return
+    /// * [`Ok`] if rule was added.
+    /// * [`Err`] if `after` is 0. Contains error message.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::Vector2;
+    ///
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("^.", '.');
+    /// tilemap.add_decay_rule('^', Some('*'), 2).unwrap();
+    /// tilemap.add_decay_rule('*', None, 3).unwrap();
+    ///
+    /// tilemap.advance(2);
+    /// assert_eq!(tilemap.get(Vector2::ZERO), Some('*'));
+    /// tilemap.advance(3);
+    /// assert_eq!(tilemap.get(Vector2::ZERO), None);
+    /// ```
+    pub fn add_decay_rule(&mut self, from: char, to: Option<char>, after: u64) -> Result<(), String> {
+        if after == 0 {
+            return Err(format!("Tiles '{from}' must decay after at least one tick"));
+        }
+
+        if !self.has_ages() {
+            self.enable_ages();
+        }
+        self.decay_rules.insert(from, (to, after));
+        return Ok(());
+    }
+
+    /// # Description
+    /// Removes rule of the value, see [`Tilemap::add_decay_rule()`].
+    ///
+    /// # Arguments
+    /// * `from: char` - Value of tiles that decay.
+    ///
+    /// # Return
+    /// `true` if rule existed, `false` otherwise.
+    pub fn remove_decay_rule(&mut self, from: char) -> bool {
+        return self.decay_rules.remove(&from).is_some();
+    }
+
+    /// # Description
+    /// Advances the clock by the number of ticks and applies decay rules to all tiles that got
+    /// old enough, see [`Tilemap::add_decay_rule()`]. Chained rules are applied as if the clock
+    /// was advanced tick by tick, so tiles that were replaced keep the age since their
+    /// replacement.
+    ///
+    /// # Arguments
+    /// * `ticks: u64` - Number of ticks.
+    ///
+    /// # Return
+    /// Number of tiles that were replaced or removed.
+    pub fn advance(&mut self, ticks: u64) -> usize {
+        self.clock = self.clock.saturating_add(ticks);
+        let Some(stamps) = &self.stamps else {
+            return 0;
+        };
+
+        let mut decayed: Vec<(Vector2, Option<char>, u64)> = Vec::new();
+        for tile in self.tiles.iter() {
+            let Some(&stamp) = stamps.get(&tile.position) else {
+                continue;
+            };
+
+            let (mut value, mut time) = (Some(tile.value), stamp);
+            while let Some(&(to, after)) = value.and_then(|value| self.decay_rules.get(&value)) {
+                match time.checked_add(after) {
+                    Some(next) if next <= self.clock => (value, time) = (to, next),
+                    _ => break,
+                }
+            }
+            if time != stamp {
+                decayed.push((tile.position, value, time));
+            }
+        }
+
+        let ops: Vec<TileOp> = decayed.iter()
+            .map(|&(position, value, _)| match value {
+                Some(value) => TileOp::Set(position, value),
+                None => TileOp::Remove(position),
+            })
+            .collect();
+        self.apply_ops(&ops);
+
+        if let Some(stamps) = &mut self.stamps {
+            for &(position, _, time) in decayed.iter().filter(|(_, value, _)| value.is_some()) {
+                stamps.insert(position, time);
+            }
+        }
+        return decayed.len();
+    }
+
+    /// # Description
+    /// Removes tiles that are older than the specified number of ticks and satisfy the
+    /// predicate. Nothing is removed if ages are not enabled, see [`Tilemap::enable_ages()`].
+    ///
+    /// # Arguments
+    /// * `ticks: u64` - Maximum age of tiles that are kept.
+    /// * `predicate: F` - Function that receives value of the tile and returns `true` if tile
+    ///   should be removed.
+    ///
+    /// # Return
+    /// Number of removed tiles.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("~#", '.');
+    /// tilemap.enable_ages();
+    /// tilemap.advance(5);
+    ///
+    /// assert_eq!(tilemap.expire_older_than(4, |value| value == '~'), 1);
+    /// assert_eq!(tilemap.build(), ".#");
+    /// ```
+    pub fn expire_older_than<F>(&mut self, ticks: u64, predicate: F) -> usize
+        where F: Fn(char) -> bool {
+        let ops: Vec<TileOp> = self.tiles.iter()
+            .filter(|tile| predicate(tile.value))
+            .filter(|tile| self.tile_age(tile.position).is_some_and(|age| age > ticks))
+            .map(|tile| TileOp::Remove(tile.position))
+            .collect();
+
+        self.apply_ops(&ops);
+        return ops.len();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[test]
+    fn advance() {
+        let mut tilemap = Tilemap::from_string("^.#\n...", '.');
+        tilemap.add_decay_rule('^', Some('*'), 2).unwrap();
+        tilemap.add_decay_rule('*', Some('^'), 3).unwrap();
+        tilemap.add_decay_rule('~', None, 1).unwrap();
+        match tilemap.add_decay_rule('#', None, 0) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true),
+        }
+
+        assert_eq!(tilemap.advance(1), 0);
+        tilemap.set(Vector2::ONE, '~');
+        assert_eq!(tilemap.advance(6), 2);
+
+        assert_eq!(tilemap.build(), "*.#\n...");
+        assert_eq!(tilemap.tile_age(Vector2::ZERO), Some(0));
+        assert_eq!(tilemap.tile_age(Vector2::new(2, 0)), Some(7));
+        assert_eq!(tilemap.clock(), 7);
+
+        assert!(tilemap.remove_decay_rule('*'));
+        assert!(!tilemap.remove_decay_rule('*'));
+        assert_eq!(tilemap.advance(10), 0);
+    }
+
+    #[test]
+    fn expire_older_than() {
+        let mut tilemap = Tilemap::from_string("~+\n#.", '.');
+        assert_eq!(tilemap.expire_older_than(0, |_| true), 0);
+
+        tilemap.enable_ages();
+        tilemap.advance(3);
+        tilemap.set(Vector2::new(1, 0), '~');
+        tilemap.advance(2);
+
+        assert_eq!(tilemap.expire_older_than(3, |value| value == '~'), 1);
+        assert_eq!(tilemap.build(), ".~\n#.");
+        assert_eq!(tilemap.expire_older_than(0, |_| true), 2);
+        assert_eq!(tilemap.build(), "..\n..");
+    }
+}
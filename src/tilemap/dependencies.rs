@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Identifier of a dependency of derived data on a region of the [`Tilemap`], see
+/// [`Tilemap::add_dependency()`].
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct DependencyId(u64);
+
+/// # Description
+/// Regions of the [`Tilemap`] on which derived data depends.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Dependencies {
+    /// # Description
+    /// Identifier of the next dependency.
+    next_id: u64,
+    /// # Description
+    /// Top left corners and sizes of regions with flags whether they were changed.
+    regions: BTreeMap<DependencyId, (Vector2, Vector2, bool)>,
+}
+
+/// # Description
+/// Data derived from a region of the [`Tilemap`], such as a field of view or a distance map,
+/// which is recalculated only after tiles of the region are changed.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::{Derived, Vector2};
+///
+/// let mut tilemap = char_tilemap::Tilemap::from_string("#..\n...\n..#", '.');
+/// let mut walls = Derived::new(&mut tilemap, Vector2::ZERO, Vector2::new(2, 2));
+///
+/// assert_eq!(*walls.get(&mut tilemap, |tilemap| tilemap.build().matches('#').count()), 2);
+/// tilemap.set(Vector2::new(2, 1), '#');
+/// assert_eq!(*walls.get(&mut tilemap, |_| unreachable!()), 2);
+/// tilemap.set(Vector2::ONE, '#');
+/// assert_eq!(*walls.get(&mut tilemap, |tilemap| tilemap.build().matches('#').count()), 4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Derived<T> {
+    /// # Description
+    /// Dependency on the region.
+    dependency: DependencyId,
+    /// # Description
+    /// The last calculated value.
+    value: Option<T>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Registers dependency of derived data on the rectangular region. Dependency becomes stale
+    /// when any tile of the region is added, updated or removed, including by operations that
+    /// replace all tiles, such as [`Tilemap::resize()`].
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Top left corner of the region.
+    /// * `size: Vector2` - Size of the region. [`Vector2::MAX`] covers everything to the right
+    ///   and below the corner.
+    ///
+    /// # Return
+    /// Identifier of the new dependency, which is not stale.
+    pub fn add_dependency(&mut self, position: Vector2, size: Vector2) -> DependencyId {
+        let id = DependencyId(self.dependencies.next_id);
+        self.dependencies.next_id += 1;
+        self.dependencies.regions.insert(id, (position, size, false));
+        return id;
+    }
+
+    /// # Description
+    /// Removes dependency, see [`Tilemap::add_dependency()`].
+    ///
+    /// # Return
+    /// `true` if dependency existed, `false` otherwise.
+    pub fn remove_dependency(&mut self, id: DependencyId) -> bool {
+        return self.dependencies.regions.remove(&id).is_some();
+    }
+
+    /// # Description
+    /// Checks if tiles of the region were changed since the dependency was added or refreshed.
+    ///
+    /// # Return
+    /// `true` if dependency is stale or does not exist, `false` otherwise.
+    pub fn is_stale(&self, id: DependencyId) -> bool {
+        return self.dependencies.regions.get(&id).is_none_or(|(_, _, stale)| *stale);
+    }
+
+    /// # Description
+    /// Marks dependency as not stale, usually after derived data was recalculated.
+    ///
+    /// # Return
+    /// `true` if dependency exists, `false` otherwise.
+    pub fn refresh_dependency(&mut self, id: DependencyId) -> bool {
+        return match self.dependencies.regions.get_mut(&id) {
+            Some((_, _, stale)) => {
+                *stale = false;
+                true
+            },
+            None => false,
+        };
+    }
+
+    /// # Description
+    /// Marks all dependencies on regions that contain the position as stale.
+    pub(crate) fn invalidate_dependencies(&mut self, position: Vector2) {
+        for (corner, size, stale) in self.dependencies.regions.values_mut() {
+            let inside_x = position.x >= corner.x && position.x - corner.x < size.x;
+            let inside_y = position.y >= corner.y && position.y - corner.y < size.y;
+            *stale |= inside_x && inside_y;
+        }
+    }
+
+    /// # Description
+    /// Marks all dependencies as stale.
+    pub(crate) fn invalidate_all_dependencies(&mut self) {
+        for (_, _, stale) in self.dependencies.regions.values_mut() {
+            *stale = true;
+        }
+    }
+}
+
+impl<T> Derived<T> {
+    /// # Description
+    /// Creates new [`Derived`] data of the region, which is calculated on the first access.
+    ///
+    /// # Arguments
+    /// * `tilemap: &mut Tilemap` - [`Tilemap`] from which data is derived.
+    /// * `position: Vector2` - Top left corner of the region.
+    /// * `size: Vector2` - Size of the region.
+    ///
+    /// # Return
+    /// New instance of [`Derived`] data.
+    pub fn new(tilemap: &mut Tilemap, position: Vector2, size: Vector2) -> Derived<T> {
+        return Derived { dependency: tilemap.add_dependency(position, size), value: None };
+    }
+
+    /// # Description
+    /// Returns derived data, recalculating it if tiles of the region were changed.
+    ///
+    /// # Arguments
+    /// * `tilemap: &mut Tilemap` - [`Tilemap`] that was passed to [`Derived::new()`].
+    /// * `calculate: F` - Calculates data from the [`Tilemap`].
+    pub fn get<F>(&mut self, tilemap: &mut Tilemap, calculate: F) -> &T
+        where F: FnOnce(&Tilemap) -> T {
+        if self.value.is_none() || tilemap.is_stale(self.dependency) {
+            if !tilemap.refresh_dependency(self.dependency) {
+                self.dependency = tilemap.add_dependency(Vector2::ZERO, Vector2::MAX);
+            }
+            self.value = Some(calculate(tilemap));
+        }
+
+        return self.value.as_ref().expect("Value was just calculated");
+    }
+
+    /// # Description
+    /// Drops calculated data, so it is recalculated on the next access.
+    pub fn invalidate(&mut self) {
+        self.value = None;
+    }
+
+    /// # Description
+    /// Returns identifier of the dependency on the region.
+    pub fn dependency(&self) -> DependencyId {
+        return self.dependency;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Derived, Orientation, Tilemap, Vector2};
+
+    #[test]
+    fn dependencies() {
+        let mut tilemap = Tilemap::from_string("....\n....\n....", '.');
+        let left = tilemap.add_dependency(Vector2::ZERO, Vector2::new(2, 3));
+        let right = tilemap.add_dependency(Vector2::new(2, 0), Vector2::MAX);
+
+        tilemap.set(Vector2::new(1, 2), '#');
+        assert!(tilemap.is_stale(left));
+        assert!(!tilemap.is_stale(right));
+
+        assert!(tilemap.refresh_dependency(left));
+        tilemap.set(Vector2::new(10, 10), '#');
+        assert!(!tilemap.is_stale(left));
+        assert!(tilemap.is_stale(right));
+
+        tilemap.refresh_dependency(right);
+        tilemap.set(Vector2::new(10, 10), '#');
+        assert!(!tilemap.is_stale(right));
+        tilemap = tilemap.oriented(Orientation::FlipHorizontal);
+        assert!(tilemap.is_stale(left));
+        assert!(tilemap.is_stale(right));
+
+        assert!(tilemap.remove_dependency(left));
+        assert!(!tilemap.remove_dependency(left));
+        assert!(!tilemap.refresh_dependency(left));
+        assert!(tilemap.is_stale(left));
+    }
+
+    #[test]
+    fn derived() {
+        let mut tilemap = Tilemap::from_string("#..\n...", '.');
+        let mut calculations = 0;
+        let mut count = Derived::new(&mut tilemap, Vector2::ZERO, Vector2::MAX);
+        let mut calculate = |tilemap: &mut Tilemap, count: &mut Derived<usize>| {
+            return *count.get(tilemap, |tilemap| {
+                calculations += 1;
+                return tilemap.build().matches('#').count();
+            });
+        };
+
+        assert_eq!(calculate(&mut tilemap, &mut count), 1);
+        assert_eq!(calculate(&mut tilemap, &mut count), 1);
+        tilemap.set(Vector2::ONE, '#');
+        assert_eq!(calculate(&mut tilemap, &mut count), 2);
+        count.invalidate();
+        assert_eq!(calculate(&mut tilemap, &mut count), 2);
+
+        tilemap.remove_dependency(count.dependency());
+        assert_eq!(calculate(&mut tilemap, &mut count), 2);
+        assert_eq!(calculate(&mut tilemap, &mut count), 2);
+        assert_eq!(calculations, 4);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::tilemap::{Tile, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Values that [`Tilemap::difference_map_with()`] uses to mark differences.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct DifferenceChars {
+    /// # Description
+    /// Marks tiles that exist only in the other [`Tilemap`]. Default is `+`.
+    pub added: char,
+    /// # Description
+    /// Marks tiles that exist only in this [`Tilemap`]. Default is `-`.
+    pub removed: char,
+    /// # Description
+    /// Marks tiles that exist in both [`Tilemap`]s with different values. Default is `~`.
+    pub changed: char,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Creates [`Tilemap`] that marks differences between this [`Tilemap`] and the `other` one
+    /// with the default [`DifferenceChars`]. Visual counterpart of [`Tilemap::diff()`].
+    ///
+    /// # Arguments
+    /// * `other: &Tilemap` - [`Tilemap`] to compare with.
+    ///
+    /// # Return
+    /// New instance of the [`Tilemap`], see [`Tilemap::difference_map_with()`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let before = char_tilemap::Tilemap::from_string("#.#\n...", '.');
+    /// let after = char_tilemap::Tilemap::from_string("#.@\n.#.", '.');
+    /// assert_eq!(before.difference_map(&after).build(), "..~\n.+.");
+    /// ```
+    pub fn difference_map(&self, other: &Tilemap) -> Tilemap {
+        return self.difference_map_with(other, DifferenceChars::default());
+    }
+
+    /// # Description
+    /// Creates [`Tilemap`] that marks differences between this [`Tilemap`] and the `other` one.
+    /// Positions without differences are empty. Result has empty tile of this [`Tilemap`] and
+    /// is big enough to contain both [`Tilemap`]s.
+    ///
+    /// # Arguments
+    /// * `other: &Tilemap` - [`Tilemap`] to compare with.
+    /// * `chars: DifferenceChars` - Values that mark differences.
+    ///
+    /// # Return
+    /// New instance of the [`Tilemap`].
+    pub fn difference_map_with(&self, other: &Tilemap, chars: DifferenceChars) -> Tilemap {
+        let mut tilemap = Tilemap::new(self.empty_tile);
+        tilemap.size = Vector2::new(self.size.x.max(other.size.x), self.size.y.max(other.size.y));
+
+        for change in self.diff(other) {
+            let value = match (change.old, change.new) {
+                (None, _) => chars.added,
+                (_, None) => chars.removed,
+                _ => chars.changed,
+            };
+            tilemap.tiles.push(Tile { position: change.position, value });
+        }
+
+        return tilemap;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl Default for DifferenceChars {
+    /// # Description
+    /// Creates [`DifferenceChars`] with `+` for added, `-` for removed and `~` for changed tiles.
+    fn default() -> DifferenceChars {
+        return DifferenceChars { added: '+', removed: '-', changed: '~' };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{DifferenceChars, Tilemap, Vector2};
+
+    #[test]
+    fn difference_map() {
+        let before = Tilemap::from_string("##..\n#..#", '.');
+        let after = Tilemap::from_string("#@..\n...#\n.#..", '.');
+
+        let difference = before.difference_map(&after);
+        assert_eq!(difference.build(), ".~..\n-...\n.+..");
+        assert_eq!(difference.size(), Vector2::new(4, 3));
+        assert_eq!(before.difference_map(&before).build(), "....\n....");
+
+        let chars = DifferenceChars { added: 'A', removed: 'R', changed: 'C' };
+        assert_eq!(after.difference_map_with(&before, chars).build(), ".C..\nA...\n.R..");
+    }
+}
@@ -0,0 +1,88 @@
+use crate::tilemap::Vector2;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// One of the four directions on the [`crate::Tilemap`]. Up decreases `y`, left decreases `x`.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Direction {
+    /// # Description
+    /// Towards the top row.
+    Up,
+    /// # Description
+    /// Towards the right column.
+    Right,
+    /// # Description
+    /// Towards the bottom row.
+    Down,
+    /// # Description
+    /// Towards the left column.
+    Left,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Direction {
+    /// # Description
+    /// All directions in the clockwise order, starting with [`Direction::Up`].
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// # Description
+    /// Returns position next to the specified one in this direction.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position to step from.
+    ///
+    /// # Return
+    /// * [`Some`] with the next position.
+    /// * [`None`] if the next position would have a negative coordinate.
+    ///
+    /// # Example
+    /// ```rust
+    /// assert_eq!(char_tilemap::Direction::Right.step(char_tilemap::Vector2::ZERO), Some(char_tilemap::Vector2::new(1, 0)));
+    /// assert_eq!(char_tilemap::Direction::Up.step(char_tilemap::Vector2::ZERO), None);
+    /// ```
+    pub fn step(self, position: Vector2) -> Option<Vector2> {
+        return match self {
+            Direction::Up => position.y.checked_sub(1).map(|y| Vector2::new(position.x, y)),
+            Direction::Right => Some(Vector2::new(position.x + 1, position.y)),
+            Direction::Down => Some(Vector2::new(position.x, position.y + 1)),
+            Direction::Left => position.x.checked_sub(1).map(|x| Vector2::new(x, position.y)),
+        };
+    }
+
+    /// # Description
+    /// Returns the opposite direction.
+    pub fn opposite(self) -> Direction {
+        return match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Direction, Vector2};
+
+    #[test]
+    fn step() {
+        for direction in Direction::ALL {
+            let next = direction.step(Vector2::ONE).unwrap();
+            assert_eq!(direction.opposite().step(next), Some(Vector2::ONE));
+        }
+
+        assert_eq!(Direction::Down.step(Vector2::ZERO), Some(Vector2::new(0, 1)));
+        assert_eq!(Direction::Left.step(Vector2::ZERO), None);
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::VecDeque;
+use crate::tilemap::{Tilemap, Vector2};
+use crate::tilemap::pathfinding::neighbors;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Number of steps from every position of the [`Tilemap`] to the nearest goal, also known as a
+/// Dijkstra map. Created by [`Tilemap::distance_map()`]. Steps are horizontal or vertical, so all
+/// of them cost the same.
+///
+/// # Example
+/// ```rust
+/// let tilemap = char_tilemap::Tilemap::from_string("...\n.#.\n...", '.');
+/// let goal = char_tilemap::Vector2::new(2, 2);
+/// let distances = tilemap.distance_map(&[goal], |value| value.is_none());
+///
+/// let mut position = char_tilemap::Vector2::ZERO;
+/// while let Some(next) = distances.next_step_toward(position) {
+///     position = next;
+/// }
+/// assert_eq!(position, goal);
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct DistanceMap {
+    /// # Description
+    /// Size of the map, same as of the source [`Tilemap`].
+    size: Vector2,
+    /// # Description
+    /// Distances row by row. [`None`] for positions from which no goal can be reached.
+    distances: Vec<Option<usize>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl DistanceMap {
+    /// # Description
+    /// Returns size of the [`DistanceMap`].
+    pub fn size(&self) -> Vector2 {
+        return self.size;
+    }
+
+    /// # Description
+    /// Returns number of steps from the position to the nearest goal.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position on the map.
+    ///
+    /// # Return
+    /// * [`Some`] with number of steps. It is 0 for goals.
+    /// * [`None`] if position is out of bounds, not walkable, or no goal can be reached from it.
+    pub fn get(&self, position: Vector2) -> Option<usize> {
+        if position.x >= self.size.x || position.y >= self.size.y {
+            return None;
+        }
+
+        return self.distances[position.y * self.size.x + position.x];
+    }
+
+    /// # Description
+    /// Picks the neighbor of the position that is closer to the nearest goal. If several
+    /// neighbors are equally close, they are preferred in order: up, right, down, left.
+    ///
+    /// # Arguments
+    /// * `from: Vector2` - Current position.
+    ///
+    /// # Return
+    /// * [`Some`] with the neighbor that has the lowest distance.
+    /// * [`None`] if `from` is a goal, or no goal can be reached from it.
+    pub fn next_step_toward(&self, from: Vector2) -> Option<Vector2> {
+        let distance = self.get(from)?;
+
+        return neighbors(from)
+            .filter_map(|neighbor| self.get(neighbor).map(|value| (value, neighbor)))
+            .filter(|(value, _)| *value < distance)
+            .min_by_key(|(value, _)| *value)
+            .map(|(_, neighbor)| neighbor);
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Calculates number of steps from every position inside of the [`Tilemap`] bounds to the
+    /// nearest goal.
+    ///
+    /// # Arguments
+    /// * `goals: &[Vector2]` - Positions to which distances are calculated. Goals that are out of
+    ///   bounds or not walkable are ignored.
+    /// * `is_walkable: F` - Returns `true` if the position with the specified tile value (or
+    ///   [`None`] for positions without tiles) can be walked through.
+    ///
+    /// # Return
+    /// New [`DistanceMap`] of the same size as the [`Tilemap`].
+    pub fn distance_map<F>(&self, goals: &[Vector2], is_walkable: F) -> DistanceMap
+        where F: Fn(Option<char>) -> bool {
+        let mut distances = DistanceMap { size: self.size, distances: vec![None; self.size.x * self.size.y] };
+        let index = |position: Vector2| position.y * self.size.x + position.x;
+        let walkable = |position: Vector2| {
+            return position.x < self.size.x && position.y < self.size.y && is_walkable(self.get(position));
+        };
+
+        let mut queue = VecDeque::new();
+        for goal in goals.iter().copied().filter(|goal| walkable(*goal)) {
+            distances.distances[index(goal)] = Some(0);
+            queue.push_back(goal);
+        }
+
+        while let Some(position) = queue.pop_front() {
+            let distance = distances.distances[index(position)].unwrap_or(0);
+            for neighbor in neighbors(position) {
+                if walkable(neighbor) && distances.distances[index(neighbor)].is_none() {
+                    distances.distances[index(neighbor)] = Some(distance + 1);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        return distances;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[test]
+    fn distance_map() {
+        let tilemap = Tilemap::from_string("...#\n.#..\n...#", '.');
+        let distances = tilemap.distance_map(&[Vector2::ZERO, Vector2::new(5, 5)], |value| value.is_none());
+
+        let rows: Vec<String> = (0..3).map(|y| (0..4).map(|x| {
+            return distances.get(Vector2::new(x, y)).map_or('#', |value| char::from_digit(value as u32, 10).unwrap());
+        }).collect()).collect();
+        assert_eq!(rows, vec!["012#", "1#34", "234#"]);
+        assert_eq!(distances.get(Vector2::new(4, 0)), None);
+    }
+
+    #[test]
+    fn next_step_toward() {
+        let tilemap = Tilemap::from_string("....\n.##.\n#...", '.');
+        let distances = tilemap.distance_map(&[Vector2::new(1, 2)], |value| value.is_none());
+
+        let mut position = Vector2::ZERO;
+        let mut steps = 0;
+        while let Some(next) = distances.next_step_toward(position) {
+            assert_eq!(distances.get(next), Some(distances.get(position).unwrap() - 1));
+            position = next;
+            steps += 1;
+        }
+        assert_eq!((position, steps), (Vector2::new(1, 2), 7));
+
+        assert_eq!(distances.next_step_toward(Vector2::new(1, 1)), None);
+        assert_eq!(tilemap.distance_map(&[], |value| value.is_none()).next_step_toward(Vector2::ZERO), None);
+    }
+}
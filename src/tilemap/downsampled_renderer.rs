@@ -0,0 +1,165 @@
+use crate::tilemap::{Renderer, TileSource, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// First character of the Unicode Braille Patterns block. Represents cell without any dots.
+const BRAILLE_BASE: u32 = 0x2800;
+
+/// # Description
+/// Bits of the Braille dots, indexed as `[y][x]` inside of the 2x4 cell.
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// # Description
+/// Quadrant block characters, indexed by bit mask where top left quadrant is `1`,
+/// top right is `2`, bottom left is `4` and bottom right is `8`.
+const QUADRANTS: [char; 16] = [' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█'];
+
+/// # Description
+/// [`Renderer`] that packs every 2x4 tiles into a single Unicode Braille character.
+/// Tile is treated as "on" if it exists and its value is not equal to the empty tile.
+/// Quadruples effective resolution when huge maps are shown in a terminal.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::Renderer;
+///
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::ZERO, '#');
+/// tilemap.set(char_tilemap::Vector2::new(1, 3), '#');
+/// assert_eq!(char_tilemap::BrailleRenderer.render(&tilemap), "⢁");
+/// ```
+#[derive(Debug, Default, Copy, Clone)]
+pub struct BrailleRenderer;
+
+/// # Description
+/// [`Renderer`] that packs every 2x2 tiles into a single quadrant block character.
+/// Tile is treated as "on" if it exists and its value is not equal to the empty tile.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::Renderer;
+///
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::ZERO, '#');
+/// tilemap.set(char_tilemap::Vector2::ONE, '#');
+/// assert_eq!(char_tilemap::QuadrantRenderer.render(&tilemap), "▚");
+/// ```
+#[derive(Debug, Default, Copy, Clone)]
+pub struct QuadrantRenderer;
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl Renderer for BrailleRenderer {
+    type Output = String;
+
+    /// # Description
+    /// Renders source into the string of Braille characters. Every output row covers 4 rows of
+    /// the source, every output column covers 2 columns of the source.
+    fn render<S: TileSource + ?Sized>(&self, source: &S) -> String {
+        return render_downsampled(source, Vector2::new(2, 4), |bits| {
+            let mut code = BRAILLE_BASE;
+            for (y, row) in BRAILLE_DOTS.iter().enumerate() {
+                for (x, dot) in row.iter().enumerate() {
+                    if bits[y * 2 + x] {
+                        code |= dot;
+                    }
+                }
+            }
+            return char::from_u32(code).unwrap_or(' ');
+        });
+    }
+}
+
+impl Renderer for QuadrantRenderer {
+    type Output = String;
+
+    /// # Description
+    /// Renders source into the string of quadrant block characters. Every output row covers
+    /// 2 rows of the source, every output column covers 2 columns of the source.
+    fn render<S: TileSource + ?Sized>(&self, source: &S) -> String {
+        return render_downsampled(source, Vector2::new(2, 2), |bits| {
+            let mut index = 0;
+            for (bit, is_on) in bits.iter().enumerate() {
+                if *is_on {
+                    index |= 1 << bit;
+                }
+            }
+            return QUADRANTS[index];
+        });
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Renders `source` by splitting it into cells of `cell_size` and turning every cell into a single
+/// character using `to_char`, which receives "on" states of the cell tiles in row-major order.
+fn render_downsampled<S, F>(source: &S, cell_size: Vector2, to_char: F) -> String
+    where S: TileSource + ?Sized, F: Fn(&[bool]) -> char {
+    let size = source.size();
+    let columns = size.x.div_ceil(cell_size.x);
+    let rows = size.y.div_ceil(cell_size.y);
+    let mut bits = vec![false; cell_size.x * cell_size.y];
+    let mut result = String::new();
+
+    for row in 0..rows {
+        if row > 0 {
+            result.push('\n');
+        }
+
+        for column in 0..columns {
+            for y in 0..cell_size.y {
+                for x in 0..cell_size.x {
+                    let position = Vector2::new(column * cell_size.x + x, row * cell_size.y + y);
+                    bits[y * cell_size.x + x] = match source.get(position) {
+                        Some(value) => value != source.empty_tile(),
+                        None => false,
+                    };
+                }
+            }
+
+            result.push(to_char(&bits));
+        }
+    }
+
+    return result;
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{BrailleRenderer, QuadrantRenderer, Renderer, Tilemap, Vector2};
+
+    fn build_test_tilemap() -> Tilemap {
+        let mut tilemap = Tilemap::new('-');
+        for i in 0..5 {
+            tilemap.set(Vector2::new(i, i), '#');
+        }
+        tilemap.set(Vector2::new(4, 0), '-');
+
+        return tilemap;
+    }
+
+    #[test]
+    fn braille() {
+        let tilemap = build_test_tilemap();
+        assert_eq!(BrailleRenderer.render(&tilemap), "⠑⢄⠀\n⠀⠀⠁");
+        assert_eq!(BrailleRenderer.render(&Tilemap::new('-')), "");
+    }
+
+    #[test]
+    fn quadrant() {
+        let tilemap = build_test_tilemap();
+        assert_eq!(QuadrantRenderer.render(&tilemap), "▚  \n ▚ \n  ▘");
+    }
+}
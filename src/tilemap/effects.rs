@@ -0,0 +1,205 @@
+use crate::tilemap::{Overlay, TileSource, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Short-lived glyph of the [`EffectLayer`], such as a spark or a muzzle flash.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Particle {
+    /// # Description
+    /// Current position of the particle.
+    pub position: Vector2,
+    /// # Description
+    /// Value with which particle is drawn.
+    pub value: char,
+    /// # Description
+    /// Number of ticks the particle is still visible for.
+    pub lifetime: u64,
+    /// # Description
+    /// Number of grid steps by which particle moves along `X` and `Y` axes every tick.
+    pub velocity: (isize, isize),
+}
+
+/// # Description
+/// Layer of short-lived glyphs that live separately from the [`Tilemap`](crate::Tilemap), so
+/// effects never pollute persistent tiles. Particles move by their velocities and disappear when
+/// their lifetimes end, and are composited over any [`TileSource`] only when it is rendered.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::{TileSource, Vector2};
+///
+/// let tilemap = char_tilemap::Tilemap::from_string("#....", '.');
+/// let mut effects = char_tilemap::EffectLayer::new();
+/// effects.spawn_moving(Vector2::new(1, 0), '*', 2, (1, 0));
+/// assert_eq!(effects.overlay(&tilemap).build(), "#*...");
+///
+/// effects.tick();
+/// assert_eq!(effects.overlay(&tilemap).build(), "#.*..");
+///
+/// effects.tick();
+/// assert_eq!(effects.overlay(&tilemap).build(), "#....");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct EffectLayer {
+    /// # Description
+    /// Alive particles in order of spawning.
+    particles: Vec<Particle>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl EffectLayer {
+    /// # Description
+    /// Creates new [`EffectLayer`] without particles.
+    ///
+    /// # Return
+    /// New instance of the [`EffectLayer`].
+    pub fn new() -> EffectLayer {
+        return EffectLayer { particles: Vec::new() };
+    }
+
+    /// # Description
+    /// Spawns particle that stays at the same position.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the particle.
+    /// * `value: char` - Value with which particle is drawn.
+    /// * `lifetime: u64` - Number of ticks the particle is visible for. Nothing is spawned if it
+    ///   is 0.
+    pub fn spawn(&mut self, position: Vector2, value: char, lifetime: u64) {
+        self.spawn_moving(position, value, lifetime, (0, 0));
+    }
+
+    /// # Description
+    /// Spawns particle that moves every tick.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Initial position of the particle.
+    /// * `value: char` - Value with which particle is drawn.
+    /// * `lifetime: u64` - Number of ticks the particle is visible for. Nothing is spawned if it
+    ///   is 0.
+    /// * `velocity: (isize, isize)` - Number of grid steps by which particle moves along `X` and
+    ///   `Y` axes every tick.
+    pub fn spawn_moving(&mut self, position: Vector2, value: char, lifetime: u64, velocity: (isize, isize)) {
+        if lifetime > 0 {
+            self.particles.push(Particle { position, value, lifetime, velocity });
+        }
+    }
+
+    /// # Description
+    /// Advances all particles by a single tick: particles with ended lifetimes are removed, the
+    /// rest are moved by their velocities. Particles that move to negative positions are removed
+    /// as well.
+    ///
+    /// # Return
+    /// Number of removed particles.
+    pub fn tick(&mut self) -> usize {
+        let count = self.particles.len();
+        self.particles.retain_mut(|particle| {
+            particle.lifetime -= 1;
+            let x = particle.position.x.checked_add_signed(particle.velocity.0);
+            let y = particle.position.y.checked_add_signed(particle.velocity.1);
+
+            return match (particle.lifetime, x, y) {
+                (1.., Some(x), Some(y)) => {
+                    particle.position = Vector2::new(x, y);
+                    true
+                },
+                _ => false,
+            };
+        });
+
+        return count - self.particles.len();
+    }
+
+    /// # Description
+    /// Returns alive particles in order of spawning.
+    pub fn particles(&self) -> &[Particle] {
+        return &self.particles;
+    }
+
+    /// # Description
+    /// Returns number of alive particles.
+    pub fn len(&self) -> usize {
+        return self.particles.len();
+    }
+
+    /// # Description
+    /// Checks if there are no alive particles.
+    pub fn is_empty(&self) -> bool {
+        return self.particles.is_empty();
+    }
+
+    /// # Description
+    /// Removes all particles.
+    pub fn clear(&mut self) {
+        self.particles.clear();
+    }
+
+    /// # Description
+    /// Composites all alive particles over the source. Particles spawned later are drawn over
+    /// the earlier ones, and particles out of the source bounds are clipped.
+    ///
+    /// # Arguments
+    /// * `source: S` - Source over which particles are drawn.
+    ///
+    /// # Return
+    /// New [`Overlay`] with particles, to which other elements can be added.
+    pub fn overlay<S: TileSource>(&self, source: S) -> Overlay<S> {
+        let mut overlay = Overlay::new(source);
+        for particle in &self.particles {
+            overlay.add_tile(particle.position, particle.value);
+        }
+
+        return overlay;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{EffectLayer, TileSource, Tilemap, Vector2};
+
+    #[test]
+    fn tick() {
+        let mut effects = EffectLayer::new();
+        effects.spawn(Vector2::ONE, '!', 3);
+        effects.spawn(Vector2::ZERO, '?', 0);
+        effects.spawn_moving(Vector2::new(1, 0), '<', 5, (-1, 0));
+        effects.spawn_moving(Vector2::ONE, 'v', 2, (0, 1));
+        assert_eq!(effects.len(), 3);
+
+        assert_eq!(effects.tick(), 0);
+        assert_eq!(effects.particles()[1].position, Vector2::ZERO);
+        assert_eq!(effects.particles()[2].position, Vector2::new(1, 2));
+
+        assert_eq!(effects.tick(), 2);
+        assert_eq!(effects.particles()[0].value, '!');
+        assert_eq!(effects.tick(), 1);
+        assert!(effects.is_empty());
+    }
+
+    #[test]
+    fn overlay() {
+        let tilemap = Tilemap::from_string("...\n.#.", '.');
+        let mut effects = EffectLayer::new();
+        effects.spawn(Vector2::ONE, '*', 1);
+        effects.spawn(Vector2::ONE, '+', 1);
+        effects.spawn(Vector2::new(5, 0), '+', 1);
+        effects.spawn(Vector2::ZERO, '@', 1);
+
+        assert_eq!(effects.overlay(&tilemap).build(), "@..\n.+.");
+        assert_eq!(tilemap.build(), "...\n.#.");
+
+        effects.clear();
+        assert_eq!(effects.overlay(&tilemap).build(), tilemap.build());
+    }
+}
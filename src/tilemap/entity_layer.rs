@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Opaque identifier of an entity. Identifiers are never reused by the same owner.
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct EntityId(u64);
+
+/// # Description
+/// Entities with positions and glyphs, which can be looked up both by identifiers and by
+/// positions in constant time. Every position holds at most one entity, and glyphs of entities
+/// are kept in sync on the [`Tilemap`] of the layer on every spawn, move and despawn.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::{EntityLayer, Vector2};
+///
+/// let mut entities = EntityLayer::new('.');
+/// let player = entities.spawn(Vector2::ZERO, '@').unwrap();
+/// entities.move_entity(player, Vector2::new(2, 0)).unwrap();
+///
+/// assert_eq!(entities.entity_at(Vector2::new(2, 0)), Some(player));
+/// assert_eq!(entities.tilemap().build(), "..@");
+/// ```
+#[derive(Debug, Clone)]
+pub struct EntityLayer {
+    /// # Description
+    /// Glyphs of all entities.
+    tilemap: Tilemap,
+    /// # Description
+    /// Positions and glyphs of entities by their identifiers.
+    entities: HashMap<EntityId, (Vector2, char)>,
+    /// # Description
+    /// Identifiers of entities by their positions.
+    positions: HashMap<Vector2, EntityId>,
+    /// # Description
+    /// Identifier of the next spawned entity.
+    next_id: u64,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl EntityLayer {
+    /// # Description
+    /// Creates new [`EntityLayer`] without entities.
+    ///
+    /// # Arguments
+    /// * `empty_tile: char` - Empty tile of the [`Tilemap`] of the layer.
+    ///
+    /// # Return
+    /// New instance of the [`EntityLayer`].
+    pub fn new(empty_tile: char) -> EntityLayer {
+        return EntityLayer { tilemap: Tilemap::new(empty_tile), entities: HashMap::new(), positions: HashMap::new(), next_id: 0 };
+    }
+
+    /// # Description
+    /// Returns [`Tilemap`] with glyphs of all entities.
+    pub fn tilemap(&self) -> &Tilemap {
+        return &self.tilemap;
+    }
+
+    /// # Description
+    /// Adds new entity and draws its glyph.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the entity.
+    /// * `glyph: char` - Value with which entity is drawn.
+    ///
+    /// # Return
+    /// * [`Ok`] with identifier of the new entity.
+    /// * [`Err`] if position is occupied by another entity. Contains error message.
+    pub fn spawn(&mut self, position: Vector2, glyph: char) -> Result<EntityId, String> {
+        if self.positions.contains_key(&position) {
+            return Err(format!("Position {position} is occupied by another entity"));
+        }
+
+        let id = EntityId(self.next_id);
+        self.next_id += 1;
+        self.entities.insert(id, (position, glyph));
+        self.positions.insert(position, id);
+        self.tilemap.set(position, glyph);
+        return Ok(id);
+    }
+
+    /// # Description
+    /// Removes entity and its glyph.
+    ///
+    /// # Arguments
+    /// * `id: EntityId` - Identifier of the entity.
+    ///
+    /// # Return
+    /// * [`Some`] with the last position of the entity.
+    /// * [`None`] if entity does not exist.
+    pub fn despawn(&mut self, id: EntityId) -> Option<Vector2> {
+        let (position, _) = self.entities.remove(&id)?;
+        self.positions.remove(&position);
+        let _ = self.tilemap.remove_tile(position);
+        return Some(position);
+    }
+
+    /// # Description
+    /// Moves entity and its glyph.
+    ///
+    /// # Arguments
+    /// * `id: EntityId` - Identifier of the entity.
+    /// * `position: Vector2` - New position of the entity.
+    ///
+    /// # Return
+    /// * [`Ok`] if entity was moved.
+    /// * [`Err`] if entity does not exist, or position is occupied by another entity. Contains
+    ///   error message.
+    pub fn move_entity(&mut self, id: EntityId, position: Vector2) -> Result<(), String> {
+        let Some((old, glyph)) = self.entities.get(&id).copied() else {
+            return Err(format!("Entity {} does not exist", id.0));
+        };
+        match self.positions.get(&position) {
+            Some(other) if *other != id => return Err(format!("Position {position} is occupied by another entity")),
+            _ => (),
+        }
+
+        self.entities.insert(id, (position, glyph));
+        self.positions.remove(&old);
+        self.positions.insert(position, id);
+        let _ = self.tilemap.remove_tile(old);
+        self.tilemap.set(position, glyph);
+        return Ok(());
+    }
+
+    /// # Description
+    /// Changes glyph of the entity and redraws it.
+    ///
+    /// # Arguments
+    /// * `id: EntityId` - Identifier of the entity.
+    /// * `glyph: char` - New value with which entity is drawn.
+    ///
+    /// # Return
+    /// * [`Ok`] if glyph was changed.
+    /// * [`Err`] if entity does not exist. Contains error message.
+    pub fn set_glyph(&mut self, id: EntityId, glyph: char) -> Result<(), String> {
+        let Some((position, old)) = self.entities.get_mut(&id) else {
+            return Err(format!("Entity {} does not exist", id.0));
+        };
+
+        *old = glyph;
+        self.tilemap.set(*position, glyph);
+        return Ok(());
+    }
+
+    /// # Description
+    /// Returns position of the entity.
+    ///
+    /// # Return
+    /// * [`Some`] with the position.
+    /// * [`None`] if entity does not exist.
+    pub fn position(&self, id: EntityId) -> Option<Vector2> {
+        return self.entities.get(&id).map(|(position, _)| *position);
+    }
+
+    /// # Description
+    /// Returns glyph of the entity.
+    ///
+    /// # Return
+    /// * [`Some`] with the glyph.
+    /// * [`None`] if entity does not exist.
+    pub fn glyph(&self, id: EntityId) -> Option<char> {
+        return self.entities.get(&id).map(|(_, glyph)| *glyph);
+    }
+
+    /// # Description
+    /// Returns entity at the position.
+    ///
+    /// # Return
+    /// * [`Some`] with identifier of the entity.
+    /// * [`None`] if there is no entity at the position.
+    pub fn entity_at(&self, position: Vector2) -> Option<EntityId> {
+        return self.positions.get(&position).copied();
+    }
+
+    /// # Description
+    /// Returns identifiers and positions of all entities in unspecified order.
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, Vector2)> + '_ {
+        return self.entities.iter().map(|(id, (position, _))| (*id, *position));
+    }
+
+    /// # Description
+    /// Returns number of entities.
+    pub fn len(&self) -> usize {
+        return self.entities.len();
+    }
+
+    /// # Description
+    /// Checks if there are no entities.
+    pub fn is_empty(&self) -> bool {
+        return self.entities.is_empty();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{EntityLayer, Vector2};
+
+    #[test]
+    fn entities() {
+        let mut entities = EntityLayer::new('.');
+        let player = entities.spawn(Vector2::ZERO, '@').unwrap();
+        let goblin = entities.spawn(Vector2::new(2, 1), 'g').unwrap();
+        match entities.spawn(Vector2::ZERO, 'x') {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true),
+        }
+        assert_eq!(entities.tilemap().build(), "@..\n..g");
+        assert_eq!(entities.len(), 2);
+
+        entities.move_entity(player, Vector2::new(1, 1)).unwrap();
+        entities.move_entity(player, Vector2::new(1, 1)).unwrap();
+        match entities.move_entity(player, Vector2::new(2, 1)) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true),
+        }
+        entities.set_glyph(goblin, 'G').unwrap();
+        assert_eq!(entities.tilemap().build(), "...\n.@G");
+        assert_eq!(entities.entity_at(Vector2::new(1, 1)), Some(player));
+        assert_eq!(entities.entity_at(Vector2::ZERO), None);
+        assert_eq!(entities.glyph(goblin), Some('G'));
+
+        assert_eq!(entities.despawn(goblin), Some(Vector2::new(2, 1)));
+        assert_eq!(entities.despawn(goblin), None);
+        assert_eq!(entities.tilemap().build(), "...\n.@.");
+        assert_eq!(entities.position(goblin), None);
+        assert_eq!(entities.iter().collect::<Vec<_>>(), vec![(player, Vector2::new(1, 1))]);
+        match entities.move_entity(goblin, Vector2::ZERO) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true),
+        }
+        match entities.set_glyph(goblin, 'x') {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true),
+        }
+    }
+
+    #[test]
+    fn ids_are_not_reused() {
+        let mut entities = EntityLayer::new('.');
+        let first = entities.spawn(Vector2::ZERO, 'a').unwrap();
+        entities.despawn(first);
+        let second = entities.spawn(Vector2::ZERO, 'b').unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(entities.position(first), None);
+        assert!(!entities.is_empty());
+    }
+}
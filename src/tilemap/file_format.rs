@@ -0,0 +1,517 @@
+use std::collections::HashMap;
+use crate::tilemap::{FileParser, Tilemap, Vector2, FILE_FORMAT_VERSION};
+use crate::tilemap::migration::{normalize_line_breaks, VERSION_KEY};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Key of the header line that stores [`Tilemap::empty_tile`].
+pub(super) const EMPTY_TILE_KEY: &str = "empty_tile";
+
+/// # Description
+/// Key of the header line that stores size of the [`Tilemap`].
+pub(super) const SIZE_KEY: &str = "size";
+
+/// # Description
+/// Key of the header line that stores [`Tilemap::cell_width`]. It is omitted if cell width is 1.
+pub(super) const CELL_WIDTH_KEY: &str = "cell_width";
+
+/// # Description
+/// Key of the header lines that store grapheme clusters of the [`Tilemap`] in format
+/// `{cluster}@{char}`.
+pub(super) const CLUSTER_KEY: &str = "cluster";
+
+/// # Description
+/// Number of columns between tab stops that is used to expand tabs of ASCII art.
+const TAB_WIDTH: usize = 8;
+
+/// # Description
+/// Empty tile of tilemaps created from ASCII art.
+const ASCII_EMPTY_TILE: char = ' ';
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Creates new [`Tilemap`] from its string representation, such as the one produced by
+    /// [`Tilemap::build()`]. Every char that is not equal to `empty_tile` becomes a tile.
+    /// Carriage returns are ignored.
+    ///
+    /// # Arguments
+    /// * `text: &str` - String representation of the tilemap. Rows are separated by `'\n'`.
+    /// * `empty_tile: char` - Value of the empty tile.
+    ///
+    /// # Return
+    /// New instance of the [`Tilemap`]. Its size covers all rows and the longest row.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("O--\n-X", '-');
+    /// assert_eq!(tilemap.get(char_tilemap::Vector2::ONE), Some('X'));
+    /// assert_eq!(tilemap.build(), "O--\n-X-");
+    /// ```
+    pub fn from_string(text: &str, empty_tile: char) -> Tilemap {
+        let mut tilemap = Tilemap::new(empty_tile);
+        if text.is_empty() {
+            return tilemap;
+        }
+
+        let mut size = Vector2::ZERO;
+        for (y, line) in text.split('\n').enumerate() {
+            let mut width = 0;
+            for (x, value) in line.chars().filter(|value| *value != '\r').enumerate() {
+                if value != empty_tile {
+                    tilemap.set(Vector2::new(x, y), value);
+                }
+                width = x + 1;
+            }
+
+            size.x = std::cmp::max(size.x, width);
+            size.y = y + 1;
+        }
+
+        tilemap.size = size;
+        return tilemap;
+    }
+
+    /// # Description
+    /// Creates new [`Tilemap`] from the template, where placeholder chars are replaced by values
+    /// bound to them, see [`Tilemap::from_string()`]. This allows one template to describe
+    /// several variants of a prefab, for example where doors or chests are placed.
+    ///
+    /// # Arguments
+    /// * `text: &str` - String representation of the template. Rows are separated by `'\n'`.
+    /// * `empty_tile: char` - Value of the empty tile.
+    /// * `bindings: &HashMap<char, Option<char>>` - Values of placeholders. Placeholders bound to
+    ///   [`None`] become empty tiles. Chars without bindings are kept as they are.
+    ///
+    /// # Return
+    /// New instance of the [`Tilemap`]. Its size is the same as the size of the template.
+    ///
+    /// # Example
+    /// ```rust
+    /// let bindings = std::collections::HashMap::from([('1', Some('+')), ('2', None)]);
+    /// let tilemap = char_tilemap::Tilemap::from_template("#1#\n#2#", '.', &bindings);
+    /// assert_eq!(tilemap.build(), "#+#\n#.#");
+    /// ```
+    pub fn from_template(text: &str, empty_tile: char, bindings: &HashMap<char, Option<char>>) -> Tilemap {
+        let text: String = text.chars()
+            .map(|value| match bindings.get(&value) {
+                Some(binding) => binding.unwrap_or(empty_tile),
+                None => value,
+            })
+            .collect();
+
+        return Tilemap::from_string(&text, empty_tile);
+    }
+
+    /// # Description
+    /// Creates new [`Tilemap`] from plain-text ASCII art. Spaces and `empty_chars` become empty
+    /// tiles, tabs are expanded to the next multiple of 8 columns, and carriage returns, a byte order
+    /// mark, trailing whitespaces of every line and trailing empty lines are ignored.
+    ///
+    /// # Arguments
+    /// * `text: &str` - ASCII art.
+    /// * `empty_chars: &[char]` - Chars that are treated as empty in addition to spaces.
+    ///
+    /// # Return
+    /// New instance of the [`Tilemap`] with space as an empty tile. Its size covers all rows up
+    /// to the last one with tiles, and the longest of these rows.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_ascii_string(" /\\ \t\n/..\\\n\n", &['.']);
+    /// assert_eq!(tilemap.build(), " /\\ \n/  \\");
+    /// ```
+    pub fn from_ascii_string(text: &str, empty_chars: &[char]) -> Tilemap {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        let mut tilemap = Tilemap::new(ASCII_EMPTY_TILE);
+        let mut widths = Vec::new();
+
+        for (y, line) in text.lines().enumerate() {
+            let mut x = 0;
+            for value in line.trim_end().chars() {
+                match value {
+                    '\t' => x = (x / TAB_WIDTH + 1) * TAB_WIDTH,
+                    value if value == ASCII_EMPTY_TILE || empty_chars.contains(&value) => x += 1,
+                    value => {
+                        tilemap.set(Vector2::new(x, y), value);
+                        x += 1;
+                    },
+                }
+            }
+            widths.push(x);
+        }
+
+        // Rows after the last tile are trailing empty lines
+        let height = tilemap.tiles.iter().last().map_or(0, |tile| tile.position.y + 1);
+        tilemap.size = Vector2::new(widths[..height].iter().copied().max().unwrap_or(0), height);
+        return tilemap;
+    }
+
+    /// # Description
+    /// Loads [`Tilemap`] from the plain-text ASCII art file, see [`Tilemap::from_ascii_string()`].
+    ///
+    /// # Arguments
+    /// * `path: P` - Path to the file.
+    /// * `empty_chars: &[char]` - Chars that are treated as empty in addition to spaces.
+    ///
+    /// # Return
+    /// * [`Ok`] with loaded [`Tilemap`].
+    /// * [`Err`] if file could not be read. Contains error message.
+    pub fn from_ascii_file<P: AsRef<std::path::Path>>(path: P, empty_chars: &[char]) -> Result<Tilemap, String> {
+        let text = std::fs::read_to_string(&path)
+            .map_err(|error| format!("Failed to load ASCII art from {}: {error}", path.as_ref().display()))?;
+        return Ok(Tilemap::from_ascii_string(&text, empty_chars));
+    }
+
+    /// # Description
+    /// Serializes [`Tilemap`] into the text save format. Format consists of `key=value` header
+    /// lines, an empty line and built rows of the tilemap. The first header line stores
+    /// [`crate::FILE_FORMAT_VERSION`]. Grapheme clusters set by [`Tilemap::set_cluster()`] are
+    /// stored in `cluster` header lines, and bookmarks set by [`Tilemap::set_bookmark()`] are
+    /// stored in `bookmark` header lines, and tags set by [`Tilemap::tag()`] are stored in `tag`
+    /// header lines. Fields of [`Tilemap::metadata()`] are stored in
+    /// `meta.{field}` header lines.
+    ///
+    /// # Return
+    /// A new [`String`] with serialized [`Tilemap`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('-');
+    /// tilemap.set(char_tilemap::Vector2::new(1, 0), 'O');
+    /// assert_eq!(tilemap.to_file_string(), "version=3\nempty_tile=-\nsize=2x1\n\n-O");
+    /// ```
+    pub fn to_file_string(&self) -> String {
+        let mut extra = self.cluster_lines();
+        extra.push_str(&self.bookmark_lines());
+        extra.push_str(&self.tag_lines());
+        if self.cell_width > 1 {
+            extra = format!("{CELL_WIDTH_KEY}={}\n{extra}", self.cell_width);
+        }
+        extra = format!("{}{extra}", self.metadata.to_lines());
+
+        return format!("{VERSION_KEY}={FILE_FORMAT_VERSION}\n{EMPTY_TILE_KEY}={}\n{SIZE_KEY}={}x{}\n{extra}\n{}",
+                       self.empty_tile, self.size.x, self.size.y, self.build_tiles());
+    }
+
+    /// # Description
+    /// Deserializes [`Tilemap`] from the text save format produced by [`Tilemap::to_file_string()`].
+    /// Text saved by older versions of the format is migrated first, see [`Tilemap::migrate()`].
+    /// Lines may end with `\r\n`.
+    ///
+    /// # Arguments
+    /// * `text: &str` - Serialized [`Tilemap`].
+    ///
+    /// # Return
+    /// * [`Ok`] with deserialized [`Tilemap`].
+    /// * [`Err`] if text is not in the save format, or its version is not supported. Contains
+    ///   error message.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_file_string("empty_tile=-\nsize=3x1\n\n-O").unwrap();
+    /// assert_eq!(tilemap.build(), "-O-");
+    /// ```
+    pub fn from_file_string(text: &str) -> Result<Tilemap, String> {
+        let text = normalize_line_breaks(text);
+        let (header, body) = match text.split_once("\n\n") {
+            Some(parts) => parts,
+            None => (text.trim_end_matches('\n'), ""),
+        };
+
+        let mut parser = FileParser::new();
+        for line in header.split('\n') {
+            parser.push_line(line)?;
+        }
+
+        parser.push_line("")?;
+        if !body.is_empty() {
+            for line in body.split('\n') {
+                parser.push_line(line)?;
+            }
+        }
+
+        return parser.finish();
+    }
+
+    /// # Description
+    /// Deserializes [`Tilemap`] same as [`Tilemap::from_file_string()`], but only accepts tiles
+    /// whose values belong to the palette, so typos of hand-authored map files are not accepted
+    /// silently.
+    ///
+    /// # Arguments
+    /// * `text: &str` - Serialized [`Tilemap`].
+    /// * `palette: &[&str]` - Allowed values of tiles, each is either a single char or a
+    ///   grapheme cluster, see [`Tilemap::set_cluster()`]. The empty tile is always allowed.
+    ///
+    /// # Return
+    /// * [`Ok`] with deserialized [`Tilemap`].
+    /// * [`Err`] if text is not in the save format, or has tiles with values outside of the
+    ///   palette. Contains error message that lists every such tile with its position and the
+    ///   text of its value.
+    ///
+    /// # Example
+    /// ```rust
+    /// let text = "empty_tile=.\nsize=3x2\n\n#.#\n.x#";
+    /// match char_tilemap::Tilemap::from_file_string_with_palette(text, &["#"]) {
+    ///     Ok(_) => unreachable!(),
+    ///     Err(message) => assert_eq!(message, "Unknown glyph 'x' at { x: 1, y: 1 }"),
+    /// }
+    /// ```
+    pub fn from_file_string_with_palette(text: &str, palette: &[&str]) -> Result<Tilemap, String> {
+        let tilemap = Tilemap::from_file_string(text)?;
+        let unknown: Vec<String> = tilemap.tiles.iter()
+            .map(|tile| (tilemap.cluster_of(tile.value), tile.position))
+            .filter(|(value, _)| !palette.contains(&value.as_ref()))
+            .map(|(value, position)| format!("Unknown glyph '{}' at {position}", value.escape_debug()))
+            .collect();
+
+        if !unknown.is_empty() {
+            return Err(unknown.join("\n"));
+        }
+
+        return Ok(tilemap);
+    }
+
+    /// # Description
+    /// Saves [`Tilemap`] to the file in the text save format.
+    ///
+    /// # Arguments
+    /// * `path: P` - Path to the file. File will be created or overwritten.
+    ///
+    /// # Return
+    /// * [`Ok`] if [`Tilemap`] was saved.
+    /// * [`Err`] if file could not be written. Contains error message.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(path = %path.as_ref().display()), err))]
+    pub fn save_to_file<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), String> {
+        return std::fs::write(&path, self.to_file_string())
+            .map_err(|error| format!("Failed to save tilemap to {}: {error}", path.as_ref().display()));
+    }
+
+    /// # Description
+    /// Loads [`Tilemap`] from the file in the text save format.
+    ///
+    /// # Arguments
+    /// * `path: P` - Path to the file.
+    ///
+    /// # Return
+    /// * [`Ok`] with loaded [`Tilemap`].
+    /// * [`Err`] if file could not be read or is not in the save format. Contains error message.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(path = %path.as_ref().display()), err))]
+    pub fn load_from_file<P: AsRef<std::path::Path>>(path: P) -> Result<Tilemap, String> {
+        let text = std::fs::read_to_string(&path)
+            .map_err(|error| format!("Failed to load tilemap from {}: {error}", path.as_ref().display()))?;
+        return Tilemap::from_file_string(&text);
+    }
+
+    /// # Description
+    /// Loads [`Tilemap`] from the file in the text save format, and validates its tiles against
+    /// the palette, see [`Tilemap::from_file_string_with_palette()`].
+    ///
+    /// # Arguments
+    /// * `path: P` - Path to the file.
+    /// * `palette: &[&str]` - Allowed values of tiles. The empty tile is always allowed.
+    ///
+    /// # Return
+    /// * [`Ok`] with loaded [`Tilemap`].
+    /// * [`Err`] if file could not be read, is not in the save format, or has tiles with values
+    ///   outside of the palette. Contains error message.
+    pub fn load_from_file_with_palette<P: AsRef<std::path::Path>>(path: P, palette: &[&str]) -> Result<Tilemap, String> {
+        let text = std::fs::read_to_string(&path)
+            .map_err(|error| format!("Failed to load tilemap from {}: {error}", path.as_ref().display()))?;
+        return Tilemap::from_file_string_with_palette(&text, palette);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Parses header value that should contain exactly one char.
+pub(super) fn parse_char(value: &str) -> Result<char, String> {
+    let mut chars = value.chars();
+    return match (chars.next(), chars.next()) {
+        (Some(value), None) => Ok(value),
+        _ => Err(format!("Expected single char, but got \"{value}\"")),
+    };
+}
+
+/// # Description
+/// Parses header value that should contain size in format `{x}x{y}`.
+pub(super) fn parse_size(value: &str) -> Result<Vector2, String> {
+    let error = || format!("Expected size in format {{x}}x{{y}}, but got \"{value}\"");
+    let (x, y) = value.split_once('x').ok_or_else(error)?;
+    let x = x.parse().map_err(|_| error())?;
+    let y = y.parse().map_err(|_| error())?;
+    return Ok(Vector2::new(x, y));
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use crate::tilemap::{Tilemap, Vector2};
+
+    fn build_test_tilemap() -> Tilemap {
+        let mut tilemap = Tilemap::new(' ');
+        tilemap.set(Vector2::new(0, 0), '#');
+        tilemap.set(Vector2::new(2, 1), '@');
+        tilemap.resize(Vector2::new(4, 3), Default::default()).unwrap();
+
+        return tilemap;
+    }
+
+    #[test]
+    fn from_string() {
+        let tilemap = Tilemap::from_string("#-\r\n---@\n", '-');
+
+        assert_eq!(tilemap.size(), Vector2::new(4, 3));
+        assert_eq!(tilemap.get(Vector2::new(3, 1)), Some('@'));
+        assert_eq!(Tilemap::from_string("", '-').size(), Vector2::ZERO);
+    }
+
+    #[test]
+    fn from_template() {
+        let template = "#1#\n2.3\n###";
+        let bindings = HashMap::from([('1', Some('+')), ('2', None), ('3', Some('.'))]);
+        let tilemap = Tilemap::from_template(template, '.', &bindings);
+
+        assert_eq!(tilemap.size(), Vector2::new(3, 3));
+        assert_eq!(tilemap.build(), "#+#\n...\n###");
+        assert_eq!(tilemap.get(Vector2::new(0, 1)), None);
+        assert_eq!(Tilemap::from_template(template, '.', &HashMap::new()).build(), template);
+    }
+
+    #[test]
+    fn from_ascii_string() {
+        let tilemap = Tilemap::from_ascii_string("\u{feff}  @~~\r\n\tX  \n\n~~~\n \t\n", &['~']);
+
+        assert_eq!(tilemap.size(), Vector2::new(9, 2));
+        assert_eq!(tilemap.get(Vector2::new(2, 0)), Some('@'));
+        assert_eq!(tilemap.get(Vector2::new(8, 1)), Some('X'));
+        assert_eq!(tilemap.build(), "  @      \n        X");
+        assert_eq!(Tilemap::from_ascii_string("", &[]).size(), Vector2::ZERO);
+    }
+
+    #[test]
+    fn from_ascii_file() {
+        let path = std::env::temp_dir().join("char_tilemap_file_format_ascii.txt");
+        std::fs::write(&path, " _\n|_|\n").unwrap();
+
+        assert_eq!(Tilemap::from_ascii_file(&path, &[]).unwrap().build(), " _ \n|_|");
+
+        std::fs::remove_file(&path).unwrap();
+        match Tilemap::from_ascii_file(&path, &[]) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+
+    #[test]
+    fn file_string() {
+        let tilemap = build_test_tilemap();
+        let text = tilemap.to_file_string();
+        assert_eq!(text, "version=3\nempty_tile= \nsize=4x3\n\n#   \n  @ \n    ");
+
+        let loaded = Tilemap::from_file_string(&text).unwrap();
+        assert_eq!(loaded.size(), tilemap.size());
+        assert_eq!(loaded.build(), tilemap.build());
+        assert_eq!(Tilemap::from_file_string("empty_tile=-\nsize=0x0").unwrap().size(), Vector2::ZERO);
+    }
+
+    #[test]
+    fn file_string_clusters() {
+        let mut tilemap = build_test_tilemap();
+        tilemap.set_cluster(Vector2::new(1, 1), "e\u{301}").unwrap();
+        let text = tilemap.to_file_string();
+        assert_eq!(text, "version=3\nempty_tile= \nsize=4x3\ncluster=e\u{301}@\u{E000}\n\n#   \n \u{E000}@ \n    ");
+
+        let loaded = Tilemap::from_file_string(&text).unwrap();
+        assert_eq!(loaded.cluster(Vector2::new(1, 1)).unwrap(), "e\u{301}");
+        assert_eq!(loaded.build_clusters(), tilemap.build_clusters());
+    }
+
+    #[test]
+    fn file_string_cell_width() {
+        let mut tilemap = build_test_tilemap();
+        tilemap.cell_width = 2;
+        let text = tilemap.to_file_string();
+        assert_eq!(text, "version=3\nempty_tile= \nsize=4x3\ncell_width=2\n\n#   \n  @ \n    ");
+        assert_eq!(Tilemap::from_file_string(&text).unwrap().build(), tilemap.build());
+
+        match Tilemap::from_file_string("empty_tile=-\nsize=1x1\ncell_width=x\n\n-") {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+
+    #[test]
+    fn file_string_with_palette() {
+        let text = "empty_tile=.\nsize=3x2\n\n#x#\n.@y";
+        assert_eq!(Tilemap::from_file_string_with_palette(text, &["#", "x", "y", "@"]).unwrap().build(), "#x#\n.@y");
+
+        match Tilemap::from_file_string_with_palette(text, &["#", "@"]) {
+            Ok(_) => assert!(false),
+            Err(message) => assert_eq!(message, "Unknown glyph 'x' at { x: 1, y: 0 }\nUnknown glyph 'y' at { x: 2, y: 1 }"),
+        }
+
+        let mut tilemap = Tilemap::from_string("#.", '.');
+        tilemap.set_cluster(Vector2::new(1, 0), "e\u{301}").unwrap();
+        let text = tilemap.to_file_string();
+        assert_eq!(Tilemap::from_file_string_with_palette(&text, &["#", "e\u{301}"]).unwrap().build_clusters(), "#e\u{301}");
+        match Tilemap::from_file_string_with_palette(&text, &["#", "\u{E000}"]) {
+            Ok(_) => assert!(false),
+            Err(message) => assert_eq!(message, "Unknown glyph 'e\u{301}' at { x: 1, y: 0 }"),
+        }
+
+        let path = std::env::temp_dir().join("char_tilemap_file_format_palette.txt");
+        std::fs::write(&path, text).unwrap();
+        match Tilemap::load_from_file_with_palette(&path, &["#"]) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn file_string_errors() {
+        for text in ["size=1x1\n\n-", "empty_tile=-\n\n-", "empty_tile=--\nsize=1x1\n\n-",
+                     "empty_tile=-\nsize=1\n\n-", "empty_tile=-\nsize=1x1\nkey=value\n\n-",
+                     "empty_tile=-\nsize=1x1\n\n-O", "invalid\n\n-"] {
+            match Tilemap::from_file_string(text) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+
+    #[test]
+    fn save_load() {
+        let path = std::env::temp_dir().join("char_tilemap_file_format_save_load.tilemap");
+        let tilemap = build_test_tilemap();
+
+        match tilemap.save_to_file(&path) {
+            Ok(_) => assert!(true),
+            Err(_) => assert!(false)
+        }
+
+        let loaded = Tilemap::load_from_file(&path).unwrap();
+        assert_eq!(loaded.build(), tilemap.build());
+
+        std::fs::remove_file(&path).unwrap();
+        match Tilemap::load_from_file(&path) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+}
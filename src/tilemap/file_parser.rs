@@ -0,0 +1,319 @@
+use crate::tilemap::{MapMetadata, Tilemap, Vector2, FILE_FORMAT_VERSION};
+use crate::tilemap::bookmarks::{parse_bookmark, BOOKMARK_KEY};
+use crate::tilemap::checksum::{Crc32, CHECKSUM_KEY};
+use crate::tilemap::clusters::parse_cluster;
+use crate::tilemap::file_format::{parse_char, parse_size, CELL_WIDTH_KEY, CLUSTER_KEY, EMPTY_TILE_KEY, SIZE_KEY};
+use crate::tilemap::metadata::METADATA_PREFIX;
+use crate::tilemap::migration::{parse_version, VERSION_KEY};
+use crate::tilemap::tags::{parse_tag, TAG_KEY};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Parser of the text save format produced by [`Tilemap::to_file_string()`] that consumes it
+/// line by line, so the whole file never has to be kept in memory. Tiles are added to the
+/// [`Tilemap`] as soon as their row is pushed. Header lines are kept until the header ends and
+/// are migrated from older versions of the format first, see [`Tilemap::migrate()`].
+///
+/// # Example
+/// ```rust
+/// let mut parser = char_tilemap::FileParser::new();
+/// for line in ["empty_tile=-", "size=3x2", "", "-O", "--X"] {
+///     parser.push_line(line).unwrap();
+/// }
+///
+/// assert_eq!(parser.finish().unwrap().build(), "-O-\n--X");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FileParser {
+    /// # Description
+    /// Header lines that were pushed before the header ended.
+    header: Vec<String>,
+    /// # Description
+    /// Value of the `empty_tile` header line.
+    empty_tile: Option<char>,
+    /// # Description
+    /// Value of the `size` header line.
+    size: Option<Vector2>,
+    /// # Description
+    /// Value of the `cell_width` header line.
+    cell_width: Option<usize>,
+    /// # Description
+    /// Values of the `cluster` header lines by their chars.
+    clusters: std::collections::BTreeMap<char, String>,
+    /// # Description
+    /// Values of the `bookmark` header lines in order.
+    bookmarks: Vec<(String, Vector2)>,
+    /// # Description
+    /// Values of the `tag` header lines in order.
+    tags: Vec<(String, std::collections::BTreeSet<Vector2>)>,
+    /// # Description
+    /// Values of the `meta.{field}` header lines.
+    metadata: MapMetadata,
+    /// # Description
+    /// [`Tilemap`] that is being filled by rows. It is created when header ends.
+    tilemap: Option<Tilemap>,
+    /// # Description
+    /// Index of the next row.
+    row: usize,
+    /// # Description
+    /// CRC32 of all pushed lines, see [`Tilemap::to_file_string_with_checksum()`].
+    crc: Crc32,
+    /// # Description
+    /// Was the checksum footer pushed and verified.
+    verified: bool,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl FileParser {
+    /// # Description
+    /// Creates new [`FileParser`] that expects the header.
+    ///
+    /// # Return
+    /// New instance of the [`FileParser`].
+    pub fn new() -> FileParser {
+        return FileParser::default();
+    }
+
+    /// # Description
+    /// Parses the next line of the save format. Header lines are followed by an empty line, rows
+    /// of the [`Tilemap`] and an optional checksum footer, which is verified when it is pushed.
+    /// Carriage returns are ignored.
+    ///
+    /// # Arguments
+    /// * `line: &str` - Line without the trailing `'\n'`.
+    ///
+    /// # Return
+    /// * [`Ok`] if line was parsed.
+    /// * [`Err`] if line is an invalid header line, version is not supported, header misses
+    ///   required keys, row does not fit into the size, checksum does not match, or there are lines
+    ///   after the checksum. Contains error message.
+    pub fn push_line(&mut self, line: &str) -> Result<(), String> {
+        let line = line.trim_end_matches('\r');
+        if self.verified {
+            return Err(String::from("Unexpected line after the checksum"));
+        }
+
+        let Some(tilemap) = &mut self.tilemap else {
+            self.crc.update_line(line);
+            return self.push_header_line(line);
+        };
+
+        let size = tilemap.size;
+        if self.row >= size.y {
+            if let Some(value) = line.strip_prefix(CHECKSUM_KEY).and_then(|value| value.strip_prefix('=')) {
+                return self.verify_checksum(value);
+            }
+        }
+
+        self.crc.update_line(line);
+        if self.row >= size.y {
+            return Err(format!("Tiles do not fit into the size {size}"));
+        }
+
+        for (x, value) in line.chars().filter(|value| *value != '\r').enumerate() {
+            if x >= size.x {
+                return Err(format!("Tiles do not fit into the size {size}"));
+            }
+            if value != tilemap.empty_tile {
+                tilemap.set(Vector2::new(x, self.row), value);
+            }
+        }
+
+        self.row += 1;
+        return Ok(());
+    }
+
+    /// # Description
+    /// Checks if the checksum footer was pushed and matched all previous lines.
+    pub fn is_verified(&self) -> bool {
+        return self.verified;
+    }
+
+    /// # Description
+    /// Finishes parsing.
+    ///
+    /// # Return
+    /// * [`Ok`] with parsed [`Tilemap`].
+    /// * [`Err`] if header misses required keys, or there are fewer rows than the size, for
+    ///   example, because the file is truncated. Contains error message.
+    pub fn finish(mut self) -> Result<Tilemap, String> {
+        if self.tilemap.is_none() {
+            self.end_header()?;
+        }
+
+        let tilemap = self.tilemap.unwrap_or_else(|| Tilemap::new(' '));
+        if self.row < tilemap.size.y {
+            return Err(format!("Expected {} rows, but got {}: file could be truncated", tilemap.size.y, self.row));
+        }
+
+        return Ok(tilemap);
+    }
+
+    /// # Description
+    /// Compares value of the checksum footer with the checksum of all pushed lines.
+    fn verify_checksum(&mut self, value: &str) -> Result<(), String> {
+        let expected = match value.len() {
+            8 => u32::from_str_radix(value, 16).map_err(|_| format!("Invalid checksum \"{value}\""))?,
+            _ => return Err(format!("Invalid checksum \"{value}\"")),
+        };
+        let actual = self.crc.value();
+        if expected != actual {
+            return Err(format!("Checksum mismatch: file is corrupted (expected {expected:08x}, got {actual:08x})"));
+        }
+
+        self.verified = true;
+        return Ok(());
+    }
+
+    /// # Description
+    /// Keeps header line until the header ends, or ends header if line is empty. Version is
+    /// checked right away.
+    fn push_header_line(&mut self, line: &str) -> Result<(), String> {
+        if line.is_empty() {
+            return self.end_header();
+        }
+
+        let (key, value) = match line.split_once('=') {
+            Some(pair) => pair,
+            None => return Err(format!("Invalid header line \"{line}\"")),
+        };
+        if key == VERSION_KEY {
+            let version = parse_version(value)?;
+            if version > FILE_FORMAT_VERSION {
+                return Err(format!("File format version {version} is newer than supported version {FILE_FORMAT_VERSION}"));
+            }
+        }
+
+        self.header.push(line.to_string());
+        return Ok(());
+    }
+
+    /// # Description
+    /// Parses header line of the current version of the format.
+    fn parse_header_line(&mut self, line: &str) -> Result<(), String> {
+        let (key, value) = match line.split_once('=') {
+            Some(pair) => pair,
+            None => return Err(format!("Invalid header line \"{line}\"")),
+        };
+
+        match key {
+            VERSION_KEY => (),
+            EMPTY_TILE_KEY => self.empty_tile = Some(parse_char(value)?),
+            SIZE_KEY => self.size = Some(parse_size(value)?),
+            CLUSTER_KEY => {
+                let (value, cluster) = parse_cluster(value)?;
+                self.clusters.insert(value, cluster);
+            },
+            BOOKMARK_KEY => self.bookmarks.push(parse_bookmark(value)?),
+            TAG_KEY => self.tags.push(parse_tag(value)?),
+            CELL_WIDTH_KEY => self.cell_width = Some(value.parse().map_err(|_| format!("Invalid cell width \"{value}\""))?),
+            _ => match key.strip_prefix(METADATA_PREFIX) {
+                Some(field) => self.metadata.parse_field(field, value)?,
+                None => return Err(format!("Unknown header key \"{key}\"")),
+            },
+        }
+
+        return Ok(());
+    }
+
+    /// # Description
+    /// Migrates and parses kept header lines, and creates empty [`Tilemap`] from them.
+    fn end_header(&mut self) -> Result<(), String> {
+        let header = std::mem::take(&mut self.header).join("\n");
+        for line in Tilemap::migrate(&header)?.split('\n').filter(|line| !line.is_empty()) {
+            self.parse_header_line(line)?;
+        }
+
+        let empty_tile = self.empty_tile.ok_or(format!("Header key \"{EMPTY_TILE_KEY}\" is missing"))?;
+        let size = self.size.ok_or(format!("Header key \"{SIZE_KEY}\" is missing"))?;
+
+        let mut tilemap = Tilemap::new(empty_tile);
+        tilemap.size = size;
+        tilemap.clusters = std::mem::take(&mut self.clusters);
+        tilemap.cell_width = self.cell_width.unwrap_or(1);
+        tilemap.metadata = std::mem::take(&mut self.metadata);
+        for (name, position) in std::mem::take(&mut self.bookmarks) {
+            tilemap.set_bookmark(&name, position)?;
+        }
+        for (name, positions) in std::mem::take(&mut self.tags) {
+            for position in positions {
+                tilemap.tag(position, &name)?;
+            }
+        }
+        self.tilemap = Some(tilemap);
+        return Ok(());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{FileParser, Tilemap, Vector2, FILE_FORMAT_VERSION};
+
+    #[test]
+    fn push_line() {
+        let mut tilemap = Tilemap::from_string("#..\n.@.", '.');
+        tilemap.set_cluster(Vector2::new(2, 0), "e\u{301}").unwrap();
+        tilemap.cell_width = 3;
+
+        let mut parser = FileParser::new();
+        for line in tilemap.to_file_string().split('\n') {
+            parser.push_line(line).unwrap();
+        }
+
+        let parsed = parser.finish().unwrap();
+        assert_eq!(parsed.to_file_string(), tilemap.to_file_string());
+        assert_eq!(parsed.build_clusters(), tilemap.build_clusters());
+    }
+
+    #[test]
+    fn errors() {
+        let mut parser = FileParser::new();
+        parser.push_line("empty_tile=.").unwrap();
+        match parser.clone().push_line("") {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+        match parser.clone().finish() {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+
+        match parser.clone().push_line(&format!("version={}", FILE_FORMAT_VERSION + 1)) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+
+        parser.push_line("size=2x1").unwrap();
+        parser.push_line("").unwrap();
+        parser.push_line("#.").unwrap();
+        for line in ["", "..."] {
+            match parser.clone().push_line(line) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+        assert_eq!(parser.finish().unwrap().build(), "#.");
+    }
+
+    #[test]
+    fn truncated() {
+        let text = Tilemap::from_string("#..\n.@.\n..#", '.').to_file_string();
+        let truncated = &text[..text.rfind('\n').unwrap()];
+        for text in [truncated, text.split_once("\n\n").unwrap().0] {
+            match Tilemap::from_file_string(text) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use crate::tilemap::{Rng, Tile, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Generates perfect maze using randomized depth-first search. Passages are placed at odd
+    /// positions and are represented by positions without tiles, everything else is a wall.
+    ///
+    /// # Arguments
+    /// * `size: Vector2` - Size of the generated [`Tilemap`].
+    /// * `wall: char` - Value of the wall tiles.
+    /// * `empty_tile: char` - Empty tile of the generated [`Tilemap`].
+    /// * `rng: &mut Rng` - Generator that decides the layout.
+    ///
+    /// # Return
+    /// New instance of the [`Tilemap`] with the specified size.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut rng = char_tilemap::Rng::new(7);
+    /// let maze = char_tilemap::Tilemap::maze(char_tilemap::Vector2::new(5, 3), '#', '.', &mut rng);
+    /// assert_eq!(maze.build(), "#####\n#...#\n#####");
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(rng)))]
+    pub fn maze(size: Vector2, wall: char, empty_tile: char, rng: &mut Rng) -> Tilemap {
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+        let mut walls = vec![true; size.x * size.y];
+        let cells = Vector2::new(size.x.saturating_sub(1) / 2, size.y.saturating_sub(1) / 2);
+
+        if cells.x > 0 && cells.y > 0 {
+            let mut visited = vec![false; cells.x * cells.y];
+            let mut stack = vec![Vector2::ZERO];
+            visited[0] = true;
+            walls[size.x + 1] = false;
+
+            while let Some(&cell) = stack.last() {
+                let mut unvisited: Vec<Vector2> = [(0, -1), (1, 0), (0, 1), (-1, 0)].iter()
+                    .filter_map(|&(dx, dy)| {
+                        let x = cell.x.checked_add_signed(dx).filter(|x| *x < cells.x)?;
+                        let y = cell.y.checked_add_signed(dy).filter(|y| *y < cells.y)?;
+                        return Some(Vector2::new(x, y));
+                    })
+                    .filter(|next| !visited[next.y * cells.x + next.x])
+                    .collect();
+
+                if unvisited.is_empty() {
+                    stack.pop();
+                    continue;
+                }
+
+                rng.shuffle(&mut unvisited);
+                let next = unvisited[0];
+                visited[next.y * cells.x + next.x] = true;
+                // Cell at (x, y) is placed at (2x + 1, 2y + 1), so the wall between two cells is at their sum + 1
+                walls[(cell.y + next.y + 1) * size.x + cell.x + next.x + 1] = false;
+                walls[(next.y * 2 + 1) * size.x + next.x * 2 + 1] = false;
+                stack.push(next);
+            }
+        }
+
+        let tilemap = Tilemap::from_walls(size, &walls, wall, empty_tile);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(tiles = tilemap.tiles.len(), elapsed_us = start.elapsed().as_micros() as u64, "generated");
+        return tilemap;
+    }
+
+    /// # Description
+    /// Generates caves using cellular automaton. Tilemap is randomly filled with walls, and then
+    /// smoothed: position becomes a wall if more than 4 of its 8 neighbors are walls, and becomes
+    /// empty if less than 4 of them are walls. Positions outside of bounds count as walls.
+    ///
+    /// # Arguments
+    /// * `size: Vector2` - Size of the generated [`Tilemap`].
+    /// * `wall: char` - Value of the wall tiles.
+    /// * `empty_tile: char` - Empty tile of the generated [`Tilemap`].
+    /// * `fill: f64` - Probability of a wall at the start.
+    /// * `smooth: usize` - Number of smoothing iterations.
+    /// * `rng: &mut Rng` - Generator that decides the layout.
+    ///
+    /// # Return
+    /// New instance of the [`Tilemap`] with the specified size.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut rng = char_tilemap::Rng::new(7);
+    /// let caves = char_tilemap::Tilemap::caves(char_tilemap::Vector2::new(20, 10), '#', '.', 0.45, 4, &mut rng);
+    /// assert_eq!(caves.size(), char_tilemap::Vector2::new(20, 10));
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(rng)))]
+    pub fn caves(size: Vector2, wall: char, empty_tile: char, fill: f64, smooth: usize, rng: &mut Rng) -> Tilemap {
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+        let mut walls: Vec<bool> = (0..size.x * size.y).map(|_| rng.chance(fill)).collect();
+
+        for _ in 0..smooth {
+            let previous = walls.clone();
+            for y in 0..size.y {
+                for x in 0..size.x {
+                    let mut neighbors = 0;
+                    for (dx, dy) in [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)] {
+                        let neighbor_x = x.checked_add_signed(dx).filter(|x| *x < size.x);
+                        let neighbor_y = y.checked_add_signed(dy).filter(|y| *y < size.y);
+                        neighbors += match (neighbor_x, neighbor_y) {
+                            (Some(neighbor_x), Some(neighbor_y)) => previous[neighbor_y * size.x + neighbor_x] as usize,
+                            _ => 1,
+                        };
+                    }
+
+                    if neighbors != 4 {
+                        walls[y * size.x + x] = neighbors > 4;
+                    }
+                }
+            }
+        }
+
+        let tilemap = Tilemap::from_walls(size, &walls, wall, empty_tile);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(tiles = tilemap.tiles.len(), elapsed_us = start.elapsed().as_micros() as u64, "generated");
+        return tilemap;
+    }
+
+    /// # Description
+    /// Fills rectangular area with values sampled independently for every position from the
+    /// weighted palette, for example to scatter textured ground after structural generation.
+    /// Positions that get [`Tilemap::empty_tile`] become empty. [`Tilemap`] grows if area does
+    /// not fit into it.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Top left corner of the area.
+    /// * `size: Vector2` - Size of the area.
+    /// * `weights: &[(char, f32)]` - Values with their relative weights, which do not have to
+    ///   sum up to 1.
+    /// * `rng: &mut Rng` - Generator that samples values.
+    ///
+    /// # Return
+    /// * [`Ok`] if area was filled.
+    /// * [`Err`] if any weight is negative or not finite, there is no positive weight, or the
+    ///   grown size does not fit into [`Tilemap::limits`]. [`Tilemap`] stays unchanged in that
+    ///   case. Contains error message.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('.');
+    /// let mut rng = char_tilemap::Rng::new(7);
+    /// let weights = [('.', 0.7), (',', 0.2), ('"', 0.1)];
+    /// tilemap.random_fill_weighted(char_tilemap::Vector2::ZERO, char_tilemap::Vector2::new(8, 4), &weights, &mut rng).unwrap();
+    /// assert!(tilemap.build().chars().all(|value| ".,\"\n".contains(value)));
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self, rng), err))]
+    pub fn random_fill_weighted(&mut self, position: Vector2, size: Vector2, weights: &[(char, f32)],
+                                rng: &mut Rng) -> Result<(), String> {
+        if let Some((value, weight)) = weights.iter().find(|(_, weight)| !weight.is_finite() || *weight < 0.0) {
+            return Err(format!("Invalid weight {weight} of the value '{value}'"));
+        }
+
+        let total: f64 = weights.iter().map(|(_, weight)| *weight as f64).sum();
+        if total <= 0.0 {
+            return Err(String::from("There are no values with positive weight"));
+        }
+
+        let end = Vector2::new(position.x.saturating_add(size.x), position.y.saturating_add(size.y));
+        let new_size = Vector2::new(std::cmp::max(self.size.x, end.x), std::cmp::max(self.size.y, end.y));
+        self.limits.check_size(new_size)?;
+
+        for y in position.y..end.y {
+            for x in position.x..end.x {
+                let mut target = rng.next_f64() * total;
+                let value = weights.iter()
+                    .filter(|(_, weight)| *weight > 0.0)
+                    .find(|(_, weight)| {
+                        target -= *weight as f64;
+                        return target < 0.0;
+                    })
+                    .or(weights.iter().rfind(|(_, weight)| *weight > 0.0))
+                    .map(|(value, _)| *value)
+                    .unwrap_or(self.empty_tile);
+
+                let tile_position = Vector2::new(x, y);
+                if value != self.empty_tile {
+                    self.set(tile_position, value);
+                } else {
+                    self.take_tile(tile_position);
+                }
+            }
+        }
+
+        self.size = new_size;
+        return Ok(());
+    }
+
+    /// # Description
+    /// Creates [`Tilemap`] of the specified size with `wall` tiles where `walls` in row-major
+    /// order are `true`.
+    fn from_walls(size: Vector2, walls: &[bool], wall: char, empty_tile: char) -> Tilemap {
+        let mut tilemap = Tilemap::new(empty_tile);
+
+        for (index, _) in walls.iter().enumerate().filter(|(_, is_wall)| **is_wall) {
+            tilemap.tiles.push(Tile { position: Vector2::new(index % size.x, index / size.x), value: wall });
+        }
+
+        tilemap.size = size;
+        return tilemap;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Rng, Tilemap, Vector2};
+
+    const SEED: u64 = 7;
+
+    #[test]
+    fn maze() {
+        let size = Vector2::new(21, 11);
+        let maze = Tilemap::maze(size, '#', '.', &mut Rng::new(SEED));
+        assert_eq!(maze.size(), size);
+        assert_eq!(maze.build(), Tilemap::maze(size, '#', '.', &mut Rng::new(SEED)).build());
+
+        // Every cell is reachable from the first one
+        for y in (1..size.y).step_by(2) {
+            for x in (1..size.x).step_by(2) {
+                let path = maze.find_path(Vector2::ONE, Vector2::new(x, y), |value| value.is_none());
+                assert!(path.is_some());
+            }
+        }
+
+        // Borders are walls
+        for x in 0..size.x {
+            assert_eq!(maze.get(Vector2::new(x, 0)), Some('#'));
+            assert_eq!(maze.get(Vector2::new(x, size.y - 1)), Some('#'));
+        }
+
+        assert_eq!(Tilemap::maze(Vector2::new(2, 2), '#', '.', &mut Rng::new(SEED)).build(), "##\n##");
+        assert_eq!(Tilemap::maze(Vector2::ZERO, '#', '.', &mut Rng::new(SEED)).build(), "");
+    }
+
+    #[test]
+    fn caves() {
+        let size = Vector2::new(40, 20);
+        let caves = Tilemap::caves(size, '#', '.', 0.45, 4, &mut Rng::new(SEED));
+        assert_eq!(caves.size(), size);
+        assert_eq!(caves.build(), Tilemap::caves(size, '#', '.', 0.45, 4, &mut Rng::new(SEED)).build());
+
+        let full = Tilemap::caves(Vector2::new(3, 3), '#', '.', 1.0, 2, &mut Rng::new(SEED));
+        assert_eq!(full.build(), "###\n###\n###");
+        let empty = Tilemap::caves(Vector2::new(3, 3), '#', '.', 0.0, 0, &mut Rng::new(SEED));
+        assert_eq!(empty.build(), "...\n...\n...");
+    }
+
+    #[test]
+    fn random_fill_weighted() {
+        let mut tilemap = Tilemap::from_string("##\n##", '.');
+        let mut rng = Rng::new(SEED);
+        tilemap.random_fill_weighted(Vector2::ONE, Vector2::new(100, 100), &[('.', 7.0), (',', 2.0), ('"', 1.0), ('x', 0.0)], &mut rng).unwrap();
+
+        assert_eq!(tilemap.size(), Vector2::new(101, 101));
+        assert_eq!(tilemap.get(Vector2::ZERO), Some('#'));
+        let text = tilemap.build();
+        let count = |value| text.chars().filter(|other| *other == value).count();
+        assert_eq!(count('x'), 0);
+        assert!((1800..2200).contains(&count(',')));
+        assert!((800..1200).contains(&count('"')));
+
+        for weights in [&[][..], &[('.', 0.0)], &[('.', 1.0), (',', -1.0)], &[('.', f32::NAN)]] {
+            match tilemap.random_fill_weighted(Vector2::ZERO, Vector2::ONE, weights, &mut rng) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+
+        let mut limited = Tilemap::from_string("##\n##", '.');
+        limited.limits.max_size = Some(Vector2::new(4, 4));
+        match limited.random_fill_weighted(Vector2::ONE, Vector2::new(4, 2), &[('x', 1.0)], &mut rng) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+        assert_eq!(limited.build(), "##\n##");
+
+        limited.random_fill_weighted(Vector2::ONE, Vector2::new(3, 2), &[('x', 1.0)], &mut rng).unwrap();
+        assert_eq!(limited.build(), "##..\n#xxx\n.xxx");
+    }
+
+    /// # Description
+    /// Subscriber that records names of spans and fields of events.
+    #[cfg(feature = "tracing")]
+    #[derive(Default, Clone)]
+    struct Recorder {
+        records: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    #[cfg(feature = "tracing")]
+    impl tracing::Subscriber for Recorder {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            return true;
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            self.records.lock().unwrap().push(span.metadata().name().to_string());
+            return tracing::span::Id::from_u64(1);
+        }
+
+        fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            let fields: Vec<&str> = event.fields().map(|field| field.name()).collect();
+            self.records.lock().unwrap().push(fields.join(","));
+        }
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn tracing() {
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let mut maze = Tilemap::maze(Vector2::new(5, 5), '#', '.', &mut Rng::new(SEED));
+            let _ = maze.add_tile(Vector2::ZERO, '#');
+        });
+
+        assert_eq!(*recorder.records.lock().unwrap(), vec!["maze", "message,tiles,elapsed_us", "add_tile", "error"]);
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Default ramp of characters for [`Tilemap::render_heatmap()`], from the lowest value to the
+/// highest one.
+pub const DEFAULT_HEATMAP_RAMP: [char; 10] = [' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Renders numeric values as a heatmap aligned with the bounds of the [`Tilemap`].
+    /// Values are normalized between the lowest and the highest value within bounds and mapped
+    /// to the characters of the `ramp`. Positions without value are rendered as
+    /// [`Tilemap::empty_tile`].
+    ///
+    /// # Arguments
+    /// * `values: &HashMap<Vector2, f64>` - Values that should be rendered. Values out of bounds of
+    ///   the [`Tilemap`] and `NaN` values are ignored.
+    /// * `ramp: &[char]` - Characters from the lowest value to the highest one.
+    ///
+    /// # Return
+    /// A new [`String`] that contains heatmap with the same layout as [`Tilemap::build()`].
+    ///
+    /// # Notes
+    /// If all values are equal, or `ramp` contains only one character, the first character of
+    /// the `ramp` is used. If `ramp` is empty, all positions are rendered as empty tiles.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// let mut tilemap = char_tilemap::Tilemap::new('?');
+    /// tilemap.set(char_tilemap::Vector2::new(2, 0), '#');
+    ///
+    /// let mut values = HashMap::new();
+    /// values.insert(char_tilemap::Vector2::new(0, 0), 0.0);
+    /// values.insert(char_tilemap::Vector2::new(1, 0), 10.0);
+    ///
+    /// assert_eq!(tilemap.render_heatmap(&values, &char_tilemap::DEFAULT_HEATMAP_RAMP), " @?");
+    /// ```
+    pub fn render_heatmap(&self, values: &HashMap<Vector2, f64>, ramp: &[char]) -> String {
+        let is_in_bounds = |position: &Vector2, value: &f64| {
+            return position.x < self.size.x && position.y < self.size.y && !value.is_nan();
+        };
+
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        for (position, value) in values.iter() {
+            if is_in_bounds(position, value) {
+                min = min.min(*value);
+                max = max.max(*value);
+            }
+        }
+
+        let mut result = String::new();
+        for y in 0..self.size.y {
+            if y > 0 {
+                result.push('\n');
+            }
+
+            for x in 0..self.size.x {
+                let value = match values.get(&Vector2::new(x, y)) {
+                    Some(value) if !ramp.is_empty() && !value.is_nan() => {
+                        let range = max - min;
+                        let ratio = if range > 0.0 { (value - min) / range } else { 0.0 };
+                        ramp[(ratio * (ramp.len() - 1) as f64).round() as usize]
+                    },
+                    _ => self.empty_tile,
+                };
+
+                result.push(value);
+            }
+        }
+
+        return result;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use crate::tilemap::{Tilemap, Vector2};
+
+    const RAMP: [char; 3] = ['a', 'b', 'c'];
+
+    #[test]
+    fn render_heatmap() {
+        let mut tilemap = Tilemap::new('-');
+        tilemap.set(Vector2::new(3, 1), '#');
+
+        let mut values = HashMap::new();
+        values.insert(Vector2::new(0, 0), 1.0);
+        values.insert(Vector2::new(1, 0), 2.0);
+        values.insert(Vector2::new(2, 0), 3.0);
+        values.insert(Vector2::new(0, 1), f64::NAN);
+        values.insert(Vector2::new(10, 10), 100.0);
+
+        assert_eq!(tilemap.render_heatmap(&values, &RAMP), "abc-\n----");
+        assert_eq!(tilemap.render_heatmap(&values, &[]), "----\n----");
+    }
+
+    #[test]
+    fn render_heatmap_equal_values() {
+        let mut tilemap = Tilemap::new('-');
+        tilemap.set(Vector2::new(1, 0), '#');
+
+        let mut values = HashMap::new();
+        values.insert(Vector2::new(0, 0), 5.0);
+        values.insert(Vector2::new(1, 0), 5.0);
+
+        assert_eq!(tilemap.render_heatmap(&values, &RAMP), "aa");
+    }
+}
@@ -0,0 +1,460 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use crate::tilemap::{Tilemap, Vector2};
+use crate::tilemap::pathfinding::neighbors;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Border between a chunk and its neighbor.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+enum Side {
+    /// # Description
+    /// Border with the chunk to the right.
+    Right,
+    /// # Description
+    /// Border with the chunk below.
+    Down,
+}
+
+/// # Description
+/// HPA*-style hierarchical pathfinder. [`Tilemap`] is split into square chunks, entrances
+/// between neighboring chunks and distances between entrances of every chunk are precomputed,
+/// so a path query searches the small abstract graph of entrances and refines only the chunks
+/// that the path goes through. Found paths are close to the shortest ones, but not always equal.
+///
+/// Precomputed data does not follow changes of the [`Tilemap`]: call
+/// [`HierarchicalPathfinder::invalidate()`] for every changed position, or create a new
+/// pathfinder if the size of the [`Tilemap`] was changed. The same `is_walkable` predicate should
+/// be passed to all methods.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::{HierarchicalPathfinder, Tilemap, Vector2};
+///
+/// let mut tilemap = Tilemap::from_string("....#...\n....#...\n........\n....#...", '.');
+/// let walkable = |value: Option<char>| value.is_none();
+/// let mut pathfinder = HierarchicalPathfinder::new(&tilemap, 4, walkable).unwrap();
+///
+/// let path = pathfinder.find_path(&tilemap, Vector2::ZERO, Vector2::new(7, 0), walkable).unwrap();
+/// assert_eq!(path.len(), 12);
+///
+/// tilemap.set(Vector2::new(4, 2), '#');
+/// pathfinder.invalidate(&tilemap, Vector2::new(4, 2), walkable);
+/// assert_eq!(pathfinder.find_path(&tilemap, Vector2::ZERO, Vector2::new(7, 0), walkable), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HierarchicalPathfinder {
+    /// # Description
+    /// Size of the [`Tilemap`] for which data was precomputed.
+    size: Vector2,
+    /// # Description
+    /// Length of the side of every chunk.
+    chunk_size: usize,
+    /// # Description
+    /// Entrances of every border, as pairs of positions on both sides of it.
+    borders: HashMap<(Vector2, Side), Vec<(Vector2, Vector2)>>,
+    /// # Description
+    /// Entrance positions of every chunk.
+    chunk_nodes: HashMap<Vector2, Vec<Vector2>>,
+    /// # Description
+    /// Edges of the abstract graph with their costs.
+    edges: HashMap<Vector2, Vec<(Vector2, usize)>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl HierarchicalPathfinder {
+    /// # Description
+    /// Creates new [`HierarchicalPathfinder`] and precomputes entrances of all chunks.
+    ///
+    /// # Arguments
+    /// * `tilemap: &Tilemap` - [`Tilemap`] in which paths will be searched.
+    /// * `chunk_size: usize` - Length of the side of every chunk.
+    /// * `is_walkable: F` - Returns `true` if the position with the specified tile value (or
+    ///   [`None`] for positions without tiles) can be walked through.
+    ///
+    /// # Return
+    /// * [`Ok`] with new [`HierarchicalPathfinder`].
+    /// * [`Err`] if chunk size is 0. Contains error message.
+    pub fn new<F>(tilemap: &Tilemap, chunk_size: usize, is_walkable: F) -> Result<HierarchicalPathfinder, String>
+        where F: Fn(Option<char>) -> bool {
+        if chunk_size == 0 {
+            return Err(String::from("Chunk size of the hierarchical pathfinder must be greater than 0"));
+        }
+
+        let mut pathfinder = HierarchicalPathfinder {
+            size: tilemap.size(),
+            chunk_size,
+            borders: HashMap::new(),
+            chunk_nodes: HashMap::new(),
+            edges: HashMap::new(),
+        };
+
+        let chunks = pathfinder.chunks();
+        for y in 0..chunks.y {
+            for x in 0..chunks.x {
+                for side in [Side::Right, Side::Down] {
+                    pathfinder.update_border(tilemap, Vector2::new(x, y), side, &is_walkable);
+                }
+            }
+        }
+        for y in 0..chunks.y {
+            for x in 0..chunks.x {
+                pathfinder.update_chunk(tilemap, Vector2::new(x, y), &is_walkable);
+            }
+        }
+
+        return Ok(pathfinder);
+    }
+
+    /// # Description
+    /// Returns length of the side of every chunk.
+    pub fn chunk_size(&self) -> usize {
+        return self.chunk_size;
+    }
+
+    /// # Description
+    /// Returns number of entrances in the abstract graph.
+    pub fn entrance_count(&self) -> usize {
+        return self.edges.len();
+    }
+
+    /// # Description
+    /// Recomputes entrances of the chunk that contains the changed position and of its neighbors.
+    ///
+    /// # Arguments
+    /// * `tilemap: &Tilemap` - Changed [`Tilemap`].
+    /// * `position: Vector2` - Changed position. Positions out of bounds are ignored.
+    /// * `is_walkable: F` - Same predicate as in [`HierarchicalPathfinder::new()`].
+    pub fn invalidate<F>(&mut self, tilemap: &Tilemap, position: Vector2, is_walkable: F)
+        where F: Fn(Option<char>) -> bool {
+        if position.x >= self.size.x || position.y >= self.size.y {
+            return;
+        }
+
+        let chunk = Vector2::new(position.x / self.chunk_size, position.y / self.chunk_size);
+        let left = chunk.x.checked_sub(1).map(|x| Vector2::new(x, chunk.y));
+        let up = chunk.y.checked_sub(1).map(|y| Vector2::new(chunk.x, y));
+
+        self.update_border(tilemap, chunk, Side::Right, &is_walkable);
+        self.update_border(tilemap, chunk, Side::Down, &is_walkable);
+        if let Some(left) = left {
+            self.update_border(tilemap, left, Side::Right, &is_walkable);
+        }
+        if let Some(up) = up {
+            self.update_border(tilemap, up, Side::Down, &is_walkable);
+        }
+
+        let chunks = self.chunks();
+        let right = Some(Vector2::new(chunk.x + 1, chunk.y)).filter(|right| right.x < chunks.x);
+        let down = Some(Vector2::new(chunk.x, chunk.y + 1)).filter(|down| down.y < chunks.y);
+        for chunk in [Some(chunk), left, up, right, down].into_iter().flatten() {
+            self.update_chunk(tilemap, chunk, &is_walkable);
+        }
+    }
+
+    /// # Description
+    /// Finds path between two positions using the precomputed entrances.
+    ///
+    /// # Arguments
+    /// * `tilemap: &Tilemap` - [`Tilemap`] in which path is searched.
+    /// * `from: Vector2` - Start of the path.
+    /// * `to: Vector2` - End of the path.
+    /// * `is_walkable: F` - Same predicate as in [`HierarchicalPathfinder::new()`].
+    ///
+    /// # Return
+    /// * [`Some`] with all positions of the path, including `from` and `to`.
+    /// * [`None`] if `from` or `to` is out of bounds or not walkable, or there is no path between
+    ///   them.
+    pub fn find_path<F>(&self, tilemap: &Tilemap, from: Vector2, to: Vector2, is_walkable: F) -> Option<Vec<Vector2>>
+        where F: Fn(Option<char>) -> bool {
+        let inside = |position: Vector2| position.x < self.size.x && position.y < self.size.y;
+        if !inside(from) || !inside(to) || !is_walkable(tilemap.get(from)) || !is_walkable(tilemap.get(to)) {
+            return None;
+        }
+
+        let from_chunk = self.chunk_of(from);
+        let to_chunk = self.chunk_of(to);
+        let from_search = self.search_chunk(tilemap, from_chunk, from, &is_walkable);
+        let to_search = self.search_chunk(tilemap, to_chunk, to, &is_walkable);
+
+        let heuristic = |position: Vector2| position.x.abs_diff(to.x) + position.y.abs_diff(to.y);
+        let mut parents: HashMap<Vector2, Vector2> = HashMap::new();
+        let mut costs: HashMap<Vector2, usize> = HashMap::from([(from, 0)]);
+        let mut open = BinaryHeap::from([Reverse((heuristic(from), 0, from))]);
+
+        while let Some(Reverse((_, cost, position))) = open.pop() {
+            if position == to {
+                return Some(self.refine(tilemap, from, to, &parents, &is_walkable));
+            }
+            if costs.get(&position).is_some_and(|known| cost > *known) {
+                continue;
+            }
+
+            let mut edges: Vec<(Vector2, usize)> = self.edges.get(&position).into_iter().flatten().copied().collect();
+            if position == from {
+                let nodes = self.chunk_nodes.get(&from_chunk).map_or(&[][..], |nodes| nodes.as_slice());
+                edges.extend(nodes.iter().filter_map(|node| from_search.get(node).map(|(_, distance)| (*node, *distance))));
+                if from_chunk == to_chunk {
+                    edges.extend(from_search.get(&to).map(|(_, distance)| (to, *distance)));
+                }
+            }
+            else if self.chunk_of(position) == to_chunk {
+                edges.extend(to_search.get(&position).map(|(_, distance)| (to, *distance)));
+            }
+
+            for (next, distance) in edges {
+                let new_cost = cost + distance;
+                if costs.get(&next).is_none_or(|known| new_cost < *known) {
+                    costs.insert(next, new_cost);
+                    parents.insert(next, position);
+                    open.push(Reverse((new_cost + heuristic(next), new_cost, next)));
+                }
+            }
+        }
+
+        return None;
+    }
+
+    /// # Description
+    /// Returns number of chunks along both axes.
+    fn chunks(&self) -> Vector2 {
+        return Vector2::new(self.size.x.div_ceil(self.chunk_size), self.size.y.div_ceil(self.chunk_size));
+    }
+
+    /// # Description
+    /// Returns chunk that contains the position.
+    fn chunk_of(&self, position: Vector2) -> Vector2 {
+        return Vector2::new(position.x / self.chunk_size, position.y / self.chunk_size);
+    }
+
+    /// # Description
+    /// Returns top left corner and size of the chunk, clipped by the [`Tilemap`] bounds.
+    fn region(&self, chunk: Vector2) -> (Vector2, Vector2) {
+        let origin = Vector2::new(chunk.x * self.chunk_size, chunk.y * self.chunk_size);
+        let size = Vector2::new(std::cmp::min(self.chunk_size, self.size.x - origin.x),
+                                std::cmp::min(self.chunk_size, self.size.y - origin.y));
+        return (origin, size);
+    }
+
+    /// # Description
+    /// Recomputes entrances of the border between the chunk and its neighbor. Every run of
+    /// positions that are walkable on both sides of the border gets a single entrance in its
+    /// middle.
+    fn update_border<F>(&mut self, tilemap: &Tilemap, chunk: Vector2, side: Side, is_walkable: &F)
+        where F: Fn(Option<char>) -> bool {
+        let chunks = self.chunks();
+        let (origin, size) = self.region(chunk);
+        let pairs: Vec<(Vector2, Vector2)> = match side {
+            Side::Right if chunk.x + 1 < chunks.x => (0..size.y).map(|y| {
+                let position = Vector2::new(origin.x + size.x - 1, origin.y + y);
+                return (position, Vector2::new(position.x + 1, position.y));
+            }).collect(),
+            Side::Down if chunk.y + 1 < chunks.y => (0..size.x).map(|x| {
+                let position = Vector2::new(origin.x + x, origin.y + size.y - 1);
+                return (position, Vector2::new(position.x, position.y + 1));
+            }).collect(),
+            _ => Vec::new(),
+        };
+
+        let mut entrances = Vec::new();
+        let mut run: Vec<(Vector2, Vector2)> = Vec::new();
+        for pair in pairs.into_iter().map(Some).chain([None]) {
+            match pair {
+                Some((first, second)) if is_walkable(tilemap.get(first)) && is_walkable(tilemap.get(second)) => {
+                    run.push((first, second));
+                },
+                _ => {
+                    if !run.is_empty() {
+                        entrances.push(run[run.len() / 2]);
+                    }
+                    run.clear();
+                },
+            }
+        }
+
+        self.borders.insert((chunk, side), entrances);
+    }
+
+    /// # Description
+    /// Recomputes entrances of the chunk and edges between them.
+    fn update_chunk<F>(&mut self, tilemap: &Tilemap, chunk: Vector2, is_walkable: &F)
+        where F: Fn(Option<char>) -> bool {
+        for node in self.chunk_nodes.remove(&chunk).unwrap_or_default() {
+            self.edges.remove(&node);
+        }
+
+        // Entrances of the chunk with positions on the other side of the border
+        let mut links: Vec<(Vector2, Vector2)> = Vec::new();
+        for side in [Side::Right, Side::Down] {
+            links.extend(self.borders.get(&(chunk, side)).into_iter().flatten().copied());
+        }
+        if let Some(x) = chunk.x.checked_sub(1) {
+            let border = self.borders.get(&(Vector2::new(x, chunk.y), Side::Right));
+            links.extend(border.into_iter().flatten().map(|(first, second)| (*second, *first)));
+        }
+        if let Some(y) = chunk.y.checked_sub(1) {
+            let border = self.borders.get(&(Vector2::new(chunk.x, y), Side::Down));
+            links.extend(border.into_iter().flatten().map(|(first, second)| (*second, *first)));
+        }
+
+        let mut nodes: Vec<Vector2> = links.iter().map(|(node, _)| *node).collect();
+        nodes.sort();
+        nodes.dedup();
+
+        for node in nodes.iter() {
+            let search = self.search_chunk(tilemap, chunk, *node, is_walkable);
+            let mut edges: Vec<(Vector2, usize)> = nodes.iter()
+                .filter(|other| *other != node)
+                .filter_map(|other| search.get(other).map(|(_, distance)| (*other, *distance)))
+                .collect();
+            edges.extend(links.iter().filter(|(first, _)| first == node).map(|(_, second)| (*second, 1)));
+            self.edges.insert(*node, edges);
+        }
+
+        self.chunk_nodes.insert(chunk, nodes);
+    }
+
+    /// # Description
+    /// Searches all walkable positions of the chunk that can be reached from the position
+    /// without leaving the chunk.
+    ///
+    /// # Return
+    /// [`HashMap`] with previous position and distance of every reached position.
+    fn search_chunk<F>(&self, tilemap: &Tilemap, chunk: Vector2, from: Vector2, is_walkable: &F) -> HashMap<Vector2, (Vector2, usize)>
+        where F: Fn(Option<char>) -> bool {
+        let (origin, size) = self.region(chunk);
+        let inside = |position: Vector2| {
+            return position.x >= origin.x && position.y >= origin.y
+                && position.x < origin.x + size.x && position.y < origin.y + size.y;
+        };
+
+        let mut reached = HashMap::from([(from, (from, 0))]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(position) = queue.pop_front() {
+            let distance = reached[&position].1;
+            for neighbor in neighbors(position) {
+                if inside(neighbor) && !reached.contains_key(&neighbor) && is_walkable(tilemap.get(neighbor)) {
+                    reached.insert(neighbor, (position, distance + 1));
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        return reached;
+    }
+
+    /// # Description
+    /// Turns path over the abstract graph into the path over positions.
+    fn refine<F>(&self, tilemap: &Tilemap, from: Vector2, to: Vector2, parents: &HashMap<Vector2, Vector2>,
+                 is_walkable: &F) -> Vec<Vector2>
+        where F: Fn(Option<char>) -> bool {
+        let mut abstract_path = vec![to];
+        while let Some(parent) = parents.get(abstract_path.last().unwrap_or(&from)) {
+            abstract_path.push(*parent);
+        }
+        abstract_path.reverse();
+
+        let mut path = vec![from];
+        for pair in abstract_path.windows(2) {
+            let (start, end) = (pair[0], pair[1]);
+            if start.x.abs_diff(end.x) + start.y.abs_diff(end.y) <= 1 {
+                if start != end {
+                    path.push(end);
+                }
+                continue;
+            }
+
+            let search = self.search_chunk(tilemap, self.chunk_of(start), start, is_walkable);
+            let mut segment = Vec::new();
+            let mut current = end;
+            while current != start {
+                segment.push(current);
+                current = search[&current].0;
+            }
+            path.extend(segment.into_iter().rev());
+        }
+
+        return path;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Anchor, HierarchicalPathfinder, OverflowPolicy, ResizePolicy, Rng, Tilemap, Vector2};
+
+    fn walkable(value: Option<char>) -> bool {
+        return value.is_none();
+    }
+
+    #[test]
+    fn new() {
+        let tilemap = Tilemap::from_string("......\n......", '.');
+        match HierarchicalPathfinder::new(&tilemap, 0, walkable) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+
+        let pathfinder = HierarchicalPathfinder::new(&tilemap, 2, walkable).unwrap();
+        assert_eq!(pathfinder.chunk_size(), 2);
+        assert_eq!(pathfinder.entrance_count(), 4);
+    }
+
+    #[test]
+    fn find_path() {
+        let mut rng = Rng::new(11);
+        for _ in 0..100 {
+            let mut tilemap = Tilemap::new('.');
+            tilemap.resize(Vector2::new(15, 11), ResizePolicy::new(OverflowPolicy::Error, Anchor::TopLeft)).unwrap();
+            for y in 0..11 {
+                for x in 0..15 {
+                    if rng.chance(0.3) {
+                        tilemap.set(Vector2::new(x, y), '#');
+                    }
+                }
+            }
+
+            let pathfinder = HierarchicalPathfinder::new(&tilemap, 4, walkable).unwrap();
+            let from = Vector2::new(rng.next_below(15), rng.next_below(11));
+            let to = Vector2::new(rng.next_below(15), rng.next_below(11));
+            let shortest = tilemap.find_path(from, to, walkable);
+            let path = pathfinder.find_path(&tilemap, from, to, walkable);
+
+            assert_eq!(path.is_some(), shortest.is_some());
+            if let (Some(path), Some(shortest)) = (path, shortest) {
+                assert!(path.len() >= shortest.len());
+                assert_eq!((path.first(), path.last()), (Some(&from), Some(&to)));
+                for pair in path.windows(2) {
+                    assert_eq!(pair[0].x.abs_diff(pair[1].x) + pair[0].y.abs_diff(pair[1].y), 1);
+                    assert_eq!(tilemap.get(pair[1]), None);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn invalidate() {
+        let mut tilemap = Tilemap::from_string("..#..\n..#..\n.....", '.');
+        let mut pathfinder = HierarchicalPathfinder::new(&tilemap, 2, walkable).unwrap();
+        let (from, to) = (Vector2::ZERO, Vector2::new(4, 0));
+        assert_eq!(pathfinder.find_path(&tilemap, from, to, walkable).map(|path| path.len()), Some(9));
+
+        tilemap.set(Vector2::new(2, 2), '#');
+        pathfinder.invalidate(&tilemap, Vector2::new(2, 2), walkable);
+        assert_eq!(pathfinder.find_path(&tilemap, from, to, walkable), None);
+
+        tilemap.remove_tile(Vector2::new(2, 0)).unwrap();
+        pathfinder.invalidate(&tilemap, Vector2::new(2, 0), walkable);
+        assert!(pathfinder.find_path(&tilemap, from, to, walkable).is_some_and(|path| path.len() >= 5));
+        assert_eq!(pathfinder.find_path(&tilemap, from, Vector2::new(9, 9), walkable), None);
+    }
+}
@@ -0,0 +1,445 @@
+use std::iter::Peekable;
+use std::str::Chars;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::tilemap::{Tile, TileChange, Tilemap, Vector2};
+use crate::tilemap::tile_change::diff_tiles;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Single recorded change of the [`Journal`].
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct JournalEntry {
+    /// # Description
+    /// Sequence number of the entry. Entries of the same journal are numbered from 0.
+    pub sequence: u64,
+    /// # Description
+    /// Time of the change in milliseconds since the Unix epoch.
+    pub timestamp: u64,
+    /// # Description
+    /// Recorded change.
+    pub change: TileChange,
+}
+
+/// # Description
+/// Opt-in record of every change of the [`Tilemap`] tiles, started by [`Tilemap::start_journal()`].
+/// Journal can be exported as JSON lines and replayed onto a fresh [`Tilemap`], so it is
+/// possible to find out how the [`Tilemap`] got into its state.
+///
+/// Changes of tiles are recorded by all operations, including bulk ones, such as
+/// [`Tilemap::resize()`]. Size, colors, annotations and other data are not recorded.
+///
+/// # Example
+/// ```rust
+/// let mut tilemap = char_tilemap::Tilemap::new('.');
+/// tilemap.start_journal();
+/// tilemap.set(char_tilemap::Vector2::ONE, '@');
+/// tilemap.set(char_tilemap::Vector2::ONE, '#');
+///
+/// let exported = tilemap.journal().unwrap().to_json_lines();
+/// let journal = char_tilemap::Journal::from_json_lines(&exported).unwrap();
+///
+/// let mut replayed = char_tilemap::Tilemap::new('.');
+/// journal.replay(&mut replayed);
+/// assert_eq!(replayed.build(), tilemap.build());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Journal {
+    /// # Description
+    /// Recorded entries in order.
+    entries: Vec<JournalEntry>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Journal {
+    /// # Description
+    /// Creates new empty [`Journal`].
+    ///
+    /// # Return
+    /// New instance of the [`Journal`].
+    pub fn new() -> Journal {
+        return Journal { entries: Vec::new() };
+    }
+
+    /// # Description
+    /// Returns recorded entries in order.
+    pub fn entries(&self) -> &[JournalEntry] {
+        return &self.entries;
+    }
+
+    /// # Description
+    /// Records the change with the current time and the next sequence number.
+    ///
+    /// # Arguments
+    /// * `change: TileChange` - Change that should be recorded.
+    pub fn record(&mut self, change: TileChange) {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_millis() as u64).unwrap_or(0);
+        self.entries.push(JournalEntry { sequence: self.entries.len() as u64, timestamp, change });
+    }
+
+    /// # Description
+    /// Applies all recorded changes to the [`Tilemap`] in order, see [`Tilemap::apply_changes()`].
+    ///
+    /// # Arguments
+    /// * `tilemap: &mut Tilemap` - [`Tilemap`] to apply changes to, usually the one the journal
+    ///   was started on, or a fresh one if it was started on an empty [`Tilemap`].
+    pub fn replay(&self, tilemap: &mut Tilemap) {
+        let changes: Vec<TileChange> = self.entries.iter().map(|entry| entry.change).collect();
+        tilemap.apply_changes(&changes);
+    }
+
+    /// # Description
+    /// Exports journal as JSON lines: one object per entry with `sequence`, `timestamp`, `x`,
+    /// `y`, `old` and `new` fields. Missing values are `null`.
+    ///
+    /// # Return
+    /// A new [`String`] with one line per entry, each terminated with a new line.
+    pub fn to_json_lines(&self) -> String {
+        let value = |value: Option<char>| match value {
+            Some(value) => json_string(value),
+            None => String::from("null"),
+        };
+
+        return self.entries.iter().map(|entry| {
+            format!("{{\"sequence\":{},\"timestamp\":{},\"x\":{},\"y\":{},\"old\":{},\"new\":{}}}\n",
+                    entry.sequence, entry.timestamp, entry.change.position.x, entry.change.position.y,
+                    value(entry.change.old), value(entry.change.new))
+        }).collect();
+    }
+
+    /// # Description
+    /// Imports journal exported by [`Journal::to_json_lines()`]. Empty lines are ignored.
+    ///
+    /// # Arguments
+    /// * `text: &str` - JSON lines.
+    ///
+    /// # Return
+    /// * [`Ok`] with the imported [`Journal`].
+    /// * [`Err`] if any line is malformed. Contains error message with the line number.
+    pub fn from_json_lines(text: &str) -> Result<Journal, String> {
+        let mut entries = Vec::new();
+
+        for (index, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let entry = parse_entry(line).map_err(|error| format!("Line {}: {error}", index + 1))?;
+            entries.push(entry);
+        }
+
+        return Ok(Journal { entries });
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Starts recording changes of tiles into the new [`Journal`], replacing the current one.
+    pub fn start_journal(&mut self) {
+        self.journal = Some(Journal::new());
+    }
+
+    /// # Description
+    /// Stops recording changes of tiles.
+    ///
+    /// # Return
+    /// * [`Some`] with the recorded [`Journal`].
+    /// * [`None`] if journal was not started.
+    pub fn stop_journal(&mut self) -> Option<Journal> {
+        return self.journal.take();
+    }
+
+    /// # Description
+    /// Returns the current [`Journal`].
+    ///
+    /// # Return
+    /// * [`Some`] with the [`Journal`] if it was started.
+    /// * [`None`] otherwise.
+    pub fn journal(&self) -> Option<&Journal> {
+        return self.journal.as_ref();
+    }
+
+    /// # Description
+    /// Applies recorded changes to this [`Tilemap`] in order, up to and including the entry with
+    /// the specified sequence number. Replaying onto the same starting state always produces
+    /// the same result, so any intermediate state of the journal can be reproduced.
+    ///
+    /// # Arguments
+    /// * `journal: &Journal` - Recorded changes.
+    /// * `up_to_sequence: u64` - Sequence number of the last applied entry.
+    ///
+    /// # Return
+    /// Number of applied entries.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('.');
+    /// let start = tilemap.clone();
+    /// tilemap.start_journal();
+    /// for x in 0..3 {
+    ///     tilemap.set(char_tilemap::Vector2::new(x, 0), '#');
+    /// }
+    ///
+    /// let mut replayed = start.clone();
+    /// assert_eq!(replayed.replay(tilemap.journal().unwrap(), 1), 2);
+    /// assert_eq!(replayed.build(), "##");
+    /// ```
+    pub fn replay(&mut self, journal: &Journal, up_to_sequence: u64) -> usize {
+        let changes: Vec<TileChange> = journal.entries.iter()
+            .take_while(|entry| entry.sequence <= up_to_sequence)
+            .map(|entry| entry.change)
+            .collect();
+        self.apply_changes(&changes);
+        return changes.len();
+    }
+
+    /// # Description
+    /// Records change of the tile at the position, if journal is started and value was changed.
+    /// Index of tile positions, row hashes, Zobrist hash, the build cache, dependencies on the
+    /// position, history of the position and the stamp of the tile are updated as well.
+    pub(crate) fn record(&mut self, position: Vector2, old: Option<char>, new: Option<char>) {
+        if old == new {
+            return;
+        }
+
+        self.update_index(position, old, new);
+        self.update_row_hash(position, old, new);
+        self.update_incremental_hash(position, old, new);
+        self.invalidate_row(position.y);
+        self.invalidate_dependencies(position);
+        self.record_history(position, old);
+        self.stamp_tile(position, new);
+        if let Some(journal) = &mut self.journal {
+            journal.record(TileChange::new(position, old, new));
+        }
+    }
+
+    /// # Description
+    /// Records changes between `old` tiles and the current ones, if journal is started, history
+    /// or ages are enabled.
+    /// Index of tile positions, row hashes and Zobrist hash are rebuilt if they are enabled, the
+    /// build cache is dropped and all dependencies become stale.
+    pub(crate) fn record_replaced(&mut self, old: &sorted_vec::SortedSet<Tile>) {
+        self.clear_build_cache();
+        self.invalidate_all_dependencies();
+        if self.is_indexed() {
+            self.enable_index();
+        }
+        if self.row_hashes.is_some() {
+            self.enable_row_hashes();
+        }
+        if self.zobrist.is_some() {
+            self.enable_incremental_hash();
+        }
+        if self.journal.is_none() && self.history.is_none() && self.stamps.is_none() {
+            return;
+        }
+
+        for change in diff_tiles(old, &self.tiles) {
+            self.record_history(change.position, change.old);
+            self.stamp_tile(change.position, change.new);
+            if let Some(journal) = &mut self.journal {
+                journal.record(change);
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Encodes char as a JSON string.
+fn json_string(value: char) -> String {
+    return match value {
+        '"' => String::from("\"\\\"\""),
+        '\\' => String::from("\"\\\\\""),
+        value if value.is_control() => format!("\"\\u{:04x}\"", value as u32),
+        value => format!("\"{value}\""),
+    };
+}
+
+/// # Description
+/// Parses single line of [`Journal::to_json_lines()`].
+fn parse_entry(line: &str) -> Result<JournalEntry, String> {
+    let mut chars = line.trim().chars().peekable();
+    expect(&mut chars, '{')?;
+
+    let (mut sequence, mut timestamp, mut x, mut y, mut old, mut new) = (None, None, None, None, None, None);
+    loop {
+        let key = parse_string(&mut chars)?;
+        expect(&mut chars, ':')?;
+        match key.as_str() {
+            "sequence" => sequence = Some(parse_number(&mut chars)?),
+            "timestamp" => timestamp = Some(parse_number(&mut chars)?),
+            "x" => x = Some(parse_number(&mut chars)?),
+            "y" => y = Some(parse_number(&mut chars)?),
+            "old" => old = Some(parse_value(&mut chars)?),
+            "new" => new = Some(parse_value(&mut chars)?),
+            _ => return Err(format!("Unknown field \"{key}\"")),
+        }
+
+        match chars.next() {
+            Some(',') => continue,
+            Some('}') if chars.peek().is_none() => break,
+            _ => return Err(String::from("Expected ',' or '}' at the end of the object")),
+        }
+    }
+
+    let missing = |name: &str| format!("Missing field \"{name}\"");
+    let position = Vector2::new(x.ok_or_else(|| missing("x"))? as usize, y.ok_or_else(|| missing("y"))? as usize);
+    return Ok(JournalEntry {
+        sequence: sequence.ok_or_else(|| missing("sequence"))?,
+        timestamp: timestamp.ok_or_else(|| missing("timestamp"))?,
+        change: TileChange::new(position, old.ok_or_else(|| missing("old"))?, new.ok_or_else(|| missing("new"))?),
+    });
+}
+
+/// # Description
+/// Consumes the expected char.
+fn expect(chars: &mut Peekable<Chars>, expected: char) -> Result<(), String> {
+    return match chars.next() {
+        Some(value) if value == expected => Ok(()),
+        _ => Err(format!("Expected '{expected}'")),
+    };
+}
+
+/// # Description
+/// Parses JSON string with the escapes produced by [`json_string()`].
+fn parse_string(chars: &mut Peekable<Chars>) -> Result<String, String> {
+    expect(chars, '"')?;
+    let mut result = String::new();
+
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(result),
+            Some('\\') => match chars.next() {
+                Some(value @ ('"' | '\\' | '/')) => result.push(value),
+                Some('u') => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    let value = u32::from_str_radix(&code, 16).ok().and_then(char::from_u32)
+                        .ok_or_else(|| format!("Invalid escape \"\\u{code}\""))?;
+                    result.push(value);
+                },
+                _ => return Err(String::from("Invalid escape in the string")),
+            },
+            Some(value) => result.push(value),
+            None => return Err(String::from("Unterminated string")),
+        }
+    }
+}
+
+/// # Description
+/// Parses non-negative integer.
+fn parse_number(chars: &mut Peekable<Chars>) -> Result<u64, String> {
+    let mut digits = String::new();
+    while let Some(digit) = chars.next_if(|value| value.is_ascii_digit()) {
+        digits.push(digit);
+    }
+
+    return digits.parse().map_err(|_| format!("Invalid number \"{digits}\""));
+}
+
+/// # Description
+/// Parses tile value, which is either `null` or a string of a single char.
+fn parse_value(chars: &mut Peekable<Chars>) -> Result<Option<char>, String> {
+    if chars.peek() == Some(&'n') {
+        for expected in "null".chars() {
+            expect(chars, expected)?;
+        }
+        return Ok(None);
+    }
+
+    let value = parse_string(chars)?;
+    let mut value_chars = value.chars();
+    return match (value_chars.next(), value_chars.next()) {
+        (Some(value), None) => Ok(Some(value)),
+        _ => Err(format!("Value \"{}\" is not a single char", value.escape_debug())),
+    };
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Journal, ResizePolicy, SymmetryAxis, TileChange, Tilemap, Vector2};
+
+    #[test]
+    fn record() {
+        let mut tilemap = Tilemap::from_string("#.\n..", '.');
+        tilemap.set(Vector2::ONE, 'X');
+        assert!(tilemap.journal().is_none());
+
+        tilemap.start_journal();
+        tilemap.set(Vector2::ZERO, '#');
+        tilemap.set(Vector2::ZERO, '@');
+        tilemap.add_tile(Vector2::new(1, 0), '"').unwrap();
+        tilemap.remove_tile(Vector2::ONE).unwrap();
+        tilemap.select(|position, _| position.y == 1).fill(&mut tilemap, '~');
+
+        let changes: Vec<TileChange> = tilemap.journal().unwrap().entries().iter().map(|entry| entry.change).collect();
+        assert_eq!(changes, vec![
+            TileChange::new(Vector2::ZERO, Some('#'), Some('@')),
+            TileChange::new(Vector2::new(1, 0), None, Some('"')),
+            TileChange::new(Vector2::ONE, Some('X'), None),
+            TileChange::new(Vector2::new(0, 1), None, Some('~')),
+            TileChange::new(Vector2::ONE, None, Some('~')),
+        ]);
+        assert!(tilemap.journal().unwrap().entries().iter().enumerate().all(|(index, entry)| entry.sequence == index as u64));
+
+        let journal = tilemap.stop_journal().unwrap();
+        tilemap.set(Vector2::ZERO, '#');
+        assert_eq!(journal.entries().len(), 5);
+        assert!(tilemap.journal().is_none());
+    }
+
+    #[test]
+    fn bulk_operations() {
+        let mut tilemap = Tilemap::from_string("ab.\n...", '.');
+        let initial = tilemap.clone();
+        tilemap.start_journal();
+
+        tilemap.symmetrize(SymmetryAxis::Vertical);
+        tilemap.resize(Vector2::new(2, 2), ResizePolicy::default()).unwrap();
+        tilemap.settle(crate::tilemap::Direction::Down, |_| false);
+        tilemap.select(|_, value| value == Some('b')).delete(&mut tilemap);
+
+        let mut replayed = initial.clone();
+        tilemap.journal().unwrap().replay(&mut replayed);
+        assert_eq!(replayed.diff(&tilemap), vec![]);
+
+        let mut partial = initial.clone();
+        assert_eq!(partial.replay(tilemap.journal().unwrap(), 0), 1);
+        assert_eq!(partial.replay(tilemap.journal().unwrap(), u64::MAX), tilemap.journal().unwrap().entries().len());
+        assert_eq!(partial.diff(&tilemap), vec![]);
+    }
+
+    #[test]
+    fn json_lines() {
+        let mut tilemap = Tilemap::new('.');
+        tilemap.start_journal();
+        for value in ['"', '\\', '\n', '\u{1F600}', 'x'] {
+            tilemap.set(Vector2::new(3, 1), value);
+        }
+        tilemap.remove_tile(Vector2::new(3, 1)).unwrap();
+
+        let journal = tilemap.journal().unwrap();
+        let exported = journal.to_json_lines();
+        assert_eq!(exported.lines().count(), 6);
+        assert!(exported.starts_with("{\"sequence\":0,\"timestamp\":"));
+        assert!(exported.contains("\"x\":3,\"y\":1,\"old\":\"\\\"\",\"new\":\"\\\\\"}\n"));
+        assert_eq!(&Journal::from_json_lines(&format!("{exported}\n")).unwrap(), journal);
+
+        for line in ["{}", "{\"sequence\":0}", "[1]", "{\"sequence\":0,\"timestamp\":0,\"x\":0,\"y\":0,\"old\":null,\"new\":\"ab\"}",
+                     "{\"sequence\":0,\"timestamp\":0,\"x\":0,\"y\":0,\"old\":null,\"new\":null} x"] {
+            match Journal::from_json_lines(line) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use crate::tilemap::{NavGraph, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Checks if every walkable position inside of the [`Tilemap`] bounds can be reached from
+    /// every other one by horizontal and vertical steps.
+    ///
+    /// # Arguments
+    /// * `is_walkable: F` - Returns `true` if the position with the specified tile value (or
+    ///   [`None`] for positions without tiles) can be walked through.
+    ///
+    /// # Return
+    /// `true` if walkable positions form a single region, or if there are none.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string(".#.\n...", '.');
+    /// assert!(tilemap.is_fully_connected(|value| value.is_none()));
+    /// ```
+    pub fn is_fully_connected<F>(&self, is_walkable: F) -> bool
+        where F: Fn(Option<char>) -> bool {
+        return self.to_nav_graph(is_walkable).connected_components().iter().all(|component| *component == 0);
+    }
+
+    /// # Description
+    /// Returns walkable positions that can not be reached from the specified position.
+    ///
+    /// # Arguments
+    /// * `from: Vector2` - Start position.
+    /// * `is_walkable: F` - Returns `true` if the position with the specified tile value (or
+    ///   [`None`] for positions without tiles) can be walked through.
+    ///
+    /// # Return
+    /// [`Vec`] of unreachable positions in row-major order. If start position is not walkable,
+    /// all walkable positions are unreachable.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("..#.", '.');
+    /// let unreachable = tilemap.unreachable_cells(char_tilemap::Vector2::ZERO, |value| value.is_none());
+    /// assert_eq!(unreachable, vec![char_tilemap::Vector2::new(3, 0)]);
+    /// ```
+    pub fn unreachable_cells<F>(&self, from: Vector2, is_walkable: F) -> Vec<Vector2>
+        where F: Fn(Option<char>) -> bool {
+        let graph = self.to_nav_graph(is_walkable);
+        let components = graph.connected_components();
+        let start_component = graph.index_of(from).map(|index| components[index]);
+
+        return (0..graph.node_count())
+            .filter(|index| Some(components[*index]) != start_component)
+            .filter_map(|index| graph.position(index))
+            .collect();
+    }
+
+    /// # Description
+    /// Returns walkable positions that have exactly one walkable horizontal or vertical neighbor,
+    /// such as ends of corridors.
+    ///
+    /// # Arguments
+    /// * `is_walkable: F` - Returns `true` if the position with the specified tile value (or
+    ///   [`None`] for positions without tiles) can be walked through.
+    ///
+    /// # Return
+    /// [`Vec`] of dead ends in row-major order.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("###\n...\n#.#", '.');
+    /// let dead_ends = tilemap.dead_ends(|value| value.is_none());
+    /// assert_eq!(dead_ends.len(), 3);
+    /// ```
+    pub fn dead_ends<F>(&self, is_walkable: F) -> Vec<Vector2>
+        where F: Fn(Option<char>) -> bool {
+        let graph = self.to_nav_graph(is_walkable);
+        return (0..graph.node_count())
+            .filter(|index| graph.neighbors(*index).len() == 1)
+            .filter_map(|index| graph.position(index))
+            .collect();
+    }
+
+    /// # Description
+    /// Returns walkable positions that split their region into disconnected parts when blocked,
+    /// such as doors and single-tile corridors.
+    ///
+    /// # Arguments
+    /// * `is_walkable: F` - Returns `true` if the position with the specified tile value (or
+    ///   [`None`] for positions without tiles) can be walked through.
+    ///
+    /// # Return
+    /// [`Vec`] of choke points in row-major order.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("..#..\n.....\n..#..", '.');
+    /// let choke_points = tilemap.choke_points(|value| value.is_none());
+    /// assert_eq!(choke_points, vec![char_tilemap::Vector2::new(1, 1),
+    ///                               char_tilemap::Vector2::new(2, 1),
+    ///                               char_tilemap::Vector2::new(3, 1)]);
+    /// ```
+    pub fn choke_points<F>(&self, is_walkable: F) -> Vec<Vector2>
+        where F: Fn(Option<char>) -> bool {
+        let graph = self.to_nav_graph(is_walkable);
+        let articulation = articulation_points(&graph);
+        return (0..graph.node_count())
+            .filter(|index| articulation[*index])
+            .filter_map(|index| graph.position(index))
+            .collect();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Finds articulation points of the graph with iterative Tarjan's algorithm.
+///
+/// # Return
+/// [`Vec`] that tells for every node whether it is an articulation point.
+fn articulation_points(graph: &NavGraph) -> Vec<bool> {
+    let count = graph.node_count();
+    let mut discovery = vec![usize::MAX; count];
+    let mut low = vec![0; count];
+    let mut articulation = vec![false; count];
+    let mut time = 0;
+
+    for root in 0..count {
+        if discovery[root] != usize::MAX {
+            continue;
+        }
+
+        discovery[root] = time;
+        low[root] = time;
+        time += 1;
+        let mut root_children = 0;
+        // Node, its parent and index of the next neighbor to visit
+        let mut stack = vec![(root, usize::MAX, 0)];
+
+        while let Some((node, parent, next)) = stack.last_mut() {
+            let (node, parent) = (*node, *parent);
+            let Some(&neighbor) = graph.neighbors(node).get(*next) else {
+                stack.pop();
+                if let Some((ancestor, _, _)) = stack.last() {
+                    low[*ancestor] = std::cmp::min(low[*ancestor], low[node]);
+                    if *ancestor != root && low[node] >= discovery[*ancestor] {
+                        articulation[*ancestor] = true;
+                    }
+                }
+                continue;
+            };
+            *next += 1;
+
+            if discovery[neighbor] == usize::MAX {
+                discovery[neighbor] = time;
+                low[neighbor] = time;
+                time += 1;
+                if node == root {
+                    root_children += 1;
+                }
+                stack.push((neighbor, node, 0));
+            } else if neighbor != parent {
+                low[node] = std::cmp::min(low[node], discovery[neighbor]);
+            }
+        }
+
+        articulation[root] = root_children > 1;
+    }
+
+    return articulation;
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Rng, Tilemap, Vector2};
+
+    const LEVEL: &str = "\
+        ..#....\n\
+        ..#.##.\n\
+        ......#\n\
+        ###.###\n\
+        #.#...#";
+
+    #[test]
+    fn connectivity() {
+        let tilemap = Tilemap::from_string(LEVEL, '.');
+        assert!(!tilemap.is_fully_connected(|value| value.is_none()));
+        assert!(Tilemap::from_string("###", '.').is_fully_connected(|value| value.is_none()));
+
+        assert_eq!(tilemap.unreachable_cells(Vector2::ZERO, |value| value.is_none()), vec![Vector2::new(1, 4)]);
+        assert_eq!(tilemap.unreachable_cells(Vector2::new(2, 0), |value| value.is_none()).len(), 21);
+    }
+
+    #[test]
+    fn dead_ends() {
+        let tilemap = Tilemap::from_string(LEVEL, '.');
+        assert_eq!(tilemap.dead_ends(|value| value.is_none()), vec![Vector2::new(6, 1), Vector2::new(5, 2), Vector2::new(5, 4)]);
+    }
+
+    #[test]
+    fn choke_points() {
+        let tilemap = Tilemap::from_string(LEVEL, '.');
+        assert_eq!(tilemap.choke_points(|value| value.is_none()), vec![
+            Vector2::new(3, 0), Vector2::new(4, 0), Vector2::new(5, 0), Vector2::new(6, 0), Vector2::new(3, 1),
+            Vector2::new(1, 2), Vector2::new(2, 2), Vector2::new(3, 2), Vector2::new(4, 2), Vector2::new(3, 3),
+            Vector2::new(3, 4), Vector2::new(4, 4),
+        ]);
+    }
+
+    #[test]
+    fn choke_points_match_brute_force() {
+        let mut rng = Rng::new(7);
+        for _ in 0..20 {
+            let mut tilemap = Tilemap::new('.');
+            tilemap.random_fill_weighted(Vector2::ZERO, Vector2::new(8, 6), &[('.', 0.6), ('#', 0.4)], &mut rng).unwrap();
+            let is_walkable = |value: Option<char>| value.is_none();
+            let components = |tilemap: &Tilemap| {
+                return tilemap.to_nav_graph(is_walkable).connected_components().iter().max().map_or(0, |max| max + 1);
+            };
+
+            let mut expected = Vec::new();
+            for y in 0..6 {
+                for x in 0..8 {
+                    let position = Vector2::new(x, y);
+                    if tilemap.get(position).is_some() {
+                        continue;
+                    }
+
+                    let mut blocked = tilemap.clone();
+                    blocked.set(position, '#');
+                    if components(&blocked) > components(&tilemap) {
+                        expected.push(position);
+                    }
+                }
+            }
+
+            assert_eq!(tilemap.choke_points(is_walkable), expected);
+        }
+    }
+}
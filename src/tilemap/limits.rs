@@ -0,0 +1,129 @@
+use crate::tilemap::{TileError, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Optional limits of the [`Tilemap`], which protect from unbounded growth when edits come from
+/// untrusted scripts, network input or runaway generators. Methods that return [`Result`] fail
+/// with [`TileError::TooManyTiles`] or [`TileError::OutOfLimits`] if the change exceeds limits,
+/// and [`Tilemap::set()`] leaves the tilemap unchanged. By default there are no limits.
+///
+/// # Example
+/// ```rust
+/// let mut tilemap = char_tilemap::Tilemap::new('.');
+/// tilemap.limits = char_tilemap::TilemapLimits { max_tiles: Some(1), max_size: Some(char_tilemap::Vector2::new(8, 8)) };
+///
+/// tilemap.add_tile(char_tilemap::Vector2::ZERO, '#').unwrap();
+/// assert_eq!(tilemap.add_tile(char_tilemap::Vector2::ONE, '#'), Err(char_tilemap::TileError::TooManyTiles(1)));
+/// ```
+#[derive(Debug, Default, Eq, PartialEq, Copy, Clone)]
+pub struct TilemapLimits {
+    /// # Description
+    /// Maximal number of tiles.
+    pub max_tiles: Option<usize>,
+    /// # Description
+    /// Maximal size of the [`Tilemap`], so positions of tiles are always less than it.
+    pub max_size: Option<Vector2>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl TilemapLimits {
+    /// # Description
+    /// Checks if the size fits into the limits.
+    ///
+    /// # Arguments
+    /// * `size: Vector2` - Size of the [`Tilemap`].
+    ///
+    /// # Return
+    /// * [`Ok`] if size fits.
+    /// * [`Err`] with [`TileError::OutOfLimits`] otherwise.
+    pub fn check_size(&self, size: Vector2) -> Result<(), TileError> {
+        return match self.max_size {
+            Some(max_size) if size.x > max_size.x || size.y > max_size.y => Err(TileError::OutOfLimits(size)),
+            _ => Ok(()),
+        };
+    }
+
+    /// # Description
+    /// Checks if a new tile can be added at the position when there are already `tiles` tiles.
+    pub(crate) fn check_new_tile(&self, position: Vector2, tiles: usize) -> Result<(), TileError> {
+        if let Some(max_size) = self.max_size {
+            if position.x >= max_size.x || position.y >= max_size.y {
+                return Err(TileError::OutOfLimits(position));
+            }
+        }
+        if let Some(max_tiles) = self.max_tiles {
+            if tiles >= max_tiles {
+                return Err(TileError::TooManyTiles(max_tiles));
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Checks if a tile can be set at the position without exceeding [`Tilemap::limits`].
+    /// Replacing an existing tile never exceeds them.
+    pub(crate) fn check_limits(&self, position: Vector2) -> Result<(), TileError> {
+        if self.limits == TilemapLimits::default() || self.get(position).is_some() {
+            return Ok(());
+        }
+
+        return self.limits.check_new_tile(position, self.tiles.len());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, TilemapLimits, TileError, Vector2};
+
+    fn build_test_tilemap() -> Tilemap {
+        let mut tilemap = Tilemap::from_string("#.\n..", '.');
+        tilemap.limits = TilemapLimits { max_tiles: Some(2), max_size: Some(Vector2::new(3, 3)) };
+
+        return tilemap;
+    }
+
+    #[test]
+    fn tiles() {
+        let mut tilemap = build_test_tilemap();
+
+        assert_eq!(tilemap.add_tile(Vector2::new(3, 0), '#'), Err(TileError::OutOfLimits(Vector2::new(3, 0))));
+        tilemap.set(Vector2::new(0, 3), '#');
+        assert_eq!(tilemap.get(Vector2::new(0, 3)), None);
+
+        tilemap.add_tile(Vector2::new(2, 2), '#').unwrap();
+        assert_eq!(tilemap.add_tile(Vector2::ONE, '#'), Err(TileError::TooManyTiles(2)));
+        tilemap.set(Vector2::ONE, '#');
+        assert_eq!(tilemap.get(Vector2::ONE), None);
+
+        tilemap.set(Vector2::ZERO, '@');
+        assert_eq!(tilemap.build(), "@..\n...\n..#");
+    }
+
+    #[test]
+    fn resize_and_merge() {
+        let mut tilemap = build_test_tilemap();
+
+        match tilemap.resize(Vector2::new(4, 3), Default::default()) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+        tilemap.resize(Vector2::new(3, 3), Default::default()).unwrap();
+
+        assert_eq!(tilemap.merge(&Tilemap::from_string("...A\n.B..\n..C.\n....", '.')).len(), 2);
+        assert_eq!(tilemap.build(), "#..\n.B.\n...");
+        assert_eq!(tilemap.size(), Vector2::new(3, 3));
+    }
+}
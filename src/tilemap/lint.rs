@@ -0,0 +1,320 @@
+use crate::tilemap::{Tilemap, Vector2};
+use crate::tilemap::pathfinding::neighbors;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Problem of the map that was found by [`Tilemap::lint()`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LintFinding {
+    /// # Description
+    /// Name of the rule that found the problem, see [`LintRule::name()`].
+    pub rule: String,
+    /// # Description
+    /// Position of the problem.
+    pub position: Vector2,
+    /// # Description
+    /// Description of the problem.
+    pub message: String,
+}
+
+/// # Description
+/// Check of the map that is run by [`Tilemap::lint()`]. Allows to add project-specific checks
+/// next to the built-in [`MapRule`]s.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::{LintFinding, LintRule, MapRule, Tilemap};
+///
+/// struct SinglePlayer;
+///
+/// impl LintRule for SinglePlayer {
+///     fn name(&self) -> &str {
+///         return "single_player";
+///     }
+///
+///     fn check(&self, tilemap: &Tilemap) -> Vec<LintFinding> {
+///         return tilemap.positions_of('@').into_iter().skip(1)
+///             .map(|position| LintFinding::new(self, position, "Extra player"))
+///             .collect();
+///     }
+/// }
+///
+/// let tilemap = Tilemap::from_string("@.@", '.');
+/// let findings = tilemap.lint(&[MapRule::Custom(Box::new(SinglePlayer))]);
+/// assert_eq!(findings[0].to_string(), "single_player at { x: 2, y: 0 }: Extra player");
+/// ```
+pub trait LintRule {
+    /// # Description
+    /// Returns name of the rule, which is stored in its findings.
+    fn name(&self) -> &str;
+
+    /// # Description
+    /// Checks the map.
+    ///
+    /// # Arguments
+    /// * `tilemap: &Tilemap` - Checked map.
+    ///
+    /// # Return
+    /// [`Vec`] of found problems.
+    fn check(&self, tilemap: &Tilemap) -> Vec<LintFinding>;
+}
+
+/// # Description
+/// Rule of [`Tilemap::lint()`]. Tile values are listed as chars, and positions without tiles
+/// have the value of [`Tilemap::empty_tile`].
+pub enum MapRule {
+    /// # Description
+    /// Walkable areas that are not connected to the largest walkable area. There is one finding
+    /// per area, at its first position in row-major order.
+    UnreachableAreas {
+        /// # Description
+        /// Values that can be walked through.
+        walkable: Vec<char>,
+    },
+    /// # Description
+    /// Doors that are not placed between two walls, either horizontally or vertically.
+    DoorsWithoutWalls {
+        /// # Description
+        /// Values of doors.
+        doors: Vec<char>,
+        /// # Description
+        /// Values of walls.
+        walls: Vec<char>,
+    },
+    /// # Description
+    /// Corridor tiles that have at most one walkable horizontal or vertical neighbor, so the
+    /// corridor leads nowhere.
+    DanglingCorridors {
+        /// # Description
+        /// Values of corridors.
+        corridors: Vec<char>,
+        /// # Description
+        /// Values that can be walked through in addition to corridors, such as floors of rooms.
+        walkable: Vec<char>,
+    },
+    /// # Description
+    /// Project-specific rule.
+    Custom(Box<dyn LintRule>),
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl LintFinding {
+    /// # Description
+    /// Creates new [`LintFinding`] of the rule.
+    ///
+    /// # Arguments
+    /// * `rule: &R` - Rule that found the problem.
+    /// * `position: Vector2` - Position of the problem.
+    /// * `message: &str` - Description of the problem.
+    ///
+    /// # Return
+    /// New instance of the [`LintFinding`].
+    pub fn new<R: LintRule + ?Sized>(rule: &R, position: Vector2, message: &str) -> LintFinding {
+        return LintFinding { rule: rule.name().to_string(), position, message: message.to_string() };
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Checks the map against the rules, for example in CI checks of hand-authored levels.
+    ///
+    /// # Arguments
+    /// * `rules: &[MapRule]` - Rules that are checked in order.
+    ///
+    /// # Return
+    /// [`Vec`] of found problems, grouped by rules in order of `rules`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::{MapRule, Vector2};
+    ///
+    /// let tilemap = char_tilemap::Tilemap::from_string("#+#\n...\n#.+", ' ');
+    /// let rules = [MapRule::DoorsWithoutWalls { doors: vec!['+'], walls: vec!['#'] }];
+    /// let findings = tilemap.lint(&rules);
+    ///
+    /// assert_eq!(findings.len(), 1);
+    /// assert_eq!(findings[0].position, Vector2::new(2, 2));
+    /// ```
+    pub fn lint(&self, rules: &[MapRule]) -> Vec<LintFinding> {
+        return rules.iter().flat_map(|rule| rule.check(self)).collect();
+    }
+
+    /// # Description
+    /// Returns value of the position, or the empty tile if there is no tile.
+    fn value_or_empty(&self, position: Vector2) -> char {
+        return self.get(position).unwrap_or(self.empty_tile);
+    }
+}
+
+impl LintRule for MapRule {
+    fn name(&self) -> &str {
+        return match self {
+            MapRule::UnreachableAreas { .. } => "unreachable_areas",
+            MapRule::DoorsWithoutWalls { .. } => "doors_without_walls",
+            MapRule::DanglingCorridors { .. } => "dangling_corridors",
+            MapRule::Custom(rule) => rule.name(),
+        };
+    }
+
+    fn check(&self, tilemap: &Tilemap) -> Vec<LintFinding> {
+        return match self {
+            MapRule::UnreachableAreas { walkable } => self.unreachable_areas(tilemap, walkable),
+            MapRule::DoorsWithoutWalls { doors, walls } => self.doors_without_walls(tilemap, doors, walls),
+            MapRule::DanglingCorridors { corridors, walkable } => self.dangling_corridors(tilemap, corridors, walkable),
+            MapRule::Custom(rule) => rule.check(tilemap),
+        };
+    }
+}
+
+impl MapRule {
+    /// # Description
+    /// Finds walkable areas except the largest one.
+    fn unreachable_areas(&self, tilemap: &Tilemap, walkable: &[char]) -> Vec<LintFinding> {
+        let graph = tilemap.to_nav_graph(|value| walkable.contains(&value.unwrap_or(tilemap.empty_tile)));
+        let components = graph.connected_components();
+
+        let mut sizes = vec![0; components.iter().max().map_or(0, |max| max + 1)];
+        for component in components.iter() {
+            sizes[*component] += 1;
+        }
+        // The first of the largest areas is the reachable one
+        let largest = (0..sizes.len()).rev().max_by_key(|component| sizes[*component]);
+
+        let mut findings = Vec::new();
+        for component in (0..sizes.len()).filter(|component| Some(*component) != largest) {
+            let first = components.iter().position(|node| *node == component).and_then(|index| graph.position(index));
+            if let Some(position) = first {
+                let message = format!("Area of {} cells is unreachable from the largest area", sizes[component]);
+                findings.push(LintFinding::new(self, position, &message));
+            }
+        }
+
+        return findings;
+    }
+
+    /// # Description
+    /// Finds doors without walls on both opposite sides.
+    fn doors_without_walls(&self, tilemap: &Tilemap, doors: &[char], walls: &[char]) -> Vec<LintFinding> {
+        let is_wall = |position: Option<Vector2>| position.is_some_and(|position| walls.contains(&tilemap.value_or_empty(position)));
+
+        return tilemap.tiles.iter()
+            .filter(|tile| doors.contains(&tile.value))
+            .filter(|tile| {
+                let Vector2 { x, y } = tile.position;
+                let horizontal = is_wall(x.checked_sub(1).map(|x| Vector2::new(x, y))) && is_wall(Some(Vector2::new(x + 1, y)));
+                let vertical = is_wall(y.checked_sub(1).map(|y| Vector2::new(x, y))) && is_wall(Some(Vector2::new(x, y + 1)));
+                return !horizontal && !vertical;
+            })
+            .map(|tile| LintFinding::new(self, tile.position, "Door is not placed between two walls"))
+            .collect();
+    }
+
+    /// # Description
+    /// Finds corridor tiles with at most one walkable neighbor.
+    fn dangling_corridors(&self, tilemap: &Tilemap, corridors: &[char], walkable: &[char]) -> Vec<LintFinding> {
+        let size = tilemap.size;
+        let is_walkable = |position: &Vector2| {
+            let value = tilemap.value_or_empty(*position);
+            return position.x < size.x && position.y < size.y && (corridors.contains(&value) || walkable.contains(&value));
+        };
+
+        return tilemap.tiles.iter()
+            .filter(|tile| corridors.contains(&tile.value))
+            .filter(|tile| neighbors(tile.position).filter(is_walkable).count() <= 1)
+            .map(|tile| LintFinding::new(self, tile.position, "Corridor leads nowhere"))
+            .collect();
+    }
+}
+
+impl std::fmt::Display for LintFinding {
+    /// # Description
+    /// Writes [`LintFinding`] to a formatter.
+    ///
+    /// # Arguments
+    /// * `formatter: &mut std::fmt::Formatter<'_>` - Formatter to which this [`LintFinding`] will be put.
+    ///
+    /// # Return
+    /// [`std::fmt::Result`] from [`write!`] macro.
+    ///
+    /// # Notes
+    /// Resulting format: `{rule} at {position}: {message}`
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(formatter, "{} at {}: {}", self.rule, self.position, self.message);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{LintFinding, LintRule, MapRule, Tilemap, Vector2};
+
+    /// # Description
+    /// Returns positions of the findings.
+    fn positions(findings: &[LintFinding]) -> Vec<Vector2> {
+        return findings.iter().map(|finding| finding.position).collect();
+    }
+
+    #[test]
+    fn unreachable_areas() {
+        let tilemap = Tilemap::from_string("..#.\n..#.\n###.\n.#..", ' ');
+        let findings = tilemap.lint(&[MapRule::UnreachableAreas { walkable: vec!['.'] }]);
+
+        assert_eq!(positions(&findings), vec![Vector2::ZERO, Vector2::new(0, 3)]);
+        assert_eq!(findings[0].message, "Area of 4 cells is unreachable from the largest area");
+        assert_eq!(findings[1].rule, "unreachable_areas");
+        assert!(Tilemap::new(' ').lint(&[MapRule::UnreachableAreas { walkable: vec![' '] }]).is_empty());
+    }
+
+    #[test]
+    fn doors_without_walls() {
+        let tilemap = Tilemap::from_string("#+#.\n.#..\n.+#+\n.#..", '.');
+        let findings = tilemap.lint(&[MapRule::DoorsWithoutWalls { doors: vec!['+'], walls: vec!['#'] }]);
+        assert_eq!(positions(&findings), vec![Vector2::new(3, 2)]);
+
+        let tilemap = Tilemap::from_string("+#", '.');
+        assert_eq!(tilemap.lint(&[MapRule::DoorsWithoutWalls { doors: vec!['+'], walls: vec!['#'] }]).len(), 1);
+    }
+
+    #[test]
+    fn dangling_corridors() {
+        let tilemap = Tilemap::from_string("....##\n...,,,\n....#,", '#');
+        let rule = MapRule::DanglingCorridors { corridors: vec![','], walkable: vec!['.'] };
+        assert_eq!(positions(&tilemap.lint(&[rule])), vec![Vector2::new(5, 2)]);
+
+        let rule = MapRule::DanglingCorridors { corridors: vec![','], walkable: vec![] };
+        assert_eq!(positions(&tilemap.lint(&[rule])), vec![Vector2::new(3, 1), Vector2::new(5, 2)]);
+    }
+
+    #[test]
+    fn custom() {
+        struct Wide;
+
+        impl LintRule for Wide {
+            fn name(&self) -> &str {
+                return "wide";
+            }
+
+            fn check(&self, tilemap: &Tilemap) -> Vec<LintFinding> {
+                return match tilemap.size().x > 3 {
+                    true => vec![LintFinding::new(self, Vector2::ZERO, "Map is too wide")],
+                    false => vec![],
+                };
+            }
+        }
+
+        let tilemap = Tilemap::from_string("....\n.+..", '.');
+        let rules = [MapRule::Custom(Box::new(Wide)), MapRule::DoorsWithoutWalls { doors: vec!['+'], walls: vec!['#'] }];
+        let findings: Vec<String> = tilemap.lint(&rules).iter().map(|finding| finding.to_string()).collect();
+        assert_eq!(findings, vec!["wide at { x: 0, y: 0 }: Map is too wide",
+                                  "doors_without_walls at { x: 1, y: 1 }: Door is not placed between two walls"]);
+    }
+}
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+use crate::tilemap::Tilemap;
+use crate::tilemap::state_hash::{fnv1a, FNV_OFFSET};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Content-addressed cache of data derived from maps, such as built strings or distance maps.
+/// Values are keyed by [`Tilemap::state_hash()`] of the map, or by parameters and seed of the
+/// generator that produced it, so unchanged maps are never processed twice. The least recently
+/// used value is evicted when the cache is full.
+///
+/// # Example
+/// ```rust
+/// let tilemap = char_tilemap::Tilemap::from_string("#.\n.#", '.');
+/// let mut cache = char_tilemap::MapCache::new(16).unwrap();
+///
+/// let key = char_tilemap::MapCache::<usize>::key_of(&tilemap);
+/// let walls = *cache.get_or_insert_with(key, || tilemap.build().matches('#').count());
+/// assert_eq!(walls, 2);
+/// assert_eq!(cache.get(key), Some(&2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MapCache<V> {
+    /// # Description
+    /// Maximum number of kept values.
+    capacity: usize,
+    /// # Description
+    /// Values by their keys, with the time of the last access.
+    values: HashMap<u64, (V, u64)>,
+    /// # Description
+    /// Counter of accesses, which is used as time to find the least recently used value.
+    time: u64,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl<V> MapCache<V> {
+    /// # Description
+    /// Creates new empty [`MapCache`].
+    ///
+    /// # Arguments
+    /// * `capacity: usize` - Maximum number of kept values.
+    ///
+    /// # Return
+    /// * [`Ok`] with new instance of the [`MapCache`].
+    /// * [`Err`] if capacity is 0. Contains error message.
+    pub fn new(capacity: usize) -> Result<MapCache<V>, String> {
+        if capacity == 0 {
+            return Err(String::from("At least one value must be kept in the cache"));
+        }
+
+        return Ok(MapCache { capacity, values: HashMap::new(), time: 0 });
+    }
+
+    /// # Description
+    /// Returns key of the map content, which is its [`Tilemap::state_hash()`].
+    pub fn key_of(tilemap: &Tilemap) -> u64 {
+        return tilemap.state_hash();
+    }
+
+    /// # Description
+    /// Returns key of the generated map by the generator name, its parameters and seed, so
+    /// results can be cached before the map is generated.
+    ///
+    /// # Arguments
+    /// * `generator: &str` - Name of the generator.
+    /// * `parameters: &[u64]` - Parameters of the generator, such as size or density.
+    /// * `seed: u64` - Seed of the generator.
+    pub fn key_of_generator(generator: &str, parameters: &[u64], seed: u64) -> u64 {
+        let mut hash = fnv1a(FNV_OFFSET, &(generator.len() as u64).to_le_bytes());
+        hash = fnv1a(hash, generator.as_bytes());
+        hash = fnv1a(hash, &(parameters.len() as u64).to_le_bytes());
+        for parameter in parameters {
+            hash = fnv1a(hash, &parameter.to_le_bytes());
+        }
+
+        return fnv1a(hash, &seed.to_le_bytes());
+    }
+
+    /// # Description
+    /// Returns maximum number of kept values.
+    pub fn capacity(&self) -> usize {
+        return self.capacity;
+    }
+
+    /// # Description
+    /// Returns number of kept values.
+    pub fn len(&self) -> usize {
+        return self.values.len();
+    }
+
+    /// # Description
+    /// Checks if there are no kept values.
+    pub fn is_empty(&self) -> bool {
+        return self.values.is_empty();
+    }
+
+    /// # Description
+    /// Returns value of the key and marks it as recently used.
+    ///
+    /// # Return
+    /// * [`Some`] with the value.
+    /// * [`None`] if there is no value of the key.
+    pub fn get(&mut self, key: u64) -> Option<&V> {
+        self.time += 1;
+        let (value, time) = self.values.get_mut(&key)?;
+        *time = self.time;
+        return Some(value);
+    }
+
+    /// # Description
+    /// Keeps value of the key, replacing the previous one. The least recently used value is
+    /// evicted if the cache is full.
+    ///
+    /// # Arguments
+    /// * `key: u64` - Key of the value.
+    /// * `value: V` - Kept value.
+    ///
+    /// # Return
+    /// * [`Some`] with the previous value of the key.
+    /// * [`None`] if key was not kept.
+    pub fn insert(&mut self, key: u64, value: V) -> Option<V> {
+        if !self.values.contains_key(&key) && self.values.len() >= self.capacity {
+            let oldest = self.values.iter().min_by_key(|(_, (_, time))| *time).map(|(key, _)| *key);
+            if let Some(oldest) = oldest {
+                self.values.remove(&oldest);
+            }
+        }
+
+        self.time += 1;
+        return self.values.insert(key, (value, self.time)).map(|(value, _)| value);
+    }
+
+    /// # Description
+    /// Returns value of the key, calculating and keeping it if there is none.
+    ///
+    /// # Arguments
+    /// * `key: u64` - Key of the value.
+    /// * `calculate: F` - Calculates the value if it is not kept.
+    pub fn get_or_insert_with<F>(&mut self, key: u64, calculate: F) -> &V
+        where F: FnOnce() -> V {
+        if self.get(key).is_none() {
+            self.insert(key, calculate());
+        }
+
+        return &self.values[&key].0;
+    }
+
+    /// # Description
+    /// Removes value of the key.
+    ///
+    /// # Return
+    /// * [`Some`] with the removed value.
+    /// * [`None`] if key was not kept.
+    pub fn remove(&mut self, key: u64) -> Option<V> {
+        return self.values.remove(&key).map(|(value, _)| value);
+    }
+
+    /// # Description
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+impl MapCache<String> {
+    /// # Description
+    /// Builds [`Tilemap`] same as [`Tilemap::build()`], unless a map with the same content was
+    /// already built and is still kept. Besides [`Tilemap::state_hash()`], the key includes
+    /// [`Tilemap::cell_width`] and grapheme clusters of the map, since they change its
+    /// representation.
+    ///
+    /// # Arguments
+    /// * `tilemap: &Tilemap` - Built map.
+    ///
+    /// # Return
+    /// Representation of the [`Tilemap`].
+    pub fn build(&mut self, tilemap: &Tilemap) -> &str {
+        return self.get_or_insert_with(build_key(tilemap), || tilemap.build());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Returns key of the built representation of the [`Tilemap`], see [`MapCache::build()`].
+fn build_key(tilemap: &Tilemap) -> u64 {
+    let mut hash = fnv1a(tilemap.state_hash(), &(tilemap.cell_width as u64).to_le_bytes());
+    hash = fnv1a(hash, &(tilemap.clusters.len() as u64).to_le_bytes());
+    for (value, cluster) in tilemap.clusters.iter() {
+        hash = fnv1a(hash, &(*value as u32).to_le_bytes());
+        hash = fnv1a(hash, &(cluster.len() as u64).to_le_bytes());
+        hash = fnv1a(hash, cluster.as_bytes());
+    }
+
+    return hash;
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{MapCache, Tilemap, Vector2};
+
+    #[test]
+    fn eviction() {
+        let mut cache = MapCache::new(2).unwrap();
+        cache.insert(1, 'a');
+        cache.insert(2, 'b');
+        assert_eq!(cache.get(1), Some(&'a'));
+
+        cache.insert(3, 'c');
+        assert_eq!(cache.get(2), None);
+        assert_eq!(cache.insert(1, 'd'), Some('a'));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.remove(3), Some('c'));
+        assert_eq!(cache.remove(3), None);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        match MapCache::<char>::new(0) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true),
+        }
+    }
+
+    #[test]
+    fn build() {
+        let mut tilemap = Tilemap::from_string("#.\n.#", '.');
+        let mut cache = MapCache::new(4).unwrap();
+        assert_eq!(cache.build(&tilemap), "#.\n.#");
+
+        tilemap.set(Vector2::ZERO, '@');
+        assert_eq!(cache.build(&tilemap), "@.\n.#");
+        assert_eq!(cache.build(&Tilemap::from_string("#.\n.#", '.')), "#.\n.#");
+        assert_eq!(cache.len(), 2);
+
+        let mut wide = Tilemap::from_string("ab", '.');
+        assert_eq!(cache.build(&wide), "ab");
+        wide.cell_width = 3;
+        assert_eq!(cache.build(&wide), " a  b ");
+
+        let mut first = wide.clone();
+        first.set_cluster(Vector2::ZERO, "e\u{301}").unwrap();
+        let mut second = wide.clone();
+        second.set_cluster(Vector2::ZERO, "o\u{301}").unwrap();
+        assert_eq!(first.state_hash(), second.state_hash());
+        assert_eq!(cache.build(&first), "e\u{301}  b ");
+        assert_eq!(cache.build(&second), "o\u{301}  b ");
+    }
+
+    #[test]
+    fn keys() {
+        let key = MapCache::<String>::key_of_generator("caves", &[40, 20], 7);
+        assert_eq!(key, MapCache::<String>::key_of_generator("caves", &[40, 20], 7));
+        assert_ne!(key, MapCache::<String>::key_of_generator("caves", &[40, 20], 8));
+        assert_ne!(key, MapCache::<String>::key_of_generator("caves", &[20, 40], 7));
+        assert_ne!(key, MapCache::<String>::key_of_generator("rooms", &[40, 20], 7));
+
+        let tilemap = Tilemap::from_string("#", '.');
+        assert_eq!(MapCache::<String>::key_of(&tilemap), tilemap.state_hash());
+    }
+}
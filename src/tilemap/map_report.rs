@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Statistics of the map produced by [`Tilemap::report()`]. [`std::fmt::Display`] writes a
+/// readable summary, and the `serde` feature allows to serialize it for other tools.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MapReport {
+    /// # Description
+    /// Size of the map.
+    pub size: Vector2,
+    /// # Description
+    /// Number of tiles.
+    pub tile_count: usize,
+    /// # Description
+    /// Number of tiles by their values.
+    pub histogram: BTreeMap<char, usize>,
+    /// # Description
+    /// Number of walkable positions.
+    pub walkable_count: usize,
+    /// # Description
+    /// Number of separate walkable regions.
+    pub region_count: usize,
+    /// # Description
+    /// Number of positions of the largest walkable region.
+    pub largest_region: usize,
+    /// # Description
+    /// Ratio of tiles to all positions of the map, from 0 to 1. It is 0 for maps without
+    /// positions.
+    pub density: f64,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl MapReport {
+    /// # Description
+    /// Checks if all walkable positions form a single region, or if there are none.
+    pub fn is_connected(&self) -> bool {
+        return self.region_count <= 1;
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Collects statistics of the map: size, number of tiles and their values, walkable regions
+    /// and density.
+    ///
+    /// # Arguments
+    /// * `is_walkable: F` - Returns `true` if the position with the specified tile value (or
+    ///   [`None`] for positions without tiles) can be walked through.
+    ///
+    /// # Return
+    /// New instance of the [`MapReport`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("#.#\n.@#", '.');
+    /// let report = tilemap.report(|value| value != Some('#'));
+    ///
+    /// assert_eq!(report.histogram[&'#'], 3);
+    /// assert_eq!(report.largest_region, 3);
+    /// assert_eq!(report.to_string(), "Size: 3x2\n\
+    ///                                 Tiles: 4 (66.7% density)\n\
+    ///                                 Values: '#' 3, '@' 1\n\
+    ///                                 Walkable: 3 in 1 region, largest has 3");
+    /// ```
+    pub fn report<F>(&self, is_walkable: F) -> MapReport
+        where F: Fn(Option<char>) -> bool {
+        let mut histogram = BTreeMap::new();
+        for tile in self.tiles.iter() {
+            *histogram.entry(tile.value).or_insert(0) += 1;
+        }
+
+        let components = self.to_nav_graph(is_walkable).connected_components();
+        let mut regions = vec![0; components.iter().max().map_or(0, |max| max + 1)];
+        for component in components.iter() {
+            regions[*component] += 1;
+        }
+
+        let area = self.size.x * self.size.y;
+        return MapReport {
+            size: self.size,
+            tile_count: self.tiles.len(),
+            histogram,
+            walkable_count: components.len(),
+            region_count: regions.len(),
+            largest_region: regions.iter().copied().max().unwrap_or(0),
+            density: if area == 0 { 0.0 } else { self.tiles.len() as f64 / area as f64 },
+        };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl std::fmt::Display for MapReport {
+    /// # Description
+    /// Writes readable summary of the [`MapReport`] to a formatter.
+    ///
+    /// # Arguments
+    /// * `formatter: &mut std::fmt::Formatter<'_>` - Formatter to which this [`MapReport`] will be put.
+    ///
+    /// # Return
+    /// [`std::fmt::Result`] from [`write!`] macro.
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values: Vec<String> = self.histogram.iter()
+            .map(|(value, count)| format!("'{}' {count}", value.escape_debug()))
+            .collect();
+        let regions = if self.region_count == 1 { "region" } else { "regions" };
+
+        writeln!(formatter, "Size: {}x{}", self.size.x, self.size.y)?;
+        writeln!(formatter, "Tiles: {} ({:.1}% density)", self.tile_count, self.density * 100.0)?;
+        writeln!(formatter, "Values: {}", if values.is_empty() { String::from("none") } else { values.join(", ") })?;
+        return write!(formatter, "Walkable: {} in {} {regions}, largest has {}",
+                      self.walkable_count, self.region_count, self.largest_region);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[test]
+    fn report() {
+        let tilemap = Tilemap::from_string("..#.\n###.\n.#..", '.');
+        let report = tilemap.report(|value| value.is_none());
+
+        assert_eq!(report.size, Vector2::new(4, 3));
+        assert_eq!(report.tile_count, 5);
+        assert_eq!(report.walkable_count, 7);
+        assert_eq!(report.region_count, 3);
+        assert_eq!(report.largest_region, 4);
+        assert!(!report.is_connected());
+        assert_eq!(report.to_string(), "Size: 4x3\nTiles: 5 (41.7% density)\nValues: '#' 5\nWalkable: 7 in 3 regions, largest has 4");
+
+        let report = Tilemap::new('.').report(|_| true);
+        assert_eq!(report.density, 0.0);
+        assert!(report.is_connected());
+        assert_eq!(report.to_string(), "Size: 0x0\nTiles: 0 (0.0% density)\nValues: none\nWalkable: 0 in 0 regions, largest has 0");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let report = Tilemap::from_string("#.\n.@", '.').report(|value| value.is_none());
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(serde_json::from_str::<crate::tilemap::MapReport>(&json).unwrap(), report);
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::BTreeMap;
+use crate::tilemap::Tilemap;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Prefix of keys of the header lines that store [`MapMetadata`].
+pub(super) const METADATA_PREFIX: &str = "meta.";
+
+/// # Description
+/// Names of the standard fields, which can not be used by custom fields.
+const STANDARD_FIELDS: [&str; 4] = ["author", "name", "seed", "created"];
+
+/// # Description
+/// Description of the map that is saved in the header of the save format, so map files are
+/// self-describing when they are shared between tools, see [`Tilemap::metadata()`]. Every field
+/// is stored in a `meta.{field}` header line. Line breaks and backslashes of values are escaped.
+///
+/// # Example
+/// ```rust
+/// let mut tilemap = char_tilemap::Tilemap::from_string("#.#", '.');
+/// tilemap.metadata_mut().author = Some(String::from("Vismar"));
+/// tilemap.metadata_mut().seed = Some(42);
+/// tilemap.metadata_mut().set_custom("difficulty", "hard").unwrap();
+///
+/// let loaded = char_tilemap::Tilemap::from_file_string(&tilemap.to_file_string()).unwrap();
+/// assert_eq!(loaded.metadata(), tilemap.metadata());
+/// assert_eq!(loaded.metadata().custom("difficulty"), Some("hard"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MapMetadata {
+    /// # Description
+    /// Author of the map.
+    pub author: Option<String>,
+    /// # Description
+    /// Name of the map.
+    pub name: Option<String>,
+    /// # Description
+    /// Seed from which the map was generated.
+    pub seed: Option<u64>,
+    /// # Description
+    /// Date of creation of the map in any format, for example ISO 8601.
+    pub created: Option<String>,
+    /// # Description
+    /// Custom fields by their keys, see [`MapMetadata::set_custom()`].
+    custom: BTreeMap<String, String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl MapMetadata {
+    /// # Description
+    /// Sets custom field of the metadata.
+    ///
+    /// # Arguments
+    /// * `key: &str` - Key of the field.
+    /// * `value: &str` - Value of the field.
+    ///
+    /// # Return
+    /// * [`Ok`] with [`Some`] previous value of the field, or [`None`] if there was no field.
+    /// * [`Err`] if key is empty, contains `=` or line breaks, or is the name of a standard
+    ///   field. Contains error message.
+    pub fn set_custom(&mut self, key: &str, value: &str) -> Result<Option<String>, String> {
+        if key.is_empty() || key.contains(['=', '\n', '\r']) {
+            return Err(format!("Invalid metadata key \"{}\"", key.escape_debug()));
+        }
+        if STANDARD_FIELDS.contains(&key) {
+            return Err(format!("Metadata key \"{key}\" is reserved for the standard field"));
+        }
+
+        return Ok(self.custom.insert(key.to_string(), value.to_string()));
+    }
+
+    /// # Description
+    /// Returns value of the custom field.
+    ///
+    /// # Return
+    /// * [`Some`] with the value.
+    /// * [`None`] if there is no field with the specified key.
+    pub fn custom(&self, key: &str) -> Option<&str> {
+        return self.custom.get(key).map(|value| value.as_str());
+    }
+
+    /// # Description
+    /// Returns keys and values of all custom fields in alphabetical order of keys.
+    pub fn custom_fields(&self) -> impl Iterator<Item = (&str, &str)> + '_ {
+        return self.custom.iter().map(|(key, value)| (key.as_str(), value.as_str()));
+    }
+
+    /// # Description
+    /// Removes custom field.
+    ///
+    /// # Return
+    /// * [`Some`] with value of the removed field.
+    /// * [`None`] if there was no field with the specified key.
+    pub fn remove_custom(&mut self, key: &str) -> Option<String> {
+        return self.custom.remove(key);
+    }
+
+    /// # Description
+    /// Checks if no field is set.
+    pub fn is_empty(&self) -> bool {
+        return *self == MapMetadata::default();
+    }
+
+    /// # Description
+    /// Returns header lines of the save format that store all set fields.
+    pub(super) fn to_lines(&self) -> String {
+        let seed = self.seed.map(|seed| seed.to_string());
+        let standard = [&self.author, &self.name, &seed, &self.created];
+
+        return STANDARD_FIELDS.iter().zip(standard)
+            .filter_map(|(key, value)| Some((*key, value.as_deref()?)))
+            .chain(self.custom_fields())
+            .map(|(key, value)| format!("{METADATA_PREFIX}{key}={}\n", escape(value)))
+            .collect();
+    }
+
+    /// # Description
+    /// Parses value of the header line with `meta.{key}` key.
+    pub(super) fn parse_field(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let value = unescape(value)?;
+        match key {
+            "author" => self.author = Some(value),
+            "name" => self.name = Some(value),
+            "seed" => self.seed = Some(value.parse().map_err(|_| format!("Invalid seed \"{value}\""))?),
+            "created" => self.created = Some(value),
+            key => {
+                self.set_custom(key, &value)?;
+            },
+        }
+
+        return Ok(());
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Returns metadata of the map that is saved by [`Tilemap::to_file_string()`].
+    pub fn metadata(&self) -> &MapMetadata {
+        return &self.metadata;
+    }
+
+    /// # Description
+    /// Returns mutable metadata of the map, see [`Tilemap::metadata()`].
+    pub fn metadata_mut(&mut self) -> &mut MapMetadata {
+        return &mut self.metadata;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Escapes backslashes and line breaks of the value, so it fits into one header line.
+fn escape(value: &str) -> String {
+    return value.replace('\\', "\\\\").replace('\n', "\\n").replace('\r', "\\r");
+}
+
+/// # Description
+/// Reverts [`escape()`].
+fn unescape(value: &str) -> Result<String, String> {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(value_char) = chars.next() {
+        if value_char != '\\' {
+            result.push(value_char);
+            continue;
+        }
+
+        match chars.next() {
+            Some('\\') => result.push('\\'),
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            _ => return Err(format!("Invalid escape sequence in metadata value \"{value}\"")),
+        }
+    }
+
+    return Ok(result);
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{MapMetadata, Tilemap};
+
+    #[test]
+    fn custom() {
+        let mut metadata = MapMetadata::default();
+        assert!(metadata.is_empty());
+        assert_eq!(metadata.set_custom("biome", "swamp"), Ok(None));
+        assert_eq!(metadata.set_custom("biome", "desert"), Ok(Some(String::from("swamp"))));
+        assert_eq!(metadata.custom("biome"), Some("desert"));
+        assert!(!metadata.is_empty());
+
+        for key in ["", "a=b", "a\nb", "seed"] {
+            match metadata.set_custom(key, "value") {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true),
+            }
+        }
+
+        assert_eq!(metadata.remove_custom("biome"), Some(String::from("desert")));
+        assert_eq!(metadata.custom_fields().count(), 0);
+    }
+
+    #[test]
+    fn file_string() {
+        let mut tilemap = Tilemap::from_string("#.", '.');
+        tilemap.metadata_mut().name = Some(String::from("Cave\\1\nlower"));
+        tilemap.metadata_mut().seed = Some(7);
+        tilemap.metadata_mut().created = Some(String::from("2024-05-01"));
+        tilemap.metadata_mut().set_custom("tool", "a=b").unwrap();
+
+        let text = tilemap.to_file_string();
+        assert_eq!(text, "version=3\nempty_tile=.\nsize=2x1\nmeta.name=Cave\\\\1\\nlower\nmeta.seed=7\n\
+                          meta.created=2024-05-01\nmeta.tool=a=b\n\n#.");
+        assert_eq!(Tilemap::from_file_string(&text).unwrap().metadata(), tilemap.metadata());
+
+        for text in ["empty_tile=.\nsize=1x1\nmeta.seed=x\n\n.", "empty_tile=.\nsize=1x1\nmeta.name=a\\b\n\n.",
+                     "empty_tile=.\nsize=1x1\nmeta.=a\n\n."] {
+            match Tilemap::from_file_string(text) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true),
+            }
+        }
+    }
+}
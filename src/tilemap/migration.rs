@@ -0,0 +1,186 @@
+use std::borrow::Cow;
+use crate::tilemap::Tilemap;
+use crate::tilemap::clusters::FIRST_CLUSTER_CHAR;
+use crate::tilemap::file_format::CLUSTER_KEY;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Current version of the text save format, see [`Tilemap::to_file_string()`]. Files without
+/// the `version` header line were saved before the format got versioned and have version 1.
+pub const FILE_FORMAT_VERSION: u32 = 3;
+
+/// # Description
+/// Key of the header line that stores version of the save format.
+pub(super) const VERSION_KEY: &str = "version";
+
+/// # Description
+/// Steps that convert text of the save format to the next version. Step at index `i` converts
+/// version `i + 1` to version `i + 2`.
+const MIGRATIONS: [fn(&str) -> String; FILE_FORMAT_VERSION as usize - 1] = [
+    migrate_from_v1,
+    migrate_from_v2,
+];
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Converts text saved by any older version of the save format into the current version,
+    /// so it can be parsed by [`Tilemap::from_file_string()`] and saved back without losses.
+    /// [`Tilemap::from_file_string()`] and [`Tilemap::load_from_file()`] migrate text themselves.
+    ///
+    /// # Arguments
+    /// * `text: &str` - Serialized [`Tilemap`] of any version.
+    ///
+    /// # Return
+    /// * [`Ok`] with text of the current version, where `\r\n` line breaks are replaced with
+    ///   `\n`. It is borrowed if text already has it.
+    /// * [`Err`] if version is invalid or newer than [`FILE_FORMAT_VERSION`]. Contains error
+    ///   message.
+    ///
+    /// # Example
+    /// ```rust
+    /// let migrated = char_tilemap::Tilemap::migrate("empty_tile=-\nsize=2x1\n\n-O").unwrap();
+    /// assert_eq!(migrated, "version=3\nempty_tile=-\nsize=2x1\n\n-O");
+    /// ```
+    pub fn migrate(text: &str) -> Result<Cow<'_, str>, String> {
+        let mut text = normalize_line_breaks(text);
+        let version = file_version(&text)?;
+        if version > FILE_FORMAT_VERSION {
+            return Err(format!("File format version {version} is newer than supported version {FILE_FORMAT_VERSION}"));
+        }
+
+        for migration in &MIGRATIONS[version as usize - 1..] {
+            text = Cow::Owned(migration(&text));
+        }
+
+        return Ok(text);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Replaces `\r\n` line breaks of the text with `\n`. Text is borrowed if it has none.
+pub(super) fn normalize_line_breaks(text: &str) -> Cow<'_, str> {
+    return match text.contains("\r\n") {
+        true => Cow::Owned(text.replace("\r\n", "\n")),
+        false => Cow::Borrowed(text),
+    };
+}
+
+/// # Description
+/// Returns version from the header of the text, or 1 if there is no `version` header line.
+pub(super) fn file_version(text: &str) -> Result<u32, String> {
+    let header = text.split('\n').take_while(|line| !line.is_empty());
+    return match header.filter_map(|line| line.strip_prefix(VERSION_KEY)?.strip_prefix('=')).next() {
+        Some(value) => parse_version(value),
+        None => Ok(1),
+    };
+}
+
+/// # Description
+/// Parses header value that should contain a positive version.
+pub(super) fn parse_version(value: &str) -> Result<u32, String> {
+    return match value.parse() {
+        Ok(version) if version > 0 => Ok(version),
+        _ => Err(format!("Invalid file format version \"{value}\"")),
+    };
+}
+
+/// # Description
+/// Converts version 1 to version 2, which only adds the `version` header line.
+fn migrate_from_v1(text: &str) -> String {
+    return format!("{VERSION_KEY}=2\n{text}");
+}
+
+/// # Description
+/// Converts version 2 to version 3, which adds optional `bookmark`, `tag` and `meta.{field}`
+/// header lines, and stores the char of every grapheme cluster in its `cluster` header line,
+/// since chars are no longer allocated in order of clusters.
+fn migrate_from_v2(text: &str) -> String {
+    let mut lines = text.split('\n');
+    let mut migrated = Vec::new();
+    let mut index = 0;
+    for line in lines.by_ref() {
+        match line.strip_prefix(CLUSTER_KEY).and_then(|cluster| cluster.strip_prefix('=')) {
+            Some(cluster) => {
+                let value = char::from_u32(FIRST_CLUSTER_CHAR + index).unwrap_or(char::REPLACEMENT_CHARACTER);
+                migrated.push(format!("{CLUSTER_KEY}={cluster}@{value}"));
+                index += 1;
+            },
+            None => migrated.push(line.to_string()),
+        }
+        if line.is_empty() {
+            break;
+        }
+    }
+    migrated.extend(lines.map(String::from));
+
+    return with_version(&migrated.join("\n"), 3);
+}
+
+/// # Description
+/// Replaces value of the `version` header line of the text.
+fn with_version(text: &str, version: u32) -> String {
+    let mut offset = 0;
+    for line in text.split('\n').take_while(|line| !line.is_empty()) {
+        if line.strip_prefix(VERSION_KEY).is_some_and(|value| value.starts_with('=')) {
+            return format!("{}{VERSION_KEY}={version}{}", &text[..offset], &text[offset + line.len()..]);
+        }
+        offset += line.len() + 1;
+    }
+
+    return format!("{VERSION_KEY}={version}\n{text}");
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use crate::tilemap::{Tilemap, FILE_FORMAT_VERSION};
+
+    #[test]
+    fn migrate() {
+        let tilemap = Tilemap::from_string("#.\n.@", '.');
+        let current = tilemap.to_file_string();
+        assert!(matches!(Tilemap::migrate(&current), Ok(Cow::Borrowed(_))));
+
+        let legacy = "empty_tile=.\nsize=2x2\n\n#.\n.@";
+        assert_eq!(Tilemap::migrate(legacy).unwrap(), current);
+        assert_eq!(Tilemap::from_file_string(legacy).unwrap().to_file_string(), current);
+
+        let v2 = "empty_tile=.\nversion=2\nsize=2x2\n\n#.\n.@";
+        assert_eq!(Tilemap::migrate(v2).unwrap(), format!("empty_tile=.\nversion={FILE_FORMAT_VERSION}\nsize=2x2\n\n#.\n.@"));
+        assert_eq!(Tilemap::from_file_string(v2).unwrap().to_file_string(), current);
+
+        let v2 = "version=2\nempty_tile=.\nsize=2x1\ncluster=e\u{301}\ncluster=a@b\n\n\u{E001}\u{E000}";
+        let loaded = Tilemap::from_file_string(v2).unwrap();
+        assert_eq!(loaded.build_clusters(), "a@be\u{301}");
+        assert_eq!(Tilemap::migrate(v2).unwrap(), loaded.to_file_string());
+
+        let crlf = "version=2\r\nempty_tile=.\r\nsize=2x2\r\n\r\n#.\r\n.@";
+        assert_eq!(Tilemap::migrate(crlf).unwrap(), current);
+        assert_eq!(Tilemap::migrate(&current.replace('\n', "\r\n")).unwrap(), current);
+        assert_eq!(Tilemap::from_file_string(crlf).unwrap().to_file_string(), current);
+        assert_eq!(Tilemap::from_file_string(&current.replace('\n', "\r\n")).unwrap().to_file_string(), current);
+
+        let newer = format!("version={}\nempty_tile=.\nsize=1x1\n\n#", FILE_FORMAT_VERSION + 1);
+        for text in [newer.as_str(), "version=0\nempty_tile=.\nsize=1x1\n\n#", "version=x\nempty_tile=.\nsize=1x1"] {
+            match Tilemap::migrate(text) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::tilemap::{Rng, TileChange, Tilemap};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Creates intermediate frames of transition from this [`Tilemap`] into the `target` one by
+    /// progressively applying diff between them in row-major order.
+    ///
+    /// # Arguments
+    /// * `target: &Tilemap` - [`Tilemap`] into which transition is made.
+    /// * `steps: usize` - Number of frames that should be created.
+    ///
+    /// # Return
+    /// [`Vec`] of `steps` frames. The last frame is always equal to the `target`.
+    /// Intermediate frames use [`Tilemap::empty_tile`] of this [`Tilemap`] and are big enough
+    /// to cover both tilemaps.
+    ///
+    /// # Example
+    /// ```rust
+    /// let source = char_tilemap::Tilemap::new('-');
+    /// let mut target = char_tilemap::Tilemap::new('-');
+    /// target.set(char_tilemap::Vector2::new(1, 0), 'O');
+    /// target.set(char_tilemap::Vector2::new(0, 0), 'O');
+    ///
+    /// let frames: Vec<String> = source.morph(&target, 2).iter().map(|frame| frame.build()).collect();
+    /// assert_eq!(frames, vec!["O-", "OO"]);
+    /// ```
+    pub fn morph(&self, target: &Tilemap, steps: usize) -> Vec<Tilemap> {
+        return self.morph_with_changes(target, steps, self.diff(target));
+    }
+
+    /// # Description
+    /// Works the same way as [`Tilemap::morph()`], but applies diff in random order.
+    ///
+    /// # Arguments
+    /// * `target: &Tilemap` - [`Tilemap`] into which transition is made.
+    /// * `steps: usize` - Number of frames that should be created.
+    /// * `rng: &mut Rng` - Generator that is used to shuffle the diff.
+    ///
+    /// # Return
+    /// [`Vec`] of `steps` frames. The last frame is always equal to the `target`.
+    ///
+    /// # Example
+    /// ```rust
+    /// let source = char_tilemap::Tilemap::new('-');
+    /// let mut target = char_tilemap::Tilemap::new('-');
+    /// target.set(char_tilemap::Vector2::new(3, 3), 'O');
+    ///
+    /// let mut rng = char_tilemap::Rng::new(7);
+    /// let frames = source.morph_shuffled(&target, 4, &mut rng);
+    /// assert_eq!(frames.last().unwrap().build(), target.build());
+    /// ```
+    pub fn morph_shuffled(&self, target: &Tilemap, steps: usize, rng: &mut Rng) -> Vec<Tilemap> {
+        let mut changes = self.diff(target);
+        rng.shuffle(&mut changes);
+        return self.morph_with_changes(target, steps, changes);
+    }
+
+    /// # Description
+    /// Creates frames of transition by applying `changes` evenly over `steps` frames.
+    fn morph_with_changes(&self, target: &Tilemap, steps: usize, changes: Vec<TileChange>) -> Vec<Tilemap> {
+        let mut frames = Vec::with_capacity(steps);
+        let mut current = self.clone();
+        let mut applied = 0;
+
+        // Intermediate frames should cover both tilemaps
+        current.size.x = std::cmp::max(self.size.x, target.size.x);
+        current.size.y = std::cmp::max(self.size.y, target.size.y);
+
+        for step in 1..steps {
+            let until = (changes.len() * step).div_ceil(steps);
+            current.apply_changes(&changes[applied..until]);
+            applied = until;
+            frames.push(current.clone());
+        }
+
+        if steps > 0 {
+            frames.push(target.clone());
+        }
+
+        return frames;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Rng, Tilemap, Vector2};
+
+    fn build_test_tilemaps() -> (Tilemap, Tilemap) {
+        let mut source = Tilemap::new('-');
+        let mut target = Tilemap::new('-');
+        for i in 0..4 {
+            source.set(Vector2::new(i, 0), 'A');
+            target.set(Vector2::new(i, 0), 'B');
+        }
+
+        return (source, target);
+    }
+
+    #[test]
+    fn morph() {
+        let (source, target) = build_test_tilemaps();
+
+        let frames: Vec<String> = source.morph(&target, 4).iter().map(|frame| frame.build()).collect();
+        assert_eq!(frames, vec!["BAAA", "BBAA", "BBBA", "BBBB"]);
+
+        let frames: Vec<String> = source.morph(&target, 3).iter().map(|frame| frame.build()).collect();
+        assert_eq!(frames, vec!["BBAA", "BBBA", "BBBB"]);
+
+        assert!(source.morph(&target, 0).is_empty());
+    }
+
+    #[test]
+    fn morph_shuffled() {
+        let (source, target) = build_test_tilemaps();
+        let frames = source.morph_shuffled(&target, 4, &mut Rng::new(3));
+
+        for (index, frame) in frames.iter().enumerate() {
+            assert_eq!(frame.build().matches('B').count(), index + 1);
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+use crate::tilemap::{Tilemap, Vector2};
+use crate::tilemap::pathfinding::neighbors;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Undirected graph of walkable positions of the [`Tilemap`], where horizontal and vertical
+/// neighbors are connected. Nodes are indexed from 0 in row order, so custom graph algorithms
+/// can store their data in plain vectors. Created by [`Tilemap::to_nav_graph()`].
+///
+/// # Example
+/// ```rust
+/// let tilemap = char_tilemap::Tilemap::from_string("..#\n#..", '.');
+/// let graph = tilemap.to_nav_graph(|value| value.is_none());
+///
+/// assert_eq!(graph.node_count(), 4);
+/// assert_eq!(graph.edge_count(), 3);
+/// let center = graph.index_of(char_tilemap::Vector2::new(1, 1)).unwrap();
+/// assert_eq!(graph.neighbors(center).len(), 2);
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct NavGraph {
+    /// # Description
+    /// Positions of all nodes.
+    positions: Vec<Vector2>,
+    /// # Description
+    /// Indices of nodes by their positions.
+    indices: HashMap<Vector2, usize>,
+    /// # Description
+    /// Indices of neighbors of every node.
+    adjacency: Vec<Vec<usize>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl NavGraph {
+    /// # Description
+    /// Returns number of nodes.
+    pub fn node_count(&self) -> usize {
+        return self.positions.len();
+    }
+
+    /// # Description
+    /// Returns number of edges. Every edge is counted once.
+    pub fn edge_count(&self) -> usize {
+        return self.adjacency.iter().map(|neighbors| neighbors.len()).sum::<usize>() / 2;
+    }
+
+    /// # Description
+    /// Returns position of the node.
+    ///
+    /// # Arguments
+    /// * `index: usize` - Index of the node.
+    ///
+    /// # Return
+    /// * [`Some`] with position of the node.
+    /// * [`None`] if there is no node with such index.
+    pub fn position(&self, index: usize) -> Option<Vector2> {
+        return self.positions.get(index).copied();
+    }
+
+    /// # Description
+    /// Returns index of the node at the position.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the node.
+    ///
+    /// # Return
+    /// * [`Some`] with index of the node.
+    /// * [`None`] if position is not walkable or out of bounds.
+    pub fn index_of(&self, position: Vector2) -> Option<usize> {
+        return self.indices.get(&position).copied();
+    }
+
+    /// # Description
+    /// Returns indices of neighbors of the node. Empty for unknown indices.
+    ///
+    /// # Arguments
+    /// * `index: usize` - Index of the node.
+    pub fn neighbors(&self, index: usize) -> &[usize] {
+        return self.adjacency.get(index).map_or(&[], |neighbors| neighbors.as_slice());
+    }
+
+    /// # Description
+    /// Returns iterator over all edges. Every edge is yielded once, as a pair of node indices
+    /// where the first one is smaller.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return self.adjacency.iter().enumerate().flat_map(|(index, neighbors)| {
+            return neighbors.iter().filter(move |neighbor| **neighbor > index).map(move |neighbor| (index, *neighbor));
+        });
+    }
+
+    /// # Description
+    /// Splits nodes into connected components.
+    ///
+    /// # Return
+    /// [`Vec`] with index of the component of every node. Components are numbered from 0 in
+    /// order of their first nodes.
+    pub fn connected_components(&self) -> Vec<usize> {
+        let mut components = vec![usize::MAX; self.node_count()];
+        let mut count = 0;
+
+        for start in 0..self.node_count() {
+            if components[start] != usize::MAX {
+                continue;
+            }
+
+            components[start] = count;
+            let mut stack = vec![start];
+            while let Some(index) = stack.pop() {
+                for neighbor in self.adjacency[index].iter() {
+                    if components[*neighbor] == usize::MAX {
+                        components[*neighbor] = count;
+                        stack.push(*neighbor);
+                    }
+                }
+            }
+            count += 1;
+        }
+
+        return components;
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Creates [`NavGraph`] of all walkable positions inside of the [`Tilemap`] bounds.
+    ///
+    /// # Arguments
+    /// * `is_walkable: F` - Returns `true` if the position with the specified tile value (or
+    ///   [`None`] for positions without tiles) can be walked through.
+    ///
+    /// # Return
+    /// New [`NavGraph`].
+    pub fn to_nav_graph<F>(&self, is_walkable: F) -> NavGraph
+        where F: Fn(Option<char>) -> bool {
+        let mut positions = Vec::new();
+        let mut indices = HashMap::new();
+        for y in 0..self.size.y {
+            for x in 0..self.size.x {
+                let position = Vector2::new(x, y);
+                if is_walkable(self.get(position)) {
+                    indices.insert(position, positions.len());
+                    positions.push(position);
+                }
+            }
+        }
+
+        let adjacency = positions.iter().map(|position| {
+            return neighbors(*position).filter_map(|neighbor| indices.get(&neighbor).copied()).collect();
+        }).collect();
+
+        return NavGraph { positions, indices, adjacency };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[test]
+    fn to_nav_graph() {
+        let tilemap = Tilemap::from_string("..#.\n.##.\n....", '.');
+        let graph = tilemap.to_nav_graph(|value| value.is_none());
+
+        assert_eq!(graph.node_count(), 9);
+        assert_eq!(graph.edge_count(), 8);
+        assert_eq!(graph.edges().count(), 8);
+        assert!(graph.edges().all(|(first, second)| first < second));
+        assert_eq!(graph.position(0), Some(Vector2::ZERO));
+        assert_eq!(graph.position(9), None);
+        assert_eq!(graph.index_of(Vector2::new(2, 0)), None);
+        assert_eq!(graph.neighbors(graph.index_of(Vector2::new(3, 2)).unwrap()).len(), 2);
+        assert!(graph.neighbors(100).is_empty());
+    }
+
+    #[test]
+    fn connected_components() {
+        let tilemap = Tilemap::from_string("..#.\n###.\n.#..", '.');
+        let graph = tilemap.to_nav_graph(|value| value.is_none());
+
+        let components = graph.connected_components();
+        assert_eq!(components, vec![0, 0, 1, 1, 2, 1, 1]);
+        assert!(Tilemap::new('.').to_nav_graph(|_| true).connected_components().is_empty());
+    }
+}
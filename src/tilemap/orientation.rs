@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+use crate::tilemap::{Tile, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// One of the 8 ways to rotate and mirror a rectangle, which is enough to enumerate all
+/// orientations of a prefab without chaining rotations and flips.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Orientation {
+    /// # Description
+    /// Orientation is not changed.
+    Identity,
+    /// # Description
+    /// Rotation by 90 degrees clockwise.
+    Rotate90,
+    /// # Description
+    /// Rotation by 180 degrees.
+    Rotate180,
+    /// # Description
+    /// Rotation by 270 degrees clockwise.
+    Rotate270,
+    /// # Description
+    /// Mirrors columns, so the left column becomes the right one.
+    FlipHorizontal,
+    /// # Description
+    /// Mirrors rows, so the top row becomes the bottom one.
+    FlipVertical,
+    /// # Description
+    /// Mirrors across the diagonal from the top left corner, so rows become columns.
+    Transpose,
+    /// # Description
+    /// Mirrors across the diagonal from the top right corner.
+    AntiTranspose,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Orientation {
+    /// # Description
+    /// All orientations, starting with [`Orientation::Identity`].
+    pub const ALL: [Orientation; 8] = [
+        Orientation::Identity, Orientation::Rotate90, Orientation::Rotate180, Orientation::Rotate270,
+        Orientation::FlipHorizontal, Orientation::FlipVertical, Orientation::Transpose, Orientation::AntiTranspose,
+    ];
+
+    /// # Description
+    /// Returns size of the rectangle after it is oriented.
+    ///
+    /// # Arguments
+    /// * `size: Vector2` - Size of the rectangle.
+    pub fn size(self, size: Vector2) -> Vector2 {
+        return match self {
+            Orientation::Identity | Orientation::Rotate180 | Orientation::FlipHorizontal | Orientation::FlipVertical => size,
+            _ => Vector2::new(size.y, size.x),
+        };
+    }
+
+    /// # Description
+    /// Returns position that the specified position of the rectangle takes after it is oriented.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position inside of the rectangle.
+    /// * `size: Vector2` - Size of the rectangle.
+    ///
+    /// # Example
+    /// ```rust
+    /// let size = char_tilemap::Vector2::new(3, 2);
+    /// let position = char_tilemap::Orientation::Rotate90.position(char_tilemap::Vector2::ZERO, size);
+    /// assert_eq!(position, char_tilemap::Vector2::new(1, 0));
+    /// ```
+    pub fn position(self, position: Vector2, size: Vector2) -> Vector2 {
+        let Vector2 { x, y } = position;
+        return match self {
+            Orientation::Identity => position,
+            Orientation::Rotate90 => Vector2::new(size.y - 1 - y, x),
+            Orientation::Rotate180 => Vector2::new(size.x - 1 - x, size.y - 1 - y),
+            Orientation::Rotate270 => Vector2::new(y, size.x - 1 - x),
+            Orientation::FlipHorizontal => Vector2::new(size.x - 1 - x, y),
+            Orientation::FlipVertical => Vector2::new(x, size.y - 1 - y),
+            Orientation::Transpose => Vector2::new(y, x),
+            Orientation::AntiTranspose => Vector2::new(size.y - 1 - y, size.x - 1 - x),
+        };
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Creates copy of the [`Tilemap`] in the specified orientation. Colors, annotations, blinks,
+    /// groups, bookmarks and tags are moved together with their tiles.
+    ///
+    /// # Arguments
+    /// * `orientation: Orientation` - Orientation of the copy.
+    ///
+    /// # Return
+    /// New instance of the [`Tilemap`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let prefab = char_tilemap::Tilemap::from_string("#.\n..", '.');
+    /// let corners: Vec<String> = char_tilemap::Orientation::ALL.iter()
+    ///     .map(|orientation| prefab.oriented(*orientation).build())
+    ///     .collect();
+    /// assert!(corners.contains(&String::from(".#\n..")));
+    /// ```
+    pub fn oriented(&self, orientation: Orientation) -> Tilemap {
+        let size = self.size;
+        let mut tilemap = self.clone();
+        tilemap.size = orientation.size(size);
+        let tiles = sorted_vec::SortedSet::from_unsorted(self.tiles.iter()
+            .map(|tile| Tile { position: orientation.position(tile.position, size), value: tile.value })
+            .collect());
+        let old_tiles = std::mem::replace(&mut tilemap.tiles, tiles);
+        tilemap.record_replaced(&old_tiles);
+        tilemap.colors = remap(&self.colors, orientation, size);
+        tilemap.annotations = remap(&self.annotations, orientation, size);
+        tilemap.blinks = remap(&self.blinks, orientation, size);
+        tilemap.groups = self.groups.iter()
+            .map(|(name, group)| (name.clone(), group.map(|position| orientation.position(position, size))))
+            .collect();
+        tilemap.bookmarks = self.bookmarks.iter()
+            .map(|(name, position)| (name.clone(), orientation.position(*position, size)))
+            .collect();
+        tilemap.tags = self.tags.iter()
+            .map(|(name, positions)| (name.clone(), positions.iter().map(|position| orientation.position(*position, size)).collect()))
+            .collect();
+        return tilemap;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Moves values of the map to their oriented positions.
+fn remap<T: Clone>(map: &BTreeMap<Vector2, T>, orientation: Orientation, size: Vector2) -> BTreeMap<Vector2, T> {
+    return map.iter().map(|(position, value)| (orientation.position(*position, size), value.clone())).collect();
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use crate::tilemap::{Color, FlipAxis, Flipped, Orientation, Rotated, Rotation, Tilemap, TileSource, Vector2};
+
+    #[test]
+    fn oriented() {
+        let mut tilemap = Tilemap::from_string("ab.\n..c", '.');
+        tilemap.set_tile_color(Vector2::ZERO, Some(Color::Red));
+        tilemap.add_group("ab", [Vector2::ZERO, Vector2::new(1, 0)]).unwrap();
+
+        let built: Vec<String> = Orientation::ALL.iter().map(|orientation| tilemap.oriented(*orientation).build()).collect();
+        assert_eq!(built, vec!["ab.\n..c", ".a\n.b\nc.", "c..\n.ba", ".c\nb.\na.",
+                               ".ba\nc..", "..c\nab.", "a.\nb.\n.c", "c.\n.b\n.a"]);
+        assert_eq!(built.iter().collect::<HashSet<_>>().len(), 8);
+
+        assert_eq!(tilemap.oriented(Orientation::Rotate90).build(), Rotated::new(&tilemap, Rotation::Clockwise90).build());
+        assert_eq!(tilemap.oriented(Orientation::Rotate270).build(), Rotated::new(&tilemap, Rotation::Clockwise270).build());
+        assert_eq!(tilemap.oriented(Orientation::FlipVertical).build(), Flipped::new(&tilemap, FlipAxis::Vertical).build());
+        assert_eq!(tilemap.oriented(Orientation::Rotate180).tile_color(Vector2::new(2, 1)), Some(Color::Red));
+        assert_eq!(tilemap.oriented(Orientation::Transpose).size(), Vector2::new(2, 3));
+        assert!(tilemap.oriented(Orientation::Rotate180).group("ab").unwrap().contains(Vector2::new(1, 1)));
+    }
+}
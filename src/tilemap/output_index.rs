@@ -0,0 +1,114 @@
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Returns position of the tile that is rendered at the specified char index of the string
+    /// returned by [`Tilemap::build()`]. Row separators and [`Tilemap::cell_width`] are taken
+    /// into account, so any char of a wide cell maps to the same tile.
+    ///
+    /// # Arguments
+    /// * `index: usize` - Index of the char (not byte) in the built string.
+    ///
+    /// # Return
+    /// * [`Some`] with the position of the tile.
+    /// * [`None`] if index points to a row separator or is out of the built string.
+    ///
+    /// # Notes
+    /// Grapheme clusters that are longer than [`Tilemap::cell_width`] are not taken into account.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("#..\n.@.", '.');
+    /// assert_eq!(tilemap.position_of_output_index(5), Some(char_tilemap::Vector2::new(1, 1)));
+    /// assert_eq!(tilemap.position_of_output_index(3), None);
+    /// ```
+    pub fn position_of_output_index(&self, index: usize) -> Option<Vector2> {
+        let stride = self.output_row_stride();
+        let y = index / stride;
+        let column = index % stride;
+        if y >= self.size.y || column >= self.size.x * self.output_cell_width() {
+            return None;
+        }
+
+        return Some(Vector2::new(column / self.output_cell_width(), y));
+    }
+
+    /// # Description
+    /// Returns char index of the first char of the tile at the specified position in the string
+    /// returned by [`Tilemap::build()`]. Reverse of [`Tilemap::position_of_output_index()`].
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with the index of the char (not byte) in the built string.
+    /// * [`None`] if position is out of bounds.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("#..\n.@.", '.');
+    /// assert_eq!(tilemap.output_index_of(char_tilemap::Vector2::new(1, 1)), Some(5));
+    ///
+    /// tilemap.cell_width = 2;
+    /// assert_eq!(tilemap.output_index_of(char_tilemap::Vector2::new(1, 1)), Some(9));
+    /// ```
+    pub fn output_index_of(&self, position: Vector2) -> Option<usize> {
+        if position.x >= self.size.x || position.y >= self.size.y {
+            return None;
+        }
+
+        return Some(position.y * self.output_row_stride() + position.x * self.output_cell_width());
+    }
+
+    /// # Description
+    /// Returns number of chars that a single tile takes in the built string.
+    fn output_cell_width(&self) -> usize {
+        return self.cell_width.max(1);
+    }
+
+    /// # Description
+    /// Returns number of chars between starts of two neighbouring rows in the built string,
+    /// including the row separator.
+    fn output_row_stride(&self) -> usize {
+        return self.size.x * self.output_cell_width() + 1;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[test]
+    fn round_trip() {
+        let mut tilemap = Tilemap::from_string("#..\n.@.\n..X", '.');
+
+        for cell_width in [1, 3] {
+            tilemap.cell_width = cell_width;
+            let built: Vec<char> = tilemap.build().chars().collect();
+
+            for (index, value) in built.iter().enumerate() {
+                match tilemap.position_of_output_index(index) {
+                    Some(position) => {
+                        let start = tilemap.output_index_of(position).unwrap();
+                        assert!((start..start + cell_width).contains(&index));
+                        let expected = tilemap.get(position).unwrap_or('.');
+                        assert_eq!(built[start + (cell_width - 1) / 2], expected);
+                    },
+                    None => assert_eq!(*value, '\n'),
+                }
+            }
+
+            assert_eq!(tilemap.position_of_output_index(built.len()), None);
+            assert_eq!(tilemap.output_index_of(Vector2::new(3, 0)), None);
+            assert_eq!(tilemap.output_index_of(Vector2::new(0, 3)), None);
+        }
+    }
+}
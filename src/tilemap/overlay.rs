@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+use crate::tilemap::{Color, TileSource, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// [`TileSource`] adapter that composites transient elements, such as text labels, paths and
+/// highlighted regions, over its source. Elements are drawn only when the overlay is rendered,
+/// the source is never modified. Elements added later are drawn over the earlier ones, and parts
+/// of elements that are out of the source bounds are clipped.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::TileSource;
+///
+/// let tilemap = char_tilemap::Tilemap::from_string("#####\n#...#\n#####", '.');
+///
+/// let mut overlay = char_tilemap::Overlay::new(&tilemap);
+/// overlay.add_label(char_tilemap::Vector2::new(1, 1), "hi");
+/// assert_eq!(overlay.build(), "#####\n#hi.#\n#####");
+/// assert_eq!(tilemap.build(), "#####\n#...#\n#####");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Overlay<S: TileSource> {
+    /// # Description
+    /// Source over which elements are drawn.
+    source: S,
+    /// # Description
+    /// Values of all drawn elements by their positions.
+    tiles: HashMap<Vector2, char>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl<S: TileSource> Overlay<S> {
+    /// # Description
+    /// Creates new [`Overlay`] without any elements.
+    ///
+    /// # Arguments
+    /// * `source: S` - Source over which elements will be drawn.
+    ///
+    /// # Return
+    /// Newly created [`Overlay`] adapter.
+    pub fn new(source: S) -> Overlay<S> {
+        return Overlay { source, tiles: HashMap::new() };
+    }
+
+    /// # Description
+    /// Adds single tile.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    /// * `value: char` - Value of the tile.
+    pub fn add_tile(&mut self, position: Vector2, value: char) {
+        self.tiles.insert(position, value);
+    }
+
+    /// # Description
+    /// Adds text label that is written from left to right starting at the specified position.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the first char of the label.
+    /// * `text: &str` - Text of the label. New lines are not handled, so it should be a single line.
+    pub fn add_label(&mut self, position: Vector2, text: &str) {
+        for (index, value) in text.chars().enumerate() {
+            self.tiles.insert(Vector2::new(position.x + index, position.y), value);
+        }
+    }
+
+    /// # Description
+    /// Adds polyline that connects all specified points in order with straight lines.
+    ///
+    /// # Arguments
+    /// * `points: &[Vector2]` - Points of the polyline. Single point is drawn as is.
+    /// * `value: char` - Value with which polyline is drawn.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::{TileSource, Vector2};
+    ///
+    /// let tilemap = char_tilemap::Tilemap::from_string("...\n...\n...", '.');
+    ///
+    /// let mut overlay = char_tilemap::Overlay::new(&tilemap);
+    /// overlay.add_path(&[Vector2::ZERO, Vector2::new(2, 0), Vector2::new(2, 2)], '*');
+    /// assert_eq!(overlay.build(), "***\n..*\n..*");
+    /// ```
+    pub fn add_path(&mut self, points: &[Vector2], value: char) {
+        if let Some(first) = points.first() {
+            self.tiles.insert(*first, value);
+        }
+
+        for segment in points.windows(2) {
+            for position in line(segment[0], segment[1]) {
+                self.tiles.insert(position, value);
+            }
+        }
+    }
+
+    /// # Description
+    /// Adds outline of the rectangular region.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Top left corner of the region.
+    /// * `size: Vector2` - Size of the region. Nothing is drawn if any of the components is zero.
+    /// * `value: char` - Value with which outline is drawn.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::{TileSource, Vector2};
+    ///
+    /// let tilemap = char_tilemap::Tilemap::from_string("....\n....\n....", '.');
+    ///
+    /// let mut overlay = char_tilemap::Overlay::new(&tilemap);
+    /// overlay.add_region(Vector2::new(1, 0), Vector2::new(3, 3), '+');
+    /// assert_eq!(overlay.build(), ".+++\n.+.+\n.+++");
+    /// ```
+    pub fn add_region(&mut self, position: Vector2, size: Vector2, value: char) {
+        if size.x == 0 || size.y == 0 {
+            return;
+        }
+
+        let end = position + size - Vector2::ONE;
+        self.add_path(&[position, Vector2::new(end.x, position.y), end, Vector2::new(position.x, end.y), position], value);
+    }
+
+    /// # Description
+    /// Removes all elements, so only the source is rendered.
+    pub fn clear(&mut self) {
+        self.tiles.clear();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl<S: TileSource> TileSource for Overlay<S> {
+    /// # Description
+    /// Returns size of the source.
+    fn size(&self) -> Vector2 {
+        return self.source.size();
+    }
+
+    /// # Description
+    /// Returns value of the element at the specified position, or value of the source tile if
+    /// there is no element.
+    fn get(&self, position: Vector2) -> Option<char> {
+        let size = self.source.size();
+        if position.x >= size.x || position.y >= size.y {
+            return None;
+        }
+
+        return self.tiles.get(&position).copied().or_else(|| self.source.get(position));
+    }
+
+    /// # Description
+    /// Returns empty tile of the source.
+    fn empty_tile(&self) -> char {
+        return self.source.empty_tile();
+    }
+
+    /// # Description
+    /// Returns color of the source tile if there is no element at the specified position.
+    fn color(&self, position: Vector2) -> Option<Color> {
+        if self.tiles.contains_key(&position) {
+            return None;
+        }
+
+        return self.source.color(position);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Returns positions of the straight line between two points using Bresenham's algorithm,
+/// including both points.
+pub(crate) fn line(from: Vector2, to: Vector2) -> Vec<Vector2> {
+    let (mut x, mut y) = (from.x as i64, from.y as i64);
+    let (to_x, to_y) = (to.x as i64, to.y as i64);
+    let (dx, dy) = ((to_x - x).abs(), -(to_y - y).abs());
+    let (step_x, step_y) = ((to_x - x).signum(), (to_y - y).signum());
+    let mut error = dx + dy;
+    let mut positions = Vec::new();
+
+    loop {
+        positions.push(Vector2::new(x as usize, y as usize));
+        if x == to_x && y == to_y {
+            return positions;
+        }
+
+        let doubled = 2 * error;
+        if doubled >= dy {
+            error += dy;
+            x += step_x;
+        }
+        if doubled <= dx {
+            error += dx;
+            y += step_y;
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Overlay, Tilemap, TileSource, Vector2};
+
+    #[test]
+    fn elements() {
+        let tilemap = Tilemap::from_string("#....\n.....\n.....\n.....", '.');
+        let mut overlay = Overlay::new(&tilemap);
+
+        overlay.add_path(&[Vector2::ZERO, Vector2::new(3, 3)], '\\');
+        assert_eq!(overlay.build(), "\\....\n.\\...\n..\\..\n...\\.");
+
+        overlay.add_region(Vector2::new(2, 1), Vector2::new(3, 2), '+');
+        overlay.add_label(Vector2::new(3, 3), "long label");
+        assert_eq!(overlay.build(), "\\....\n.\\+++\n..+++\n...lo");
+        assert_eq!(overlay.get(Vector2::new(5, 3)), None);
+
+        overlay.clear();
+        assert_eq!(overlay.build(), tilemap.build());
+    }
+
+    #[test]
+    fn empty_elements() {
+        let tilemap = Tilemap::from_string("...", '.');
+        let mut overlay = Overlay::new(&tilemap);
+
+        overlay.add_path(&[], '*');
+        overlay.add_region(Vector2::ZERO, Vector2::new(0, 1), '*');
+        overlay.add_path(&[Vector2::new(1, 0)], '*');
+        assert_eq!(overlay.build(), ".*.");
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::BTreeMap;
+use crate::tilemap::{Overlay, Tilemap, TileSource, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Connection of the path position to its upper neighbor.
+const UP: u8 = 0b0001;
+
+/// # Description
+/// Connection of the path position to its right neighbor.
+const RIGHT: u8 = 0b0010;
+
+/// # Description
+/// Connection of the path position to its lower neighbor.
+const DOWN: u8 = 0b0100;
+
+/// # Description
+/// Connection of the path position to its left neighbor.
+const LEFT: u8 = 0b1000;
+
+/// # Description
+/// Glyphs that are used by [`Tilemap::draw_path()`] and [`Overlay::add_path_glyphs()`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum PathChars {
+    /// # Description
+    /// Every position of the path is drawn with the same char.
+    Single(char),
+    /// # Description
+    /// Direction-aware box drawing chars, such as `─`, `│`, `┌` and `┼`.
+    Lines,
+    /// # Description
+    /// Direction-aware ASCII chars: `-` and `|` for straight parts, `+` for turns and crossings.
+    Ascii,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl PathChars {
+    /// # Description
+    /// Returns glyph of the position by its connections to the neighbors.
+    fn glyph(&self, connections: u8) -> char {
+        let horizontal = connections & !(LEFT | RIGHT) == 0;
+        let vertical = connections & !(UP | DOWN) == 0;
+
+        return match self {
+            PathChars::Single(value) => *value,
+            PathChars::Ascii if connections == 0 => 'o',
+            PathChars::Ascii if horizontal => '-',
+            PathChars::Ascii if vertical => '|',
+            PathChars::Ascii => '+',
+            PathChars::Lines => match connections {
+                0 => '•',
+                _ if horizontal => '─',
+                _ if vertical => '│',
+                0b0011 => '└',
+                0b0110 => '┌',
+                0b1100 => '┐',
+                0b1001 => '┘',
+                0b0111 => '├',
+                0b1110 => '┬',
+                0b1101 => '┤',
+                0b1011 => '┴',
+                _ => '┼',
+            },
+        };
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Draws the path, such as the result of [`Tilemap::find_path()`], into the [`Tilemap`].
+    /// Direction-aware glyphs are chosen by the neighbors of every position along the path, so
+    /// positions that are visited several times are drawn as crossings.
+    ///
+    /// # Arguments
+    /// * `path: &[Vector2]` - Positions of the path. Consecutive positions that are not
+    ///   horizontal or vertical neighbors are not connected.
+    /// * `chars: PathChars` - Glyphs of the path.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::Vector2;
+    ///
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("...\n...", '.');
+    /// let path = [Vector2::ZERO, Vector2::new(1, 0), Vector2::new(1, 1), Vector2::new(2, 1)];
+    /// tilemap.draw_path(&path, char_tilemap::PathChars::Lines);
+    /// assert_eq!(tilemap.build(), "─┐.\n.└─");
+    /// ```
+    pub fn draw_path(&mut self, path: &[Vector2], chars: PathChars) {
+        for (position, value) in path_glyphs(path, chars) {
+            self.set(position, value);
+        }
+    }
+}
+
+impl<S: TileSource> Overlay<S> {
+    /// # Description
+    /// Adds the path drawn with direction-aware glyphs. Works the same way as
+    /// [`Tilemap::draw_path()`], but the source is not modified.
+    ///
+    /// # Arguments
+    /// * `path: &[Vector2]` - Positions of the path.
+    /// * `chars: PathChars` - Glyphs of the path.
+    pub fn add_path_glyphs(&mut self, path: &[Vector2], chars: PathChars) {
+        for (position, value) in path_glyphs(path, chars) {
+            self.add_label(position, &value.to_string());
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Returns glyph of every position of the path.
+fn path_glyphs(path: &[Vector2], chars: PathChars) -> BTreeMap<Vector2, char> {
+    let mut connections: BTreeMap<Vector2, u8> = path.iter().map(|position| (*position, 0)).collect();
+
+    for pair in path.windows(2) {
+        let (from, to) = (pair[0], pair[1]);
+        let direction = match (to.x as i64 - from.x as i64, to.y as i64 - from.y as i64) {
+            (0, -1) => UP,
+            (1, 0) => RIGHT,
+            (0, 1) => DOWN,
+            (-1, 0) => LEFT,
+            _ => continue,
+        };
+        let opposite = (direction << 2 | direction >> 2) & 0b1111;
+
+        *connections.entry(from).or_default() |= direction;
+        *connections.entry(to).or_default() |= opposite;
+    }
+
+    return connections.into_iter().map(|(position, connections)| (position, chars.glyph(connections))).collect();
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Overlay, PathChars, Tilemap, TileSource, Vector2};
+
+    fn build_test_path() -> Vec<Vector2> {
+        return vec![Vector2::new(0, 1), Vector2::new(1, 1), Vector2::new(2, 1), Vector2::new(2, 2),
+                    Vector2::new(1, 2), Vector2::new(1, 1), Vector2::new(1, 0)];
+    }
+
+    #[test]
+    fn draw_path() {
+        for (chars, expected) in [(PathChars::Lines, ".│.\n─┼┐\n.└┘"),
+                                  (PathChars::Ascii, ".|.\n-++\n.++"),
+                                  (PathChars::Single('*'), ".*.\n***\n.**")] {
+            let mut tilemap = Tilemap::from_string("...\n...\n...", '.');
+            tilemap.draw_path(&build_test_path(), chars);
+            assert_eq!(tilemap.build(), expected);
+        }
+    }
+
+    #[test]
+    fn draw_separate_points() {
+        let mut tilemap = Tilemap::from_string("....", '.');
+        tilemap.draw_path(&[Vector2::ZERO, Vector2::new(2, 0)], PathChars::Lines);
+        assert_eq!(tilemap.build(), "•.•.");
+
+        let mut tilemap = Tilemap::new('.');
+        tilemap.draw_path(&[], PathChars::Ascii);
+        assert_eq!(tilemap.build(), "");
+    }
+
+    #[test]
+    fn overlay_path() {
+        let tilemap = Tilemap::from_string("...\n...\n...", '.');
+        let mut overlay = Overlay::new(&tilemap);
+        overlay.add_path_glyphs(&build_test_path(), PathChars::Lines);
+        assert_eq!(overlay.build(), ".│.\n─┼┐\n.└┘");
+        assert_eq!(tilemap.build(), "...\n...\n...");
+    }
+}
@@ -0,0 +1,327 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Finds the shortest path between two positions inside of the [`Tilemap`] bounds. Path moves
+    /// only horizontally or vertically, one tile per step.
+    ///
+    /// # Arguments
+    /// * `from: Vector2` - Start of the path.
+    /// * `to: Vector2` - End of the path.
+    /// * `is_walkable: F` - Returns `true` if the position with the specified tile value (or
+    ///   [`None`] for positions without tiles) can be walked through.
+    ///
+    /// # Return
+    /// * [`Some`] with all positions of the path, including `from` and `to`.
+    /// * [`None`] if `from` or `to` is out of bounds or not walkable, or there is no path between
+    ///   them.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("..#\n.##\n...", '.');
+    /// let path = tilemap.find_path(char_tilemap::Vector2::ZERO, char_tilemap::Vector2::new(2, 2),
+    ///                              |value| value.is_none()).unwrap();
+    /// assert_eq!(path.len(), 5);
+    /// ```
+    pub fn find_path<F>(&self, from: Vector2, to: Vector2, is_walkable: F) -> Option<Vec<Vector2>>
+        where F: Fn(Option<char>) -> bool {
+        let walkable = |position: Vector2| {
+            return position.x < self.size.x && position.y < self.size.y && is_walkable(self.get(position));
+        };
+
+        if !walkable(from) || !walkable(to) {
+            return None;
+        }
+
+        let mut previous = HashMap::from([(from, from)]);
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(position) = queue.pop_front() {
+            if position == to {
+                let mut path = vec![to];
+                let mut current = to;
+                while current != from {
+                    current = previous[&current];
+                    path.push(current);
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            for neighbor in neighbors(position) {
+                if walkable(neighbor) && !previous.contains_key(&neighbor) {
+                    previous.insert(neighbor, position);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        return None;
+    }
+
+    /// # Description
+    /// Finds the shortest path between two positions using jump point search. Result has the same
+    /// length as the one of [`Tilemap::find_path()`], but on large open maps it is found much
+    /// faster, since straight corridors and open areas are skipped without visiting every tile.
+    ///
+    /// # Arguments
+    /// * `from: Vector2` - Start of the path.
+    /// * `to: Vector2` - End of the path.
+    /// * `is_walkable: F` - Returns `true` if the position with the specified tile value (or
+    ///   [`None`] for positions without tiles) can be walked through.
+    ///
+    /// # Return
+    /// * [`Some`] with all positions of the path, including `from` and `to`.
+    /// * [`None`] if `from` or `to` is out of bounds or not walkable, or there is no path between
+    ///   them.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("..#\n.##\n...", '.');
+    /// let path = tilemap.find_path_jps(char_tilemap::Vector2::ZERO, char_tilemap::Vector2::new(2, 2),
+    ///                                  |value| value.is_none()).unwrap();
+    /// assert_eq!(path.len(), 5);
+    /// ```
+    pub fn find_path_jps<F>(&self, from: Vector2, to: Vector2, is_walkable: F) -> Option<Vec<Vector2>>
+        where F: Fn(Option<char>) -> bool {
+        let search = JumpPointSearch {
+            size: (self.size.x as i64, self.size.y as i64),
+            goal: (to.x as i64, to.y as i64),
+            is_walkable: |x: usize, y: usize| is_walkable(self.get(Vector2::new(x, y))),
+        };
+        let start = (from.x as i64, from.y as i64);
+        if !search.walkable(start) || !search.walkable(search.goal) {
+            return None;
+        }
+
+        let mut parents: HashMap<(i64, i64), (i64, i64)> = HashMap::new();
+        let mut costs = HashMap::from([(start, 0)]);
+        let mut closed = HashSet::new();
+        let mut open = BinaryHeap::from([Reverse((search.heuristic(start), 0, start))]);
+
+        while let Some(Reverse((_, cost, point))) = open.pop() {
+            if point == search.goal {
+                return Some(expand_jumps(point, &parents));
+            }
+            if !closed.insert(point) {
+                continue;
+            }
+
+            let direction = parents.get(&point).map(|parent| ((point.0 - parent.0).signum(), (point.1 - parent.1).signum()));
+            for direction in search.directions(point, direction) {
+                let Some(jump) = search.jump(point, direction) else {
+                    continue;
+                };
+
+                let new_cost = cost + (jump.0 - point.0).abs() + (jump.1 - point.1).abs();
+                if costs.get(&jump).is_none_or(|known| new_cost < *known) {
+                    costs.insert(jump, new_cost);
+                    parents.insert(jump, point);
+                    open.push(Reverse((new_cost + search.heuristic(jump), new_cost, jump)));
+                }
+            }
+        }
+
+        return None;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Jump point search
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// State of the jump point search over 4-connected grid. Vertical moves are preferred: jumps
+/// along Y axis check horizontal jumps at every step, while jumps along X axis stop only where
+/// an obstacle ends and a vertical move becomes possible.
+struct JumpPointSearch<F: Fn(usize, usize) -> bool> {
+    /// # Description
+    /// Size of the grid.
+    size: (i64, i64),
+    /// # Description
+    /// Position to which path is searched.
+    goal: (i64, i64),
+    /// # Description
+    /// Returns `true` if position inside of the grid can be walked through.
+    is_walkable: F,
+}
+
+impl<F: Fn(usize, usize) -> bool> JumpPointSearch<F> {
+    /// # Description
+    /// Checks that position is inside of the grid and walkable.
+    fn walkable(&self, (x, y): (i64, i64)) -> bool {
+        return x >= 0 && y >= 0 && x < self.size.0 && y < self.size.1 && (self.is_walkable)(x as usize, y as usize);
+    }
+
+    /// # Description
+    /// Returns Manhattan distance from the position to the goal.
+    fn heuristic(&self, (x, y): (i64, i64)) -> i64 {
+        return (self.goal.0 - x).abs() + (self.goal.1 - y).abs();
+    }
+
+    /// # Description
+    /// Returns directions in which jumps should be made from the jump point, depending on the
+    /// direction in which it was reached.
+    fn directions(&self, (x, y): (i64, i64), direction: Option<(i64, i64)>) -> Vec<(i64, i64)> {
+        return match direction {
+            None => vec![(0, -1), (1, 0), (0, 1), (-1, 0)],
+            Some((0, dy)) => vec![(0, dy), (1, 0), (-1, 0)],
+            Some((dx, _)) => {
+                let mut directions = vec![(dx, 0)];
+                for dy in [-1, 1] {
+                    if self.walkable((x, y + dy)) && !self.walkable((x - dx, y + dy)) {
+                        directions.push((0, dy));
+                    }
+                }
+                directions
+            },
+        };
+    }
+
+    /// # Description
+    /// Moves from the position in the direction until the next jump point is found.
+    ///
+    /// # Return
+    /// * [`Some`] with the jump point.
+    /// * [`None`] if an obstacle or edge of the grid was reached first.
+    fn jump(&self, (mut x, mut y): (i64, i64), (dx, dy): (i64, i64)) -> Option<(i64, i64)> {
+        loop {
+            (x, y) = (x + dx, y + dy);
+            if !self.walkable((x, y)) {
+                return None;
+            }
+            if (x, y) == self.goal {
+                return Some((x, y));
+            }
+
+            if dx != 0 {
+                for side in [-1, 1] {
+                    if self.walkable((x, y + side)) && !self.walkable((x - dx, y + side)) {
+                        return Some((x, y));
+                    }
+                }
+            }
+            else if self.jump((x, y), (1, 0)).is_some() || self.jump((x, y), (-1, 0)).is_some() {
+                return Some((x, y));
+            }
+        }
+    }
+}
+
+/// # Description
+/// Restores full path to the jump point by filling straight lines between jump points.
+fn expand_jumps(end: (i64, i64), parents: &HashMap<(i64, i64), (i64, i64)>) -> Vec<Vector2> {
+    let mut path = vec![Vector2::new(end.0 as usize, end.1 as usize)];
+    let mut current = end;
+
+    while let Some(parent) = parents.get(&current) {
+        let step = ((parent.0 - current.0).signum(), (parent.1 - current.1).signum());
+        while current != *parent {
+            current = (current.0 + step.0, current.1 + step.1);
+            path.push(Vector2::new(current.0 as usize, current.1 as usize));
+        }
+    }
+
+    path.reverse();
+    return path;
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Returns horizontal and vertical neighbors of the position that do not underflow.
+pub(super) fn neighbors(position: Vector2) -> impl Iterator<Item = Vector2> {
+    let up = position.y.checked_sub(1).map(|y| Vector2::new(position.x, y));
+    let left = position.x.checked_sub(1).map(|x| Vector2::new(x, position.y));
+    let down = Some(Vector2::new(position.x, position.y + 1));
+    let right = Some(Vector2::new(position.x + 1, position.y));
+    return [up, right, down, left].into_iter().flatten();
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Anchor, OverflowPolicy, ResizePolicy, Rng, Tilemap, Vector2};
+
+    #[test]
+    fn find_path() {
+        let tilemap = Tilemap::from_string(".....\n.###.\n.#...\n.#.##\n...#.", '.');
+
+        let path = tilemap.find_path(Vector2::new(2, 2), Vector2::new(4, 4), |value| value.is_none());
+        assert_eq!(path, None);
+
+        let path = tilemap.find_path(Vector2::new(2, 2), Vector2::new(0, 0), |value| value.is_none()).unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!(path.first(), Some(&Vector2::new(2, 2)));
+        assert_eq!(path.last(), Some(&Vector2::ZERO));
+        for pair in path.windows(2) {
+            assert_eq!(pair[0].x.abs_diff(pair[1].x) + pair[0].y.abs_diff(pair[1].y), 1);
+            assert_eq!(tilemap.get(pair[1]), None);
+        }
+
+        let path = tilemap.find_path(Vector2::ZERO, Vector2::ZERO, |value| value.is_none());
+        assert_eq!(path, Some(vec![Vector2::ZERO]));
+    }
+
+    #[test]
+    fn find_path_jps() {
+        let tilemap = Tilemap::from_string(".....\n.###.\n.#...\n.#.##\n...#.", '.');
+        assert_eq!(tilemap.find_path_jps(Vector2::new(2, 2), Vector2::new(4, 4), |value| value.is_none()), None);
+        assert_eq!(tilemap.find_path_jps(Vector2::ZERO, Vector2::ZERO, |value| value.is_none()), Some(vec![Vector2::ZERO]));
+        assert_eq!(tilemap.find_path_jps(Vector2::ZERO, Vector2::new(9, 0), |_| true), None);
+
+        let path = tilemap.find_path_jps(Vector2::new(2, 2), Vector2::ZERO, |value| value.is_none()).unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!(path.first(), Some(&Vector2::new(2, 2)));
+        for pair in path.windows(2) {
+            assert_eq!(pair[0].x.abs_diff(pair[1].x) + pair[0].y.abs_diff(pair[1].y), 1);
+            assert_eq!(tilemap.get(pair[1]), None);
+        }
+    }
+
+    #[test]
+    fn find_path_jps_same_length() {
+        let mut rng = Rng::new(7);
+        for _ in 0..50 {
+            let mut tilemap = Tilemap::new('.');
+            tilemap.resize(Vector2::new(12, 9), ResizePolicy::new(OverflowPolicy::Error, Anchor::TopLeft)).unwrap();
+            for y in 0..9 {
+                for x in 0..12 {
+                    if rng.chance(0.3) {
+                        tilemap.set(Vector2::new(x, y), '#');
+                    }
+                }
+            }
+
+            let from = Vector2::new(rng.next_below(12), rng.next_below(9));
+            let to = Vector2::new(rng.next_below(12), rng.next_below(9));
+            let expected = tilemap.find_path(from, to, |value| value.is_none()).map(|path| path.len());
+            let path = tilemap.find_path_jps(from, to, |value| value.is_none());
+            assert_eq!(path.as_ref().map(|path| path.len()), expected);
+            if let Some(path) = path {
+                assert_eq!((path.first(), path.last()), (Some(&from), Some(&to)));
+                assert!(path.iter().all(|position| tilemap.get(*position).is_none()));
+            }
+        }
+    }
+
+    #[test]
+    fn find_path_not_walkable() {
+        let tilemap = Tilemap::from_string("..#\n...", '.');
+
+        assert_eq!(tilemap.find_path(Vector2::ZERO, Vector2::new(2, 0), |value| value.is_none()), None);
+        assert_eq!(tilemap.find_path(Vector2::ZERO, Vector2::new(5, 0), |_| true), None);
+        assert_eq!(tilemap.find_path(Vector2::ZERO, Vector2::new(2, 0), |_| true).map(|path| path.len()), Some(3));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::tilemap::{Rng, Tilemap};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Generation pass that changes the map, such as carving caves, eroding walls or placing stairs.
+/// Passes are composed by [`Pipeline`]. Closures with the same arguments are passes as well.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::{Pass, Pipeline, Rng, Tilemap, Vector2};
+///
+/// struct PlaceStairs;
+///
+/// impl Pass for PlaceStairs {
+///     fn apply(&self, tilemap: &mut Tilemap, rng: &mut Rng) {
+///         if let Some(position) = tilemap.random_empty_position(rng) {
+///             tilemap.set(position, '>');
+///         }
+///     }
+/// }
+///
+/// let pipeline = Pipeline::new()
+///     .pass(|tilemap: &mut Tilemap, _: &mut Rng| tilemap.set(Vector2::new(3, 2), '#'))
+///     .pass(PlaceStairs);
+///
+/// let mut tilemap = Tilemap::new('.');
+/// pipeline.run(&mut tilemap, 7);
+/// assert_eq!(tilemap.build().matches('>').count(), 1);
+/// ```
+pub trait Pass {
+    /// # Description
+    /// Applies the pass to the map.
+    ///
+    /// # Arguments
+    /// * `tilemap: &mut Tilemap` - Changed map.
+    /// * `rng: &mut Rng` - Generator of random values that belongs to this pass.
+    fn apply(&self, tilemap: &mut Tilemap, rng: &mut Rng);
+}
+
+/// # Description
+/// Sequence of generation [`Pass`]es that are applied in order. Every pass gets its own [`Rng`]
+/// split from the seed of the run, so adding, removing or changing a pass does not change random
+/// values of passes before it.
+#[derive(Default)]
+pub struct Pipeline {
+    /// # Description
+    /// Passes in order of application.
+    passes: Vec<Box<dyn Pass>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Pipeline {
+    /// # Description
+    /// Creates new [`Pipeline`] without passes.
+    ///
+    /// # Return
+    /// New instance of the [`Pipeline`].
+    pub fn new() -> Pipeline {
+        return Pipeline { passes: Vec::new() };
+    }
+
+    /// # Description
+    /// Adds the pass to the end of the pipeline.
+    pub fn pass<P: Pass + 'static>(mut self, pass: P) -> Pipeline {
+        self.passes.push(Box::new(pass));
+        return self;
+    }
+
+    /// # Description
+    /// Returns number of passes.
+    pub fn len(&self) -> usize {
+        return self.passes.len();
+    }
+
+    /// # Description
+    /// Checks if there are no passes.
+    pub fn is_empty(&self) -> bool {
+        return self.passes.is_empty();
+    }
+
+    /// # Description
+    /// Applies all passes to the map in order.
+    ///
+    /// # Arguments
+    /// * `tilemap: &mut Tilemap` - Changed map.
+    /// * `seed: u64` - Seed from which generators of passes are split. The same seed and passes
+    ///   always produce the same map.
+    pub fn run(&self, tilemap: &mut Tilemap, seed: u64) {
+        self.apply(tilemap, &mut Rng::new(seed));
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl<F> Pass for F
+    where F: Fn(&mut Tilemap, &mut Rng) {
+    fn apply(&self, tilemap: &mut Tilemap, rng: &mut Rng) {
+        self(tilemap, rng);
+    }
+}
+
+impl Pass for Pipeline {
+    /// # Description
+    /// Applies all passes in order, so pipelines can be nested.
+    fn apply(&self, tilemap: &mut Tilemap, rng: &mut Rng) {
+        for pass in self.passes.iter() {
+            pass.apply(tilemap, &mut rng.split());
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Pass, Pipeline, Rng, Tilemap, Vector2};
+
+    /// # Description
+    /// Pass that sets random tiles of the row.
+    struct Scatter(char, usize);
+
+    impl Pass for Scatter {
+        fn apply(&self, tilemap: &mut Tilemap, rng: &mut Rng) {
+            for _ in 0..3 {
+                tilemap.set(Vector2::new(rng.next_below(16), self.1), self.0);
+            }
+        }
+    }
+
+    #[test]
+    fn run() {
+        let pipeline = Pipeline::new().pass(Scatter('a', 0)).pass(Scatter('b', 1));
+        assert_eq!(pipeline.len(), 2);
+        assert!(Pipeline::default().is_empty());
+
+        let mut first = Tilemap::new('.');
+        pipeline.run(&mut first, 7);
+        let mut second = Tilemap::new('.');
+        pipeline.run(&mut second, 7);
+        assert_eq!(first.build(), second.build());
+
+        let mut changed = Tilemap::new('.');
+        Pipeline::new().pass(Scatter('a', 0)).pass(Scatter('c', 1)).pass(Scatter('d', 2)).run(&mut changed, 7);
+        assert_eq!(changed.positions_of('a'), first.positions_of('a'));
+        assert_eq!(changed.positions_of('c'), first.positions_of('b'));
+
+        let mut reseeded = Tilemap::new('.');
+        pipeline.run(&mut reseeded, 8);
+        assert_ne!(reseeded.build(), first.build());
+    }
+
+    #[test]
+    fn nested() {
+        let inner = Pipeline::new().pass(|tilemap: &mut Tilemap, _: &mut Rng| tilemap.set(Vector2::ZERO, '#'));
+        let pipeline = Pipeline::new()
+            .pass(inner)
+            .pass(|tilemap: &mut Tilemap, _: &mut Rng| tilemap.set(Vector2::ONE, '@'));
+
+        let mut tilemap = Tilemap::new('.');
+        pipeline.run(&mut tilemap, 0);
+        assert_eq!(tilemap.build(), "#.\n.@");
+    }
+}
@@ -0,0 +1,100 @@
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Checks if the prefab can be placed at the specified position without overlapping existing
+    /// tiles. Only tiles of the prefab are checked, its empty tiles may overlap anything.
+    /// Prefab may extend beyond bounds, since [`Tilemap`] grows when tiles are set.
+    ///
+    /// # Arguments
+    /// * `prefab: &Tilemap` - [`Tilemap`] that should be placed.
+    /// * `position: Vector2` - Position of the top left corner of the prefab.
+    ///
+    /// # Return
+    /// * [`Ok`] if prefab can be placed.
+    /// * [`Err`] with positions where prefab overlaps existing tiles, in the row-major order.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("...\n.#.", '.');
+    /// let prefab = char_tilemap::Tilemap::from_string("@@\n@.", '.');
+    ///
+    /// assert_eq!(tilemap.can_place(&prefab, char_tilemap::Vector2::ZERO), Ok(()));
+    /// assert_eq!(tilemap.can_place(&prefab, char_tilemap::Vector2::new(1, 0)), Err(vec![char_tilemap::Vector2::ONE]));
+    /// ```
+    pub fn can_place(&self, prefab: &Tilemap, position: Vector2) -> Result<(), Vec<Vector2>> {
+        let conflicts: Vec<Vector2> = prefab.tiles.iter()
+            .map(|tile| tile.position + position)
+            .filter(|target| self.get(*target).is_some())
+            .collect();
+
+        return if conflicts.is_empty() { Ok(()) } else { Err(conflicts) };
+    }
+
+    /// # Description
+    /// Checks if the group can be moved by [`Tilemap::move_group()`], so its top left corner is
+    /// at the specified position.
+    ///
+    /// # Arguments
+    /// * `name: &str` - Name of the group.
+    /// * `position: Vector2` - New position of the top left corner of the group.
+    ///
+    /// # Return
+    /// * [`Ok`] if group can be moved.
+    /// * [`Err`] with new positions of the group that would be out of bounds or overlap tiles
+    ///   that do not belong to the group. Empty if there is no group with the specified name.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("<>.\n..#", '.');
+    /// tilemap.add_group("ship", [char_tilemap::Vector2::ZERO, char_tilemap::Vector2::new(1, 0)]).unwrap();
+    ///
+    /// assert_eq!(tilemap.can_place_group("ship", char_tilemap::Vector2::new(1, 0)), Ok(()));
+    /// assert_eq!(tilemap.can_place_group("ship", char_tilemap::Vector2::ONE), Err(vec![char_tilemap::Vector2::new(2, 1)]));
+    /// ```
+    pub fn can_place_group(&self, name: &str, position: Vector2) -> Result<(), Vec<Vector2>> {
+        let Some(group) = self.group(name) else {
+            return Err(Vec::new());
+        };
+
+        let origin = group.position();
+        let conflicts = self.group_conflicts(group, |tile_position| tile_position - origin + position);
+        return if conflicts.is_empty() { Ok(()) } else { Err(conflicts) };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[test]
+    fn can_place() {
+        let tilemap = Tilemap::from_string("#...\n..#.\n....", '.');
+        let prefab = Tilemap::from_string("@.@\n.@.", '.');
+
+        assert_eq!(tilemap.can_place(&prefab, Vector2::new(1, 1)), Ok(()));
+        assert_eq!(tilemap.can_place(&prefab, Vector2::new(3, 2)), Ok(()));
+        assert_eq!(tilemap.can_place(&prefab, Vector2::new(0, 0)), Err(vec![Vector2::new(0, 0)]));
+        assert_eq!(tilemap.can_place(&prefab, Vector2::new(0, 1)), Err(vec![Vector2::new(2, 1)]));
+        assert_eq!(tilemap.can_place(&Tilemap::new('.'), Vector2::ZERO), Ok(()));
+    }
+
+    #[test]
+    fn can_place_group() {
+        let mut tilemap = Tilemap::from_string("AB..\n...#", '.');
+        tilemap.add_group("ship", [Vector2::ZERO, Vector2::new(1, 0)]).unwrap();
+
+        assert_eq!(tilemap.can_place_group("ship", Vector2::new(1, 0)), Ok(()));
+        assert_eq!(tilemap.can_place_group("ship", Vector2::new(2, 1)), Err(vec![Vector2::new(3, 1)]));
+        assert_eq!(tilemap.can_place_group("ship", Vector2::new(3, 0)), Err(vec![Vector2::new(4, 0)]));
+        assert_eq!(tilemap.can_place_group("boss", Vector2::ZERO), Err(vec![]));
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use crate::tilemap::{Rng, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// [`Tilemap`] that is stamped into other tilemaps, where tiles may have a chance of appearing.
+/// Chances are resolved with [`Rng`] when prefab is placed, so every placed copy gets its own
+/// variation, such as rubble or cracks, and same seeds give same results.
+///
+/// # Example
+/// ```rust
+/// let mut prefab = char_tilemap::Prefab::new(char_tilemap::Tilemap::from_string("#,#\n#,#", '.'));
+/// prefab.set_value_chance(',', 0.0);
+///
+/// let mut tilemap = char_tilemap::Tilemap::from_string("....", '.');
+/// tilemap.place_prefab(&prefab, char_tilemap::Vector2::new(1, 0), &mut char_tilemap::Rng::new(1));
+/// assert_eq!(tilemap.build(), ".#.#\n.#.#");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Prefab {
+    /// # Description
+    /// Tiles of the prefab.
+    tilemap: Tilemap,
+    /// # Description
+    /// Chances of tiles at specific positions.
+    position_chances: HashMap<Vector2, f64>,
+    /// # Description
+    /// Chances of tiles with specific values. Chances of positions take precedence.
+    value_chances: HashMap<char, f64>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Prefab {
+    /// # Description
+    /// Creates new [`Prefab`] where all tiles always appear.
+    ///
+    /// # Arguments
+    /// * `tilemap: Tilemap` - Tiles of the prefab.
+    ///
+    /// # Return
+    /// New instance of the [`Prefab`].
+    pub fn new(tilemap: Tilemap) -> Prefab {
+        return Prefab { tilemap, position_chances: HashMap::new(), value_chances: HashMap::new() };
+    }
+
+    /// # Description
+    /// Returns tiles of the [`Prefab`].
+    pub fn tilemap(&self) -> &Tilemap {
+        return &self.tilemap;
+    }
+
+    /// # Description
+    /// Sets chance of the tile at the specified position to appear.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile in the prefab.
+    /// * `chance: f64` - Probability in range `[0.0, 1.0]`. Other values are clamped.
+    pub fn set_chance(&mut self, position: Vector2, chance: f64) {
+        self.position_chances.insert(position, chance.clamp(0.0, 1.0));
+    }
+
+    /// # Description
+    /// Sets chance of all tiles with the specified value to appear, unless their positions have
+    /// their own chances set by [`Prefab::set_chance()`].
+    ///
+    /// # Arguments
+    /// * `value: char` - Value of tiles.
+    /// * `chance: f64` - Probability in range `[0.0, 1.0]`. Other values are clamped.
+    pub fn set_value_chance(&mut self, value: char, chance: f64) {
+        self.value_chances.insert(value, chance.clamp(0.0, 1.0));
+    }
+
+    /// # Description
+    /// Returns chance of the tile at the specified position to appear.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile in the prefab.
+    ///
+    /// # Return
+    /// Probability in range `[0.0, 1.0]`. It is `0.0` if there is no tile at the position.
+    pub fn chance(&self, position: Vector2) -> f64 {
+        let Some(value) = self.tilemap.get(position) else {
+            return 0.0;
+        };
+
+        return self.position_chances.get(&position)
+            .or_else(|| self.value_chances.get(&value))
+            .copied()
+            .unwrap_or(1.0);
+    }
+
+    /// # Description
+    /// Resolves chances of all tiles.
+    ///
+    /// # Arguments
+    /// * `rng: &mut Rng` - Generator that decides which tiles appear.
+    ///
+    /// # Return
+    /// New [`Tilemap`] of the same size with tiles that appeared.
+    pub fn resolve(&self, rng: &mut Rng) -> Tilemap {
+        let mut result = self.tilemap.clone();
+        for tile in self.tilemap.tiles.iter() {
+            let chance = self.chance(tile.position);
+            if chance < 1.0 && !rng.chance(chance) {
+                result.take_tile(tile.position);
+            }
+        }
+
+        return result;
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Resolves chances of the prefab and sets its tiles that appeared. Existing tiles at the same
+    /// positions are overwritten, empty tiles of the prefab do not change anything.
+    ///
+    /// # Arguments
+    /// * `prefab: &Prefab` - Prefab that should be placed.
+    /// * `position: Vector2` - Position of the top left corner of the prefab.
+    /// * `rng: &mut Rng` - Generator that decides which tiles appear.
+    pub fn place_prefab(&mut self, prefab: &Prefab, position: Vector2, rng: &mut Rng) {
+        let resolved = prefab.resolve(rng);
+        for tile in resolved.tiles.iter() {
+            self.set(tile.position + position, tile.value);
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Prefab, Rng, Tilemap, Vector2};
+
+    #[test]
+    fn chance() {
+        let mut prefab = Prefab::new(Tilemap::from_string("#,\n,.", '.'));
+        prefab.set_value_chance(',', 0.3);
+        prefab.set_chance(Vector2::new(0, 1), 2.0);
+
+        assert_eq!(prefab.chance(Vector2::ZERO), 1.0);
+        assert_eq!(prefab.chance(Vector2::new(1, 0)), 0.3);
+        assert_eq!(prefab.chance(Vector2::new(0, 1)), 1.0);
+        assert_eq!(prefab.chance(Vector2::ONE), 0.0);
+    }
+
+    #[test]
+    fn resolve() {
+        let mut prefab = Prefab::new(Tilemap::from_string(&",".repeat(1000), '.'));
+        prefab.set_value_chance(',', 0.25);
+
+        let first = prefab.resolve(&mut Rng::new(42));
+        let count = first.tiles.len();
+        assert!((150..350).contains(&count));
+        assert_eq!(first.size(), prefab.tilemap().size());
+        assert_eq!(prefab.resolve(&mut Rng::new(42)).build(), first.build());
+    }
+
+    #[test]
+    fn place_prefab() {
+        let mut prefab = Prefab::new(Tilemap::from_string("#x#", '.'));
+        prefab.set_chance(Vector2::new(1, 0), 0.0);
+
+        let mut tilemap = Tilemap::from_string("....\n.@@.", '.');
+        tilemap.place_prefab(&prefab, Vector2::ONE, &mut Rng::new(3));
+        assert_eq!(tilemap.build(), "....\n.#@#");
+    }
+}
@@ -0,0 +1,105 @@
+use crate::tilemap::{Direction, TileOp, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Moves the tile one step in the direction, pushing all pushable tiles in front of it, as
+    /// in Sokoban. Chain of pushable tiles of any length is moved as a whole, and only if the
+    /// tile after the chain is free: empty, or neither pushable nor blocking. Such free tile is
+    /// overwritten by the last tile of the chain. The edge of the [`Tilemap`] blocks as a wall.
+    ///
+    /// # Arguments
+    /// * `from: Vector2` - Position of the moved tile, for example the player.
+    /// * `direction: Direction` - Direction of the move.
+    /// * `pushable: P` - Function that receives value of the tile and returns `true` if tile can
+    ///   be pushed. It is checked before `blocking`.
+    /// * `blocking: B` - Function that receives value of the tile and returns `true` if tile
+    ///   stops the move.
+    ///
+    /// # Return
+    /// * [`Some`] with the number of pushed tiles if the tile was moved.
+    /// * [`None`] if there is no tile at `from`, or the move is blocked. Nothing is changed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::{Direction, Vector2};
+    ///
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("#@oo.#", '.');
+    /// let push = |tilemap: &mut char_tilemap::Tilemap, from| {
+    ///     return tilemap.try_push(from, Direction::Right, |value| value == 'o', |value| value == '#');
+    /// };
+    ///
+    /// assert_eq!(push(&mut tilemap, Vector2::new(1, 0)), Some(2));
+    /// assert_eq!(tilemap.build(), "#.@oo#");
+    /// assert_eq!(push(&mut tilemap, Vector2::new(2, 0)), None);
+    /// ```
+    pub fn try_push<P, B>(&mut self, from: Vector2, direction: Direction, pushable: P, blocking: B) -> Option<usize>
+        where P: Fn(char) -> bool,
+              B: Fn(char) -> bool {
+        let mut chain = vec![(from, self.get(from)?)];
+        let mut position = from;
+
+        loop {
+            position = direction.step(position).filter(|next| next.x < self.size.x && next.y < self.size.y)?;
+            match self.get(position) {
+                Some(value) if pushable(value) => chain.push((position, value)),
+                Some(value) if blocking(value) => return None,
+                _ => break,
+            }
+        }
+
+        let mut ops = vec![TileOp::Remove(from)];
+        let targets = chain.iter().skip(1).map(|(position, _)| *position).chain(std::iter::once(position));
+        ops.extend(targets.zip(chain.iter()).map(|(target, (_, value))| TileOp::Set(target, *value)));
+        self.apply_ops(&ops);
+
+        return Some(chain.len() - 1);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Direction, Tilemap, Vector2};
+
+    /// # Description
+    /// Pushes with boxes `o` and walls `#`.
+    fn push(tilemap: &mut Tilemap, from: Vector2, direction: Direction) -> Option<usize> {
+        return tilemap.try_push(from, direction, |value| value == 'o', |value| value == '#');
+    }
+
+    #[test]
+    fn try_push() {
+        let mut tilemap = Tilemap::from_string("#####\n#@o_#\n#.o.#\n#.o.#\n#...#\n#####", '.');
+
+        assert_eq!(push(&mut tilemap, Vector2::new(1, 1), Direction::Right), Some(1));
+        assert_eq!(tilemap.build(), "#####\n#.@o#\n#.o.#\n#.o.#\n#...#\n#####");
+
+        assert_eq!(push(&mut tilemap, Vector2::new(2, 1), Direction::Right), None);
+        assert_eq!(push(&mut tilemap, Vector2::new(2, 1), Direction::Down), Some(2));
+        assert_eq!(tilemap.build(), "#####\n#..o#\n#.@.#\n#.o.#\n#.o.#\n#####");
+
+        assert_eq!(push(&mut tilemap, Vector2::new(2, 2), Direction::Down), None);
+        assert_eq!(push(&mut tilemap, Vector2::new(2, 2), Direction::Left), Some(0));
+        assert_eq!(push(&mut tilemap, Vector2::new(3, 3), Direction::Left), None);
+        assert_eq!(tilemap.build(), "#####\n#..o#\n#@..#\n#.o.#\n#.o.#\n#####");
+    }
+
+    #[test]
+    fn edges() {
+        let mut tilemap = Tilemap::from_string("@o\no.", '.');
+
+        assert_eq!(push(&mut tilemap, Vector2::ZERO, Direction::Right), None);
+        assert_eq!(push(&mut tilemap, Vector2::ZERO, Direction::Up), None);
+        assert_eq!(push(&mut tilemap, Vector2::new(0, 1), Direction::Left), None);
+        assert_eq!(push(&mut tilemap, Vector2::new(1, 0), Direction::Down), Some(0));
+        assert_eq!(tilemap.build(), "@.\noo");
+        assert_eq!(tilemap.size(), Vector2::new(2, 2));
+    }
+}
@@ -0,0 +1,515 @@
+use crate::tilemap::{Tile, TileSource, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Maximum number of tiles in a leaf of the quadtree before it is split into four quadrants.
+const LEAF_CAPACITY: usize = 8;
+
+/// # Description
+/// Node of the quadtree. Quadrants of branches are stored in order: top left, top right,
+/// bottom left, bottom right.
+#[derive(Debug, Clone)]
+enum QuadNode {
+    /// # Description
+    /// Node that stores tiles of its square directly.
+    Leaf(Vec<Tile>),
+    /// # Description
+    /// Node that is split into four quadrants.
+    Branch(Box<[QuadNode; 4]>),
+}
+
+/// # Description
+/// Square area of the quadtree. Coordinates are wider than [`usize`], so the root can cover
+/// the whole coordinate space.
+#[derive(Debug, Copy, Clone)]
+struct Square {
+    /// # Description
+    /// `X` coordinate of the top left corner.
+    x: u128,
+    /// # Description
+    /// `Y` coordinate of the top left corner.
+    y: u128,
+    /// # Description
+    /// Length of the side. Always a power of two.
+    side: u128,
+}
+
+/// # Description
+/// Alternative to [`Tilemap`] that stores tiles in a quadtree instead of a sorted set. Memory and
+/// time of operations depend only on the number of tiles and how they are clustered, so it suits
+/// very sparse maps with huge coordinates. It is a [`TileSource`], so it can be rendered, viewed
+/// or turned into a [`Tilemap`] with [`Tilemap::from_source()`].
+///
+/// # Example
+/// ```rust
+/// let mut quadtree = char_tilemap::QuadtreeTilemap::new('.');
+/// quadtree.set(char_tilemap::Vector2::new(1_000_000, 5), '@');
+/// quadtree.set(char_tilemap::Vector2::new(1_000_002, 4), 'g');
+///
+/// let nearest = quadtree.nearest(char_tilemap::Vector2::new(1_000_005, 0)).unwrap();
+/// assert_eq!(nearest.value, 'g');
+///
+/// let region = quadtree.region(char_tilemap::Vector2::new(1_000_000, 4), char_tilemap::Vector2::new(3, 2));
+/// assert_eq!(region.build(), "..g\n@..");
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuadtreeTilemap {
+    /// # Description
+    /// Value that is used for empty tiles during the build.
+    pub empty_tile: char,
+    /// # Description
+    /// Root of the quadtree. Its top left corner is at the origin.
+    root: QuadNode,
+    /// # Description
+    /// Length of the side of the root square.
+    extent: u128,
+    /// # Description
+    /// Size that covers all tiles that were ever set.
+    size: Vector2,
+    /// # Description
+    /// Number of tiles.
+    len: usize,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl QuadtreeTilemap {
+    /// # Description
+    /// Creates new empty [`QuadtreeTilemap`].
+    ///
+    /// # Arguments
+    /// * `empty_tile: char` - Value that will be used for empty tiles during the build.
+    ///
+    /// # Return
+    /// New instance of the [`QuadtreeTilemap`].
+    pub fn new(empty_tile: char) -> QuadtreeTilemap {
+        return QuadtreeTilemap { empty_tile, root: QuadNode::Leaf(Vec::new()), extent: 1, size: Vector2::ZERO, len: 0 };
+    }
+
+    /// # Description
+    /// Creates new [`QuadtreeTilemap`] with all tiles and the size of the [`Tilemap`].
+    ///
+    /// # Arguments
+    /// * `tilemap: &Tilemap` - [`Tilemap`] which tiles are copied.
+    ///
+    /// # Return
+    /// New instance of the [`QuadtreeTilemap`].
+    pub fn from_tilemap(tilemap: &Tilemap) -> QuadtreeTilemap {
+        let mut quadtree = QuadtreeTilemap::new(tilemap.empty_tile);
+        for tile in tilemap.tiles.iter() {
+            quadtree.set(tile.position, tile.value);
+        }
+        quadtree.size = tilemap.size();
+        return quadtree;
+    }
+
+    /// # Description
+    /// Returns number of tiles.
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    /// # Description
+    /// Checks if there are no tiles.
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    /// # Description
+    /// Returns size that covers all tiles that were ever set. It does not shrink when tiles are
+    /// removed, same as the size of the [`Tilemap`].
+    pub fn size(&self) -> Vector2 {
+        return self.size;
+    }
+
+    /// # Description
+    /// Returns value of the tile at the specified position.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with value of the tile if it exists.
+    /// * [`None`] if there is no tile at the specified position.
+    pub fn get(&self, position: Vector2) -> Option<char> {
+        let mut node = &self.root;
+        let mut square = self.root_square();
+
+        loop {
+            match node {
+                QuadNode::Leaf(tiles) => {
+                    return tiles.iter().find(|tile| tile.position == position).map(|tile| tile.value);
+                },
+                QuadNode::Branch(children) => {
+                    let index = square.quadrant_of(position)?;
+                    square = square.quadrant(index);
+                    node = &children[index];
+                },
+            }
+        }
+    }
+
+    /// # Description
+    /// Sets tile at the specified position, so it either is added or its value is changed.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    /// * `value: char` - Value of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with the previous value of the tile.
+    /// * [`None`] if tile was added.
+    pub fn set(&mut self, position: Vector2, value: char) -> Option<char> {
+        let furthest = std::cmp::max(position.x, position.y) as u128;
+        while furthest >= self.extent {
+            // Leaf covers any square, so only branches have to be nested into a bigger root
+            if let QuadNode::Branch(_) = self.root {
+                let old = std::mem::replace(&mut self.root, QuadNode::Leaf(Vec::new()));
+                self.root = QuadNode::Branch(Box::new([old, QuadNode::Leaf(Vec::new()),
+                                                       QuadNode::Leaf(Vec::new()), QuadNode::Leaf(Vec::new())]));
+            }
+            self.extent *= 2;
+        }
+
+        let square = self.root_square();
+        let old = insert(&mut self.root, square, Tile { position, value });
+        if old.is_none() {
+            self.len += 1;
+        }
+        self.size.x = std::cmp::max(self.size.x, position.x.saturating_add(1));
+        self.size.y = std::cmp::max(self.size.y, position.y.saturating_add(1));
+        return old;
+    }
+
+    /// # Description
+    /// Removes tile at the specified position. Quadrants that become small enough are merged back.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with value of the removed tile.
+    /// * [`None`] if there was no tile at the specified position.
+    pub fn remove(&mut self, position: Vector2) -> Option<char> {
+        let square = self.root_square();
+        let removed = remove(&mut self.root, square, position);
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        return removed;
+    }
+
+    /// # Description
+    /// Returns all tiles inside of the rectangle. Only quadrants that intersect the rectangle are
+    /// visited.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Top left corner of the rectangle.
+    /// * `size: Vector2` - Size of the rectangle.
+    ///
+    /// # Return
+    /// Tiles in the row-major order.
+    pub fn tiles_in(&self, position: Vector2, size: Vector2) -> Vec<Tile> {
+        let first = (position.x as u128, position.y as u128);
+        let end = (first.0 + size.x as u128, first.1 + size.y as u128);
+
+        let mut result = Vec::new();
+        collect(&self.root, self.root_square(), first, end, &mut result);
+        result.sort_by_key(|tile| tile.position);
+        return result;
+    }
+
+    /// # Description
+    /// Extracts the rectangle into a new [`Tilemap`], see [`QuadtreeTilemap::tiles_in()`].
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Top left corner of the rectangle.
+    /// * `size: Vector2` - Size of the rectangle and of the new [`Tilemap`].
+    ///
+    /// # Return
+    /// New [`Tilemap`] where tiles are positioned relative to the top left corner of the rectangle.
+    pub fn region(&self, position: Vector2, size: Vector2) -> Tilemap {
+        let mut tilemap = Tilemap::new(self.empty_tile);
+        tilemap.tiles = sorted_vec::SortedSet::from_unsorted(self.tiles_in(position, size).into_iter()
+            .map(|tile| Tile { position: tile.position - position, value: tile.value })
+            .collect());
+        tilemap.size = size;
+        return tilemap;
+    }
+
+    /// # Description
+    /// Returns tile that is the closest to the position by Euclidean distance.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position from which distance is measured.
+    ///
+    /// # Return
+    /// * [`Some`] with the closest tile. If several tiles are equally close, any of them is returned.
+    /// * [`None`] if there are no tiles.
+    pub fn nearest(&self, position: Vector2) -> Option<Tile> {
+        return self.nearest_where(position, |_| true);
+    }
+
+    /// # Description
+    /// Returns tile that satisfies the predicate and is the closest to the position by Euclidean
+    /// distance. Quadrants that are farther than the closest found tile are skipped.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position from which distance is measured.
+    /// * `predicate: F` - Function that receives value of the tile and returns `true` if tile fits.
+    ///
+    /// # Return
+    /// * [`Some`] with the closest tile. If several tiles are equally close, any of them is returned.
+    /// * [`None`] if no tiles satisfy the predicate.
+    pub fn nearest_where<F>(&self, position: Vector2, predicate: F) -> Option<Tile>
+        where F: Fn(char) -> bool {
+        let mut best = None;
+        nearest(&self.root, self.root_square(), position, &predicate, &mut best);
+        return best.map(|(_, tile)| tile);
+    }
+
+    /// # Description
+    /// Returns square that is covered by the root.
+    fn root_square(&self) -> Square {
+        return Square { x: 0, y: 0, side: self.extent };
+    }
+}
+
+impl Square {
+    /// # Description
+    /// Returns index of the quadrant that contains the position, or [`None`] if position is out
+    /// of the square.
+    fn quadrant_of(&self, position: Vector2) -> Option<usize> {
+        let (x, y) = (position.x as u128, position.y as u128);
+        if x < self.x || y < self.y || x >= self.x + self.side || y >= self.y + self.side {
+            return None;
+        }
+
+        let half = self.side / 2;
+        return Some(usize::from(x >= self.x + half) + 2 * usize::from(y >= self.y + half));
+    }
+
+    /// # Description
+    /// Returns quadrant with the specified index.
+    fn quadrant(&self, index: usize) -> Square {
+        let half = self.side / 2;
+        let x = if index % 2 == 1 { self.x + half } else { self.x };
+        let y = if index >= 2 { self.y + half } else { self.y };
+        return Square { x, y, side: half };
+    }
+
+    /// # Description
+    /// Checks if the square intersects the rectangle from `first` inclusive to `end` exclusive.
+    fn intersects(&self, first: (u128, u128), end: (u128, u128)) -> bool {
+        return self.x < end.0 && first.0 < self.x + self.side && self.y < end.1 && first.1 < self.y + self.side;
+    }
+
+    /// # Description
+    /// Returns squared Euclidean distance from the position to the closest point of the square.
+    fn distance_squared(&self, position: Vector2) -> u128 {
+        let axis = |value: u128, start: u128| {
+            return if value < start { start - value } else { value.saturating_sub(start + self.side - 1) };
+        };
+        let dx = axis(position.x as u128, self.x);
+        let dy = axis(position.y as u128, self.y);
+        return dx.saturating_mul(dx).saturating_add(dy.saturating_mul(dy));
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl TileSource for QuadtreeTilemap {
+    /// # Description
+    /// Returns size that covers all tiles that were ever set.
+    fn size(&self) -> Vector2 {
+        return self.size;
+    }
+
+    /// # Description
+    /// Returns value of the tile at the specified position if it exists.
+    fn get(&self, position: Vector2) -> Option<char> {
+        return QuadtreeTilemap::get(self, position);
+    }
+
+    /// # Description
+    /// Returns value of the empty tile.
+    fn empty_tile(&self) -> char {
+        return self.empty_tile;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Inserts tile into the node that covers the square, splitting leaves that become too big.
+fn insert(node: &mut QuadNode, square: Square, tile: Tile) -> Option<char> {
+    match node {
+        QuadNode::Leaf(tiles) => {
+            if let Some(existing) = tiles.iter_mut().find(|existing| existing.position == tile.position) {
+                return Some(std::mem::replace(&mut existing.value, tile.value));
+            }
+
+            tiles.push(tile);
+            if tiles.len() > LEAF_CAPACITY && square.side > 1 {
+                let tiles = std::mem::take(tiles);
+                *node = QuadNode::Branch(Box::new(std::array::from_fn(|_| QuadNode::Leaf(Vec::new()))));
+                for tile in tiles {
+                    insert(node, square, tile);
+                }
+            }
+            return None;
+        },
+        QuadNode::Branch(children) => {
+            let index = square.quadrant_of(tile.position).expect("Quadtree root covers all tiles");
+            return insert(&mut children[index], square.quadrant(index), tile);
+        },
+    }
+}
+
+/// # Description
+/// Removes tile from the node that covers the square, merging quadrants that become small enough.
+fn remove(node: &mut QuadNode, square: Square, position: Vector2) -> Option<char> {
+    match node {
+        QuadNode::Leaf(tiles) => {
+            let index = tiles.iter().position(|tile| tile.position == position)?;
+            return Some(tiles.swap_remove(index).value);
+        },
+        QuadNode::Branch(children) => {
+            let index = square.quadrant_of(position)?;
+            let removed = remove(&mut children[index], square.quadrant(index), position)?;
+
+            let mut merged = Vec::new();
+            for child in children.iter() {
+                match child {
+                    QuadNode::Leaf(tiles) if merged.len() + tiles.len() <= LEAF_CAPACITY => merged.extend_from_slice(tiles),
+                    _ => return Some(removed),
+                }
+            }
+            *node = QuadNode::Leaf(merged);
+            return Some(removed);
+        },
+    }
+}
+
+/// # Description
+/// Collects tiles of the node that are inside of the rectangle from `first` inclusive to `end`
+/// exclusive.
+fn collect(node: &QuadNode, square: Square, first: (u128, u128), end: (u128, u128), result: &mut Vec<Tile>) {
+    if !square.intersects(first, end) {
+        return;
+    }
+
+    match node {
+        QuadNode::Leaf(tiles) => {
+            result.extend(tiles.iter().filter(|tile| {
+                let (x, y) = (tile.position.x as u128, tile.position.y as u128);
+                return first.0 <= x && x < end.0 && first.1 <= y && y < end.1;
+            }));
+        },
+        QuadNode::Branch(children) => {
+            for (index, child) in children.iter().enumerate() {
+                collect(child, square.quadrant(index), first, end, result);
+            }
+        },
+    }
+}
+
+/// # Description
+/// Updates `best` with the tile of the node that satisfies the predicate and is closer to the
+/// position. Closer quadrants are visited first.
+fn nearest<F>(node: &QuadNode, square: Square, position: Vector2, predicate: &F, best: &mut Option<(u128, Tile)>)
+    where F: Fn(char) -> bool {
+    if best.is_some_and(|(distance, _)| square.distance_squared(position) >= distance) {
+        return;
+    }
+
+    match node {
+        QuadNode::Leaf(tiles) => {
+            for tile in tiles.iter().filter(|tile| predicate(tile.value)) {
+                let distance = Square { x: tile.position.x as u128, y: tile.position.y as u128, side: 1 }
+                    .distance_squared(position);
+                if best.is_none_or(|(best_distance, _)| distance < best_distance) {
+                    *best = Some((distance, *tile));
+                }
+            }
+        },
+        QuadNode::Branch(children) => {
+            let mut quadrants: Vec<(u128, usize)> = (0..4)
+                .map(|index| (square.quadrant(index).distance_squared(position), index))
+                .collect();
+            quadrants.sort();
+            for (_, index) in quadrants {
+                nearest(&children[index], square.quadrant(index), position, predicate, best);
+            }
+        },
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{QuadtreeTilemap, TileSource, Tilemap, Vector2};
+
+    #[test]
+    fn set_get_remove() {
+        let mut quadtree = QuadtreeTilemap::new('.');
+        for index in 0..100 {
+            assert_eq!(quadtree.set(Vector2::new(index * 3, index % 7), '#'), None);
+        }
+        assert_eq!(quadtree.set(Vector2::new(usize::MAX, usize::MAX), '@'), None);
+        assert_eq!(quadtree.set(Vector2::new(9, 3), '+'), Some('#'));
+
+        assert_eq!(quadtree.len(), 101);
+        assert_eq!(quadtree.size(), Vector2::MAX);
+        assert_eq!(quadtree.get(Vector2::new(9, 3)), Some('+'));
+        assert_eq!(quadtree.get(Vector2::new(usize::MAX, usize::MAX)), Some('@'));
+        assert_eq!(quadtree.get(Vector2::new(10, 3)), None);
+
+        for index in 0..100 {
+            assert!(quadtree.remove(Vector2::new(index * 3, index % 7)).is_some());
+        }
+        assert_eq!(quadtree.remove(Vector2::ZERO), None);
+        assert_eq!(quadtree.len(), 1);
+        assert!(!quadtree.is_empty());
+    }
+
+    #[test]
+    fn region() {
+        let tilemap = Tilemap::from_string("#..#\n.@..\n..g.\n#..#", '.');
+        let quadtree = QuadtreeTilemap::from_tilemap(&tilemap);
+
+        assert_eq!(quadtree.build(), tilemap.build());
+        assert_eq!(quadtree.tiles_in(Vector2::ONE, Vector2::new(2, 2)).len(), 2);
+        assert_eq!(quadtree.region(Vector2::ONE, Vector2::new(3, 2)).build(), "@..\n.g.");
+        assert_eq!(quadtree.region(Vector2::new(100, 100), Vector2::ONE).build(), ".");
+    }
+
+    #[test]
+    fn nearest() {
+        let mut quadtree = QuadtreeTilemap::new('.');
+        assert!(quadtree.nearest(Vector2::ZERO).is_none());
+
+        for index in 0..50 {
+            quadtree.set(Vector2::new(index * 1_000, index * 1_000), '#');
+        }
+        quadtree.set(Vector2::new(20_500, 20_400), 'g');
+
+        assert_eq!(quadtree.nearest(Vector2::new(20_400, 20_500)).unwrap().value, 'g');
+        assert_eq!(quadtree.nearest(Vector2::new(20_100, 20_100)).unwrap().position, Vector2::new(20_000, 20_000));
+        assert_eq!(quadtree.nearest_where(Vector2::ZERO, |value| value == 'g').unwrap().position,
+                   Vector2::new(20_500, 20_400));
+        assert!(quadtree.nearest_where(Vector2::ZERO, |value| value == 'x').is_none());
+    }
+}
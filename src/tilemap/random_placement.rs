@@ -0,0 +1,322 @@
+use crate::tilemap::{Rng, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Number of random positions that are checked before all positions are scanned.
+const REJECTION_ATTEMPTS: usize = 64;
+
+/// # Description
+/// Number of candidates around every placed position that Poisson-disk sampling tries before
+/// the position is considered surrounded.
+const SCATTER_ATTEMPTS: usize = 30;
+
+/// # Description
+/// Background grid of Poisson-disk sampling. Cells are small enough to contain at most one
+/// placed position, so only nearby cells are checked for positions that are too close.
+struct ScatterGrid {
+    /// # Description
+    /// Number of cells on every axis.
+    cells: Vector2,
+    /// # Description
+    /// Size of a cell.
+    cell_size: f64,
+    /// # Description
+    /// Minimum distance between positions.
+    min_distance: f64,
+    /// # Description
+    /// Placed positions in row-major order of cells.
+    positions: Vec<Option<Vector2>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Returns uniformly random position inside of the [`Tilemap`] without a tile.
+    ///
+    /// # Arguments
+    /// * `rng: &mut Rng` - Generator that picks the position.
+    ///
+    /// # Return
+    /// * [`Some`] with the position.
+    /// * [`None`] if there are no empty positions.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("##\n#.", '.');
+    /// let mut rng = char_tilemap::Rng::new(7);
+    /// assert_eq!(tilemap.random_empty_position(&mut rng), Some(char_tilemap::Vector2::ONE));
+    /// ```
+    pub fn random_empty_position(&self, rng: &mut Rng) -> Option<Vector2> {
+        return self.random_position_where(|_, value| value.is_none(), rng);
+    }
+
+    /// # Description
+    /// Returns uniformly random position inside of the [`Tilemap`] that satisfies the predicate.
+    /// Random positions are checked first, so no list of candidates is built when they are
+    /// common. If none of them fits, all positions are scanned twice: to count candidates, and
+    /// to find the randomly chosen one.
+    ///
+    /// # Arguments
+    /// * `predicate: F` - Function that receives position and value of the tile at it, and
+    ///   returns `true` if position fits.
+    /// * `rng: &mut Rng` - Generator that picks the position.
+    ///
+    /// # Return
+    /// * [`Some`] with the position.
+    /// * [`None`] if no position satisfies the predicate.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("#~#\n~~.", '.');
+    /// let mut rng = char_tilemap::Rng::new(7);
+    /// let position = tilemap.random_position_where(|_, value| value == Some('~'), &mut rng).unwrap();
+    /// assert_eq!(tilemap.get(position), Some('~'));
+    /// ```
+    pub fn random_position_where<F>(&self, predicate: F, rng: &mut Rng) -> Option<Vector2>
+        where F: Fn(Vector2, Option<char>) -> bool {
+        return self.random_area_position(Vector2::ZERO, self.size, predicate, rng);
+    }
+
+    /// # Description
+    /// Places tiles at empty positions of the area using Poisson-disk sampling, so they are
+    /// distributed randomly, but never closer to each other than the minimum distance, and
+    /// without large gaps. Existing tiles are not replaced and block placement.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Top left corner of the area.
+    /// * `size: Vector2` - Size of the area.
+    /// * `value: char` - Value of placed tiles.
+    /// * `min_distance: f64` - Minimum Euclidean distance between placed tiles.
+    /// * `rng: &mut Rng` - Generator that picks positions.
+    ///
+    /// # Return
+    /// * [`Ok`] with positions of placed tiles in order of placement.
+    /// * [`Err`] if minimum distance is not positive or not finite. Contains error message.
+    ///
+    /// # Notes
+    /// Sampling spreads from a single random position, so parts of the area that are separated
+    /// by existing tiles wider than twice the minimum distance can stay empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string(&".".repeat(20), '.');
+    /// let mut rng = char_tilemap::Rng::new(7);
+    /// let mut trees = tilemap.scatter(char_tilemap::Vector2::ZERO, tilemap.size(), 'T', 3.0, &mut rng).unwrap();
+    ///
+    /// trees.sort();
+    /// assert!(trees.windows(2).all(|pair| pair[1].x - pair[0].x >= 3));
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self, rng), err))]
+    pub fn scatter(&mut self, position: Vector2, size: Vector2, value: char, min_distance: f64,
+                   rng: &mut Rng) -> Result<Vec<Vector2>, String> {
+        if !min_distance.is_finite() || min_distance <= 0.0 {
+            return Err(format!("Invalid minimum distance {min_distance}"));
+        }
+
+        let mut placed = Vec::new();
+        let Some(start) = self.random_area_position(position, size, |_, value| value.is_none(), rng) else {
+            return Ok(placed);
+        };
+
+        let mut grid = ScatterGrid::new(size, min_distance);
+        let mut active = vec![start - position];
+        grid.insert(start - position);
+        self.set(start, value);
+        placed.push(start);
+
+        while !active.is_empty() {
+            let index = rng.next_below(active.len());
+            let center = active[index];
+
+            let candidate = (0..SCATTER_ATTEMPTS).find_map(|_| {
+                let angle = rng.next_f64() * std::f64::consts::TAU;
+                let distance = min_distance * (1.0 + rng.next_f64());
+                let x = (center.x as f64 + angle.cos() * distance).round();
+                let y = (center.y as f64 + angle.sin() * distance).round();
+                if x < 0.0 || y < 0.0 || x >= size.x as f64 || y >= size.y as f64 {
+                    return None;
+                }
+
+                let local = Vector2::new(x as usize, y as usize);
+                return (self.get(position + local).is_none() && grid.is_free(local)).then_some(local);
+            });
+
+            match candidate {
+                Some(local) => {
+                    grid.insert(local);
+                    self.set(position + local, value);
+                    placed.push(position + local);
+                    active.push(local);
+                },
+                None => {
+                    active.swap_remove(index);
+                },
+            }
+        }
+
+        return Ok(placed);
+    }
+
+    /// # Description
+    /// Returns uniformly random position of the area that satisfies the predicate, see
+    /// [`Tilemap::random_position_where()`].
+    fn random_area_position<F>(&self, position: Vector2, size: Vector2, predicate: F, rng: &mut Rng) -> Option<Vector2>
+        where F: Fn(Vector2, Option<char>) -> bool {
+        let area = size.x * size.y;
+        if area == 0 {
+            return None;
+        }
+
+        let position_at = |index: usize| position + Vector2::new(index % size.x, index / size.x);
+        let fits = |position: Vector2| predicate(position, self.get(position));
+
+        for _ in 0..REJECTION_ATTEMPTS {
+            let position = position_at(rng.next_below(area));
+            if fits(position) {
+                return Some(position);
+            }
+        }
+
+        let count = (0..area).filter(|index| fits(position_at(*index))).count();
+        if count == 0 {
+            return None;
+        }
+
+        let chosen = rng.next_below(count);
+        return (0..area).map(position_at).filter(|position| fits(*position)).nth(chosen);
+    }
+}
+
+impl ScatterGrid {
+    /// # Description
+    /// Creates empty grid that covers area of the specified size.
+    fn new(size: Vector2, min_distance: f64) -> ScatterGrid {
+        let cell_size = min_distance / std::f64::consts::SQRT_2;
+        let cells = Vector2::new((size.x as f64 / cell_size).ceil() as usize, (size.y as f64 / cell_size).ceil() as usize);
+        return ScatterGrid { cells, cell_size, min_distance, positions: vec![None; cells.x * cells.y] };
+    }
+
+    /// # Description
+    /// Returns cell that contains the position.
+    fn cell(&self, position: Vector2) -> Vector2 {
+        return Vector2::new((position.x as f64 / self.cell_size) as usize, (position.y as f64 / self.cell_size) as usize);
+    }
+
+    /// # Description
+    /// Adds the position to the grid.
+    fn insert(&mut self, position: Vector2) {
+        let cell = self.cell(position);
+        self.positions[cell.y * self.cells.x + cell.x] = Some(position);
+    }
+
+    /// # Description
+    /// Checks that there are no positions closer than the minimum distance. Such positions can
+    /// be only in cells that are at most two cells away.
+    fn is_free(&self, position: Vector2) -> bool {
+        let cell = self.cell(position);
+        for y in cell.y.saturating_sub(2)..std::cmp::min(cell.y + 3, self.cells.y) {
+            for x in cell.x.saturating_sub(2)..std::cmp::min(cell.x + 3, self.cells.x) {
+                if let Some(other) = self.positions[y * self.cells.x + x] {
+                    let dx = other.x as f64 - position.x as f64;
+                    let dy = other.y as f64 - position.y as f64;
+                    if dx * dx + dy * dy < self.min_distance * self.min_distance {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        return true;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Rng, Tilemap, Vector2};
+
+    const SEED: u64 = 7;
+
+    #[test]
+    fn random_empty_position() {
+        let mut rng = Rng::new(SEED);
+        let mut tilemap = Tilemap::new('.');
+        assert_eq!(tilemap.random_empty_position(&mut rng), None);
+
+        tilemap.random_fill_weighted(Vector2::ZERO, Vector2::new(3, 3), &[('#', 1.0)], &mut rng).unwrap();
+        assert_eq!(tilemap.random_empty_position(&mut rng), None);
+
+        tilemap.remove_tile(Vector2::new(2, 1)).unwrap();
+        tilemap.remove_tile(Vector2::new(0, 2)).unwrap();
+        let mut counts = [0; 2];
+        for _ in 0..1000 {
+            match tilemap.random_empty_position(&mut rng) {
+                Some(position) if position == Vector2::new(2, 1) => counts[0] += 1,
+                Some(position) if position == Vector2::new(0, 2) => counts[1] += 1,
+                _ => assert!(false),
+            }
+        }
+        assert!(counts.iter().all(|count| (400..600).contains(count)));
+    }
+
+    #[test]
+    fn random_position_where() {
+        let mut rng = Rng::new(SEED);
+        let tilemap = Tilemap::from_string(&".".repeat(1000), '.');
+
+        // A single candidate among many positions is found by the scan after rejected attempts
+        for _ in 0..10 {
+            let position = tilemap.random_position_where(|position, _| position.x == 777, &mut rng);
+            assert_eq!(position, Some(Vector2::new(777, 0)));
+        }
+
+        let position = tilemap.random_position_where(|position, value| position.x % 2 == 0 && value.is_none(), &mut rng);
+        assert_eq!(position.map(|position| position.x % 2), Some(0));
+        assert_eq!(tilemap.random_position_where(|_, value| value.is_some(), &mut rng), None);
+    }
+
+    #[test]
+    fn scatter() {
+        let mut rng = Rng::new(SEED);
+        let mut tilemap = Tilemap::from_string("#", '.');
+        let placed = tilemap.scatter(Vector2::new(2, 3), Vector2::new(40, 20), 'T', 2.5, &mut rng).unwrap();
+
+        assert!(placed.len() > 50);
+        assert_eq!(tilemap.size(), Vector2::new(42, 23));
+        assert_eq!(tilemap.get(Vector2::ZERO), Some('#'));
+        for (index, first) in placed.iter().enumerate() {
+            assert_eq!(tilemap.get(*first), Some('T'));
+            assert!(first.x >= 2 && first.y >= 3);
+            for second in &placed[index + 1..] {
+                let dx = first.x as f64 - second.x as f64;
+                let dy = first.y as f64 - second.y as f64;
+                assert!(dx * dx + dy * dy >= 2.5 * 2.5);
+            }
+        }
+
+        // Every empty position of the area is close to some placed one
+        for y in 3..23 {
+            for x in 2..42 {
+                let position = Vector2::new(x, y);
+                assert!(placed.iter().any(|other| position.x.abs_diff(other.x).pow(2) + position.y.abs_diff(other.y).pow(2) < 25));
+            }
+        }
+
+        assert_eq!(tilemap.scatter(Vector2::ZERO, Vector2::ONE, 'T', 1.0, &mut rng), Ok(Vec::new()));
+        for min_distance in [0.0, -1.0, f64::INFINITY] {
+            match tilemap.scatter(Vector2::ZERO, Vector2::ONE, 'T', min_distance, &mut rng) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+}
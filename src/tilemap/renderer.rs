@@ -0,0 +1,361 @@
+use crate::tilemap::{Color, Theme, TileSource, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// ANSI escape sequence that resets all terminal attributes.
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// # Description
+/// Object that turns any [`TileSource`] into some output representation.
+/// Allows to add new ways of rendering without changing [`crate::Tilemap`].
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::Renderer;
+///
+/// struct CountRenderer;
+///
+/// impl Renderer for CountRenderer {
+///     type Output = usize;
+///
+///     fn render<S: char_tilemap::TileSource + ?Sized>(&self, source: &S) -> usize {
+///         return source.build().chars().filter(|value| *value == 'O').count();
+///     }
+/// }
+///
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::ONE, 'O');
+/// assert_eq!(CountRenderer.render(&tilemap), 1);
+/// ```
+pub trait Renderer {
+    /// # Description
+    /// Type of the result of the rendering.
+    type Output;
+
+    /// # Description
+    /// Renders the specified source.
+    ///
+    /// # Arguments
+    /// * `source: &S` - Source of tiles that should be rendered.
+    ///
+    /// # Return
+    /// Result of the rendering.
+    fn render<S: TileSource + ?Sized>(&self, source: &S) -> Self::Output;
+}
+
+/// # Description
+/// [`Renderer`] that produces plain string, same as [`TileSource::build()`].
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::Renderer;
+///
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::ONE, 'O');
+/// assert_eq!(char_tilemap::PlainRenderer.render(&tilemap), "--\n-O");
+/// ```
+#[derive(Debug, Default, Copy, Clone)]
+pub struct PlainRenderer;
+
+/// # Description
+/// [`Renderer`] that produces string colored with ANSI escape sequences.
+/// Tiles are colored by their own color from [`TileSource::color()`], or by the [`Theme`] color
+/// of their value. Tiles without any color are not colored.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::Renderer;
+///
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::ZERO, 'O');
+///
+/// let mut renderer = char_tilemap::AnsiRenderer::new();
+/// renderer.set_color('O', char_tilemap::Color::Red);
+/// assert_eq!(renderer.render(&tilemap), "\x1b[31mO\x1b[0m");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct AnsiRenderer {
+    /// # Description
+    /// Colors assigned to tile values.
+    theme: Theme,
+}
+
+/// # Description
+/// [`Renderer`] that produces HTML `<pre>` block. Tiles are colored by their own color from
+/// [`TileSource::color()`], or by the [`Theme`] color of their value, via `<span>` elements.
+/// Tiles without any color are not colored.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::Renderer;
+///
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::ZERO, '<');
+///
+/// let renderer = char_tilemap::HtmlRenderer::new();
+/// assert_eq!(renderer.render(&tilemap), "<pre>&lt;</pre>");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct HtmlRenderer {
+    /// # Description
+    /// Colors assigned to tile values.
+    theme: Theme,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl AnsiRenderer {
+    /// # Description
+    /// Creates new [`AnsiRenderer`] without any assigned colors.
+    ///
+    /// # Return
+    /// New instance of the [`AnsiRenderer`].
+    pub fn new() -> AnsiRenderer {
+        return AnsiRenderer { theme: Theme::new() };
+    }
+
+    /// # Description
+    /// Creates new [`AnsiRenderer`] that uses colors of the theme.
+    ///
+    /// # Arguments
+    /// * `theme: Theme` - Colors of tile values.
+    ///
+    /// # Return
+    /// New instance of the [`AnsiRenderer`].
+    pub fn with_theme(theme: Theme) -> AnsiRenderer {
+        return AnsiRenderer { theme };
+    }
+
+    /// # Description
+    /// Returns mutable reference to the theme of the renderer.
+    pub fn theme_mut(&mut self) -> &mut Theme {
+        return &mut self.theme;
+    }
+
+    /// # Description
+    /// Assigns color to the tile value. Same as [`Theme::set()`] for the theme of the renderer.
+    ///
+    /// # Arguments
+    /// * `value: char` - Tile value.
+    /// * `color: Color` - Color that will be used for tiles with the specified value.
+    pub fn set_color(&mut self, value: char, color: Color) {
+        self.theme.set(value, color);
+    }
+}
+
+impl HtmlRenderer {
+    /// # Description
+    /// Creates new [`HtmlRenderer`] without any assigned colors.
+    ///
+    /// # Return
+    /// New instance of the [`HtmlRenderer`].
+    pub fn new() -> HtmlRenderer {
+        return HtmlRenderer { theme: Theme::new() };
+    }
+
+    /// # Description
+    /// Creates new [`HtmlRenderer`] that uses colors of the theme.
+    ///
+    /// # Arguments
+    /// * `theme: Theme` - Colors of tile values.
+    ///
+    /// # Return
+    /// New instance of the [`HtmlRenderer`].
+    pub fn with_theme(theme: Theme) -> HtmlRenderer {
+        return HtmlRenderer { theme };
+    }
+
+    /// # Description
+    /// Returns mutable reference to the theme of the renderer.
+    pub fn theme_mut(&mut self) -> &mut Theme {
+        return &mut self.theme;
+    }
+
+    /// # Description
+    /// Assigns color to the tile value. Same as [`Theme::set()`] for the theme of the renderer.
+    ///
+    /// # Arguments
+    /// * `value: char` - Tile value.
+    /// * `color: Color` - Color that will be used for tiles with the specified value.
+    pub fn set_color(&mut self, value: char, color: Color) {
+        self.theme.set(value, color);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl Renderer for PlainRenderer {
+    type Output = String;
+
+    /// # Description
+    /// Renders source into the plain string.
+    fn render<S: TileSource + ?Sized>(&self, source: &S) -> String {
+        return source.build();
+    }
+}
+
+impl Renderer for AnsiRenderer {
+    type Output = String;
+
+    /// # Description
+    /// Renders source into the string colored with ANSI escape sequences. Every row that ends
+    /// with a colored tile is terminated with the reset sequence.
+    fn render<S: TileSource + ?Sized>(&self, source: &S) -> String {
+        let size = source.size();
+        let mut result = String::new();
+
+        for y in 0..size.y {
+            if y > 0 {
+                result.push('\n');
+            }
+
+            let mut current_color = None;
+            for x in 0..size.x {
+                let position = Vector2::new(x, y);
+                let value = source.get(position).unwrap_or(source.empty_tile());
+                let color = source.color(position).or_else(|| self.theme.get(value));
+
+                if color != current_color {
+                    match color {
+                        Some(color) => result.push_str(&color.ansi_foreground()),
+                        None => result.push_str(ANSI_RESET),
+                    }
+                    current_color = color;
+                }
+
+                result.push(value);
+            }
+
+            if current_color.is_some() {
+                result.push_str(ANSI_RESET);
+            }
+        }
+
+        return result;
+    }
+}
+
+impl Renderer for HtmlRenderer {
+    type Output = String;
+
+    /// # Description
+    /// Renders source into the HTML `<pre>` block. Special HTML characters are escaped.
+    fn render<S: TileSource + ?Sized>(&self, source: &S) -> String {
+        let size = source.size();
+        let mut result = String::from("<pre>");
+
+        for y in 0..size.y {
+            if y > 0 {
+                result.push('\n');
+            }
+
+            let mut current_color = None;
+            for x in 0..size.x {
+                let position = Vector2::new(x, y);
+                let value = source.get(position).unwrap_or(source.empty_tile());
+                let color = source.color(position).or_else(|| self.theme.get(value));
+
+                if color != current_color {
+                    if current_color.is_some() {
+                        result.push_str("</span>");
+                    }
+                    if let Some(color) = color {
+                        result.push_str(&format!("<span style=\"color:{}\">", color.html()));
+                    }
+                    current_color = color;
+                }
+
+                push_html_escaped(&mut result, value);
+            }
+
+            if current_color.is_some() {
+                result.push_str("</span>");
+            }
+        }
+
+        result.push_str("</pre>");
+        return result;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Pushes `value` to the `result`, escaping special HTML characters.
+fn push_html_escaped(result: &mut String, value: char) {
+    match value {
+        '&' => result.push_str("&amp;"),
+        '<' => result.push_str("&lt;"),
+        '>' => result.push_str("&gt;"),
+        '"' => result.push_str("&quot;"),
+        _ => result.push(value),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{AnsiRenderer, Color, HtmlRenderer, PlainRenderer, Renderer, Theme, Tilemap, Vector2};
+
+    fn build_test_tilemap() -> Tilemap {
+        let mut tilemap = Tilemap::new('-');
+        tilemap.set(Vector2::new(0, 0), '#');
+        tilemap.set(Vector2::new(1, 0), '#');
+        tilemap.set(Vector2::new(2, 1), '&');
+
+        return tilemap;
+    }
+
+    #[test]
+    fn plain() {
+        let tilemap = build_test_tilemap();
+        assert_eq!(PlainRenderer.render(&tilemap), tilemap.build());
+    }
+
+    #[test]
+    fn ansi() {
+        let tilemap = build_test_tilemap();
+        let mut renderer = AnsiRenderer::new();
+        assert_eq!(renderer.render(&tilemap), tilemap.build());
+
+        renderer.set_color('#', Color::Green);
+        assert_eq!(renderer.render(&tilemap), "\x1b[32m##\x1b[0m-\n--&");
+    }
+
+    #[test]
+    fn tile_colors_over_theme() {
+        let mut tilemap = build_test_tilemap();
+        tilemap.set_tile_color(Vector2::new(1, 0), Some(Color::Red));
+        tilemap.set_tile_color(Vector2::new(0, 1), Some(Color::Blue));
+
+        let mut theme = Theme::new();
+        theme.set('#', Color::Green);
+        assert_eq!(AnsiRenderer::with_theme(theme.clone()).render(&tilemap),
+                   "\x1b[32m#\x1b[31m#\x1b[0m-\n\x1b[34m-\x1b[0m-&");
+        assert_eq!(HtmlRenderer::with_theme(theme).render(&tilemap),
+                   "<pre><span style=\"color:#0dbc79\">#</span><span style=\"color:#cd3131\">#</span>-\n\
+                    <span style=\"color:#2472c8\">-</span>-&amp;</pre>");
+    }
+
+    #[test]
+    fn html() {
+        let tilemap = build_test_tilemap();
+        let mut renderer = HtmlRenderer::new();
+        assert_eq!(renderer.render(&tilemap), "<pre>##-\n--&amp;</pre>");
+
+        renderer.set_color('#', Color::Rgb(0, 255, 0));
+        assert_eq!(renderer.render(&tilemap), "<pre><span style=\"color:#00ff00\">##</span>-\n--&amp;</pre>");
+    }
+}
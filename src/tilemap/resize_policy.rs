@@ -0,0 +1,141 @@
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Describes what should happen to [`crate::Tile`]s that end up outside of the new bounds of the
+/// [`crate::Tilemap`] during [`crate::Tilemap::resize()`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum OverflowPolicy {
+    /// # Description
+    /// Tiles that are out of new bounds are silently removed.
+    Truncate,
+    /// # Description
+    /// Resize is aborted with an error if at least one tile is out of new bounds.
+    /// Tilemap stays unchanged in that case.
+    Error,
+    /// # Description
+    /// Tiles that are out of new bounds are wrapped around to the opposite side of the tilemap.
+    Wrap,
+}
+
+/// # Description
+/// Describes which point of the [`crate::Tilemap`] stays in place during
+/// [`crate::Tilemap::resize()`]. All tiles are shifted accordingly.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Anchor {
+    /// # Description
+    /// Top left corner stays in place. Tiles are not moved.
+    TopLeft,
+    /// # Description
+    /// Top right corner stays in place. Tiles are moved along X axis.
+    TopRight,
+    /// # Description
+    /// Bottom left corner stays in place. Tiles are moved along Y axis.
+    BottomLeft,
+    /// # Description
+    /// Bottom right corner stays in place. Tiles are moved along both axes.
+    BottomRight,
+    /// # Description
+    /// Center stays in place. Tiles are moved along both axes by the half of the size difference.
+    Center,
+}
+
+/// # Description
+/// Policy that is used by [`crate::Tilemap::resize()`] to determine how tiles should be treated.
+///
+/// # Example
+/// ```rust
+/// let policy = char_tilemap::ResizePolicy::new(char_tilemap::OverflowPolicy::Truncate,
+///                                              char_tilemap::Anchor::Center);
+/// ```
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct ResizePolicy {
+    /// # Description
+    /// What should happen to tiles that are out of new bounds.
+    pub overflow: OverflowPolicy,
+    /// # Description
+    /// Which point of the tilemap stays in place.
+    pub anchor: Anchor,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl ResizePolicy {
+    /// # Description
+    /// Creates new [`ResizePolicy`] using specified values.
+    ///
+    /// # Arguments
+    /// * `overflow: OverflowPolicy` - What should happen to tiles that are out of new bounds.
+    /// * `anchor: Anchor` - Which point of the tilemap stays in place.
+    ///
+    /// # Return
+    /// Newly created [`ResizePolicy`] with specified values.
+    pub fn new(overflow: OverflowPolicy, anchor: Anchor) -> ResizePolicy {
+        return ResizePolicy { overflow, anchor };
+    }
+}
+
+impl Anchor {
+    /// # Description
+    /// Calculates offset by which tiles should be moved when tilemap is resized from `old_size`
+    /// to `new_size`.
+    ///
+    /// # Arguments
+    /// * `old_size: crate::Vector2` - Size of the tilemap before resize.
+    /// * `new_size: crate::Vector2` - Size of the tilemap after resize.
+    ///
+    /// # Return
+    /// Offset as a pair of signed values `(x, y)`.
+    pub(crate) fn offset(&self, old_size: crate::Vector2, new_size: crate::Vector2) -> (isize, isize) {
+        let delta_x = new_size.x as isize - old_size.x as isize;
+        let delta_y = new_size.y as isize - old_size.y as isize;
+
+        return match self {
+            Anchor::TopLeft => (0, 0),
+            Anchor::TopRight => (delta_x, 0),
+            Anchor::BottomLeft => (0, delta_y),
+            Anchor::BottomRight => (delta_x, delta_y),
+            Anchor::Center => (delta_x / 2, delta_y / 2),
+        };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl Default for ResizePolicy {
+    /// # Description
+    /// Creates default [`ResizePolicy`] that truncates out of bounds tiles and anchors to the
+    /// top left corner.
+    ///
+    /// # Return
+    /// [`ResizePolicy`] with [`OverflowPolicy::Truncate`] and [`Anchor::TopLeft`].
+    fn default() -> Self {
+        return ResizePolicy::new(OverflowPolicy::Truncate, Anchor::TopLeft);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Anchor, Vector2};
+
+    const OLD_SIZE: Vector2 = Vector2 { x: 4, y: 4 };
+    const NEW_SIZE: Vector2 = Vector2 { x: 8, y: 2 };
+
+    #[test]
+    fn offset() {
+        assert_eq!(Anchor::TopLeft.offset(OLD_SIZE, NEW_SIZE), (0, 0));
+        assert_eq!(Anchor::TopRight.offset(OLD_SIZE, NEW_SIZE), (4, 0));
+        assert_eq!(Anchor::BottomLeft.offset(OLD_SIZE, NEW_SIZE), (0, -2));
+        assert_eq!(Anchor::BottomRight.offset(OLD_SIZE, NEW_SIZE), (4, -2));
+        assert_eq!(Anchor::Center.offset(OLD_SIZE, NEW_SIZE), (2, -1));
+    }
+}
@@ -0,0 +1,155 @@
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Small deterministic pseudo-random number generator (SplitMix64).
+/// Same seed always produces the same sequence, so everything that is built with it
+/// can be reproduced.
+///
+/// # Example
+/// ```rust
+/// let mut first = char_tilemap::Rng::new(7);
+/// let mut second = char_tilemap::Rng::new(7);
+/// assert_eq!(first.next_u64(), second.next_u64());
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Rng {
+    /// # Description
+    /// Current state of the generator.
+    state: u64,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Rng {
+    /// # Description
+    /// Creates new [`Rng`] using specified seed.
+    ///
+    /// # Arguments
+    /// * `seed: u64` - Seed of the generator.
+    ///
+    /// # Return
+    /// New instance of the [`Rng`].
+    pub fn new(seed: u64) -> Rng {
+        return Rng { state: seed };
+    }
+
+    /// # Description
+    /// Generates next random [`u64`].
+    ///
+    /// # Return
+    /// Random [`u64`] value.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        return value ^ (value >> 31);
+    }
+
+    /// # Description
+    /// Generates random [`f64`] in range `[0.0, 1.0)`.
+    ///
+    /// # Return
+    /// Random [`f64`] value.
+    pub fn next_f64(&mut self) -> f64 {
+        return (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+    }
+
+    /// # Description
+    /// Generates random [`usize`] in range `[0, bound)`.
+    ///
+    /// # Arguments
+    /// * `bound: usize` - Exclusive upper bound.
+    ///
+    /// # Return
+    /// Random [`usize`] value. Returns `0` if `bound` is `0`.
+    pub fn next_below(&mut self, bound: usize) -> usize {
+        if bound == 0 {
+            return 0;
+        }
+
+        return (((self.next_u64() as u128) * (bound as u128)) >> 64) as usize;
+    }
+
+    /// # Description
+    /// Returns `true` with the specified probability.
+    ///
+    /// # Arguments
+    /// * `probability: f64` - Probability in range `[0.0, 1.0]`.
+    ///
+    /// # Return
+    /// Random [`bool`] value.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        return self.next_f64() < probability;
+    }
+
+    /// # Description
+    /// Shuffles slice in place using Fisher-Yates algorithm.
+    ///
+    /// # Arguments
+    /// * `values: &mut [T]` - Values that should be shuffled.
+    pub fn shuffle<T>(&mut self, values: &mut [T]) {
+        for index in (1..values.len()).rev() {
+            values.swap(index, self.next_below(index + 1));
+        }
+    }
+
+    /// # Description
+    /// Creates new independent [`Rng`] seeded from this one.
+    ///
+    /// # Return
+    /// New instance of the [`Rng`].
+    pub fn split(&mut self) -> Rng {
+        return Rng::new(self.next_u64());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::Rng;
+
+    const SEED: u64 = 42;
+
+    #[test]
+    fn deterministic() {
+        let mut first = Rng::new(SEED);
+        let mut second = Rng::new(SEED);
+
+        for _ in 0..100 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+        assert_ne!(Rng::new(SEED).next_u64(), Rng::new(SEED + 1).next_u64());
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(SEED);
+
+        for _ in 0..1000 {
+            let value = rng.next_f64();
+            assert!((0.0..1.0).contains(&value));
+            assert!(rng.next_below(7) < 7);
+        }
+        assert_eq!(rng.next_below(0), 0);
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut values: Vec<usize> = (0..20).collect();
+        Rng::new(SEED).shuffle(&mut values);
+
+        assert_ne!(values, (0..20).collect::<Vec<usize>>());
+        values.sort();
+        assert_eq!(values, (0..20).collect::<Vec<usize>>());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::tilemap::{Rng, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Returns hashes of tiles of every row. Rows with the same tiles at the same columns have
+    /// the same hash regardless of how they were changed, so changed rows can be detected by
+    /// comparing hashes instead of built strings. Hashes do not depend on the empty tile.
+    ///
+    /// Hashes are calculated from all tiles, unless they are kept, see
+    /// [`Tilemap::enable_row_hashes()`].
+    ///
+    /// # Return
+    /// [`Vec`] with a hash for every row.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("#.\n.#\n#.", '.');
+    /// let hashes = tilemap.row_hashes();
+    /// assert_eq!(hashes[0], hashes[2]);
+    /// assert_ne!(hashes[0], hashes[1]);
+    ///
+    /// tilemap.set(char_tilemap::Vector2::new(1, 2), '@');
+    /// assert_ne!(tilemap.row_hashes()[2], hashes[2]);
+    /// ```
+    pub fn row_hashes(&self) -> Vec<u64> {
+        let mut hashes = match &self.row_hashes {
+            Some(hashes) => hashes.clone(),
+            None => calculate_row_hashes(self),
+        };
+
+        hashes.resize(self.size.y, 0);
+        return hashes;
+    }
+
+    /// # Description
+    /// Starts to keep hashes of rows and update them on every change of a tile, so
+    /// [`Tilemap::row_hashes()`] does not visit all tiles. Hashes are recalculated if they are
+    /// already kept.
+    pub fn enable_row_hashes(&mut self) {
+        self.row_hashes = Some(calculate_row_hashes(self));
+    }
+
+    /// # Description
+    /// Stops to keep hashes of rows, see [`Tilemap::enable_row_hashes()`].
+    pub fn disable_row_hashes(&mut self) {
+        self.row_hashes = None;
+    }
+
+    /// # Description
+    /// Updates hash of the row after the tile was changed, if hashes are kept.
+    pub(crate) fn update_row_hash(&mut self, position: Vector2, old: Option<char>, new: Option<char>) {
+        if let Some(hashes) = &mut self.row_hashes {
+            if hashes.len() <= position.y {
+                hashes.resize(position.y + 1, 0);
+            }
+
+            let hash = |value: Option<char>| value.map_or(0, |value| tile_hash(position.x, value));
+            hashes[position.y] ^= hash(old) ^ hash(new);
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Returns hash of the tile at the column. Hashes of all tiles of a row are combined by XOR, so
+/// a single tile can be added to or removed from the row hash.
+fn tile_hash(x: usize, value: char) -> u64 {
+    return Rng::new(Rng::new(value as u64).next_u64() ^ x as u64).next_u64();
+}
+
+/// # Description
+/// Calculates hashes of all rows from tiles.
+fn calculate_row_hashes(tilemap: &Tilemap) -> Vec<u64> {
+    let mut hashes = vec![0; tilemap.size.y];
+    for tile in tilemap.tiles.iter() {
+        hashes[tile.position.y] ^= tile_hash(tile.position.x, tile.value);
+    }
+
+    return hashes;
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Orientation, Tilemap, Vector2};
+
+    #[test]
+    fn row_hashes() {
+        let tilemap = Tilemap::from_string("ab\nba\nab\n..", '.');
+        let hashes = tilemap.row_hashes();
+
+        assert_eq!(hashes.len(), 4);
+        assert_eq!(hashes[0], hashes[2]);
+        assert_ne!(hashes[0], hashes[1]);
+        assert_eq!(hashes[3], 0);
+    }
+
+    #[test]
+    fn enable_row_hashes() {
+        let mut tilemap = Tilemap::from_string("ab\nba\nab", '.');
+        tilemap.enable_row_hashes();
+
+        tilemap.set(Vector2::new(0, 1), 'a');
+        tilemap.set(Vector2::new(1, 1), 'b');
+        tilemap.remove_tile(Vector2::ZERO).unwrap();
+        tilemap.set(Vector2::new(3, 4), '#');
+
+        let tracked = tilemap.row_hashes();
+        tilemap.disable_row_hashes();
+        assert_eq!(tracked, tilemap.row_hashes());
+        assert_eq!(tracked[1], tracked[2]);
+
+        tilemap.enable_row_hashes();
+        let oriented = tilemap.oriented(Orientation::Transpose);
+        let tracked = oriented.row_hashes();
+        let mut untracked = oriented.clone();
+        untracked.disable_row_hashes();
+        assert_eq!(tracked, untracked.row_hashes());
+    }
+}
@@ -0,0 +1,221 @@
+use crate::tilemap::{Tile, TileSource, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Compressed representation of the [`Tilemap`] where every row is stored as runs of equal
+/// values. Maps dominated by long horizontal walls and floors take several times less memory,
+/// and are built by repeating values instead of looking up every tile.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::TileSource;
+///
+/// let tilemap = char_tilemap::Tilemap::from_string("########\n#......#\n########", ' ');
+/// let runs = tilemap.to_row_runs();
+///
+/// assert_eq!(runs.run_count(), 5);
+/// assert_eq!(runs.build(), tilemap.build());
+/// assert_eq!(char_tilemap::Tilemap::from_row_runs(&runs).build(), tilemap.build());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowRuns {
+    /// # Description
+    /// Value that is used for empty tiles during the build.
+    pub empty_tile: char,
+    /// # Description
+    /// Size of the represented [`Tilemap`].
+    size: Vector2,
+    /// # Description
+    /// Runs of every row as values and lengths. Runs of empty tiles have [`None`] value. Runs of
+    /// every row cover the whole width.
+    rows: Vec<Vec<(Option<char>, usize)>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl RowRuns {
+    /// # Description
+    /// Returns runs of the row as values and lengths, from left to right. Runs of empty tiles
+    /// have [`None`] value.
+    ///
+    /// # Arguments
+    /// * `y: usize` - Index of the row.
+    ///
+    /// # Return
+    /// * [`Some`] with runs that cover the whole row.
+    /// * [`None`] if row is out of bounds.
+    pub fn row(&self, y: usize) -> Option<&[(Option<char>, usize)]> {
+        return self.rows.get(y).map(|runs| runs.as_slice());
+    }
+
+    /// # Description
+    /// Returns number of runs in all rows.
+    pub fn run_count(&self) -> usize {
+        return self.rows.iter().map(|runs| runs.len()).sum();
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Compresses [`Tilemap`] into [`RowRuns`]. Only values of tiles are kept, grapheme clusters,
+    /// colors and other data of positions are not.
+    ///
+    /// # Return
+    /// New instance of [`RowRuns`] with the same size and tiles.
+    pub fn to_row_runs(&self) -> RowRuns {
+        let mut rows = vec![Vec::new(); self.size.y];
+        let mut x = 0;
+
+        for tile in self.tiles.iter() {
+            let runs: &mut Vec<(Option<char>, usize)> = &mut rows[tile.position.y];
+            if runs.is_empty() {
+                x = 0;
+            }
+            if tile.position.x > x {
+                runs.push((None, tile.position.x - x));
+            }
+
+            match runs.last_mut() {
+                Some((Some(value), length)) if *value == tile.value => *length += 1,
+                _ => runs.push((Some(tile.value), 1)),
+            }
+            x = tile.position.x + 1;
+        }
+
+        for runs in rows.iter_mut() {
+            let width: usize = runs.iter().map(|(_, length)| *length).sum();
+            if width < self.size.x {
+                runs.push((None, self.size.x - width));
+            }
+        }
+
+        return RowRuns { empty_tile: self.empty_tile, size: self.size, rows };
+    }
+
+    /// # Description
+    /// Creates new [`Tilemap`] from [`RowRuns`].
+    ///
+    /// # Arguments
+    /// * `runs: &RowRuns` - Compressed tiles.
+    ///
+    /// # Return
+    /// New instance of the [`Tilemap`] with the same size and tiles.
+    pub fn from_row_runs(runs: &RowRuns) -> Tilemap {
+        let mut tilemap = Tilemap::new(runs.empty_tile);
+        for (y, row) in runs.rows.iter().enumerate() {
+            let mut x = 0;
+            for (value, length) in row {
+                if let Some(value) = value {
+                    for offset in 0..*length {
+                        tilemap.tiles.push(Tile { position: Vector2::new(x + offset, y), value: *value });
+                    }
+                }
+                x += length;
+            }
+        }
+
+        tilemap.size = runs.size;
+        return tilemap;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl TileSource for RowRuns {
+    /// # Description
+    /// Returns size of the represented [`Tilemap`].
+    fn size(&self) -> Vector2 {
+        return self.size;
+    }
+
+    /// # Description
+    /// Returns value of the run that covers the position.
+    fn get(&self, position: Vector2) -> Option<char> {
+        let mut x = 0;
+        for (value, length) in self.rows.get(position.y)? {
+            x += length;
+            if position.x < x {
+                return *value;
+            }
+        }
+
+        return None;
+    }
+
+    /// # Description
+    /// Returns value of the empty tile.
+    fn empty_tile(&self) -> char {
+        return self.empty_tile;
+    }
+
+    /// # Description
+    /// Builds rows by repeating values of their runs.
+    fn build(&self) -> String {
+        let mut result = String::with_capacity(self.size.y * (self.size.x + 1));
+        for (y, runs) in self.rows.iter().enumerate() {
+            if y > 0 {
+                result.push('\n');
+            }
+
+            for (value, length) in runs {
+                result.extend(std::iter::repeat_n(value.unwrap_or(self.empty_tile), *length));
+            }
+        }
+
+        return result;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{TileSource, Tilemap, Vector2};
+
+    #[test]
+    fn to_row_runs() {
+        let mut tilemap = Tilemap::from_string("###..##\n.......\n..@@#..", '.');
+        tilemap.resize(Vector2::new(7, 4), Default::default()).unwrap();
+        let runs = tilemap.to_row_runs();
+
+        assert_eq!(runs.row(0).unwrap(), &[(Some('#'), 3), (None, 2), (Some('#'), 2)]);
+        assert_eq!(runs.row(1).unwrap(), &[(None, 7)]);
+        assert_eq!(runs.row(2).unwrap(), &[(None, 2), (Some('@'), 2), (Some('#'), 1), (None, 2)]);
+        assert_eq!(runs.row(3).unwrap(), &[(None, 7)]);
+        assert_eq!(runs.row(4), None);
+        assert_eq!(runs.run_count(), 9);
+        assert_eq!(Tilemap::new('.').to_row_runs().run_count(), 0);
+    }
+
+    #[test]
+    fn source() {
+        let tilemap = Tilemap::from_string("###..##\n.......\n..@@#..", '.');
+        let runs = tilemap.to_row_runs();
+
+        assert_eq!(runs.size(), tilemap.size());
+        assert_eq!(runs.get(Vector2::new(3, 2)), Some('@'));
+        assert_eq!(runs.get(Vector2::new(3, 0)), None);
+        assert_eq!(runs.get(Vector2::new(7, 0)), None);
+        assert_eq!(runs.build(), tilemap.build());
+    }
+
+    #[test]
+    fn from_row_runs() {
+        let tilemap = Tilemap::from_string("###..##\n.......\n..@@#..", '.');
+        let restored = Tilemap::from_row_runs(&tilemap.to_row_runs());
+
+        assert_eq!(restored.size(), tilemap.size());
+        assert_eq!(restored.build(), tilemap.build());
+        assert_eq!(restored.get(Vector2::new(4, 2)), Some('#'));
+        assert_eq!(restored.to_row_runs(), tilemap.to_row_runs());
+    }
+}
@@ -0,0 +1,147 @@
+use crate::tilemap::{Tile, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Builds a single row of the [`Tilemap`], same as the corresponding line of
+    /// [`Tilemap::build()`] with cell width 1. Only tiles of the row are visited.
+    ///
+    /// # Arguments
+    /// * `y: usize` - Index of the row.
+    ///
+    /// # Return
+    /// * [`Some`] with the row where positions without tiles are filled with the empty tile.
+    /// * [`None`] if row is out of bounds.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("#..\n.@.", '.');
+    /// assert_eq!(tilemap.row(1), Some(String::from(".@.")));
+    /// assert_eq!(tilemap.row(2), None);
+    /// ```
+    pub fn row(&self, y: usize) -> Option<String> {
+        if y >= self.size.y {
+            return None;
+        }
+
+        let start = self.tiles.partition_point(|tile| tile.position < Vector2::new(0, y));
+        let end = self.tiles.partition_point(|tile| tile.position.y <= y);
+        return Some(self.build_row_tiles(&self.tiles[start..end]));
+    }
+
+    /// # Description
+    /// Builds all rows of the [`Tilemap`] as separate strings, same as lines of
+    /// [`Tilemap::build()`] with cell width 1.
+    ///
+    /// # Return
+    /// [`Vec`] with a [`String`] for every row.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("#..\n.@.", '.');
+    /// assert_eq!(tilemap.rows_as_strings(), vec!["#..", ".@."]);
+    /// ```
+    pub fn rows_as_strings(&self) -> Vec<String> {
+        let mut rows = Vec::with_capacity(self.size.y);
+        let mut start = 0;
+
+        for y in 0..self.size.y {
+            let end = start + self.tiles[start..].partition_point(|tile| tile.position.y <= y);
+            rows.push(self.build_row_tiles(&self.tiles[start..end]));
+            start = end;
+        }
+
+        return rows;
+    }
+
+    /// # Description
+    /// Searches every row of the [`Tilemap`] for the pattern, including overlapping occurrences.
+    /// Empty tiles are matched by the empty tile char, same as in [`Tilemap::row()`].
+    ///
+    /// # Arguments
+    /// * `pattern: &str` - Sequence of tile values that should be found within a single row.
+    ///
+    /// # Return
+    /// [`Vec`] with positions of the first tile of every occurrence, in the row-major order.
+    /// Empty if pattern is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("CAT..\n.CAT.", '.');
+    /// assert_eq!(tilemap.find_in_rows("CAT"), vec![char_tilemap::Vector2::ZERO, char_tilemap::Vector2::new(1, 1)]);
+    /// ```
+    pub fn find_in_rows(&self, pattern: &str) -> Vec<Vector2> {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let mut positions = Vec::new();
+        if pattern.is_empty() {
+            return positions;
+        }
+
+        for (y, row) in self.rows_as_strings().iter().enumerate() {
+            let row: Vec<char> = row.chars().collect();
+            for (x, window) in row.windows(pattern.len()).enumerate() {
+                if window == pattern.as_slice() {
+                    positions.push(Vector2::new(x, y));
+                }
+            }
+        }
+
+        return positions;
+    }
+
+    /// # Description
+    /// Builds row that contains the specified tiles, which are sorted by their position.
+    fn build_row_tiles(&self, tiles: &[Tile]) -> String {
+        let mut row = String::with_capacity(self.size.x);
+        let mut x = 0;
+
+        for tile in tiles {
+            row.extend(std::iter::repeat_n(self.empty_tile, tile.position.x - x));
+            row.push(tile.value);
+            x = tile.position.x + 1;
+        }
+
+        row.extend(std::iter::repeat_n(self.empty_tile, self.size.x - x));
+        return row;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[test]
+    fn rows() {
+        let mut tilemap = Tilemap::from_string("#...\n....\n.@.X", '.');
+        tilemap.resize(Vector2::new(4, 4), Default::default()).unwrap();
+
+        let rows = tilemap.rows_as_strings();
+        assert_eq!(rows, vec!["#...", "....", ".@.X", "...."]);
+        assert_eq!(rows.join("\n"), tilemap.build());
+        for (y, row) in rows.iter().enumerate() {
+            assert_eq!(tilemap.row(y).as_ref(), Some(row));
+        }
+
+        assert_eq!(tilemap.row(4), None);
+        assert!(Tilemap::new('.').rows_as_strings().is_empty());
+    }
+
+    #[test]
+    fn find_in_rows() {
+        let tilemap = Tilemap::from_string("ABAB.\n.BABA\nAB..A", '.');
+
+        assert_eq!(tilemap.find_in_rows("ABA"), vec![Vector2::new(0, 0), Vector2::new(2, 1)]);
+        assert_eq!(tilemap.find_in_rows("B."), vec![Vector2::new(3, 0), Vector2::new(1, 2)]);
+        assert_eq!(tilemap.find_in_rows("..A"), vec![Vector2::new(2, 2)]);
+        assert!(tilemap.find_in_rows("ABABAB").is_empty());
+        assert!(tilemap.find_in_rows("A\nB").is_empty());
+        assert!(tilemap.find_in_rows("").is_empty());
+    }
+}
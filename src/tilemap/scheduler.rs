@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use crate::tilemap::{EntityId, EntityLayer, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Energy that an entity spends on a single turn of the [`Scheduler`]. Entity with the speed
+/// equal to it acts once per tick.
+pub const ACTION_COST: u64 = 100;
+
+/// # Description
+/// Energy of an entity of the [`Scheduler`].
+#[derive(Debug, Copy, Clone)]
+struct ScheduledEntity {
+    /// # Description
+    /// Energy gained every tick.
+    speed: u64,
+    /// # Description
+    /// Accumulated energy.
+    energy: u64,
+}
+
+/// # Description
+/// Turn-based scheduler of entities with positions on the map. Every tick all entities gain
+/// energy equal to their speed, and entities with at least [`ACTION_COST`] energy take turns,
+/// so entities with speed 200 act twice as often as entities with speed 100. Positions and
+/// glyphs of entities are kept by the [`EntityLayer`], which can be drawn over the terrain.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::{Scheduler, Vector2};
+///
+/// let mut scheduler = Scheduler::new('.');
+/// let player = scheduler.spawn(Vector2::ZERO, '@', 100).unwrap();
+/// let snail = scheduler.spawn(Vector2::new(2, 0), 's', 50).unwrap();
+///
+/// let turns: Vec<_> = (0..3).map(|_| scheduler.next_turn().unwrap()).collect();
+/// assert_eq!(turns, vec![player, player, snail]);
+///
+/// scheduler.move_entity(player, Vector2::new(1, 0)).unwrap();
+/// assert_eq!(scheduler.layer().build(), ".@s");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scheduler {
+    /// # Description
+    /// Positions and glyphs of all entities.
+    layer: EntityLayer,
+    /// # Description
+    /// Energy of entities by their identifiers, ordered by spawning.
+    entities: BTreeMap<EntityId, ScheduledEntity>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Scheduler {
+    /// # Description
+    /// Creates new [`Scheduler`] without entities.
+    ///
+    /// # Arguments
+    /// * `empty_tile: char` - Empty tile of the entity layer.
+    ///
+    /// # Return
+    /// New instance of the [`Scheduler`].
+    pub fn new(empty_tile: char) -> Scheduler {
+        return Scheduler { layer: EntityLayer::new(empty_tile), entities: BTreeMap::new() };
+    }
+
+    /// # Description
+    /// Returns layer with glyphs of all entities.
+    pub fn layer(&self) -> &Tilemap {
+        return self.layer.tilemap();
+    }
+
+    /// # Description
+    /// Returns positions and glyphs of all entities.
+    pub fn entities(&self) -> &EntityLayer {
+        return &self.layer;
+    }
+
+    /// # Description
+    /// Adds new entity without energy and draws it on the layer.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the entity.
+    /// * `glyph: char` - Value with which entity is drawn.
+    /// * `speed: u64` - Energy gained every tick. Entities with speed 0 never act.
+    ///
+    /// # Return
+    /// * [`Ok`] with identifier of the new entity.
+    /// * [`Err`] if position is occupied by another entity. Contains error message.
+    pub fn spawn(&mut self, position: Vector2, glyph: char, speed: u64) -> Result<EntityId, String> {
+        let id = self.layer.spawn(position, glyph)?;
+        self.entities.insert(id, ScheduledEntity { speed, energy: 0 });
+        return Ok(id);
+    }
+
+    /// # Description
+    /// Removes entity and its glyph from the layer.
+    ///
+    /// # Arguments
+    /// * `id: EntityId` - Identifier of the entity.
+    ///
+    /// # Return
+    /// `true` if entity existed, `false` otherwise.
+    pub fn despawn(&mut self, id: EntityId) -> bool {
+        self.entities.remove(&id);
+        return self.layer.despawn(id).is_some();
+    }
+
+    /// # Description
+    /// Moves entity and its glyph on the layer.
+    ///
+    /// # Arguments
+    /// * `id: EntityId` - Identifier of the entity.
+    /// * `position: Vector2` - New position of the entity.
+    ///
+    /// # Return
+    /// * [`Ok`] if entity was moved.
+    /// * [`Err`] if entity does not exist, or position is occupied by another entity. Contains
+    ///   error message.
+    pub fn move_entity(&mut self, id: EntityId, position: Vector2) -> Result<(), String> {
+        return self.layer.move_entity(id, position);
+    }
+
+    /// # Description
+    /// Returns position of the entity.
+    ///
+    /// # Return
+    /// * [`Some`] with the position.
+    /// * [`None`] if entity does not exist.
+    pub fn position(&self, id: EntityId) -> Option<Vector2> {
+        return self.layer.position(id);
+    }
+
+    /// # Description
+    /// Returns entity at the position.
+    ///
+    /// # Return
+    /// * [`Some`] with identifier of the entity.
+    /// * [`None`] if there is no entity at the position.
+    pub fn entity_at(&self, position: Vector2) -> Option<EntityId> {
+        return self.layer.entity_at(position);
+    }
+
+    /// # Description
+    /// Returns number of entities.
+    pub fn len(&self) -> usize {
+        return self.entities.len();
+    }
+
+    /// # Description
+    /// Checks if there are no entities.
+    pub fn is_empty(&self) -> bool {
+        return self.entities.is_empty();
+    }
+
+    /// # Description
+    /// Returns entity that takes the next turn and spends [`ACTION_COST`] of its energy. If no
+    /// entity has enough energy, ticks pass until one does. The entity with the most energy acts
+    /// first, ties are resolved in order of spawning.
+    ///
+    /// # Return
+    /// * [`Some`] with identifier of the acting entity.
+    /// * [`None`] if no entity can ever act.
+    pub fn next_turn(&mut self) -> Option<EntityId> {
+        let ready = |entity: &ScheduledEntity| entity.energy >= ACTION_COST;
+        if !self.entities.values().any(ready) {
+            let ticks = self.entities.values()
+                .filter(|entity| entity.speed > 0)
+                .map(|entity| (ACTION_COST - entity.energy).div_ceil(entity.speed))
+                .min()?;
+            for entity in self.entities.values_mut() {
+                entity.energy = entity.energy.saturating_add(entity.speed.saturating_mul(ticks));
+            }
+        }
+
+        let (id, entity) = self.entities.iter_mut()
+            .filter(|(_, entity)| ready(entity))
+            .rev()
+            .max_by_key(|(_, entity)| entity.energy)?;
+        entity.energy -= ACTION_COST;
+        return Some(*id);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Scheduler, Vector2};
+
+    #[test]
+    fn next_turn() {
+        let mut scheduler = Scheduler::new('.');
+        assert_eq!(scheduler.next_turn(), None);
+
+        let fast = scheduler.spawn(Vector2::ZERO, 'f', 300).unwrap();
+        let normal = scheduler.spawn(Vector2::ONE, 'n', 100).unwrap();
+        let statue = scheduler.spawn(Vector2::new(2, 2), 's', 0).unwrap();
+
+        let turns: Vec<_> = (0..8).map(|_| scheduler.next_turn().unwrap()).collect();
+        assert_eq!(turns, vec![fast, fast, fast, normal, fast, fast, fast, normal]);
+        assert!(!turns.contains(&statue));
+
+        assert!(scheduler.despawn(fast));
+        assert!(!scheduler.despawn(fast));
+        assert_eq!(scheduler.next_turn(), Some(normal));
+        assert!(scheduler.despawn(normal));
+        assert_eq!(scheduler.next_turn(), None);
+        assert_eq!(scheduler.len(), 1);
+    }
+
+    #[test]
+    fn layer() {
+        let mut scheduler = Scheduler::new('.');
+        let player = scheduler.spawn(Vector2::ZERO, '@', 100).unwrap();
+        let goblin = scheduler.spawn(Vector2::new(2, 1), 'g', 100).unwrap();
+        match scheduler.spawn(Vector2::ZERO, 'x', 100) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true),
+        }
+        assert_eq!(scheduler.layer().build(), "@..\n..g");
+
+        scheduler.move_entity(player, Vector2::new(1, 1)).unwrap();
+        match scheduler.move_entity(player, Vector2::new(2, 1)) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true),
+        }
+        assert_eq!(scheduler.layer().build(), "...\n.@g");
+        assert_eq!(scheduler.entity_at(Vector2::new(1, 1)), Some(player));
+        assert_eq!(scheduler.entity_at(Vector2::ZERO), None);
+
+        scheduler.despawn(goblin);
+        assert_eq!(scheduler.layer().build(), "...\n.@.");
+        assert_eq!(scheduler.position(goblin), None);
+        match scheduler.move_entity(goblin, Vector2::ZERO) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true),
+        }
+    }
+}
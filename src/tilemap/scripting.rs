@@ -0,0 +1,207 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use rhai::{Array, Dynamic, Engine, EvalAltResult, INT};
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Maximum number of operations that a single script can execute, so endless loops are stopped.
+const MAX_SCRIPT_OPERATIONS: u64 = 10_000_000;
+
+/// # Description
+/// Maximum depth of function calls in a script.
+const MAX_SCRIPT_CALL_LEVELS: usize = 64;
+
+/// # Description
+/// Maximum length of strings and arrays that a script can create.
+const MAX_SCRIPT_DATA_SIZE: usize = 65_536;
+
+/// # Description
+/// Maximum size of the tilemap on both axes that a script can reach by setting tiles, unless
+/// [`Tilemap::limits`] set another maximum size, or the tilemap is already larger.
+const MAX_SCRIPT_SIZE: usize = 1024;
+
+/// # Description
+/// Maximum number of tiles that a script can write with `fill_rect`, since every call counts as
+/// a single operation.
+const MAX_SCRIPT_WRITES: u64 = 10_000_000;
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Runs the [rhai](https://rhai.rs) script against the [`Tilemap`]. Script has access only to
+    /// the following functions, and can not touch files, environment or anything else of the host:
+    /// * `get(x, y)` - Value of the tile as a char, or `()` if there is no tile.
+    /// * `set(x, y, value)` - Sets the tile to the char, same as [`Tilemap::set()`].
+    /// * `size()` - Size of the tilemap as an array `[x, y]`.
+    /// * `fill_rect(x, y, width, height, value)` - Sets all tiles of the rectangle to the char.
+    ///
+    /// # Arguments
+    /// * `script: &str` - Source code of the script.
+    ///
+    /// # Return
+    /// * [`Ok`] if script was executed.
+    /// * [`Err`] if script could not be compiled, failed, used negative coordinates, set tiles
+    ///   out of its bounds or exceeded the limit of operations or written tiles. Contains error
+    ///   message.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("....\n....", '.');
+    /// tilemap.run_script(r#"
+    ///     let size = size();
+    ///     fill_rect(0, 0, size[0], 1, '#');
+    ///     if get(3, 1) == () { set(3, 1, '@'); }
+    /// "#).unwrap();
+    /// assert_eq!(tilemap.build(), "####\n...@");
+    /// ```
+    ///
+    /// # Notes
+    /// Script works with a copy of the [`Tilemap`], so if it fails, no changes are applied.
+    pub fn run_script(&mut self, script: &str) -> Result<(), String> {
+        let shared = Rc::new(RefCell::new(self.clone()));
+        script_engine(&shared).run(script).map_err(|error| format!("Script failed: {error}"))?;
+
+        *self = match Rc::try_unwrap(shared) {
+            Ok(tilemap) => tilemap.into_inner(),
+            Err(shared) => shared.borrow().clone(),
+        };
+        return Ok(());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Creates script engine with limits and the restricted API that works with the shared tilemap.
+fn script_engine(tilemap: &Rc<RefCell<Tilemap>>) -> Engine {
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_SCRIPT_OPERATIONS)
+          .set_max_call_levels(MAX_SCRIPT_CALL_LEVELS)
+          .set_max_string_size(MAX_SCRIPT_DATA_SIZE)
+          .set_max_array_size(MAX_SCRIPT_DATA_SIZE)
+          .set_max_map_size(MAX_SCRIPT_DATA_SIZE)
+          .disable_symbol("eval")
+          .on_print(|_| {})
+          .on_debug(|_, _, _| {});
+
+    let shared = Rc::clone(tilemap);
+    engine.register_fn("get", move |x: INT, y: INT| -> Result<Dynamic, Box<EvalAltResult>> {
+        return Ok(shared.borrow().get(script_position(x, y)?).map_or(Dynamic::UNIT, Dynamic::from));
+    });
+
+    let shared = Rc::clone(tilemap);
+    engine.register_fn("set", move |x: INT, y: INT, value: char| -> Result<(), Box<EvalAltResult>> {
+        let position = script_position(x, y)?;
+        let mut tilemap = shared.borrow_mut();
+        check_script_bounds(&tilemap, position, Vector2::ONE)?;
+        tilemap.set(position, value);
+        return Ok(());
+    });
+
+    let shared = Rc::clone(tilemap);
+    engine.register_fn("size", move || -> Array {
+        let size = shared.borrow().size();
+        return vec![Dynamic::from(size.x as INT), Dynamic::from(size.y as INT)];
+    });
+
+    let shared = Rc::clone(tilemap);
+    let written = Cell::new(0u64);
+    engine.register_fn("fill_rect", move |x: INT, y: INT, width: INT, height: INT, value: char|
+                                          -> Result<(), Box<EvalAltResult>> {
+        let position = script_position(x, y)?;
+        let size = script_position(width, height)?;
+        let mut tilemap = shared.borrow_mut();
+        check_script_bounds(&tilemap, position, size)?;
+        written.set(written.get().saturating_add((size.x as u64).saturating_mul(size.y as u64)));
+        if written.get() > MAX_SCRIPT_WRITES {
+            return Err(format!("Script wrote more than {MAX_SCRIPT_WRITES} tiles").into());
+        }
+
+        for y in position.y..position.y + size.y {
+            for x in position.x..position.x + size.x {
+                tilemap.set(Vector2::new(x, y), value);
+            }
+        }
+        return Ok(());
+    });
+
+    return engine;
+}
+
+/// # Description
+/// Converts coordinates passed by the script into a [`Vector2`].
+fn script_position(x: INT, y: INT) -> Result<Vector2, Box<EvalAltResult>> {
+    return match (usize::try_from(x), usize::try_from(y)) {
+        (Ok(x), Ok(y)) => Ok(Vector2::new(x, y)),
+        _ => Err(format!("Invalid coordinates ({x}, {y})").into()),
+    };
+}
+
+/// # Description
+/// Checks that the rectangle set by the script fits into the bounds of the script, see
+/// [`MAX_SCRIPT_SIZE`].
+fn check_script_bounds(tilemap: &Tilemap, position: Vector2, size: Vector2) -> Result<(), Box<EvalAltResult>> {
+    let max_size = tilemap.limits.max_size.unwrap_or(Vector2::new(MAX_SCRIPT_SIZE, MAX_SCRIPT_SIZE));
+    let bounds = Vector2::new(std::cmp::max(max_size.x, tilemap.size.x), std::cmp::max(max_size.y, tilemap.size.y));
+    if position.x.saturating_add(size.x) > bounds.x || position.y.saturating_add(size.y) > bounds.y {
+        return Err(format!("Rectangle at {position} of size {size} is out of bounds {bounds} of the script").into());
+    }
+
+    return Ok(());
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[test]
+    fn run_script() {
+        let mut tilemap = Tilemap::from_string("...\n.#.\n...", '.');
+        tilemap.run_script(r#"
+            let size = size();
+            for y in 0..size[1] {
+                for x in 0..size[0] {
+                    if get(x, y) == '#' { set(x, y, '@'); }
+                }
+            }
+            fill_rect(0, 2, 2, 1, '=');
+        "#).unwrap();
+
+        assert_eq!(tilemap.build(), "...\n.@.\n==.");
+        assert_eq!(tilemap.get(Vector2::ZERO), None);
+    }
+
+    #[test]
+    fn failed_script() {
+        let mut tilemap = Tilemap::from_string("#.\n..", '.');
+        let scripts = [
+            "set(1, 1, '@'); set(-1, 0, '@');",
+            "set(1, 1, '@'); set(4611686018427387903, 0, '#');",
+            "set(1, 1, '@'); fill_rect(0, 0, 1000000000, 1000000000, '#');",
+            "set(1, 1, '@'); loop {}",
+            "eval(\"set(1, 1, '@')\")",
+            "set(1, 1,",
+        ];
+
+        for script in scripts {
+            match tilemap.run_script(script) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+            assert_eq!(tilemap.build(), "#.\n..");
+        }
+    }
+}
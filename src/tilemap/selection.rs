@@ -0,0 +1,339 @@
+use std::collections::BTreeSet;
+use crate::tilemap::{Tilemap, Vector2};
+use crate::tilemap::pathfinding::neighbors;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Set of positions inside of the [`Tilemap`] bounds, created by [`Tilemap::select()`].
+/// Selection can be changed by chained operations and then applied to the [`Tilemap`].
+///
+/// # Example
+/// ```rust
+/// let mut tilemap = char_tilemap::Tilemap::from_string(".....\n..#..\n.....", '.');
+///
+/// tilemap.select(|_, value| value == Some('#')).grow(1).fill(&mut tilemap, '~');
+/// assert_eq!(tilemap.build(), "..~..\n.~~~.\n..~..");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    /// # Description
+    /// Size of the [`Tilemap`] the selection was made in.
+    size: Vector2,
+    /// # Description
+    /// Selected positions.
+    positions: BTreeSet<Vector2>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Selection {
+    /// # Description
+    /// Creates new [`Selection`] of the specified positions. Positions out of bounds are ignored.
+    ///
+    /// # Arguments
+    /// * `size: Vector2` - Size of the [`Tilemap`] the selection is made in.
+    /// * `positions: I` - Selected positions.
+    ///
+    /// # Return
+    /// New instance of the [`Selection`].
+    pub fn new<I: IntoIterator<Item = Vector2>>(size: Vector2, positions: I) -> Selection {
+        let positions = positions.into_iter()
+            .filter(|position| position.x < size.x && position.y < size.y)
+            .collect();
+        return Selection { size, positions };
+    }
+
+    /// # Description
+    /// Returns size of the [`Tilemap`] the selection was made in.
+    ///
+    /// # Return
+    /// [`Vector2`] that stores size of the selection bounds.
+    pub fn size(&self) -> Vector2 {
+        return self.size;
+    }
+
+    /// # Description
+    /// Returns selected positions in the row-major order.
+    ///
+    /// # Return
+    /// Iterator over selected positions.
+    pub fn positions(&self) -> impl Iterator<Item = Vector2> + '_ {
+        return self.positions.iter().copied();
+    }
+
+    /// # Description
+    /// Checks if the position is selected.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position to check.
+    ///
+    /// # Return
+    /// `true` if position is selected, `false` otherwise.
+    pub fn contains(&self, position: Vector2) -> bool {
+        return self.positions.contains(&position);
+    }
+
+    /// # Description
+    /// Returns number of selected positions.
+    pub fn len(&self) -> usize {
+        return self.positions.len();
+    }
+
+    /// # Description
+    /// Checks if nothing is selected.
+    pub fn is_empty(&self) -> bool {
+        return self.positions.is_empty();
+    }
+
+    /// # Description
+    /// Expands selection by the specified number of steps. On each step positions next to the
+    /// selected ones, horizontally or vertically, are selected too.
+    ///
+    /// # Arguments
+    /// * `steps: usize` - Number of steps.
+    ///
+    /// # Return
+    /// Expanded [`Selection`].
+    pub fn grow(mut self, steps: usize) -> Selection {
+        for _ in 0..steps {
+            let border: Vec<Vector2> = self.positions.iter()
+                .flat_map(|position| neighbors(*position))
+                .filter(|position| position.x < self.size.x && position.y < self.size.y)
+                .collect();
+            self.positions.extend(border);
+        }
+
+        return self;
+    }
+
+    /// # Description
+    /// Contracts selection by the specified number of steps. On each step positions next to the
+    /// not selected ones, horizontally or vertically, are deselected. Bounds of the [`Tilemap`]
+    /// do not contract the selection.
+    ///
+    /// # Arguments
+    /// * `steps: usize` - Number of steps.
+    ///
+    /// # Return
+    /// Contracted [`Selection`].
+    pub fn shrink(mut self, steps: usize) -> Selection {
+        for _ in 0..steps {
+            let border: Vec<Vector2> = self.positions.iter()
+                .filter(|position| {
+                    return neighbors(**position)
+                        .filter(|neighbor| neighbor.x < self.size.x && neighbor.y < self.size.y)
+                        .any(|neighbor| !self.positions.contains(&neighbor));
+                })
+                .copied()
+                .collect();
+            for position in border {
+                self.positions.remove(&position);
+            }
+        }
+
+        return self;
+    }
+
+    /// # Description
+    /// Selects all not selected positions within bounds and deselects selected ones.
+    ///
+    /// # Return
+    /// Inverted [`Selection`].
+    pub fn invert(self) -> Selection {
+        let positions = (0..self.size.y)
+            .flat_map(|y| (0..self.size.x).map(move |x| Vector2::new(x, y)))
+            .filter(|position| !self.positions.contains(position))
+            .collect();
+        return Selection { size: self.size, positions };
+    }
+
+    /// # Description
+    /// Sets all selected tiles of the [`Tilemap`] to the value.
+    ///
+    /// # Arguments
+    /// * `tilemap: &mut Tilemap` - [`Tilemap`] to change.
+    /// * `value: char` - New value of the tiles.
+    pub fn fill(&self, tilemap: &mut Tilemap, value: char) {
+        for position in &self.positions {
+            tilemap.set(*position, value);
+        }
+    }
+
+    /// # Description
+    /// Removes all selected tiles from the [`Tilemap`].
+    ///
+    /// # Arguments
+    /// * `tilemap: &mut Tilemap` - [`Tilemap`] to change.
+    pub fn delete(&self, tilemap: &mut Tilemap) {
+        for position in &self.positions {
+            tilemap.take_tile(*position);
+        }
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Selects all positions inside of the [`Tilemap`] that satisfy the predicate.
+    ///
+    /// # Arguments
+    /// * `predicate: F` - Function that receives position and value of the tile at it, and
+    ///   returns `true` if position should be selected.
+    ///
+    /// # Return
+    /// New [`Selection`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("#~#\n~~.", '.');
+    ///
+    /// let selection = tilemap.select(|_, value| value == Some('~'));
+    /// assert_eq!(selection.len(), 3);
+    ///
+    /// selection.invert().delete(&mut tilemap);
+    /// assert_eq!(tilemap.build(), ".~.\n~~.");
+    /// ```
+    pub fn select<F>(&self, predicate: F) -> Selection
+        where F: Fn(Vector2, Option<char>) -> bool {
+        let positions = (0..self.size.y)
+            .flat_map(|y| (0..self.size.x).map(move |x| Vector2::new(x, y)))
+            .filter(|position| predicate(*position, self.get(*position)))
+            .collect();
+        return Selection { size: self.size, positions };
+    }
+
+    /// # Description
+    /// Selects region of horizontally or vertically connected tiles with the same value as the
+    /// tile at the start position, like the magic wand tool. Empty tiles form regions too.
+    ///
+    /// # Arguments
+    /// * `start: Vector2` - Position the region grows from.
+    ///
+    /// # Return
+    /// New [`Selection`]. Empty if start is out of bounds.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("##.\n.#.\n#..", '.');
+    /// let selection = tilemap.select_connected(char_tilemap::Vector2::ZERO);
+    /// assert_eq!(selection.len(), 3);
+    /// ```
+    pub fn select_connected(&self, start: Vector2) -> Selection {
+        return self.select_connected_with(start, |start_value, value| start_value == value);
+    }
+
+    /// # Description
+    /// Selects region of horizontally or vertically connected tiles that are considered similar
+    /// to the tile at the start position. Works the same way as [`Tilemap::select_connected()`].
+    ///
+    /// # Arguments
+    /// * `start: Vector2` - Position the region grows from.
+    /// * `is_similar: F` - Function that receives value of the start tile and value of another
+    ///   tile, and returns `true` if that tile belongs to the region.
+    ///
+    /// # Return
+    /// New [`Selection`]. Empty if start is out of bounds.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string("~\u{2248}#\n..~", '.');
+    /// let water = |value: Option<char>| matches!(value, Some('~' | '\u{2248}'));
+    /// let selection = tilemap.select_connected_with(char_tilemap::Vector2::ZERO, |_, value| water(value));
+    /// assert_eq!(selection.len(), 2);
+    /// ```
+    pub fn select_connected_with<F>(&self, start: Vector2, is_similar: F) -> Selection
+        where F: Fn(Option<char>, Option<char>) -> bool {
+        let mut positions = BTreeSet::new();
+        if start.x >= self.size.x || start.y >= self.size.y {
+            return Selection { size: self.size, positions };
+        }
+
+        let start_value = self.get(start);
+        let mut stack = vec![start];
+        positions.insert(start);
+
+        while let Some(position) = stack.pop() {
+            for neighbor in neighbors(position) {
+                if neighbor.x < self.size.x && neighbor.y < self.size.y && !positions.contains(&neighbor)
+                    && is_similar(start_value, self.get(neighbor)) {
+                    positions.insert(neighbor);
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        return Selection { size: self.size, positions };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Selection, Tilemap, Vector2};
+
+    #[test]
+    fn select() {
+        let tilemap = Tilemap::from_string("#.#\n.#.", '.');
+
+        let selection = tilemap.select(|position, value| value.is_some() && position.y == 0);
+        assert_eq!(selection.positions().collect::<Vec<_>>(), vec![Vector2::ZERO, Vector2::new(2, 0)]);
+        assert_eq!(selection.size(), Vector2::new(3, 2));
+        assert!(selection.contains(Vector2::ZERO));
+        assert!(!selection.contains(Vector2::ONE));
+
+        assert!(tilemap.select(|_, value| value == Some('@')).is_empty());
+        assert_eq!(Selection::new(Vector2::ONE, [Vector2::ZERO, Vector2::ONE]).len(), 1);
+    }
+
+    #[test]
+    fn grow_and_shrink() {
+        let tilemap = Tilemap::from_string(".....\n.....\n..#..\n.....\n.....", '.');
+        let selection = tilemap.select(|_, value| value.is_some());
+
+        let grown = selection.clone().grow(2);
+        assert_eq!(grown.len(), 13);
+        assert_eq!(grown.clone().shrink(2), selection);
+        assert!(selection.clone().shrink(1).is_empty());
+
+        let all = tilemap.select(|_, _| true);
+        assert_eq!(all.clone().shrink(1), all);
+        assert_eq!(all.clone().grow(1), all);
+    }
+
+    #[test]
+    fn invert_fill_delete() {
+        let mut tilemap = Tilemap::from_string("#.\n.#", '.');
+
+        let selection = tilemap.select(|_, value| value.is_some());
+        assert_eq!(selection.clone().invert().invert(), selection);
+
+        selection.clone().invert().fill(&mut tilemap, '~');
+        assert_eq!(tilemap.build(), "#~\n~#");
+
+        selection.delete(&mut tilemap);
+        assert_eq!(tilemap.build(), ".~\n~.");
+        assert_eq!(tilemap.size(), Vector2::new(2, 2));
+    }
+
+    #[test]
+    fn select_connected() {
+        let tilemap = Tilemap::from_string("##.#\n.#.#\n##.#", '.');
+
+        let walls = tilemap.select_connected(Vector2::ZERO);
+        assert_eq!(walls.positions().collect::<Vec<_>>(),
+                   vec![Vector2::new(0, 0), Vector2::new(1, 0), Vector2::new(1, 1), Vector2::new(0, 2), Vector2::new(1, 2)]);
+        assert_eq!(tilemap.select_connected(Vector2::new(2, 1)).len(), 3);
+        assert_eq!(tilemap.select_connected(Vector2::new(0, 1)).len(), 1);
+        assert!(tilemap.select_connected(Vector2::new(4, 0)).is_empty());
+
+        let everything = tilemap.select_connected_with(Vector2::ZERO, |_, _| true);
+        assert_eq!(everything.len(), 12);
+    }
+}
@@ -0,0 +1,104 @@
+use std::cmp::Reverse;
+use crate::tilemap::{Direction, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Lets every tile that is not solid fall in the specified direction until it is blocked by
+    /// another tile or by the bounds of the [`Tilemap`], like sand. Solid tiles stay in place.
+    /// Tiles that are closer to the side they fall to are moved first, so falling tiles pile up.
+    ///
+    /// # Arguments
+    /// * `direction: Direction` - Direction in which tiles fall.
+    /// * `is_solid: F` - Function that receives value of the tile and returns `true` if it
+    ///   should not move.
+    ///
+    /// # Return
+    /// [`Vec`] with the performed moves as pairs of the old and new positions, in order.
+    ///
+    /// # Notes
+    /// Only values of tiles are moved. Colors, annotations and blinks stay at their positions.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("o.o\n...\n.#.", '.');
+    ///
+    /// let moves = tilemap.settle(char_tilemap::Direction::Down, |value| value == '#');
+    /// assert_eq!(tilemap.build(), "...\n...\no#o");
+    /// assert_eq!(moves.len(), 2);
+    /// ```
+    pub fn settle<F>(&mut self, direction: Direction, is_solid: F) -> Vec<(Vector2, Vector2)>
+        where F: Fn(char) -> bool {
+        let mut positions: Vec<Vector2> = self.tiles.iter()
+            .filter(|tile| !is_solid(tile.value))
+            .map(|tile| tile.position)
+            .collect();
+        match direction {
+            Direction::Up => positions.sort_by_key(|position| position.y),
+            Direction::Right => positions.sort_by_key(|position| Reverse(position.x)),
+            Direction::Down => positions.sort_by_key(|position| Reverse(position.y)),
+            Direction::Left => positions.sort_by_key(|position| position.x),
+        }
+
+        let mut moves = Vec::new();
+        for position in positions {
+            let mut target = position;
+            while let Some(next) = direction.step(target) {
+                if next.x >= self.size.x || next.y >= self.size.y || self.get(next).is_some() {
+                    break;
+                }
+                target = next;
+            }
+
+            if target != position {
+                if let Some(value) = self.take_tile(position) {
+                    self.set(target, value);
+                    moves.push((position, target));
+                }
+            }
+        }
+
+        return moves;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Direction, Tilemap, Vector2};
+
+    #[test]
+    fn settle() {
+        let mut tilemap = Tilemap::from_string("o.o.\n.o..\n..#.\n....", '.');
+
+        let moves = tilemap.settle(Direction::Down, |value| value == '#');
+        assert_eq!(tilemap.build(), "....\n..o.\n..#.\noo..");
+        assert_eq!(moves, vec![(Vector2::new(1, 1), Vector2::new(1, 3)), (Vector2::new(0, 0), Vector2::new(0, 3)),
+                               (Vector2::new(2, 0), Vector2::new(2, 1))]);
+
+        assert!(tilemap.settle(Direction::Down, |value| value == '#').is_empty());
+
+        tilemap.settle(Direction::Right, |value| value == '#');
+        assert_eq!(tilemap.build(), "....\n...o\n..#.\n..oo");
+
+        tilemap.settle(Direction::Up, |_| false);
+        assert_eq!(tilemap.build(), "..#o\n..oo\n....\n....");
+    }
+
+    #[test]
+    fn settle_piles_up() {
+        let mut tilemap = Tilemap::from_string("o\no\n.\n.", '.');
+
+        tilemap.settle(Direction::Down, |_| false);
+        assert_eq!(tilemap.build(), ".\n.\no\no");
+
+        tilemap.settle(Direction::Left, |_| false);
+        assert_eq!(tilemap.build(), ".\n.\no\no");
+    }
+}
@@ -0,0 +1,101 @@
+use crate::tilemap::Tilemap;
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Measures how similar tiles of two maps are, for example to quantify how much output of a
+    /// generator changed between parameter sets. It is the product of
+    /// [`Tilemap::occupancy_similarity()`] and [`Tilemap::value_agreement()`], which equals the
+    /// number of positions with equal tiles divided by the number of positions that have a tile
+    /// on any of the maps. Sizes and empty tiles are not compared.
+    ///
+    /// # Arguments
+    /// * `other: &Tilemap` - Compared map.
+    ///
+    /// # Return
+    /// Similarity from 0 (no equal tiles) to 1 (equal tiles). Maps without tiles are equal.
+    ///
+    /// # Example
+    /// ```rust
+    /// let first = char_tilemap::Tilemap::from_string("##.\n.@.", '.');
+    /// let second = char_tilemap::Tilemap::from_string("#..\n.@#", '.');
+    ///
+    /// assert_eq!(first.occupancy_similarity(&second), 0.5);
+    /// assert_eq!(first.value_agreement(&second), 1.0);
+    /// assert_eq!(first.similarity(&second), 0.5);
+    /// ```
+    pub fn similarity(&self, other: &Tilemap) -> f64 {
+        let (shared, equal) = self.count_shared(other);
+        let union = self.tiles.len() + other.tiles.len() - shared;
+        return if union == 0 { 1.0 } else { equal as f64 / union as f64 };
+    }
+
+    /// # Description
+    /// Returns Jaccard index of positions that have tiles: the number of positions with tiles on
+    /// both maps divided by the number of positions with a tile on any of the maps. Values of
+    /// tiles are not compared.
+    ///
+    /// # Arguments
+    /// * `other: &Tilemap` - Compared map.
+    ///
+    /// # Return
+    /// Similarity from 0 to 1. Maps without tiles are equal.
+    pub fn occupancy_similarity(&self, other: &Tilemap) -> f64 {
+        let (shared, _) = self.count_shared(other);
+        let union = self.tiles.len() + other.tiles.len() - shared;
+        return if union == 0 { 1.0 } else { shared as f64 / union as f64 };
+    }
+
+    /// # Description
+    /// Returns ratio of positions with equal values among positions that have tiles on both maps.
+    ///
+    /// # Arguments
+    /// * `other: &Tilemap` - Compared map.
+    ///
+    /// # Return
+    /// Ratio from 0 to 1. It is 1 if no position has tiles on both maps.
+    pub fn value_agreement(&self, other: &Tilemap) -> f64 {
+        let (shared, equal) = self.count_shared(other);
+        return if shared == 0 { 1.0 } else { equal as f64 / shared as f64 };
+    }
+
+    /// # Description
+    /// Counts positions that have tiles on both maps, and those of them with equal values.
+    fn count_shared(&self, other: &Tilemap) -> (usize, usize) {
+        let (mut shared, mut equal) = (0, 0);
+        for tile in self.tiles.iter() {
+            if let Some(value) = other.get(tile.position) {
+                shared += 1;
+                equal += (value == tile.value) as usize;
+            }
+        }
+
+        return (shared, equal);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::Tilemap;
+
+    #[test]
+    fn similarity() {
+        let first = Tilemap::from_string("ab\ncd", '.');
+        assert_eq!(first.similarity(&first.clone()), 1.0);
+        assert_eq!(first.similarity(&Tilemap::from_string("..\n..", '.')), 0.0);
+        assert_eq!(Tilemap::new('.').similarity(&Tilemap::new(' ')), 1.0);
+
+        let second = Tilemap::from_string("ax\n.d.e", '.');
+        assert_eq!(first.occupancy_similarity(&second), 0.6);
+        assert_eq!(first.value_agreement(&second), 2.0 / 3.0);
+        assert_eq!(first.similarity(&second), 0.4);
+        assert_eq!(second.similarity(&first), first.similarity(&second));
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::{BTreeSet, HashMap};
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Positions of tiles grouped by their values, see [`Tilemap::enable_index()`].
+#[derive(Debug, Clone)]
+pub(crate) struct SpatialIndex {
+    /// # Description
+    /// Positions of tiles with every value in the row-major order.
+    positions: HashMap<char, BTreeSet<Vector2>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl SpatialIndex {
+    /// # Description
+    /// Moves the position from the set of the old value to the set of the new value.
+    fn update(&mut self, position: Vector2, old: Option<char>, new: Option<char>) {
+        if let Some(old) = old {
+            if let Some(positions) = self.positions.get_mut(&old) {
+                positions.remove(&position);
+                if positions.is_empty() {
+                    self.positions.remove(&old);
+                }
+            }
+        }
+        if let Some(new) = new {
+            self.positions.entry(new).or_default().insert(position);
+        }
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Builds index of tile positions by their values, which is kept up to date by all mutations.
+    /// It makes [`Tilemap::positions_of()`] and [`Tilemap::positions_within()`] take time
+    /// proportional to the number of found tiles instead of the number of all tiles, at the cost of
+    /// memory and slower mutations. Index is rebuilt if it is already enabled.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("g..\n.@g", '.');
+    /// tilemap.enable_index();
+    /// tilemap.set(char_tilemap::Vector2::new(2, 0), 'g');
+    ///
+    /// assert_eq!(tilemap.positions_within('g', char_tilemap::Vector2::ONE, 1),
+    ///            vec![char_tilemap::Vector2::new(2, 1)]);
+    /// ```
+    pub fn enable_index(&mut self) {
+        let mut index = SpatialIndex { positions: HashMap::new() };
+        for tile in self.tiles.iter() {
+            index.update(tile.position, None, Some(tile.value));
+        }
+        self.index = Some(index);
+    }
+
+    /// # Description
+    /// Drops index of tile positions, see [`Tilemap::enable_index()`].
+    pub fn disable_index(&mut self) {
+        self.index = None;
+    }
+
+    /// # Description
+    /// Checks if index of tile positions is enabled, see [`Tilemap::enable_index()`].
+    pub fn is_indexed(&self) -> bool {
+        return self.index.is_some();
+    }
+
+    /// # Description
+    /// Returns positions of all tiles with the specified value.
+    ///
+    /// # Arguments
+    /// * `value: char` - Value of tiles.
+    ///
+    /// # Return
+    /// Positions in the row-major order.
+    pub fn positions_of(&self, value: char) -> Vec<Vector2> {
+        return match &self.index {
+            Some(index) => index.positions.get(&value).map_or(Vec::new(), |positions| positions.iter().copied().collect()),
+            None => self.tiles.iter().filter(|tile| tile.value == value).map(|tile| tile.position).collect(),
+        };
+    }
+
+    /// # Description
+    /// Returns positions of tiles with the specified value that are not farther than the radius
+    /// from the center, using Euclidean distance.
+    ///
+    /// # Arguments
+    /// * `value: char` - Value of tiles.
+    /// * `center: Vector2` - Center of the circle.
+    /// * `radius: usize` - Radius of the circle.
+    ///
+    /// # Return
+    /// Positions in the row-major order.
+    pub fn positions_within(&self, value: char, center: Vector2, radius: usize) -> Vec<Vector2> {
+        let is_within = |position: &Vector2| {
+            let distance = Vector2::new(position.x.abs_diff(center.x), position.y.abs_diff(center.y));
+            return distance.x.saturating_mul(distance.x).saturating_add(distance.y.saturating_mul(distance.y))
+                <= radius.saturating_mul(radius);
+        };
+
+        let Some(index) = &self.index else {
+            return self.positions_of(value).into_iter().filter(is_within).collect();
+        };
+        let Some(positions) = index.positions.get(&value) else {
+            return Vec::new();
+        };
+
+        // Positions are ordered by rows, so only rows that intersect the circle are visited
+        let first = Vector2::new(0, center.y.saturating_sub(radius));
+        let last = Vector2::new(usize::MAX, center.y.saturating_add(radius));
+        return positions.range(first..=last).copied().filter(is_within).collect();
+    }
+
+    /// # Description
+    /// Updates index of tile positions after the tile was changed, if index is enabled.
+    pub(crate) fn update_index(&mut self, position: Vector2, old: Option<char>, new: Option<char>) {
+        if let Some(index) = &mut self.index {
+            index.update(position, old, new);
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Orientation, Tilemap, Vector2};
+
+    #[test]
+    fn positions_of() {
+        let mut tilemap = Tilemap::from_string("g.g\n.@.\ng..", '.');
+        let expected = vec![Vector2::ZERO, Vector2::new(2, 0), Vector2::new(0, 2)];
+        assert_eq!(tilemap.positions_of('g'), expected);
+
+        tilemap.enable_index();
+        assert!(tilemap.is_indexed());
+        assert_eq!(tilemap.positions_of('g'), expected);
+
+        tilemap.set(Vector2::ONE, 'g');
+        tilemap.remove_tile(Vector2::ZERO).unwrap();
+        tilemap.update_tile(Vector2::new(2, 0), '#').unwrap();
+        assert_eq!(tilemap.positions_of('g'), vec![Vector2::ONE, Vector2::new(0, 2)]);
+        assert_eq!(tilemap.positions_of('@'), vec![]);
+
+        let mut oriented = tilemap.oriented(Orientation::Rotate90);
+        assert_eq!(oriented.positions_of('#'), vec![Vector2::new(2, 2)]);
+
+        oriented.disable_index();
+        assert!(!oriented.is_indexed());
+        assert_eq!(oriented.positions_of('#'), vec![Vector2::new(2, 2)]);
+    }
+
+    #[test]
+    fn positions_within() {
+        let mut tilemap = Tilemap::from_string("g...g\n.....\n..@..\n.g...\n....g", '.');
+        let center = Vector2::new(2, 2);
+
+        for indexed in [false, true] {
+            if indexed {
+                tilemap.enable_index();
+            }
+            assert_eq!(tilemap.positions_within('g', center, 1), vec![]);
+            assert_eq!(tilemap.positions_within('g', center, 2), vec![Vector2::new(1, 3)]);
+            assert_eq!(tilemap.positions_within('g', center, 3).len(), 4);
+            assert_eq!(tilemap.positions_within('x', center, 3), vec![]);
+        }
+    }
+}
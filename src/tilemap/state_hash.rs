@@ -0,0 +1,96 @@
+use crate::tilemap::Tilemap;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Offset basis of the 64-bit FNV-1a hash.
+pub(crate) const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+/// # Description
+/// Prime of the 64-bit FNV-1a hash.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Calculates hash of the empty tile, size and tiles of the [`Tilemap`]. Unlike
+    /// [`std::hash::Hash`], the hash is stable: it is the same on all platforms, runs and
+    /// versions of the crate, so it can be used to detect desyncs between two simulations or as
+    /// a key of cached results. Colors, annotations and other data of positions are not hashed.
+    ///
+    /// # Return
+    /// 64-bit FNV-1a hash of the state.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut first = char_tilemap::Tilemap::from_string("#.\n.@", '.');
+    /// let mut second = char_tilemap::Tilemap::new('.');
+    /// second.set(char_tilemap::Vector2::ONE, '@');
+    /// second.set(char_tilemap::Vector2::ZERO, '#');
+    /// assert_eq!(first.state_hash(), second.state_hash());
+    ///
+    /// first.empty_tile = ' ';
+    /// assert_ne!(first.state_hash(), second.state_hash());
+    /// ```
+    pub fn state_hash(&self) -> u64 {
+        let mut hash = FNV_OFFSET;
+        let mut write = |bytes: &[u8]| hash = fnv1a(hash, bytes);
+
+        write(&(self.empty_tile as u32).to_le_bytes());
+        write(&(self.size.x as u64).to_le_bytes());
+        write(&(self.size.y as u64).to_le_bytes());
+        write(&(self.tiles.len() as u64).to_le_bytes());
+        for tile in self.tiles.iter() {
+            write(&(tile.position.x as u64).to_le_bytes());
+            write(&(tile.position.y as u64).to_le_bytes());
+            write(&(tile.value as u32).to_le_bytes());
+        }
+
+        return hash;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Continues 64-bit FNV-1a hash with bytes. Hashing starts from [`FNV_OFFSET`].
+pub(crate) fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    return bytes.iter().fold(hash, |hash, byte| (hash ^ *byte as u64).wrapping_mul(FNV_PRIME));
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[test]
+    fn state_hash() {
+        let tilemap = Tilemap::from_string("ab\n.c", '.');
+        let hash = tilemap.state_hash();
+
+        let mut changed = tilemap.clone();
+        changed.set(Vector2::new(1, 1), 'd');
+        assert_ne!(changed.state_hash(), hash);
+        changed.set(Vector2::new(1, 1), 'c');
+        assert_eq!(changed.state_hash(), hash);
+
+        changed.resize(Vector2::new(3, 2), Default::default()).unwrap();
+        assert_ne!(changed.state_hash(), hash);
+        assert_ne!(Tilemap::from_string("ab", '.').state_hash(), Tilemap::from_string("ba", '.').state_hash());
+    }
+
+    #[test]
+    fn stable() {
+        assert_eq!(Tilemap::new('.').state_hash(), 5205959793838969179);
+        assert_eq!(Tilemap::from_string("#.\n.@", '.').state_hash(), 12347694949228739802);
+    }
+}
@@ -0,0 +1,163 @@
+use crate::tilemap::{Tile, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Pairs of glyphs that turn into each other when mirrored left to right.
+const VERTICAL_AXIS_GLYPHS: [(char, char); 9] = [
+    ('/', '\\'), ('(', ')'), ('[', ']'), ('{', '}'), ('<', '>'),
+    ('┌', '┐'), ('└', '┘'), ('├', '┤'), ('╔', '╗'),
+];
+
+/// # Description
+/// Pairs of glyphs that turn into each other when mirrored top to bottom.
+const HORIZONTAL_AXIS_GLYPHS: [(char, char); 7] = [
+    ('/', '\\'), ('^', 'v'), ('┌', '└'), ('┐', '┘'), ('┬', '┴'), ('╔', '╚'), ('╗', '╝'),
+];
+
+/// # Description
+/// Pairs of glyphs that turn into each other when mirrored across the main diagonal.
+const DIAGONAL_AXIS_GLYPHS: [(char, char); 6] = [
+    ('-', '|'), ('<', '^'), ('>', 'v'), ('─', '│'), ('┐', '└'), ('├', '┬'),
+];
+
+/// # Description
+/// Axis across which [`Tilemap::symmetrize()`] mirrors the half of the [`Tilemap`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum SymmetryAxis {
+    /// # Description
+    /// Vertical line through the middle. The left half is mirrored to the right.
+    Vertical,
+    /// # Description
+    /// Horizontal line through the middle. The top half is mirrored to the bottom.
+    Horizontal,
+    /// # Description
+    /// Diagonal from the top left corner. The part above the diagonal is mirrored below it.
+    Diagonal,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl SymmetryAxis {
+    /// # Description
+    /// Returns glyph that looks like the mirrored one, for example `'/'` for `'\'` mirrored across
+    /// the vertical axis. Glyphs without known mirrored pairs are returned unchanged.
+    ///
+    /// # Arguments
+    /// * `value: char` - Glyph that is mirrored.
+    ///
+    /// # Example
+    /// ```rust
+    /// assert_eq!(char_tilemap::SymmetryAxis::Diagonal.mirror_glyph('-'), '|');
+    /// assert_eq!(char_tilemap::SymmetryAxis::Vertical.mirror_glyph('#'), '#');
+    /// ```
+    pub fn mirror_glyph(self, value: char) -> char {
+        let pairs: &[(char, char)] = match self {
+            SymmetryAxis::Vertical => &VERTICAL_AXIS_GLYPHS,
+            SymmetryAxis::Horizontal => &HORIZONTAL_AXIS_GLYPHS,
+            SymmetryAxis::Diagonal => &DIAGONAL_AXIS_GLYPHS,
+        };
+
+        for (first, second) in pairs {
+            if value == *first {
+                return *second;
+            }
+            if value == *second {
+                return *first;
+            }
+        }
+
+        return value;
+    }
+
+    /// # Description
+    /// Returns mirrored position, or [`None`] if position belongs to the mirrored half.
+    fn source_to_target(self, position: Vector2, size: Vector2) -> Option<Vector2> {
+        return match self {
+            SymmetryAxis::Vertical if position.x < size.x.div_ceil(2) => Some(Vector2::new(size.x - 1 - position.x, position.y)),
+            SymmetryAxis::Horizontal if position.y < size.y.div_ceil(2) => Some(Vector2::new(position.x, size.y - 1 - position.y)),
+            SymmetryAxis::Diagonal if position.x >= position.y => Some(Vector2::new(position.y, position.x)),
+            _ => None,
+        };
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Makes [`Tilemap`] symmetric by mirroring its half across the axis, replacing the other
+    /// half. Asymmetric glyphs are replaced by their mirrored pairs, see
+    /// [`SymmetryAxis::mirror_glyph()`]. Diagonal symmetry makes [`Tilemap`] square.
+    ///
+    /// # Arguments
+    /// * `axis: SymmetryAxis` - Axis of the symmetry.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("/#..\n(...", '.');
+    /// tilemap.symmetrize(char_tilemap::SymmetryAxis::Vertical);
+    /// assert_eq!(tilemap.build(), "/##\\\n(..)");
+    /// ```
+    pub fn symmetrize(&mut self, axis: SymmetryAxis) {
+        self.symmetrize_with(axis, |value| axis.mirror_glyph(value));
+    }
+
+    /// # Description
+    /// Makes [`Tilemap`] symmetric, same as [`Tilemap::symmetrize()`], but mirrored glyphs are
+    /// produced by the specified function.
+    ///
+    /// # Arguments
+    /// * `axis: SymmetryAxis` - Axis of the symmetry.
+    /// * `mirror_glyph: F` - Returns glyph that replaces the mirrored one.
+    pub fn symmetrize_with<F>(&mut self, axis: SymmetryAxis, mirror_glyph: F)
+        where F: Fn(char) -> char {
+        if axis == SymmetryAxis::Diagonal {
+            let side = std::cmp::max(self.size.x, self.size.y);
+            self.size = Vector2::new(side, side);
+        }
+
+        let size = self.size;
+        let mut tiles = Vec::new();
+        for tile in self.tiles.iter() {
+            if let Some(target) = axis.source_to_target(tile.position, size) {
+                tiles.push(*tile);
+                if target != tile.position {
+                    tiles.push(Tile { position: target, value: mirror_glyph(tile.value) });
+                }
+            }
+        }
+
+        let old_tiles = std::mem::replace(&mut self.tiles, sorted_vec::SortedSet::from_unsorted(tiles));
+        self.record_replaced(&old_tiles);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{SymmetryAxis, Tilemap};
+
+    #[test]
+    fn symmetrize() {
+        let mut tilemap = Tilemap::from_string("^#.X.\n.┌─Y.\n<....", '.');
+        tilemap.symmetrize(SymmetryAxis::Vertical);
+        assert_eq!(tilemap.build(), "^#.#^\n.┌─┐.\n<...>");
+
+        tilemap.symmetrize(SymmetryAxis::Horizontal);
+        assert_eq!(tilemap.build(), "^#.#^\n.┌─┐.\nv#.#v");
+
+        let mut tilemap = Tilemap::from_string("#->\n.X.", '.');
+        tilemap.symmetrize(SymmetryAxis::Diagonal);
+        assert_eq!(tilemap.build(), "#->\n|X.\nv..");
+
+        let mut tilemap = Tilemap::from_string("ab..", '.');
+        tilemap.symmetrize_with(SymmetryAxis::Vertical, |value| value.to_ascii_uppercase());
+        assert_eq!(tilemap.build(), "abBA");
+    }
+}
@@ -0,0 +1,356 @@
+use crate::tilemap::{TileChange, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Version of the wire encoding of [`ChangeBatch`]. It is the first byte of every encoded batch.
+const WIRE_VERSION: u8 = 1;
+
+/// # Description
+/// Batch of [`TileChange`]s with the sequence number, which can be sent over any transport.
+///
+/// Encoding is compact: all numbers are LEB128 varints, positions are zigzag encoded deltas
+/// from the previous change, and only [`TileChange::new`] values are stored, since receiver
+/// knows old values from its own [`Tilemap`].
+///
+/// # Example
+/// ```rust
+/// let change = char_tilemap::TileChange::new(char_tilemap::Vector2::new(5, 3), None, Some('#'));
+/// let batch = char_tilemap::ChangeBatch::new(7, vec![change]);
+///
+/// let bytes = batch.encode();
+/// assert_eq!(bytes.len(), 6);
+/// assert_eq!(char_tilemap::ChangeBatch::decode(&bytes).unwrap().changes[0].new, Some('#'));
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ChangeBatch {
+    /// # Description
+    /// Sequence number of the batch. Batches of the same session are numbered from 0.
+    pub sequence: u64,
+    /// # Description
+    /// Changes of the batch in order of their application. Decoded changes have no `old` values.
+    pub changes: Vec<TileChange>,
+}
+
+/// # Description
+/// Helper that mirrors [`Tilemap`] changes between two sides, such as a server and a client.
+/// Sending side numbers encoded batches, receiving side applies them strictly in order and
+/// rejects lost, duplicated or reordered ones, so the mirror never silently diverges.
+///
+/// # Example
+/// ```rust
+/// let mut server_map = char_tilemap::Tilemap::from_string("...\n...", '.');
+/// let mut client_map = server_map.clone();
+/// let mut server = char_tilemap::SyncSession::new();
+/// let mut client = char_tilemap::SyncSession::new();
+///
+/// let previous = server_map.clone();
+/// server_map.set(char_tilemap::Vector2::ONE, '@');
+/// let message = server.send(&previous.diff(&server_map));
+///
+/// client.receive(&mut client_map, &message).unwrap();
+/// assert_eq!(client_map.build(), server_map.build());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SyncSession {
+    /// # Description
+    /// Sequence number of the next sent batch.
+    next_sequence: u64,
+    /// # Description
+    /// Sequence number of the next received batch.
+    expected_sequence: u64,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl ChangeBatch {
+    /// # Description
+    /// Creates new [`ChangeBatch`].
+    ///
+    /// # Arguments
+    /// * `sequence: u64` - Sequence number of the batch.
+    /// * `changes: Vec<TileChange>` - Changes of the batch.
+    ///
+    /// # Return
+    /// New instance of the [`ChangeBatch`].
+    pub fn new(sequence: u64, changes: Vec<TileChange>) -> ChangeBatch {
+        return ChangeBatch { sequence, changes };
+    }
+
+    /// # Description
+    /// Encodes batch into the compact wire format.
+    ///
+    /// # Return
+    /// [`Vec`] of encoded bytes.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![WIRE_VERSION];
+        write_varint(&mut bytes, self.sequence);
+        write_varint(&mut bytes, self.changes.len() as u64);
+
+        let mut previous = Vector2::ZERO;
+        for change in &self.changes {
+            write_varint(&mut bytes, zigzag(change.position.x as i64 - previous.x as i64));
+            write_varint(&mut bytes, zigzag(change.position.y as i64 - previous.y as i64));
+            write_varint(&mut bytes, change.new.map_or(0, |value| value as u64 + 1));
+            previous = change.position;
+        }
+
+        return bytes;
+    }
+
+    /// # Description
+    /// Decodes batch from the wire format produced by [`ChangeBatch::encode()`].
+    ///
+    /// # Arguments
+    /// * `bytes: &[u8]` - Encoded batch.
+    ///
+    /// # Return
+    /// * [`Ok`] with decoded [`ChangeBatch`].
+    /// * [`Err`] if bytes are truncated, have unknown version or contain invalid values.
+    ///   Contains error message.
+    pub fn decode(bytes: &[u8]) -> Result<ChangeBatch, String> {
+        let mut reader = bytes.iter().copied();
+        match reader.next() {
+            Some(WIRE_VERSION) => {},
+            Some(version) => return Err(format!("Unknown wire version {version}")),
+            None => return Err(String::from("Change batch is empty")),
+        }
+
+        let sequence = read_varint(&mut reader)?;
+        let count = read_varint(&mut reader)?;
+        let mut changes = Vec::new();
+        let mut previous = Vector2::ZERO;
+        for _ in 0..count {
+            let x = offset(previous.x, unzigzag(read_varint(&mut reader)?))?;
+            let y = offset(previous.y, unzigzag(read_varint(&mut reader)?))?;
+            let new = match read_varint(&mut reader)? {
+                0 => None,
+                value => Some(u32::try_from(value - 1).ok().and_then(char::from_u32)
+                    .ok_or(format!("Invalid char code {}", value - 1))?),
+            };
+
+            previous = Vector2::new(x, y);
+            changes.push(TileChange::new(previous, None, new));
+        }
+
+        if reader.next().is_some() {
+            return Err(String::from("Change batch has trailing bytes"));
+        }
+
+        return Ok(ChangeBatch { sequence, changes });
+    }
+}
+
+impl SyncSession {
+    /// # Description
+    /// Creates new [`SyncSession`] where both sent and received batches start from 0.
+    ///
+    /// # Return
+    /// New instance of the [`SyncSession`].
+    pub fn new() -> SyncSession {
+        return SyncSession::default();
+    }
+
+    /// # Description
+    /// Returns sequence number of the next sent batch.
+    pub fn next_sequence(&self) -> u64 {
+        return self.next_sequence;
+    }
+
+    /// # Description
+    /// Returns sequence number of the batch that is expected to be received next.
+    pub fn expected_sequence(&self) -> u64 {
+        return self.expected_sequence;
+    }
+
+    /// # Description
+    /// Encodes changes as the next batch of the session.
+    ///
+    /// # Arguments
+    /// * `changes: &[TileChange]` - Changes that should be sent, for example from [`Tilemap::diff()`].
+    ///
+    /// # Return
+    /// Encoded batch, see [`ChangeBatch::encode()`].
+    pub fn send(&mut self, changes: &[TileChange]) -> Vec<u8> {
+        let batch = ChangeBatch::new(self.next_sequence, changes.to_vec());
+        self.next_sequence += 1;
+        return batch.encode();
+    }
+
+    /// # Description
+    /// Decodes the received batch and applies it to the [`Tilemap`] if it is the expected one.
+    ///
+    /// # Arguments
+    /// * `tilemap: &mut Tilemap` - Mirrored [`Tilemap`].
+    /// * `message: &[u8]` - Encoded batch.
+    ///
+    /// # Return
+    /// * [`Ok`] with applied changes, which have `old` values taken from the [`Tilemap`].
+    /// * [`Err`] if message could not be decoded, or its sequence number is not the expected one.
+    ///   [`Tilemap`] is not changed in this case. Contains error message.
+    pub fn receive(&mut self, tilemap: &mut Tilemap, message: &[u8]) -> Result<Vec<TileChange>, String> {
+        let mut batch = ChangeBatch::decode(message)?;
+        if batch.sequence != self.expected_sequence {
+            return Err(format!("Expected change batch {}, but received {}", self.expected_sequence, batch.sequence));
+        }
+
+        for change in &mut batch.changes {
+            change.old = tilemap.get(change.position);
+            match change.new {
+                Some(value) => tilemap.set(change.position, value),
+                None => {
+                    let _ = tilemap.remove_tile(change.position);
+                },
+            }
+        }
+
+        self.expected_sequence += 1;
+        return Ok(batch.changes);
+    }
+
+    /// # Description
+    /// Sets sequence number of the batch that is expected next, for example after the receiving
+    /// side got a full copy of the [`Tilemap`] to recover from a lost batch.
+    ///
+    /// # Arguments
+    /// * `sequence: u64` - Sequence number of the next received batch.
+    pub fn resync(&mut self, sequence: u64) {
+        self.expected_sequence = sequence;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Writes `value` as the LEB128 varint.
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// # Description
+/// Reads the LEB128 varint.
+fn read_varint<I: Iterator<Item = u8>>(reader: &mut I) -> Result<u64, String> {
+    let mut value = 0;
+    for shift in (0..64).step_by(7) {
+        let byte = reader.next().ok_or(String::from("Change batch is truncated"))?;
+        value |= ((byte & 0x7F) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    return Err(String::from("Varint is too long"));
+}
+
+/// # Description
+/// Maps signed value to unsigned one, so small negative values stay small.
+fn zigzag(value: i64) -> u64 {
+    return ((value << 1) ^ (value >> 63)) as u64;
+}
+
+/// # Description
+/// Reverts [`zigzag()`].
+fn unzigzag(value: u64) -> i64 {
+    return (value >> 1) as i64 ^ -((value & 1) as i64);
+}
+
+/// # Description
+/// Applies decoded delta to the coordinate.
+fn offset(coordinate: usize, delta: i64) -> Result<usize, String> {
+    let coordinate = i64::try_from(coordinate).ok()
+        .and_then(|coordinate| coordinate.checked_add(delta))
+        .ok_or(String::from("Change position is out of range"))?;
+    return usize::try_from(coordinate).map_err(|_| String::from("Change position is negative"));
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{ChangeBatch, SyncSession, TileChange, Tilemap, Vector2};
+    use crate::tilemap::sync_session::{unzigzag, write_varint, zigzag};
+
+    #[test]
+    fn encode_decode() {
+        let batch = ChangeBatch::new(300, vec![
+            TileChange::new(Vector2::new(1000, 2), Some('a'), Some('\u{1F332}')),
+            TileChange::new(Vector2::new(3, 1), Some('b'), None),
+            TileChange::new(Vector2::new(4, 1), None, Some('#')),
+        ]);
+
+        let decoded = ChangeBatch::decode(&batch.encode()).unwrap();
+        assert_eq!(decoded.sequence, 300);
+        assert_eq!(decoded.changes, batch.changes.iter().map(|change| TileChange { old: None, ..*change }).collect::<Vec<_>>());
+        assert_eq!(ChangeBatch::new(0, Vec::new()).encode(), vec![1, 0, 0]);
+
+        for value in [0, 1, -1, 63, -64, i64::MAX, i64::MIN] {
+            assert_eq!(unzigzag(zigzag(value)), value);
+        }
+    }
+
+    #[test]
+    fn decode_errors() {
+        let bytes = ChangeBatch::new(1, vec![TileChange::new(Vector2::ONE, None, Some('#'))]).encode();
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        let mut overflow = vec![1, 0, 2];
+        for _ in 0..2 {
+            write_varint(&mut overflow, zigzag(i64::MAX));
+            overflow.extend([0, 0]);
+        }
+
+        for bytes in [&[][..], &[2, 0, 0], &bytes[..bytes.len() - 1], &trailing, &[1, 0, 1, 1, 0, 0], &[1, 0, 1, 0, 0, 0xFF, 0xFF, 0x7F],
+                      &overflow] {
+            match ChangeBatch::decode(bytes) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+
+    #[test]
+    fn session() {
+        let mut server_map = Tilemap::from_string("#..\n...", '.');
+        let mut client_map = server_map.clone();
+        let mut server = SyncSession::new();
+        let mut client = SyncSession::new();
+
+        let mut messages = Vec::new();
+        for (position, value) in [(Vector2::ZERO, None), (Vector2::new(2, 1), Some('@'))] {
+            let previous = server_map.clone();
+            match value {
+                Some(value) => server_map.set(position, value),
+                None => server_map.remove_tile(position).unwrap(),
+            }
+            messages.push(server.send(&previous.diff(&server_map)));
+        }
+        assert_eq!(server.next_sequence(), 2);
+
+        match client.receive(&mut client_map, &messages[1]) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+        assert_eq!(client_map.build(), "#..\n...");
+
+        assert_eq!(client.receive(&mut client_map, &messages[0]).unwrap(),
+                   vec![TileChange::new(Vector2::ZERO, Some('#'), None)]);
+        client.receive(&mut client_map, &messages[1]).unwrap();
+        assert_eq!(client_map.build(), server_map.build());
+        assert_eq!(client.expected_sequence(), 2);
+
+        client.resync(5);
+        assert_eq!(client.expected_sequence(), 5);
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::BTreeSet;
+use crate::tilemap::{Tilemap, Vector2};
+use crate::tilemap::file_format::parse_size;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Key of the header lines that store tags in format `{name}@{x}x{y},{x}x{y},...`.
+pub(super) const TAG_KEY: &str = "tag";
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Tags the position, so generation passes can tell later passes and the game what the
+    /// position is for, such as `"spawnable"`, without overloading tile values. Tags are saved
+    /// with the map by [`Tilemap::to_file_string()`] and are moved by [`Tilemap::oriented()`].
+    /// Tiles are not changed.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Tagged position.
+    /// * `name: &str` - Name of the tag.
+    ///
+    /// # Return
+    /// * [`Ok`] with `true` if position was not tagged with the name before, `false` otherwise.
+    /// * [`Err`] if name is empty or contains line breaks, or position is out of bounds. Contains
+    ///   error message.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::Vector2;
+    ///
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("...\n...", '.');
+    /// tilemap.tag(Vector2::new(2, 0), "spawnable").unwrap();
+    /// tilemap.tag(Vector2::ONE, "spawnable").unwrap();
+    ///
+    /// let loaded = char_tilemap::Tilemap::from_file_string(&tilemap.to_file_string()).unwrap();
+    /// assert_eq!(loaded.positions_with_tag("spawnable"), vec![Vector2::new(2, 0), Vector2::ONE]);
+    /// ```
+    pub fn tag(&mut self, position: Vector2, name: &str) -> Result<bool, String> {
+        if name.is_empty() || name.contains(['\n', '\r']) {
+            return Err(format!("Invalid tag name \"{}\"", name.escape_debug()));
+        }
+        if !self.is_in_bounds(position) {
+            return Err(format!("Position {position} of the tag \"{name}\" is out of bounds"));
+        }
+
+        return Ok(self.tags.entry(name.to_string()).or_default().insert(position));
+    }
+
+    /// # Description
+    /// Removes the tag from the position.
+    ///
+    /// # Return
+    /// `true` if position was tagged with the name, `false` otherwise.
+    pub fn untag(&mut self, position: Vector2, name: &str) -> bool {
+        let Some(positions) = self.tags.get_mut(name) else {
+            return false;
+        };
+
+        let removed = positions.remove(&position);
+        if positions.is_empty() {
+            self.tags.remove(name);
+        }
+        return removed;
+    }
+
+    /// # Description
+    /// Checks if the position is tagged with the name.
+    pub fn has_tag(&self, position: Vector2, name: &str) -> bool {
+        return self.tags.get(name).is_some_and(|positions| positions.contains(&position));
+    }
+
+    /// # Description
+    /// Returns positions tagged with the name in row-major order.
+    pub fn positions_with_tag(&self, name: &str) -> Vec<Vector2> {
+        return self.tags.get(name).map_or(Vec::new(), |positions| positions.iter().copied().collect());
+    }
+
+    /// # Description
+    /// Returns names of all tags of the position in alphabetical order.
+    pub fn tags_at(&self, position: Vector2) -> Vec<&str> {
+        return self.tags.iter()
+            .filter(|(_, positions)| positions.contains(&position))
+            .map(|(name, _)| name.as_str())
+            .collect();
+    }
+
+    /// # Description
+    /// Removes the tag from all positions.
+    ///
+    /// # Return
+    /// Positions that were tagged with the name in row-major order.
+    pub fn clear_tag(&mut self, name: &str) -> Vec<Vector2> {
+        return self.tags.remove(name).map_or(Vec::new(), |positions| positions.into_iter().collect());
+    }
+
+    /// # Description
+    /// Returns header lines of the save format that store tags.
+    pub(super) fn tag_lines(&self) -> String {
+        return self.tags.iter()
+            .map(|(name, positions)| {
+                let positions: Vec<String> = positions.iter().map(|position| format!("{}x{}", position.x, position.y)).collect();
+                return format!("{TAG_KEY}={name}@{}\n", positions.join(","));
+            })
+            .collect();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Parses header value that should contain tag in format `{name}@{x}x{y},{x}x{y},...`. Name may
+/// contain `@` itself, since positions follow the last one.
+pub(super) fn parse_tag(value: &str) -> Result<(String, BTreeSet<Vector2>), String> {
+    let (name, positions) = value.rsplit_once('@')
+        .ok_or_else(|| format!("Expected tag in format {{name}}@{{x}}x{{y}},..., but got \"{value}\""))?;
+    let positions = positions.split(',').map(parse_size).collect::<Result<_, _>>()?;
+    return Ok((name.to_string(), positions));
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Orientation, Tilemap, Vector2};
+
+    #[test]
+    fn tags() {
+        let mut tilemap = Tilemap::from_string("...\n...", '.');
+        assert_eq!(tilemap.tag(Vector2::ZERO, "spawnable"), Ok(true));
+        assert_eq!(tilemap.tag(Vector2::ZERO, "spawnable"), Ok(false));
+        tilemap.tag(Vector2::ZERO, "lit").unwrap();
+        tilemap.tag(Vector2::new(2, 1), "spawnable").unwrap();
+
+        assert!(tilemap.has_tag(Vector2::ZERO, "lit"));
+        assert_eq!(tilemap.tags_at(Vector2::ZERO), vec!["lit", "spawnable"]);
+        assert_eq!(tilemap.positions_with_tag("spawnable"), vec![Vector2::ZERO, Vector2::new(2, 1)]);
+        assert_eq!(tilemap.oriented(Orientation::FlipHorizontal).positions_with_tag("lit"), vec![Vector2::new(2, 0)]);
+
+        assert!(tilemap.untag(Vector2::ZERO, "lit"));
+        assert!(!tilemap.untag(Vector2::ZERO, "lit"));
+        assert_eq!(tilemap.tags_at(Vector2::ZERO), vec!["spawnable"]);
+        assert_eq!(tilemap.clear_tag("spawnable"), vec![Vector2::ZERO, Vector2::new(2, 1)]);
+        assert!(tilemap.positions_with_tag("spawnable").is_empty());
+
+        for (position, name) in [(Vector2::ZERO, ""), (Vector2::ZERO, "a\rb"), (Vector2::new(0, 2), "far")] {
+            match tilemap.tag(position, name) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true),
+            }
+        }
+    }
+
+    #[test]
+    fn file_string() {
+        let mut tilemap = Tilemap::from_string("#..\n..#", '.');
+        tilemap.tag(Vector2::new(1, 0), "spawnable").unwrap();
+        tilemap.tag(Vector2::new(0, 1), "spawnable").unwrap();
+        tilemap.tag(Vector2::new(2, 1), "exit@east").unwrap();
+        let text = tilemap.to_file_string();
+        assert_eq!(text, "version=3\nempty_tile=.\nsize=3x2\ntag=exit@east@2x1\ntag=spawnable@1x0,0x1\n\n#..\n..#");
+
+        let loaded = Tilemap::from_file_string(&text).unwrap();
+        assert_eq!(loaded.positions_with_tag("spawnable"), tilemap.positions_with_tag("spawnable"));
+        assert_eq!(loaded.tags_at(Vector2::new(2, 1)), vec!["exit@east"]);
+
+        for text in ["empty_tile=.\nsize=1x1\ntag=lit\n\n.", "empty_tile=.\nsize=1x1\ntag=lit@0x0,1x0\n\n.",
+                     "empty_tile=.\nsize=1x1\ntag=lit@\n\n."] {
+            match Tilemap::from_file_string(text) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true),
+            }
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::tilemap::{Direction, Tilemap, Vector2};
+use crate::tilemap::overlay::line;
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Returns positions of the 90 degree cone that spreads from the origin in the direction,
+    /// such as a breath attack. Positions that are out of bounds, blocking, or hidden behind
+    /// blocking tiles from the origin are skipped.
+    ///
+    /// # Arguments
+    /// * `origin: Vector2` - Position from which cone spreads. It is not included.
+    /// * `direction: Direction` - Direction of the cone.
+    /// * `length: usize` - Number of rows of the cone.
+    /// * `is_blocking: F` - Returns `true` if the position with the specified tile value (or
+    ///   [`None`] for positions without tiles) stops the effect.
+    ///
+    /// # Return
+    /// [`Vec`] of affected positions in row-major order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::{Direction, Vector2};
+    ///
+    /// let tilemap = char_tilemap::Tilemap::from_string(".....\n.....\n..@..", '.');
+    /// let cone = tilemap.cone(Vector2::new(2, 2), Direction::Up, 2, |value| value == Some('#'));
+    /// assert_eq!(cone.len(), 8);
+    /// ```
+    pub fn cone<F>(&self, origin: Vector2, direction: Direction, length: usize, is_blocking: F) -> Vec<Vector2>
+        where F: Fn(Option<char>) -> bool {
+        let (forward, side) = axes(direction);
+        let mut targets = Vec::new();
+
+        for distance in 1..=length as isize {
+            for lateral in -distance..=distance {
+                targets.push(offset(origin, forward, distance, side, lateral));
+            }
+        }
+
+        return self.visible_targets(origin, targets.into_iter().flatten(), &is_blocking);
+    }
+
+    /// # Description
+    /// Returns positions of the straight beam that goes from the origin in the direction, such
+    /// as a lightning bolt. Wide beams consist of parallel lanes centered on the origin, and
+    /// every lane stops at the first position that is out of bounds or blocking.
+    ///
+    /// # Arguments
+    /// * `origin: Vector2` - Position from which beam starts. It is not included.
+    /// * `direction: Direction` - Direction of the beam.
+    /// * `length: usize` - Length of the beam.
+    /// * `width: usize` - Number of lanes of the beam. Even widths have an extra lane to the
+    ///   right of the direction.
+    /// * `is_blocking: F` - Returns `true` if the position with the specified tile value (or
+    ///   [`None`] for positions without tiles) stops the effect.
+    ///
+    /// # Return
+    /// [`Vec`] of affected positions in row-major order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::{Direction, Vector2};
+    ///
+    /// let tilemap = char_tilemap::Tilemap::from_string("@...#.", '.');
+    /// let beam = tilemap.beam(Vector2::ZERO, Direction::Right, 10, 1, |value| value == Some('#'));
+    /// assert_eq!(beam, vec![Vector2::new(1, 0), Vector2::new(2, 0), Vector2::new(3, 0)]);
+    /// ```
+    pub fn beam<F>(&self, origin: Vector2, direction: Direction, length: usize, width: usize, is_blocking: F) -> Vec<Vector2>
+        where F: Fn(Option<char>) -> bool {
+        let (forward, side) = axes(direction);
+        let mut positions = Vec::new();
+
+        let lanes = width as isize;
+        for lateral in (0..lanes).map(|lane| lane - (lanes - 1) / 2) {
+            for distance in 1..=length as isize {
+                match offset(origin, forward, distance, side, lateral).filter(|position| self.is_passable(*position, &is_blocking)) {
+                    Some(position) => positions.push(position),
+                    None => break,
+                }
+            }
+        }
+
+        positions.sort();
+        return positions;
+    }
+
+    /// # Description
+    /// Returns positions of the circular burst around the center, such as an explosion.
+    /// Positions that are out of bounds, blocking, or hidden behind blocking tiles from the
+    /// center are skipped.
+    ///
+    /// # Arguments
+    /// * `center: Vector2` - Center of the burst. It is included, unless it is blocking.
+    /// * `radius: usize` - Maximum Euclidean distance from the center.
+    /// * `is_blocking: F` - Returns `true` if the position with the specified tile value (or
+    ///   [`None`] for positions without tiles) stops the effect.
+    ///
+    /// # Return
+    /// [`Vec`] of affected positions in row-major order.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string(".....\n.....\n.....", '.');
+    /// let burst = tilemap.burst(char_tilemap::Vector2::new(2, 1), 1, |value| value == Some('#'));
+    /// assert_eq!(burst.len(), 5);
+    /// ```
+    pub fn burst<F>(&self, center: Vector2, radius: usize, is_blocking: F) -> Vec<Vector2>
+        where F: Fn(Option<char>) -> bool {
+        let radius = radius as isize;
+        let mut targets = Vec::new();
+
+        for dy in -radius..=radius {
+            for dx in (-radius..=radius).filter(|dx| dx * dx + dy * dy <= radius * radius) {
+                targets.push(offset(center, (1, 0), dx, (0, 1), dy));
+            }
+        }
+
+        return self.visible_targets(center, targets.into_iter().flatten(), &is_blocking);
+    }
+
+    /// # Description
+    /// Returns targets that are passable and have no blocking tiles on the straight line from
+    /// the origin, in row-major order.
+    fn visible_targets<I, F>(&self, origin: Vector2, targets: I, is_blocking: &F) -> Vec<Vector2>
+        where I: Iterator<Item = Vector2>,
+              F: Fn(Option<char>) -> bool {
+        let mut visible: Vec<Vector2> = targets
+            .filter(|target| self.is_passable(*target, is_blocking))
+            .filter(|target| line(origin, *target).into_iter().skip(1).all(|position| !is_blocking(self.get(position))))
+            .collect();
+
+        visible.sort();
+        visible.dedup();
+        return visible;
+    }
+
+    /// # Description
+    /// Checks if position is in bounds and is not blocking.
+    fn is_passable<F>(&self, position: Vector2, is_blocking: &F) -> bool
+        where F: Fn(Option<char>) -> bool {
+        return position.x < self.size.x && position.y < self.size.y && !is_blocking(self.get(position));
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Returns unit steps along the direction and to the right of it.
+fn axes(direction: Direction) -> ((isize, isize), (isize, isize)) {
+    return match direction {
+        Direction::Up => ((0, -1), (1, 0)),
+        Direction::Right => ((1, 0), (0, 1)),
+        Direction::Down => ((0, 1), (-1, 0)),
+        Direction::Left => ((-1, 0), (0, -1)),
+    };
+}
+
+/// # Description
+/// Returns position that is `distance` steps along `forward` and `lateral` steps along `side`
+/// from the origin, or [`None`] if it has a negative coordinate.
+fn offset(origin: Vector2, forward: (isize, isize), distance: isize, side: (isize, isize), lateral: isize) -> Option<Vector2> {
+    let x = origin.x.checked_add_signed(forward.0 * distance + side.0 * lateral)?;
+    let y = origin.y.checked_add_signed(forward.1 * distance + side.1 * lateral)?;
+    return Some(Vector2::new(x, y));
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Direction, Tilemap, Vector2};
+
+    /// # Description
+    /// Draws positions with `*` over the tilemap.
+    fn draw(tilemap: &Tilemap, positions: &[Vector2]) -> String {
+        let mut result = tilemap.clone();
+        for position in positions {
+            result.set(*position, '*');
+        }
+
+        return result.build();
+    }
+
+    /// # Description
+    /// Checks if the value is a wall.
+    fn is_wall(value: Option<char>) -> bool {
+        return value == Some('#');
+    }
+
+    #[test]
+    fn cone() {
+        let tilemap = Tilemap::from_string(".......\n.......\n..#....\n...@...", '.');
+        let cone = tilemap.cone(Vector2::new(3, 3), Direction::Up, 3, is_wall);
+        assert_eq!(draw(&tilemap, &cone), "..*****\n...***.\n..#**..\n...@...");
+
+        let cone = tilemap.cone(Vector2::new(3, 3), Direction::Left, 2, is_wall);
+        assert_eq!(draw(&tilemap, &cone), ".......\n.......\n..#....\n.**@...");
+    }
+
+    #[test]
+    fn beam() {
+        let tilemap = Tilemap::from_string(".....\n..#..\n.....\n..@..", '.');
+
+        let beam = tilemap.beam(Vector2::new(2, 3), Direction::Up, 5, 3, is_wall);
+        assert_eq!(draw(&tilemap, &beam), ".*.*.\n.*#*.\n.***.\n..@..");
+
+        let beam = tilemap.beam(Vector2::new(2, 3), Direction::Right, 1, 2, is_wall);
+        assert_eq!(beam, vec![Vector2::new(3, 3)]);
+        assert_eq!(tilemap.beam(Vector2::new(2, 3), Direction::Up, 5, 0, is_wall), vec![]);
+    }
+
+    #[test]
+    fn burst() {
+        let tilemap = Tilemap::from_string(".....\n.#...\n..@..\n.....", '.');
+        let burst = tilemap.burst(Vector2::new(2, 2), 2, is_wall);
+        assert_eq!(draw(&tilemap, &burst), "..*..\n.#**.\n*****\n.***.");
+
+        assert!(!tilemap.burst(Vector2::new(1, 1), 3, is_wall).contains(&Vector2::new(1, 1)));
+        assert_eq!(tilemap.burst(Vector2::ZERO, 0, is_wall), vec![Vector2::ZERO]);
+    }
+}
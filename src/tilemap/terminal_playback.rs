@@ -0,0 +1,141 @@
+use std::io::Write;
+use std::time::Duration;
+use crate::tilemap::{Animation, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// ANSI escape sequence that clears the screen and moves cursor to the top left corner.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Animation {
+    /// # Description
+    /// Plays [`Animation`] in the terminal using standard output. The first frame, and every
+    /// frame that changes size, is drawn fully, other frames are drawn by redrawing only changed
+    /// tiles.
+    ///
+    /// # Arguments
+    /// * `fps: u32` - Number of frames per second. If it is `0`, durations of frames are used.
+    ///
+    /// # Return
+    /// * [`Ok`] when all frames were played.
+    /// * [`Err`] if writing to the standard output failed.
+    ///
+    /// # Notes
+    /// Playback uses only plain ANSI escape sequences and does not switch terminal to the raw mode
+    /// or to the alternate screen, so interrupting it with ctrl-c leaves terminal in a clean state.
+    pub fn play_to_terminal(&self, fps: u32) -> std::io::Result<()> {
+        let stdout = std::io::stdout();
+        let mut output = stdout.lock();
+        return self.play(&mut output, fps, true);
+    }
+
+    /// # Description
+    /// Plays [`Animation`] into the specified output. Works the same way as
+    /// [`Animation::play_to_terminal()`].
+    ///
+    /// # Arguments
+    /// * `output: &mut W` - Output into which frames are written.
+    /// * `fps: u32` - Number of frames per second. If it is `0`, durations of frames are used.
+    /// * `wait: bool` - Should playback wait between frames.
+    ///
+    /// # Return
+    /// * [`Ok`] when all frames were played.
+    /// * [`Err`] if writing to the output failed.
+    pub fn play<W: Write>(&self, output: &mut W, fps: u32, wait: bool) -> std::io::Result<()> {
+        let mut previous: Option<Tilemap> = None;
+
+        for (state, duration) in self.states() {
+            match &previous {
+                Some(previous) if previous.size() == state.size() => {
+                    for change in previous.diff(&state) {
+                        write_tile(output, change.position, change.new.unwrap_or(state.empty_tile))?;
+                    }
+                    if previous.empty_tile != state.empty_tile {
+                        write_full(output, &state)?;
+                    }
+                },
+                _ => write_full(output, &state)?,
+            }
+            output.flush()?;
+
+            if wait {
+                std::thread::sleep(if fps > 0 { Duration::from_secs(1) / fps } else { duration });
+            }
+            previous = Some(state);
+        }
+
+        // Move cursor below the last frame, so the following output does not overwrite it
+        if let Some(previous) = previous {
+            writeln!(output, "\x1b[{};1H", previous.size().y)?;
+            output.flush()?;
+        }
+
+        return Ok(());
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Plays blinks of the [`Tilemap`] in the terminal using standard output, see
+    /// [`Tilemap::set_blink()`]. Only blinking tiles are redrawn after the first frame.
+    ///
+    /// # Arguments
+    /// * `fps: u32` - Number of frames per second. If it is `0`, one frame per second is played.
+    /// * `cycles: usize` - How many times all blinks should be repeated.
+    ///
+    /// # Return
+    /// * [`Ok`] when all frames were played.
+    /// * [`Err`] if writing to the standard output failed.
+    pub fn play_blinks_to_terminal(&self, fps: u32, cycles: usize) -> std::io::Result<()> {
+        return self.blink_animation(cycles, Duration::from_secs(1)).play_to_terminal(fps);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Clears the screen and writes the whole `tilemap`.
+fn write_full<W: Write>(output: &mut W, tilemap: &Tilemap) -> std::io::Result<()> {
+    return write!(output, "{CLEAR_SCREEN}{}", tilemap.build().replace('\n', "\r\n"));
+}
+
+/// # Description
+/// Moves cursor to the `position` and writes `value` there.
+fn write_tile<W: Write>(output: &mut W, position: Vector2, value: char) -> std::io::Result<()> {
+    return write!(output, "\x1b[{};{}H{value}", position.y + 1, position.x + 1);
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::tilemap::{Animation, Tilemap, Vector2};
+
+    #[test]
+    fn play() {
+        let mut tilemap = Tilemap::new('-');
+        tilemap.set(Vector2::ONE, 'X');
+
+        let mut animation = Animation::new();
+        animation.push_frame(tilemap.clone(), Duration::ZERO);
+        tilemap.set(Vector2::ZERO, 'O');
+        animation.push_frame_as_diff(&tilemap, Duration::ZERO).unwrap();
+
+        let mut output = Vec::new();
+        animation.play(&mut output, 0, false).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "\x1b[2J\x1b[H--\r\n-X\x1b[1;1HO\x1b[2;1H\n");
+    }
+}
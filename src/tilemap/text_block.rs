@@ -0,0 +1,172 @@
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Value of the tiles that fill the space around the text in [`Tilemap::draw_text_block()`].
+const PADDING: char = ' ';
+
+/// # Description
+/// Horizontal alignment of lines in [`Tilemap::draw_text_block()`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum TextAlignment {
+    /// # Description
+    /// Lines start at the left edge of the region.
+    Left,
+    /// # Description
+    /// Lines are centered. If the remaining space is odd, extra space goes to the right.
+    Center,
+    /// # Description
+    /// Lines end at the right edge of the region.
+    Right,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Word-wraps the text and writes it into the rectangular region line by line. Line breaks in
+    /// the text start new lines, words longer than the region width are split. All positions of
+    /// the region that are not covered by the text are filled with spaces, so previous tiles do
+    /// not show through.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Top left corner of the region.
+    /// * `size: Vector2` - Size of the region.
+    /// * `text: &str` - Written text.
+    /// * `alignment: TextAlignment` - Horizontal alignment of every line.
+    ///
+    /// # Return
+    /// * [`Ok`] if text was written.
+    /// * [`Err`] if wrapped text has more lines than the region height, or region has zero width
+    ///   while text is not empty. Contains error message. [`Tilemap`] stays unchanged in that case.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('.');
+    /// tilemap.draw_text_block(char_tilemap::Vector2::ZERO, char_tilemap::Vector2::new(7, 2),
+    ///                         "Hello brave", char_tilemap::TextAlignment::Center).unwrap();
+    /// assert_eq!(tilemap.build(), " Hello \n brave ");
+    /// ```
+    pub fn draw_text_block(&mut self, position: Vector2, size: Vector2, text: &str,
+                           alignment: TextAlignment) -> Result<(), String> {
+        let lines = wrap(text, size.x);
+        if lines.len() > size.y || (size.x == 0 && !lines.is_empty()) {
+            return Err(format!("Text does not fit into the region of size {size}"));
+        }
+
+        for y in 0..size.y {
+            let line: Vec<char> = lines.get(y).map_or(Vec::new(), |line| line.chars().collect());
+            let start = match alignment {
+                TextAlignment::Left => 0,
+                TextAlignment::Center => (size.x - line.len()) / 2,
+                TextAlignment::Right => size.x - line.len(),
+            };
+
+            for x in 0..size.x {
+                let value = x.checked_sub(start).and_then(|index| line.get(index)).copied().unwrap_or(PADDING);
+                self.set(Vector2::new(position.x + x, position.y + y), value);
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Splits text into lines that are not longer than `width` chars. Words are separated by
+/// whitespaces and are never joined, words that are longer than `width` are split.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    if text.is_empty() {
+        return lines;
+    }
+    if width == 0 {
+        return text.split_whitespace().map(|word| word.to_string()).collect();
+    }
+
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        let mut length = 0;
+
+        for word in paragraph.split_whitespace() {
+            let mut word: Vec<char> = word.chars().collect();
+
+            if length > 0 && length + 1 + word.len() > width {
+                lines.push(std::mem::take(&mut line));
+                length = 0;
+            }
+
+            while word.len() > width {
+                let rest = word.split_off(width);
+                lines.push(word.into_iter().collect());
+                word = rest;
+            }
+
+            if length > 0 {
+                line.push(' ');
+                length += 1;
+            }
+            line.extend(word.iter());
+            length += word.len();
+        }
+
+        lines.push(line);
+    }
+
+    return lines;
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{TextAlignment, Tilemap, Vector2};
+
+    #[test]
+    fn alignment() {
+        let text = "The quick brown fox";
+        let size = Vector2::new(10, 3);
+
+        for (alignment, expected) in [(TextAlignment::Left, "The quick \nbrown fox \n          "),
+                                      (TextAlignment::Center, "The quick \nbrown fox \n          "),
+                                      (TextAlignment::Right, " The quick\n brown fox\n          ")] {
+            let mut tilemap = Tilemap::new('.');
+            tilemap.draw_text_block(Vector2::ZERO, size, text, alignment).unwrap();
+            assert_eq!(tilemap.build(), expected);
+        }
+    }
+
+    #[test]
+    fn wrapping() {
+        let mut tilemap = Tilemap::from_string("#######\n#######\n#######\n#######\n#######", '.');
+        tilemap.draw_text_block(Vector2::ONE, Vector2::new(5, 3), "Hi\nabcdefgh", TextAlignment::Left).unwrap();
+        assert_eq!(tilemap.build(), "#######\n#Hi   #\n#abcde#\n#fgh  #\n#######");
+    }
+
+    #[test]
+    fn overflow() {
+        let mut tilemap = Tilemap::from_string("###", '.');
+
+        for (size, text) in [(Vector2::new(3, 1), "one two"), (Vector2::new(0, 1), "a"), (Vector2::new(3, 0), "a")] {
+            match tilemap.draw_text_block(Vector2::ZERO, size, text, TextAlignment::Left) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+        assert_eq!(tilemap.build(), "###");
+
+        tilemap.draw_text_block(Vector2::ZERO, Vector2::ZERO, "", TextAlignment::Left).unwrap();
+        assert_eq!(tilemap.build(), "###");
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use crate::tilemap::Color;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Default colors of tile values that are used by colored renderers, such as
+/// [`crate::AnsiRenderer`] and [`crate::HtmlRenderer`]. Colors assigned to single tiles by
+/// [`crate::Tilemap::set_tile_color()`] take priority over the theme.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::Renderer;
+///
+/// let mut theme = char_tilemap::Theme::new();
+/// theme.set('~', char_tilemap::Color::Blue);
+///
+/// let tilemap = char_tilemap::Tilemap::from_string("~", '.');
+/// let renderer = char_tilemap::AnsiRenderer::with_theme(theme);
+/// assert_eq!(renderer.render(&tilemap), "\x1b[34m~\x1b[0m");
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Theme {
+    /// # Description
+    /// Colors assigned to tile values.
+    colors: HashMap<char, Color>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Theme {
+    /// # Description
+    /// Creates new [`Theme`] without any assigned colors.
+    ///
+    /// # Return
+    /// New instance of the [`Theme`].
+    pub fn new() -> Theme {
+        return Theme { colors: HashMap::new() };
+    }
+
+    /// # Description
+    /// Creates [`Theme`] with common colors of roguelike maps: walls, floors, water, grass,
+    /// doors, lava, gold and the player.
+    ///
+    /// # Return
+    /// New instance of the [`Theme`].
+    pub fn roguelike() -> Theme {
+        let mut theme = Theme::new();
+        theme.set('#', Color::White);
+        theme.set('.', Color::Rgb(128, 128, 128));
+        theme.set('~', Color::Blue);
+        theme.set('"', Color::Green);
+        theme.set('+', Color::Yellow);
+        theme.set('^', Color::Red);
+        theme.set('$', Color::Yellow);
+        theme.set('@', Color::Cyan);
+
+        return theme;
+    }
+
+    /// # Description
+    /// Assigns color to the tile value.
+    ///
+    /// # Arguments
+    /// * `value: char` - Tile value.
+    /// * `color: Color` - Color of tiles with the specified value.
+    pub fn set(&mut self, value: char, color: Color) {
+        self.colors.insert(value, color);
+    }
+
+    /// # Description
+    /// Removes color of the tile value.
+    ///
+    /// # Arguments
+    /// * `value: char` - Tile value.
+    pub fn remove(&mut self, value: char) {
+        self.colors.remove(&value);
+    }
+
+    /// # Description
+    /// Returns color of the tile value.
+    ///
+    /// # Arguments
+    /// * `value: char` - Tile value.
+    ///
+    /// # Return
+    /// * [`Some`] with the color assigned to the value.
+    /// * [`None`] if value has no color.
+    pub fn get(&self, value: char) -> Option<Color> {
+        return self.colors.get(&value).copied();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Color, Theme};
+
+    #[test]
+    fn set_get_remove() {
+        let mut theme = Theme::new();
+        assert_eq!(theme.get('#'), None);
+
+        theme.set('#', Color::Red);
+        theme.set('#', Color::Green);
+        assert_eq!(theme.get('#'), Some(Color::Green));
+
+        theme.remove('#');
+        assert_eq!(theme.get('#'), None);
+        assert_eq!(Theme::roguelike().get('~'), Some(Color::Blue));
+    }
+}
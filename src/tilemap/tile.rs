@@ -5,7 +5,7 @@
 /// # Description
 /// Object that describes a tile, point on a 'map' described by position and specific 'value',
 /// which describes how it looks on said map.
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone)]
 pub struct Tile {
     /// # Description
     /// Position represented as [`crate::tilemap::Vector2`] of the [`Tile`] in 2d space.
@@ -42,7 +42,7 @@ impl PartialOrd for Tile {
     /// # Return
     /// [`Some`] with result of [`Tile::cmp()`].
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        return Some(self.cmp(&other));
+        return Some(self.cmp(other));
     }
 }
 
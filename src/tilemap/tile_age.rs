@@ -0,0 +1,125 @@
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Returns the current time of the [`Tilemap`] clock in ticks. Clock starts at 0 and is
+    /// advanced only by [`Tilemap::tick()`].
+    pub fn clock(&self) -> u64 {
+        return self.clock;
+    }
+
+    /// # Description
+    /// Advances the [`Tilemap`] clock by a single tick, so all tiles get one tick older.
+    ///
+    /// # Return
+    /// The new time of the clock.
+    pub fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        return self.clock;
+    }
+
+    /// # Description
+    /// Starts to stamp tiles with the current time of the clock whenever they are added or
+    /// updated, so their ages are known, see [`Tilemap::tile_age()`]. Existing tiles are stamped
+    /// with the current time. Already kept stamps are replaced.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::Vector2;
+    ///
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("#.", '.');
+    /// tilemap.enable_ages();
+    /// tilemap.tick();
+    /// tilemap.set(Vector2::new(1, 0), '~');
+    /// tilemap.tick();
+    ///
+    /// assert_eq!(tilemap.tile_age(Vector2::ZERO), Some(2));
+    /// assert_eq!(tilemap.tile_age(Vector2::new(1, 0)), Some(1));
+    /// ```
+    pub fn enable_ages(&mut self) {
+        let clock = self.clock;
+        self.stamps = Some(self.tiles.iter().map(|tile| (tile.position, clock)).collect());
+    }
+
+    /// # Description
+    /// Stops to stamp tiles and drops already kept stamps.
+    pub fn disable_ages(&mut self) {
+        self.stamps = None;
+    }
+
+    /// # Description
+    /// Checks if tiles are stamped, see [`Tilemap::enable_ages()`].
+    pub fn has_ages(&self) -> bool {
+        return self.stamps.is_some();
+    }
+
+    /// # Description
+    /// Returns number of ticks since the tile was added or updated for the last time.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with the age of the tile.
+    /// * [`None`] if there is no tile or ages are not enabled.
+    pub fn tile_age(&self, position: Vector2) -> Option<u64> {
+        return self.stamps.as_ref()
+            .and_then(|stamps| stamps.get(&position))
+            .map(|stamp| self.clock - stamp);
+    }
+
+    /// # Description
+    /// Stamps the changed tile with the current time, or drops the stamp of the removed one, if
+    /// ages are enabled.
+    pub(crate) fn stamp_tile(&mut self, position: Vector2, new: Option<char>) {
+        if let Some(stamps) = &mut self.stamps {
+            match new {
+                Some(_) => stamps.insert(position, self.clock),
+                None => stamps.remove(&position),
+            };
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[test]
+    fn tile_age() {
+        let mut tilemap = Tilemap::from_string("ab\n..", '.');
+        assert_eq!(tilemap.tile_age(Vector2::ZERO), None);
+
+        tilemap.enable_ages();
+        assert!(tilemap.has_ages());
+        assert_eq!(tilemap.tile_age(Vector2::ZERO), Some(0));
+
+        for _ in 0..5 {
+            tilemap.tick();
+        }
+        tilemap.set(Vector2::ZERO, 'c');
+        tilemap.set(Vector2::new(1, 0), 'b');
+        tilemap.add_tile(Vector2::ONE, 'd').unwrap();
+        assert_eq!(tilemap.tick(), 6);
+
+        assert_eq!(tilemap.tile_age(Vector2::ZERO), Some(1));
+        assert_eq!(tilemap.tile_age(Vector2::new(1, 0)), Some(6));
+        assert_eq!(tilemap.tile_age(Vector2::ONE), Some(1));
+        assert_eq!(tilemap.tile_age(Vector2::new(0, 1)), None);
+
+        tilemap.remove_tile(Vector2::ONE).unwrap();
+        assert_eq!(tilemap.tile_age(Vector2::ONE), None);
+
+        tilemap.disable_ages();
+        assert_eq!(tilemap.tile_age(Vector2::ZERO), None);
+        assert_eq!(tilemap.clock(), 6);
+    }
+}
@@ -0,0 +1,231 @@
+use crate::tilemap::{Tile, TileOp, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Describes change of a single position of the [`Tilemap`].
+/// `None` value means that there is no tile at the position.
+///
+/// # Example
+/// ```rust
+/// let change = char_tilemap::TileChange::new(char_tilemap::Vector2::ZERO, None, Some('O'));
+/// assert!(change.is_addition());
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct TileChange {
+    /// # Description
+    /// Position of the changed tile.
+    pub position: Vector2,
+    /// # Description
+    /// Value of the tile before the change.
+    pub old: Option<char>,
+    /// # Description
+    /// Value of the tile after the change.
+    pub new: Option<char>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl TileChange {
+    /// # Description
+    /// Creates new [`TileChange`] using specified values.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the changed tile.
+    /// * `old: Option<char>` - Value of the tile before the change.
+    /// * `new: Option<char>` - Value of the tile after the change.
+    ///
+    /// # Return
+    /// Newly created [`TileChange`] with specified values.
+    pub fn new(position: Vector2, old: Option<char>, new: Option<char>) -> TileChange {
+        return TileChange { position, old, new };
+    }
+
+    /// # Description
+    /// Checks if this change adds a new tile.
+    ///
+    /// # Return
+    /// `true` if there was no tile before the change, but there is one after.
+    pub fn is_addition(&self) -> bool {
+        return self.old.is_none() && self.new.is_some();
+    }
+
+    /// # Description
+    /// Checks if this change removes a tile.
+    ///
+    /// # Return
+    /// `true` if there was a tile before the change, but there is none after.
+    pub fn is_removal(&self) -> bool {
+        return self.old.is_some() && self.new.is_none();
+    }
+
+    /// # Description
+    /// Checks if this change modifies value of an existing tile.
+    ///
+    /// # Return
+    /// `true` if there is a tile before and after the change.
+    pub fn is_modification(&self) -> bool {
+        return self.old.is_some() && self.new.is_some();
+    }
+
+    /// # Description
+    /// Creates change that reverts this one.
+    ///
+    /// # Return
+    /// New [`TileChange`] with swapped `old` and `new` values.
+    pub fn inverted(&self) -> TileChange {
+        return TileChange { position: self.position, old: self.new, new: self.old };
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Calculates changes that turn this [`Tilemap`] into the `other` one.
+    /// Only tiles are compared, size and empty tile are ignored.
+    ///
+    /// # Arguments
+    /// * `other: &Tilemap` - Target [`Tilemap`].
+    ///
+    /// # Return
+    /// [`Vec`] of [`TileChange`]s in row-major order of their positions.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut first = char_tilemap::Tilemap::new('-');
+    /// let mut second = char_tilemap::Tilemap::new('-');
+    /// second.set(char_tilemap::Vector2::ONE, 'O');
+    ///
+    /// let changes = first.diff(&second);
+    /// first.apply_changes(&changes);
+    /// assert_eq!(first.build(), second.build());
+    /// ```
+    pub fn diff(&self, other: &Tilemap) -> Vec<TileChange> {
+        return diff_tiles(&self.tiles, &other.tiles);
+    }
+
+    /// # Description
+    /// Applies changes to this [`Tilemap`]. Only [`TileChange::new`] values are used, so changes
+    /// are applied regardless of the current state of tiles. All changes are applied as a single
+    /// batch, see [`Tilemap::apply_ops()`].
+    ///
+    /// # Arguments
+    /// * `changes: &[TileChange]` - Changes that should be applied in order.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('-');
+    /// let change = char_tilemap::TileChange::new(char_tilemap::Vector2::ZERO, None, Some('O'));
+    /// tilemap.apply_changes(&[change]);
+    /// assert_eq!(tilemap.get(char_tilemap::Vector2::ZERO), Some('O'));
+    /// ```
+    pub fn apply_changes(&mut self, changes: &[TileChange]) {
+        let ops: Vec<TileOp> = changes.iter()
+            .map(|change| match change.new {
+                Some(value) => TileOp::Set(change.position, value),
+                None => TileOp::Remove(change.position),
+            })
+            .collect();
+
+        // Removal of missing tiles is not an error for changes
+        let _ = self.apply_ops(&ops);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Calculates changes that turn `old` tiles into `new` ones, in row-major order of their positions.
+pub(super) fn diff_tiles(old: &sorted_vec::SortedSet<Tile>, new: &sorted_vec::SortedSet<Tile>) -> Vec<TileChange> {
+    let mut changes = Vec::new();
+    let mut old_tiles = old.iter().peekable();
+    let mut new_tiles = new.iter().peekable();
+
+    loop {
+        let change = match (old_tiles.peek(), new_tiles.peek()) {
+            (Some(old), Some(new)) => match old.position.cmp(&new.position) {
+                std::cmp::Ordering::Less => TileChange::new(old.position, Some(old.value), None),
+                std::cmp::Ordering::Greater => TileChange::new(new.position, None, Some(new.value)),
+                std::cmp::Ordering::Equal => TileChange::new(old.position, Some(old.value), Some(new.value)),
+            },
+            (Some(old), None) => TileChange::new(old.position, Some(old.value), None),
+            (None, Some(new)) => TileChange::new(new.position, None, Some(new.value)),
+            (None, None) => break,
+        };
+
+        if change.old.is_some() {
+            old_tiles.next();
+        }
+        if change.new.is_some() {
+            new_tiles.next();
+        }
+        if change.old != change.new {
+            changes.push(change);
+        }
+    }
+
+    return changes;
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{TileChange, Tilemap, Vector2};
+
+    fn build_test_tilemaps() -> (Tilemap, Tilemap) {
+        let mut first = Tilemap::new('-');
+        first.set(Vector2::new(0, 0), 'A');
+        first.set(Vector2::new(1, 0), 'B');
+        first.set(Vector2::new(2, 2), 'C');
+
+        let mut second = Tilemap::new('-');
+        second.set(Vector2::new(1, 0), 'X');
+        second.set(Vector2::new(1, 1), 'Y');
+        second.set(Vector2::new(2, 2), 'C');
+
+        return (first, second);
+    }
+
+    #[test]
+    fn kinds() {
+        let change = TileChange::new(Vector2::ZERO, Some('A'), None);
+        assert!(change.is_removal());
+        assert!(!change.is_addition());
+        assert!(!change.is_modification());
+        assert!(change.inverted().is_addition());
+        assert!(TileChange::new(Vector2::ZERO, Some('A'), Some('B')).is_modification());
+    }
+
+    #[test]
+    fn diff() {
+        let (first, second) = build_test_tilemaps();
+
+        assert_eq!(first.diff(&second), vec![
+            TileChange::new(Vector2::new(0, 0), Some('A'), None),
+            TileChange::new(Vector2::new(1, 0), Some('B'), Some('X')),
+            TileChange::new(Vector2::new(1, 1), None, Some('Y')),
+        ]);
+        assert!(first.diff(&first).is_empty());
+    }
+
+    #[test]
+    fn apply_changes() {
+        let (mut first, second) = build_test_tilemaps();
+        let changes = first.diff(&second);
+
+        first.apply_changes(&changes);
+        assert_eq!(first.build(), second.build());
+
+        let inverted: Vec<TileChange> = changes.iter().rev().map(|change| change.inverted()).collect();
+        first.apply_changes(&inverted);
+        assert_eq!(first.build(), build_test_tilemaps().0.build());
+    }
+}
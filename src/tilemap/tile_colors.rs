@@ -0,0 +1,81 @@
+use crate::tilemap::{Color, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Assigns color to the position. Colors are stored in a separate layer, so they stay in place
+    /// when tiles are changed or removed. Colored renderers use it instead of their
+    /// [`crate::Theme`] color.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    /// * `color: Option<Color>` - New color, or [`None`] to remove the color.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::Renderer;
+    ///
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("##", '.');
+    /// tilemap.set_tile_color(char_tilemap::Vector2::new(1, 0), Some(char_tilemap::Color::Red));
+    /// assert_eq!(char_tilemap::AnsiRenderer::new().render(&tilemap), "#\x1b[31m#\x1b[0m");
+    /// ```
+    pub fn set_tile_color(&mut self, position: Vector2, color: Option<Color>) {
+        match color {
+            Some(color) => self.colors.insert(position, color),
+            None => self.colors.remove(&position),
+        };
+    }
+
+    /// # Description
+    /// Returns color assigned to the position.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with the color set by [`Tilemap::set_tile_color()`].
+    /// * [`None`] if position has no color.
+    pub fn tile_color(&self, position: Vector2) -> Option<Color> {
+        return self.colors.get(&position).copied();
+    }
+
+    /// # Description
+    /// Removes colors of all positions.
+    pub fn clear_tile_colors(&mut self) {
+        self.colors.clear();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Color, FlipAxis, Flipped, Tilemap, TileSource, Vector2};
+
+    #[test]
+    fn tile_colors() {
+        let mut tilemap = Tilemap::from_string("#.\n.#", '.');
+        tilemap.set_tile_color(Vector2::ZERO, Some(Color::Red));
+        tilemap.set_tile_color(Vector2::ONE, Some(Color::Blue));
+        tilemap.set_tile_color(Vector2::ONE, None);
+        assert_eq!(tilemap.tile_color(Vector2::ZERO), Some(Color::Red));
+        assert_eq!(tilemap.tile_color(Vector2::ONE), None);
+
+        let flipped = Flipped::new(&tilemap, FlipAxis::Horizontal);
+        assert_eq!(flipped.color(Vector2::new(1, 0)), Some(Color::Red));
+        let view = tilemap.view(Vector2::ZERO, Vector2::ONE).unwrap();
+        assert_eq!(view.color(Vector2::ZERO), Some(Color::Red));
+        assert_eq!(view.color(Vector2::ONE), None);
+
+        let cropped = tilemap.crop(Vector2::ZERO, Vector2::new(1, 1)).unwrap();
+        assert_eq!(cropped.tile_color(Vector2::ZERO), Some(Color::Red));
+
+        tilemap.clear_tile_colors();
+        assert_eq!(tilemap.tile_color(Vector2::ZERO), None);
+    }
+}
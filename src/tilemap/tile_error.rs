@@ -0,0 +1,82 @@
+use crate::tilemap::Vector2;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Error of a single tile operation, such as [`crate::Tilemap::add_tile()`]. Human-readable
+/// message is produced by its [`std::fmt::Display`] implementation only when it is needed, and
+/// it converts into [`String`], so it works with `?` in functions that return string errors.
+///
+/// # Example
+/// ```rust
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.add_tile(char_tilemap::Vector2::ZERO, 'O').unwrap();
+///
+/// let error = tilemap.add_tile(char_tilemap::Vector2::ZERO, 'X').unwrap_err();
+/// assert_eq!(error, char_tilemap::TileError::Occupied(char_tilemap::Vector2::ZERO));
+/// assert_eq!(error.to_string(), "There is already a tile at the position { x: 0, y: 0 }");
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum TileError {
+    /// # Description
+    /// There is already a tile at the position.
+    Occupied(Vector2),
+    /// # Description
+    /// There is no tile at the position.
+    Missing(Vector2),
+    /// # Description
+    /// Value is a control char rejected by [`crate::ControlCharPolicy::Reject`].
+    ControlChar(char),
+    /// # Description
+    /// Number of tiles would exceed [`crate::TilemapLimits::max_tiles`], which is stored.
+    TooManyTiles(usize),
+    /// # Description
+    /// Position or size is out of [`crate::TilemapLimits::max_size`].
+    OutOfLimits(Vector2),
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl std::fmt::Display for TileError {
+    /// # Description
+    /// Writes human-readable description of the error.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            TileError::Occupied(position) => write!(f, "There is already a tile at the position {position}"),
+            TileError::Missing(position) => write!(f, "There is no tile at the position {position}"),
+            TileError::ControlChar(value) => write!(f, "Control char '{}' can not be a tile value", value.escape_debug()),
+            TileError::TooManyTiles(max_tiles) => write!(f, "Tilemap can not have more than {max_tiles} tiles"),
+            TileError::OutOfLimits(position) => write!(f, "Position or size {position} is out of limits of the tilemap"),
+        };
+    }
+}
+
+impl std::error::Error for TileError {}
+
+impl From<TileError> for String {
+    /// # Description
+    /// Converts error into its human-readable description.
+    fn from(error: TileError) -> String {
+        return error.to_string();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{TileError, Vector2};
+
+    #[test]
+    fn display() {
+        assert_eq!(TileError::Missing(Vector2::new(1, 2)).to_string(), "There is no tile at the position { x: 1, y: 2 }");
+        assert_eq!(String::from(TileError::ControlChar('\n')), "Control char '\\n' can not be a tile value");
+        assert_eq!(TileError::TooManyTiles(10).to_string(), "Tilemap can not have more than 10 tiles");
+    }
+}
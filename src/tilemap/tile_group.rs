@@ -0,0 +1,346 @@
+use std::collections::BTreeSet;
+use crate::tilemap::{Orientation, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Named set of positions of the [`Tilemap`] that is moved, oriented and deleted as a unit,
+/// such as a multi-tile ship or a boss. Positions may be empty, they still belong to the group.
+/// Groups are registered by [`Tilemap::add_group()`].
+///
+/// # Example
+/// ```rust
+/// let mut tilemap = char_tilemap::Tilemap::from_string("<=>..\n.....", '.');
+/// let ship = [char_tilemap::Vector2::ZERO, char_tilemap::Vector2::new(1, 0), char_tilemap::Vector2::new(2, 0)];
+/// tilemap.add_group("ship", ship).unwrap();
+///
+/// tilemap.move_group("ship", char_tilemap::Vector2::new(2, 1)).unwrap();
+/// assert_eq!(tilemap.build(), ".....\n..<=>");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TileGroup {
+    /// # Description
+    /// Positions that belong to the group.
+    positions: BTreeSet<Vector2>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl TileGroup {
+    /// # Description
+    /// Returns positions of the group in the row-major order.
+    ///
+    /// # Return
+    /// Iterator over positions of the group.
+    pub fn positions(&self) -> impl Iterator<Item = Vector2> + '_ {
+        return self.positions.iter().copied();
+    }
+
+    /// # Description
+    /// Checks if the position belongs to the group.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position to check.
+    ///
+    /// # Return
+    /// `true` if position belongs to the group, `false` otherwise.
+    pub fn contains(&self, position: Vector2) -> bool {
+        return self.positions.contains(&position);
+    }
+
+    /// # Description
+    /// Returns number of positions in the group.
+    pub fn len(&self) -> usize {
+        return self.positions.len();
+    }
+
+    /// # Description
+    /// Checks if the group has no positions. Registered groups are never empty.
+    pub fn is_empty(&self) -> bool {
+        return self.positions.is_empty();
+    }
+
+    /// # Description
+    /// Returns top left corner of the smallest rectangle that contains all positions of the group.
+    pub fn position(&self) -> Vector2 {
+        let x = self.positions.iter().map(|position| position.x).min().unwrap_or(0);
+        let y = self.positions.first().map(|position| position.y).unwrap_or(0);
+        return Vector2::new(x, y);
+    }
+
+    /// # Description
+    /// Returns size of the smallest rectangle that contains all positions of the group.
+    pub fn size(&self) -> Vector2 {
+        let Some(last) = self.positions.last() else {
+            return Vector2::ZERO;
+        };
+
+        let position = self.position();
+        let right = self.positions.iter().map(|position| position.x).max().unwrap_or(0);
+        return Vector2::new(right - position.x + 1, last.y - position.y + 1);
+    }
+
+    /// # Description
+    /// Returns group where every position is replaced by the result of `transform`.
+    pub(super) fn map<F: Fn(Vector2) -> Vector2>(&self, transform: F) -> TileGroup {
+        return TileGroup { positions: self.positions.iter().map(|position| transform(*position)).collect() };
+    }
+}
+
+impl Tilemap {
+    /// # Description
+    /// Registers named group of positions. Tiles are not changed.
+    ///
+    /// # Arguments
+    /// * `name: &str` - Name of the group.
+    /// * `positions: I` - Positions that belong to the group.
+    ///
+    /// # Return
+    /// * [`Ok`] if group was registered.
+    /// * [`Err`] if group with the same name exists, there are no positions, or some of them are
+    ///   out of bounds. Contains error message.
+    pub fn add_group<I: IntoIterator<Item = Vector2>>(&mut self, name: &str, positions: I) -> Result<(), String> {
+        if self.groups.contains_key(name) {
+            return Err(format!("Group \"{name}\" already exists"));
+        }
+
+        let positions: BTreeSet<Vector2> = positions.into_iter().collect();
+        if positions.is_empty() {
+            return Err(format!("Group \"{name}\" has no positions"));
+        }
+        if let Some(position) = positions.iter().find(|position| !self.is_in_bounds(**position)) {
+            return Err(format!("Position {position} of the group \"{name}\" is out of bounds"));
+        }
+
+        self.groups.insert(name.to_string(), TileGroup { positions });
+        return Ok(());
+    }
+
+    /// # Description
+    /// Returns registered group.
+    ///
+    /// # Arguments
+    /// * `name: &str` - Name of the group.
+    ///
+    /// # Return
+    /// * [`Some`] with the group.
+    /// * [`None`] if there is no group with the specified name.
+    pub fn group(&self, name: &str) -> Option<&TileGroup> {
+        return self.groups.get(name);
+    }
+
+    /// # Description
+    /// Returns names of all registered groups in alphabetical order.
+    pub fn group_names(&self) -> impl Iterator<Item = &str> + '_ {
+        return self.groups.keys().map(|name| name.as_str());
+    }
+
+    /// # Description
+    /// Unregisters group, tiles are not changed.
+    ///
+    /// # Arguments
+    /// * `name: &str` - Name of the group.
+    ///
+    /// # Return
+    /// * [`Some`] with the removed group.
+    /// * [`None`] if there was no group with the specified name.
+    pub fn remove_group(&mut self, name: &str) -> Option<TileGroup> {
+        return self.groups.remove(name);
+    }
+
+    /// # Description
+    /// Removes all tiles of the group and unregisters it.
+    ///
+    /// # Arguments
+    /// * `name: &str` - Name of the group.
+    ///
+    /// # Return
+    /// * [`Ok`] if group was deleted.
+    /// * [`Err`] if there is no group with the specified name. Contains error message.
+    pub fn delete_group(&mut self, name: &str) -> Result<(), String> {
+        let group = self.groups.remove(name).ok_or_else(|| format!("There is no group \"{name}\""))?;
+        for position in &group.positions {
+            self.take_tile(*position);
+        }
+
+        return Ok(());
+    }
+
+    /// # Description
+    /// Moves all tiles of the group, so the top left corner of the group is at the specified
+    /// position. Group can not overlap tiles that do not belong to it.
+    ///
+    /// # Arguments
+    /// * `name: &str` - Name of the group.
+    /// * `position: Vector2` - New position of the top left corner of the group.
+    ///
+    /// # Return
+    /// * [`Ok`] if group was moved.
+    /// * [`Err`] if there is no group with the specified name, or group would be out of bounds
+    ///   or overlap other tiles. Contains error message. [`Tilemap`] stays unchanged.
+    pub fn move_group(&mut self, name: &str, position: Vector2) -> Result<(), String> {
+        let origin = self.group(name).ok_or_else(|| format!("There is no group \"{name}\""))?.position();
+        return self.transform_group(name, |tile_position| tile_position - origin + position);
+    }
+
+    /// # Description
+    /// Rotates or mirrors all tiles of the group within the smallest rectangle that contains
+    /// the group. Top left corner of the rectangle stays in place. Group can not overlap tiles
+    /// that do not belong to it.
+    ///
+    /// # Arguments
+    /// * `name: &str` - Name of the group.
+    /// * `orientation: Orientation` - How tiles of the group should be oriented.
+    ///
+    /// # Return
+    /// * [`Ok`] if group was oriented.
+    /// * [`Err`] if there is no group with the specified name, or group would be out of bounds
+    ///   or overlap other tiles. Contains error message. [`Tilemap`] stays unchanged.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("AB.\n...", '.');
+    /// tilemap.add_group("pair", [char_tilemap::Vector2::ZERO, char_tilemap::Vector2::new(1, 0)]).unwrap();
+    ///
+    /// tilemap.orient_group("pair", char_tilemap::Orientation::Rotate90).unwrap();
+    /// assert_eq!(tilemap.build(), "A..\nB..");
+    /// ```
+    pub fn orient_group(&mut self, name: &str, orientation: Orientation) -> Result<(), String> {
+        let group = self.group(name).ok_or_else(|| format!("There is no group \"{name}\""))?;
+        let (origin, size) = (group.position(), group.size());
+        return self.transform_group(name, |position| orientation.position(position - origin, size) + origin);
+    }
+
+    /// # Description
+    /// Moves every tile of the group to the position returned by `transform`, if all new
+    /// positions are in bounds and do not overlap tiles that do not belong to the group.
+    fn transform_group<F: Fn(Vector2) -> Vector2>(&mut self, name: &str, transform: F) -> Result<(), String> {
+        let group = self.groups.get(name).ok_or_else(|| format!("There is no group \"{name}\""))?;
+        let moves: Vec<(Vector2, Vector2, Option<char>)> = group.positions.iter()
+            .map(|position| (*position, transform(*position), self.get(*position)))
+            .collect();
+
+        if let Some(target) = self.group_conflicts(group, &transform).first() {
+            return Err(format!("Group \"{name}\" would be out of bounds or overlap tile at {target}"));
+        }
+
+        for (position, _, _) in &moves {
+            self.take_tile(*position);
+        }
+        for (_, target, value) in &moves {
+            if let Some(value) = value {
+                self.set(*target, *value);
+            }
+        }
+
+        let positions = moves.into_iter().map(|(_, target, _)| target).collect();
+        self.groups.insert(name.to_string(), TileGroup { positions });
+        return Ok(());
+    }
+
+    /// # Description
+    /// Returns new positions of the group that would be out of bounds or overlap tiles that do
+    /// not belong to the group, if every position is moved to the result of `transform`.
+    pub(super) fn group_conflicts<F: Fn(Vector2) -> Vector2>(&self, group: &TileGroup, transform: F) -> Vec<Vector2> {
+        return group.positions.iter()
+            .map(|position| transform(*position))
+            .filter(|target| !self.is_in_bounds(*target) || (!group.contains(*target) && self.get(*target).is_some()))
+            .collect();
+    }
+
+    /// # Description
+    /// Checks if the position is inside of the [`Tilemap`].
+    pub(crate) fn is_in_bounds(&self, position: Vector2) -> bool {
+        return position.x < self.size.x && position.y < self.size.y;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Orientation, Tilemap, Vector2};
+
+    fn build_test_tilemap() -> Tilemap {
+        let mut tilemap = Tilemap::from_string("AB...\n.C..#\n.....", '.');
+        tilemap.add_group("ship", [Vector2::new(0, 0), Vector2::new(1, 0), Vector2::new(1, 1), Vector2::new(0, 1)]).unwrap();
+
+        return tilemap;
+    }
+
+    #[test]
+    fn add_group() {
+        let mut tilemap = build_test_tilemap();
+
+        let group = tilemap.group("ship").unwrap();
+        assert_eq!(group.len(), 4);
+        assert_eq!(group.position(), Vector2::ZERO);
+        assert_eq!(group.size(), Vector2::new(2, 2));
+        assert!(group.contains(Vector2::new(0, 1)));
+        assert_eq!(tilemap.group_names().collect::<Vec<_>>(), vec!["ship"]);
+
+        for (name, positions) in [("ship", vec![Vector2::ZERO]), ("empty", vec![]), ("far", vec![Vector2::new(5, 0)])] {
+            match tilemap.add_group(name, positions) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+
+        assert!(tilemap.remove_group("ship").is_some());
+        assert!(tilemap.group("ship").is_none());
+        assert_eq!(tilemap.build(), "AB...\n.C..#\n.....");
+    }
+
+    #[test]
+    fn move_group() {
+        let mut tilemap = build_test_tilemap();
+
+        tilemap.move_group("ship", Vector2::new(1, 1)).unwrap();
+        assert_eq!(tilemap.build(), ".....\n.AB.#\n..C..");
+        assert_eq!(tilemap.group("ship").unwrap().position(), Vector2::ONE);
+
+        for position in [Vector2::new(3, 0), Vector2::new(4, 1), Vector2::new(0, 2)] {
+            match tilemap.move_group("ship", position) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+        assert_eq!(tilemap.build(), ".....\n.AB.#\n..C..");
+
+        match tilemap.move_group("boss", Vector2::ZERO) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+
+    #[test]
+    fn orient_group() {
+        let mut tilemap = build_test_tilemap();
+
+        tilemap.orient_group("ship", Orientation::Rotate90).unwrap();
+        assert_eq!(tilemap.build(), ".A...\nCB..#\n.....");
+
+        tilemap.orient_group("ship", Orientation::Rotate270).unwrap();
+        assert_eq!(tilemap.build(), "AB...\n.C..#\n.....");
+    }
+
+    #[test]
+    fn delete_group() {
+        let mut tilemap = build_test_tilemap();
+
+        tilemap.delete_group("ship").unwrap();
+        assert_eq!(tilemap.build(), ".....\n....#\n.....");
+        assert!(tilemap.group("ship").is_none());
+
+        match tilemap.delete_group("ship") {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+}
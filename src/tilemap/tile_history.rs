@@ -0,0 +1,126 @@
+use std::collections::{HashMap, VecDeque};
+use crate::tilemap::{Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Previous values of changed positions of the [`Tilemap`], see [`Tilemap::enable_history()`].
+#[derive(Debug, Clone)]
+pub(crate) struct TileHistory {
+    /// # Description
+    /// Maximum number of previous values that are kept for a single position.
+    capacity: usize,
+    /// # Description
+    /// Previous values of positions from the oldest to the latest. Empty tiles have [`None`]
+    /// value.
+    cells: HashMap<Vector2, VecDeque<Option<char>>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Starts to keep previous values of every position that is changed, so it is possible to
+    /// trace how a single cell evolved over time. Only the latest values are kept for each
+    /// position, older ones are dropped. Already kept values are dropped as well.
+    ///
+    /// # Arguments
+    /// * `capacity: usize` - Maximum number of previous values that are kept for a single
+    ///   position.
+    ///
+    /// # Return
+    /// * [`Ok`] if history was enabled.
+    /// * [`Err`] if capacity is 0. Contains error message.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut tilemap = char_tilemap::Tilemap::new('.');
+    /// tilemap.enable_history(2).unwrap();
+    /// for value in ['a', 'b', 'c'] {
+    ///     tilemap.set(char_tilemap::Vector2::ZERO, value);
+    /// }
+    ///
+    /// assert_eq!(tilemap.history_at(char_tilemap::Vector2::ZERO), vec![Some('a'), Some('b')]);
+    /// ```
+    pub fn enable_history(&mut self, capacity: usize) -> Result<(), String> {
+        if capacity == 0 {
+            return Err(String::from("At least one previous value must be kept"));
+        }
+
+        self.history = Some(TileHistory { capacity, cells: HashMap::new() });
+        return Ok(());
+    }
+
+    /// # Description
+    /// Stops to keep previous values of positions and drops already kept ones.
+    pub fn disable_history(&mut self) {
+        self.history = None;
+    }
+
+    /// # Description
+    /// Returns previous values of the position, see [`Tilemap::enable_history()`].
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    ///
+    /// # Return
+    /// Previous values from the oldest to the latest, where empty tiles are [`None`]. The current
+    /// value is not included. Empty if position was not changed or history is not enabled.
+    pub fn history_at(&self, position: Vector2) -> Vec<Option<char>> {
+        return self.history.as_ref()
+            .and_then(|history| history.cells.get(&position))
+            .map(|values| values.iter().copied().collect())
+            .unwrap_or_default();
+    }
+
+    /// # Description
+    /// Keeps previous value of the changed position, if history is enabled.
+    pub(crate) fn record_history(&mut self, position: Vector2, old: Option<char>) {
+        if let Some(history) = &mut self.history {
+            let values = history.cells.entry(position).or_default();
+            if values.len() == history.capacity {
+                values.pop_front();
+            }
+            values.push_back(old);
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{SymmetryAxis, Tilemap, Vector2};
+
+    #[test]
+    fn history_at() {
+        let mut tilemap = Tilemap::from_string("a.\n..", '.');
+        assert_eq!(tilemap.history_at(Vector2::ZERO), vec![]);
+        match tilemap.enable_history(0) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true),
+        }
+
+        tilemap.enable_history(3).unwrap();
+        tilemap.set(Vector2::ZERO, 'b');
+        tilemap.set(Vector2::ZERO, 'b');
+        tilemap.remove_tile(Vector2::ZERO).unwrap();
+        tilemap.add_tile(Vector2::ZERO, 'c').unwrap();
+        tilemap.set(Vector2::ZERO, 'd');
+
+        assert_eq!(tilemap.history_at(Vector2::ZERO), vec![Some('b'), None, Some('c')]);
+        assert_eq!(tilemap.history_at(Vector2::ONE), vec![]);
+
+        tilemap.symmetrize(SymmetryAxis::Vertical);
+        assert_eq!(tilemap.history_at(Vector2::new(1, 0)), vec![None]);
+
+        tilemap.disable_history();
+        assert_eq!(tilemap.history_at(Vector2::ZERO), vec![]);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::tilemap::{Tile, Tilemap};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Iterator over tiles of the [`Tilemap`] that is guaranteed to return them in the row-major
+/// order: by rows from top to bottom, and by columns from left to right inside of a row.
+/// Created by [`Tilemap::iter_ordered()`].
+#[derive(Debug, Clone)]
+pub struct OrderedTiles<'a> {
+    /// # Description
+    /// Tiles in the row-major order.
+    tiles: std::slice::Iter<'a, Tile>,
+}
+
+/// # Description
+/// Iterator over tiles of the [`Tilemap`] in unspecified order, which may change between versions
+/// of the crate or storages of tiles. Created by [`Tilemap::iter_unordered()`].
+#[derive(Debug, Clone)]
+pub struct UnorderedTiles<'a> {
+    /// # Description
+    /// Tiles in the order they are stored.
+    tiles: std::slice::Iter<'a, Tile>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Returns iterator over all tiles in the row-major order. Use it when result depends on the
+    /// order, for example when it is saved or compared.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string(".b\na.", '.');
+    /// let values: String = tilemap.iter_ordered().map(|tile| tile.value).collect();
+    /// assert_eq!(values, "ba");
+    /// ```
+    pub fn iter_ordered(&self) -> OrderedTiles<'_> {
+        return OrderedTiles { tiles: self.tiles.iter() };
+    }
+
+    /// # Description
+    /// Returns iterator over all tiles in unspecified order, which is allowed to be the fastest
+    /// one for the storage of tiles. Use it when result does not depend on the order, for example
+    /// when tiles are counted.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tilemap = char_tilemap::Tilemap::from_string(".b\na.", '.');
+    /// assert_eq!(tilemap.iter_unordered().filter(|tile| tile.value == 'a').count(), 1);
+    /// ```
+    pub fn iter_unordered(&self) -> UnorderedTiles<'_> {
+        return UnorderedTiles { tiles: self.tiles.iter() };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl Iterator for OrderedTiles<'_> {
+    type Item = Tile;
+
+    /// # Description
+    /// Returns the next tile in the row-major order.
+    fn next(&mut self) -> Option<Tile> {
+        return self.tiles.next().copied();
+    }
+
+    /// # Description
+    /// Returns exact number of remaining tiles.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.tiles.size_hint();
+    }
+}
+
+impl DoubleEndedIterator for OrderedTiles<'_> {
+    /// # Description
+    /// Returns the last remaining tile in the row-major order.
+    fn next_back(&mut self) -> Option<Tile> {
+        return self.tiles.next_back().copied();
+    }
+}
+
+impl ExactSizeIterator for OrderedTiles<'_> {}
+
+impl Iterator for UnorderedTiles<'_> {
+    type Item = Tile;
+
+    /// # Description
+    /// Returns the next tile.
+    fn next(&mut self) -> Option<Tile> {
+        return self.tiles.next().copied();
+    }
+
+    /// # Description
+    /// Returns exact number of remaining tiles.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.tiles.size_hint();
+    }
+}
+
+impl ExactSizeIterator for UnorderedTiles<'_> {}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    #[test]
+    fn iter_ordered() {
+        let mut tilemap = Tilemap::new('.');
+        tilemap.set(Vector2::new(2, 1), 'c');
+        tilemap.set(Vector2::new(0, 1), 'b');
+        tilemap.set(Vector2::new(3, 0), 'a');
+
+        let positions: Vec<Vector2> = tilemap.iter_ordered().map(|tile| tile.position).collect();
+        assert_eq!(positions, vec![Vector2::new(3, 0), Vector2::new(0, 1), Vector2::new(2, 1)]);
+        assert_eq!(tilemap.iter_ordered().next_back().unwrap().value, 'c');
+        assert_eq!(tilemap.iter_ordered().len(), 3);
+    }
+
+    #[test]
+    fn iter_unordered() {
+        let tilemap = Tilemap::from_string("ab\n.c", '.');
+
+        let mut values: Vec<char> = tilemap.iter_unordered().map(|tile| tile.value).collect();
+        values.sort();
+        assert_eq!(values, vec!['a', 'b', 'c']);
+        assert_eq!(tilemap.iter_unordered().len(), 3);
+    }
+}
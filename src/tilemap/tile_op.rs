@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+use crate::tilemap::{Tile, TileChange, TileError, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Single operation of the batch that is applied by [`Tilemap::apply_ops()`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum TileOp {
+    /// # Description
+    /// Adds a new tile, same as [`Tilemap::add_tile()`].
+    Add(Vector2, char),
+    /// # Description
+    /// Removes the tile, same as [`Tilemap::remove_tile()`].
+    Remove(Vector2),
+    /// # Description
+    /// Adds or updates the tile, same as [`Tilemap::set()`].
+    Set(Vector2, char),
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Applies batch of operations in order. Result is the same as if every operation was applied
+    /// by its own method, but tiles are merged in a single pass instead of being inserted one by
+    /// one, so big batches, such as replayed journals or network patches, are applied much faster.
+    /// Operations that can not be applied are skipped.
+    ///
+    /// # Arguments
+    /// * `ops: &[TileOp]` - Operations that should be applied in order.
+    ///
+    /// # Return
+    /// Indices of skipped operations in `ops` with their errors. Empty if all operations were
+    /// applied.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::{TileError, TileOp, Vector2};
+    ///
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("#.\n..", '.');
+    /// let errors = tilemap.apply_ops(&[
+    ///     TileOp::Add(Vector2::ZERO, '@'),
+    ///     TileOp::Remove(Vector2::ZERO),
+    ///     TileOp::Set(Vector2::new(2, 1), '+'),
+    /// ]);
+    ///
+    /// assert_eq!(errors, vec![(0, TileError::Occupied(Vector2::ZERO))]);
+    /// assert_eq!(tilemap.build(), "...\n..+");
+    /// ```
+    pub fn apply_ops(&mut self, ops: &[TileOp]) -> Vec<(usize, TileError)> {
+        let mut pending: BTreeMap<Vector2, Option<char>> = BTreeMap::new();
+        let mut errors = Vec::new();
+        let mut count = self.tiles.len();
+
+        for (index, op) in ops.iter().enumerate() {
+            let position = match op {
+                TileOp::Add(position, _) | TileOp::Remove(position) | TileOp::Set(position, _) => *position,
+            };
+            let current = pending.get(&position).copied().unwrap_or_else(|| self.get(position));
+
+            let result = match (op, current) {
+                (TileOp::Add(..), Some(_)) => Err(TileError::Occupied(position)),
+                (TileOp::Remove(_), None) => Err(TileError::Missing(position)),
+                (TileOp::Remove(_), Some(_)) => Ok(None),
+                (TileOp::Add(_, value) | TileOp::Set(_, value), current) => self.control_chars.apply(*value)
+                    .and_then(|value| match current {
+                        Some(_) => Ok(value),
+                        None => self.limits.check_new_tile(position, count).map(|_| value),
+                    })
+                    .map(Some),
+            };
+
+            match result {
+                Ok(new) => {
+                    match (current, new) {
+                        (None, Some(_)) => count += 1,
+                        (Some(_), None) => count -= 1,
+                        _ => (),
+                    }
+                    if new.is_some() {
+                        self.size.x = std::cmp::max(self.size.x, position.x + 1);
+                        self.size.y = std::cmp::max(self.size.y, position.y + 1);
+                    }
+                    pending.insert(position, new);
+                },
+                Err(error) => errors.push((index, error)),
+            }
+        }
+
+        self.merge_pending(pending);
+        return errors;
+    }
+
+    /// # Description
+    /// Merges final values of changed positions with existing tiles in a single pass.
+    fn merge_pending(&mut self, pending: BTreeMap<Vector2, Option<char>>) {
+        let mut tiles = Vec::with_capacity(self.tiles.len() + pending.len());
+        let mut changes = Vec::new();
+        let mut existing = self.tiles.iter().peekable();
+
+        for (position, new) in pending {
+            while let Some(tile) = existing.next_if(|tile| tile.position < position) {
+                tiles.push(*tile);
+            }
+
+            let old = existing.next_if(|tile| tile.position == position).map(|tile| tile.value);
+            if let Some(value) = new {
+                tiles.push(Tile { position, value });
+            }
+            changes.push(TileChange::new(position, old, new));
+        }
+        tiles.extend(existing.copied());
+
+        self.tiles = sorted_vec::SortedSet::from_unsorted(tiles);
+        for change in changes {
+            self.record(change.position, change.old, change.new);
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{ControlCharPolicy, TileError, TileOp, Tilemap, TilemapLimits, Vector2};
+
+    #[test]
+    fn apply_ops() {
+        let mut tilemap = Tilemap::from_string("#..\n.#.\n..#", '.');
+        tilemap.control_chars = ControlCharPolicy::Reject;
+        tilemap.start_journal();
+        let errors = tilemap.apply_ops(&[
+            TileOp::Remove(Vector2::ONE),
+            TileOp::Remove(Vector2::ONE),
+            TileOp::Add(Vector2::ONE, '@'),
+            TileOp::Set(Vector2::ZERO, '+'),
+            TileOp::Set(Vector2::new(3, 0), '>'),
+            TileOp::Add(Vector2::new(1, 0), '\u{7}'),
+            TileOp::Add(Vector2::new(4, 3), '<'),
+            TileOp::Remove(Vector2::new(4, 3)),
+        ]);
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0], (1, TileError::Missing(Vector2::ONE)));
+        assert_eq!(errors[1].0, 5);
+        assert_eq!(tilemap.build(), "+..>.\n.@...\n..#..\n.....");
+        assert_eq!(tilemap.journal().unwrap().entries().len(), 3);
+    }
+
+    #[test]
+    fn same_as_single_calls() {
+        let mut batched = Tilemap::from_string("ab\ncd", '.');
+        let mut single = batched.clone();
+        let ops: Vec<TileOp> = (0..20)
+            .map(|index| match index % 3 {
+                0 => TileOp::Set(Vector2::new(index % 4, index % 5), 'x'),
+                1 => TileOp::Remove(Vector2::new(index % 3, index % 2)),
+                _ => TileOp::Add(Vector2::new(index % 6, 1), 'y'),
+            })
+            .collect();
+
+        let errors = batched.apply_ops(&ops);
+        let mut single_errors = Vec::new();
+        for (index, op) in ops.iter().enumerate() {
+            let result = match *op {
+                TileOp::Add(position, value) => single.add_tile(position, value),
+                TileOp::Remove(position) => single.remove_tile(position),
+                TileOp::Set(position, value) => {
+                    single.set(position, value);
+                    Ok(())
+                },
+            };
+            if let Err(error) = result {
+                single_errors.push((index, error));
+            }
+        }
+
+        assert_eq!(errors, single_errors);
+        assert_eq!(batched.size(), single.size());
+        assert_eq!(batched.build(), single.build());
+    }
+
+    #[test]
+    fn limits() {
+        let mut tilemap = Tilemap::new('.');
+        tilemap.limits = TilemapLimits { max_tiles: Some(2), max_size: Some(Vector2::new(3, 3)) };
+        let errors = tilemap.apply_ops(&[
+            TileOp::Set(Vector2::ZERO, '#'),
+            TileOp::Set(Vector2::new(3, 0), '#'),
+            TileOp::Add(Vector2::ONE, '#'),
+            TileOp::Add(Vector2::new(2, 2), '#'),
+            TileOp::Remove(Vector2::ZERO),
+            TileOp::Add(Vector2::new(2, 2), '#'),
+        ]);
+
+        assert_eq!(errors, vec![(1, TileError::OutOfLimits(Vector2::new(3, 0))), (3, TileError::TooManyTiles(2))]);
+        assert_eq!(tilemap.build(), "...\n.#.\n..#");
+    }
+}
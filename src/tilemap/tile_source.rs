@@ -0,0 +1,275 @@
+use crate::tilemap::{Color, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Owned [`TileSource`] of any type that can be passed between threads, so separately compiled
+/// plugins and scripting hosts can hand maps across an abstraction boundary, see
+/// [`TileSource::into_boxed()`].
+pub type BoxedTileSource = Box<dyn TileSource + Send + Sync>;
+
+/// # Description
+/// Read-only source of tiles. Decouples rendering and other read-only algorithms from the concrete
+/// storage of tiles, so they can work over [`crate::Tilemap`], its views and any lazy adapters.
+/// The trait is object safe, so sources can also be used as `&dyn TileSource` or
+/// [`BoxedTileSource`].
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::TileSource;
+///
+/// fn count_tiles(source: &impl TileSource) -> usize {
+///     let mut count = 0;
+///     for y in 0..source.size().y {
+///         for x in 0..source.size().x {
+///             if source.get(char_tilemap::Vector2::new(x, y)).is_some() {
+///                 count += 1;
+///             }
+///         }
+///     }
+///     return count;
+/// }
+///
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::ONE, 'O');
+/// assert_eq!(count_tiles(&tilemap), 1);
+/// ```
+pub trait TileSource {
+    /// # Description
+    /// Returns size of the source.
+    ///
+    /// # Return
+    /// [`Vector2`] that stores size of the source.
+    fn size(&self) -> Vector2;
+
+    /// # Description
+    /// Returns value of the tile at the specified position if it exists.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with value of the tile if it exists.
+    /// * [`None`] if there is no tile at the specified position or it is out of the source.
+    fn get(&self, position: Vector2) -> Option<char>;
+
+    /// # Description
+    /// Returns value that should be used for empty tiles during the build.
+    ///
+    /// # Return
+    /// Value of the empty tile as [`char`].
+    fn empty_tile(&self) -> char;
+
+    /// # Description
+    /// Returns color that is explicitly assigned to the tile at the specified position.
+    /// Sources without colors do not need to implement it.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with the color of the tile.
+    /// * [`None`] if tile has no color, so colored renderers fall back to their [`crate::Theme`].
+    fn color(&self, _position: Vector2) -> Option<Color> {
+        return None;
+    }
+
+    /// # Description
+    /// Builds source into the string representation. X = 0 is a left column, Y = 0 is a top row.
+    /// Rows are separated by `'\n'`.
+    ///
+    /// # Return
+    /// A new [`String`] that contains representation of the source.
+    fn build(&self) -> String {
+        let size = self.size();
+        let mut result = String::new();
+
+        for y in 0..size.y {
+            if y > 0 {
+                result.push('\n');
+            }
+
+            for x in 0..size.x {
+                result.push(self.get(Vector2::new(x, y)).unwrap_or(self.empty_tile()));
+            }
+        }
+
+        return result;
+    }
+
+    /// # Description
+    /// Moves source into the [`BoxedTileSource`], hiding its concrete type.
+    ///
+    /// # Return
+    /// New [`BoxedTileSource`] with the source.
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::{BoxedTileSource, FlipAxis, Flipped, TileSource, Tilemap};
+    ///
+    /// let tilemap = Tilemap::from_string("#.", '.');
+    /// let sources: Vec<BoxedTileSource> = vec![
+    ///     Flipped::new(tilemap.clone(), FlipAxis::Horizontal).into_boxed(),
+    ///     tilemap.into_boxed(),
+    /// ];
+    ///
+    /// let built: Vec<String> = sources.iter().map(|source| source.build()).collect();
+    /// assert_eq!(built, vec![".#", "#."]);
+    /// ```
+    fn into_boxed(self) -> BoxedTileSource
+        where Self: Sized + Send + Sync + 'static {
+        return Box::new(self);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl<S: TileSource + ?Sized> TileSource for &S {
+    /// # Description
+    /// Returns size of the referenced source.
+    fn size(&self) -> Vector2 {
+        return (**self).size();
+    }
+
+    /// # Description
+    /// Returns value of the tile of the referenced source.
+    fn get(&self, position: Vector2) -> Option<char> {
+        return (**self).get(position);
+    }
+
+    /// # Description
+    /// Returns empty tile of the referenced source.
+    fn empty_tile(&self) -> char {
+        return (**self).empty_tile();
+    }
+
+    /// # Description
+    /// Returns color of the tile of the referenced source.
+    fn color(&self, position: Vector2) -> Option<Color> {
+        return (**self).color(position);
+    }
+
+    /// # Description
+    /// Builds the referenced source, so its own implementation of the build is used.
+    fn build(&self) -> String {
+        return (**self).build();
+    }
+}
+
+impl<S: TileSource + ?Sized> TileSource for Box<S> {
+    /// # Description
+    /// Returns size of the boxed source.
+    fn size(&self) -> Vector2 {
+        return (**self).size();
+    }
+
+    /// # Description
+    /// Returns value of the tile of the boxed source.
+    fn get(&self, position: Vector2) -> Option<char> {
+        return (**self).get(position);
+    }
+
+    /// # Description
+    /// Returns empty tile of the boxed source.
+    fn empty_tile(&self) -> char {
+        return (**self).empty_tile();
+    }
+
+    /// # Description
+    /// Returns color of the tile of the boxed source.
+    fn color(&self, position: Vector2) -> Option<Color> {
+        return (**self).color(position);
+    }
+
+    /// # Description
+    /// Builds the boxed source, so its own implementation of the build is used.
+    fn build(&self) -> String {
+        return (**self).build();
+    }
+}
+
+impl<S: TileSource + ?Sized> TileSource for std::sync::Arc<S> {
+    /// # Description
+    /// Returns size of the shared source.
+    fn size(&self) -> Vector2 {
+        return (**self).size();
+    }
+
+    /// # Description
+    /// Returns value of the tile of the shared source.
+    fn get(&self, position: Vector2) -> Option<char> {
+        return (**self).get(position);
+    }
+
+    /// # Description
+    /// Returns empty tile of the shared source.
+    fn empty_tile(&self) -> char {
+        return (**self).empty_tile();
+    }
+
+    /// # Description
+    /// Returns color of the tile of the shared source.
+    fn color(&self, position: Vector2) -> Option<Color> {
+        return (**self).color(position);
+    }
+
+    /// # Description
+    /// Builds the shared source, so its own implementation of the build is used.
+    fn build(&self) -> String {
+        return (**self).build();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::tilemap::{BoxedTileSource, Color, FlipAxis, Flipped, PlainRenderer, Renderer, TileSource, Tilemap, Vector2};
+
+    struct Checkerboard;
+
+    impl TileSource for Checkerboard {
+        fn size(&self) -> Vector2 {
+            return Vector2::new(3, 2);
+        }
+
+        fn get(&self, position: Vector2) -> Option<char> {
+            return if (position.x + position.y).is_multiple_of(2) { Some('#') } else { None };
+        }
+
+        fn empty_tile(&self) -> char {
+            return '.';
+        }
+    }
+
+    #[test]
+    fn build() {
+        assert_eq!(Checkerboard.build(), "#.#\n.#.");
+    }
+
+    #[test]
+    fn dyn_sources() {
+        let mut tilemap = Tilemap::from_string("#..", '.');
+        tilemap.set_tile_color(Vector2::ZERO, Some(Color::Red));
+
+        let boxed: BoxedTileSource = tilemap.clone().into_boxed();
+        assert_eq!(boxed.color(Vector2::ZERO), Some(Color::Red));
+        assert_eq!(Flipped::new(boxed, FlipAxis::Horizontal).build(), "..#");
+
+        let sources: Vec<&dyn TileSource> = vec![&tilemap, &Checkerboard];
+        let rendered: Vec<String> = sources.iter().map(|source| PlainRenderer.render(*source)).collect();
+        assert_eq!(rendered, vec!["#..", "#.#\n.#."]);
+
+        let shared: Arc<dyn TileSource + Send + Sync> = Arc::new(Checkerboard);
+        let copy = Tilemap::from_source(&shared);
+        assert_eq!(copy.build(), "#.#\n.#.");
+        assert_eq!(copy.empty_tile, '.');
+    }
+}
@@ -0,0 +1,408 @@
+use crate::tilemap::{Color, TileSource, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Axis across which [`Flipped`] mirrors its source.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum FlipAxis {
+    /// # Description
+    /// Mirrors columns, so the left column becomes the right one.
+    Horizontal,
+    /// # Description
+    /// Mirrors rows, so the top row becomes the bottom one.
+    Vertical,
+}
+
+/// # Description
+/// Clockwise rotation that is applied by [`Rotated`] to its source.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Rotation {
+    /// # Description
+    /// Rotation by 90 degrees clockwise.
+    Clockwise90,
+    /// # Description
+    /// Rotation by 180 degrees.
+    Clockwise180,
+    /// # Description
+    /// Rotation by 270 degrees clockwise, which is the same as 90 degrees counterclockwise.
+    Clockwise270,
+}
+
+/// # Description
+/// Lazy [`TileSource`] adapter that mirrors its source across the specified axis.
+/// Tiles are computed on demand, nothing is copied.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::TileSource;
+///
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::new(1, 0), 'O');
+///
+/// let flipped = char_tilemap::Flipped::new(&tilemap, char_tilemap::FlipAxis::Horizontal);
+/// assert_eq!(flipped.build(), "O-");
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Flipped<S: TileSource> {
+    /// # Description
+    /// Source that is flipped.
+    source: S,
+    /// # Description
+    /// Axis of the flip.
+    axis: FlipAxis,
+}
+
+/// # Description
+/// Lazy [`TileSource`] adapter that rotates its source clockwise.
+/// Tiles are computed on demand, nothing is copied.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::TileSource;
+///
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::new(1, 0), 'O');
+///
+/// let rotated = char_tilemap::Rotated::new(&tilemap, char_tilemap::Rotation::Clockwise90);
+/// assert_eq!(rotated.build(), "-\nO");
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Rotated<S: TileSource> {
+    /// # Description
+    /// Source that is rotated.
+    source: S,
+    /// # Description
+    /// Applied rotation.
+    rotation: Rotation,
+}
+
+/// # Description
+/// Lazy [`TileSource`] adapter that moves all tiles of its source by the specified offset.
+/// Size of the adapter grows by the same offset. Tiles are computed on demand, nothing is copied.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::TileSource;
+///
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::ZERO, 'O');
+///
+/// let offset = char_tilemap::Offset::new(&tilemap, char_tilemap::Vector2::new(1, 0));
+/// assert_eq!(offset.build(), "-O");
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Offset<S: TileSource> {
+    /// # Description
+    /// Source that is moved.
+    source: S,
+    /// # Description
+    /// Offset by which tiles are moved.
+    offset: Vector2,
+}
+
+/// # Description
+/// Lazy [`TileSource`] adapter that maps value of every existing tile of its source with the
+/// specified function. Tiles are computed on demand, nothing is copied.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::TileSource;
+///
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::ZERO, 'o');
+///
+/// let mapped = char_tilemap::Mapped::new(&tilemap, |value| value.to_ascii_uppercase());
+/// assert_eq!(mapped.build(), "O");
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Mapped<S: TileSource, F: Fn(char) -> char> {
+    /// # Description
+    /// Source which tiles are mapped.
+    source: S,
+    /// # Description
+    /// Function that is applied to the value of every existing tile.
+    function: F,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl<S: TileSource> Flipped<S> {
+    /// # Description
+    /// Creates new [`Flipped`] adapter.
+    ///
+    /// # Arguments
+    /// * `source: S` - Source that will be flipped.
+    /// * `axis: FlipAxis` - Axis of the flip.
+    ///
+    /// # Return
+    /// Newly created [`Flipped`] adapter.
+    pub fn new(source: S, axis: FlipAxis) -> Flipped<S> {
+        return Flipped { source, axis };
+    }
+
+    /// # Description
+    /// Returns position of the source tile that is mirrored to the specified position, or
+    /// [`None`] if position is out of bounds.
+    fn source_position(&self, position: Vector2) -> Option<Vector2> {
+        let size = self.source.size();
+        if position.x >= size.x || position.y >= size.y {
+            return None;
+        }
+
+        return Some(match self.axis {
+            FlipAxis::Horizontal => Vector2::new(size.x - 1 - position.x, position.y),
+            FlipAxis::Vertical => Vector2::new(position.x, size.y - 1 - position.y),
+        });
+    }
+}
+
+impl<S: TileSource> Rotated<S> {
+    /// # Description
+    /// Creates new [`Rotated`] adapter.
+    ///
+    /// # Arguments
+    /// * `source: S` - Source that will be rotated.
+    /// * `rotation: Rotation` - Rotation that will be applied.
+    ///
+    /// # Return
+    /// Newly created [`Rotated`] adapter.
+    pub fn new(source: S, rotation: Rotation) -> Rotated<S> {
+        return Rotated { source, rotation };
+    }
+
+    /// # Description
+    /// Returns position of the source tile that is rotated to the specified position, or
+    /// [`None`] if position is out of bounds.
+    fn source_position(&self, position: Vector2) -> Option<Vector2> {
+        let rotated_size = TileSource::size(self);
+        if position.x >= rotated_size.x || position.y >= rotated_size.y {
+            return None;
+        }
+
+        let size = self.source.size();
+        return Some(match self.rotation {
+            Rotation::Clockwise90 => Vector2::new(position.y, size.y - 1 - position.x),
+            Rotation::Clockwise180 => Vector2::new(size.x - 1 - position.x, size.y - 1 - position.y),
+            Rotation::Clockwise270 => Vector2::new(size.x - 1 - position.y, position.x),
+        });
+    }
+}
+
+impl<S: TileSource> Offset<S> {
+    /// # Description
+    /// Creates new [`Offset`] adapter.
+    ///
+    /// # Arguments
+    /// * `source: S` - Source that will be moved.
+    /// * `offset: Vector2` - Offset by which tiles will be moved.
+    ///
+    /// # Return
+    /// Newly created [`Offset`] adapter.
+    pub fn new(source: S, offset: Vector2) -> Offset<S> {
+        return Offset { source, offset };
+    }
+
+    /// # Description
+    /// Returns position of the source tile that is moved to the specified position, or
+    /// [`None`] if position is before the offset.
+    fn source_position(&self, position: Vector2) -> Option<Vector2> {
+        if position.x < self.offset.x || position.y < self.offset.y {
+            return None;
+        }
+
+        return Some(position - self.offset);
+    }
+}
+
+impl<S: TileSource, F: Fn(char) -> char> Mapped<S, F> {
+    /// # Description
+    /// Creates new [`Mapped`] adapter.
+    ///
+    /// # Arguments
+    /// * `source: S` - Source which tiles will be mapped.
+    /// * `function: F` - Function that will be applied to the value of every existing tile.
+    ///
+    /// # Return
+    /// Newly created [`Mapped`] adapter.
+    pub fn new(source: S, function: F) -> Mapped<S, F> {
+        return Mapped { source, function };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl<S: TileSource> TileSource for Flipped<S> {
+    /// # Description
+    /// Returns size of the source.
+    fn size(&self) -> Vector2 {
+        return self.source.size();
+    }
+
+    /// # Description
+    /// Returns value of the source tile that is mirrored to the specified position.
+    fn get(&self, position: Vector2) -> Option<char> {
+        return self.source_position(position).and_then(|position| self.source.get(position));
+    }
+
+    /// # Description
+    /// Returns color of the source tile that is mirrored to the specified position.
+    fn color(&self, position: Vector2) -> Option<Color> {
+        return self.source_position(position).and_then(|position| self.source.color(position));
+    }
+
+    /// # Description
+    /// Returns empty tile of the source.
+    fn empty_tile(&self) -> char {
+        return self.source.empty_tile();
+    }
+}
+
+impl<S: TileSource> TileSource for Rotated<S> {
+    /// # Description
+    /// Returns size of the source, with swapped axes in case of 90 and 270 degrees rotation.
+    fn size(&self) -> Vector2 {
+        let size = self.source.size();
+        return match self.rotation {
+            Rotation::Clockwise180 => size,
+            Rotation::Clockwise90 | Rotation::Clockwise270 => Vector2::new(size.y, size.x),
+        };
+    }
+
+    /// # Description
+    /// Returns value of the source tile that is rotated to the specified position.
+    fn get(&self, position: Vector2) -> Option<char> {
+        return self.source_position(position).and_then(|position| self.source.get(position));
+    }
+
+    /// # Description
+    /// Returns color of the source tile that is rotated to the specified position.
+    fn color(&self, position: Vector2) -> Option<Color> {
+        return self.source_position(position).and_then(|position| self.source.color(position));
+    }
+
+    /// # Description
+    /// Returns empty tile of the source.
+    fn empty_tile(&self) -> char {
+        return self.source.empty_tile();
+    }
+}
+
+impl<S: TileSource> TileSource for Offset<S> {
+    /// # Description
+    /// Returns size of the source increased by the offset.
+    fn size(&self) -> Vector2 {
+        return self.source.size() + self.offset;
+    }
+
+    /// # Description
+    /// Returns value of the source tile that is moved to the specified position.
+    fn get(&self, position: Vector2) -> Option<char> {
+        return self.source_position(position).and_then(|position| self.source.get(position));
+    }
+
+    /// # Description
+    /// Returns color of the source tile that is moved to the specified position.
+    fn color(&self, position: Vector2) -> Option<Color> {
+        return self.source_position(position).and_then(|position| self.source.color(position));
+    }
+
+    /// # Description
+    /// Returns empty tile of the source.
+    fn empty_tile(&self) -> char {
+        return self.source.empty_tile();
+    }
+}
+
+impl<S: TileSource, F: Fn(char) -> char> TileSource for Mapped<S, F> {
+    /// # Description
+    /// Returns size of the source.
+    fn size(&self) -> Vector2 {
+        return self.source.size();
+    }
+
+    /// # Description
+    /// Returns mapped value of the source tile at the specified position.
+    fn get(&self, position: Vector2) -> Option<char> {
+        return self.source.get(position).map(&self.function);
+    }
+
+    /// # Description
+    /// Returns color of the source tile at the specified position.
+    fn color(&self, position: Vector2) -> Option<Color> {
+        return self.source.color(position);
+    }
+
+    /// # Description
+    /// Returns empty tile of the source. Empty tile is not mapped.
+    fn empty_tile(&self) -> char {
+        return self.source.empty_tile();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{FlipAxis, Flipped, Mapped, Offset, Rotated, Rotation, Tilemap, TileSource, Vector2};
+
+    fn build_test_tilemap() -> Tilemap {
+        let mut tilemap = Tilemap::new('-');
+        tilemap.set(Vector2::new(0, 0), 'A');
+        tilemap.set(Vector2::new(2, 0), 'B');
+        tilemap.set(Vector2::new(0, 1), 'C');
+
+        return tilemap;
+    }
+
+    #[test]
+    fn flipped() {
+        let tilemap = build_test_tilemap();
+
+        assert_eq!(Flipped::new(&tilemap, FlipAxis::Horizontal).build(), "B-A\n--C");
+        assert_eq!(Flipped::new(&tilemap, FlipAxis::Vertical).build(), "C--\nA-B");
+        assert_eq!(Flipped::new(&tilemap, FlipAxis::Vertical).get(Vector2::new(3, 0)), None);
+    }
+
+    #[test]
+    fn rotated() {
+        let tilemap = build_test_tilemap();
+
+        assert_eq!(Rotated::new(&tilemap, Rotation::Clockwise90).build(), "CA\n--\n-B");
+        assert_eq!(Rotated::new(&tilemap, Rotation::Clockwise180).build(), "--C\nB-A");
+        assert_eq!(Rotated::new(&tilemap, Rotation::Clockwise270).build(), "B-\n--\nAC");
+        assert_eq!(Rotated::new(&tilemap, Rotation::Clockwise90).get(Vector2::new(0, 3)), None);
+    }
+
+    #[test]
+    fn offset() {
+        let tilemap = build_test_tilemap();
+        let offset = Offset::new(&tilemap, Vector2::ONE);
+
+        assert_eq!(offset.size(), Vector2::new(4, 3));
+        assert_eq!(offset.build(), "----\n-A-B\n-C--");
+    }
+
+    #[test]
+    fn mapped() {
+        let tilemap = build_test_tilemap();
+
+        assert_eq!(Mapped::new(&tilemap, |value| value.to_ascii_lowercase()).build(), "a-b\nc--");
+    }
+
+    #[test]
+    fn chained() {
+        let tilemap = build_test_tilemap();
+        let chained = Rotated::new(Flipped::new(&tilemap, FlipAxis::Horizontal), Rotation::Clockwise180);
+
+        assert_eq!(chained.build(), Flipped::new(&tilemap, FlipAxis::Vertical).build());
+    }
+}
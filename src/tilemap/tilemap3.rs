@@ -0,0 +1,282 @@
+use crate::tilemap::{Color, TileSource, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Volume of tiles that consists of a stack of [`Tilemap`] slices, one per Z level, such as floors
+/// of a dungeon. Z = 0 is the lowest slice.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::TileSource;
+///
+/// let mut volume = char_tilemap::Tilemap3::new('.');
+/// volume.set(char_tilemap::Vector2::ZERO, 0, '#');
+/// volume.set(char_tilemap::Vector2::new(1, 0), 1, '>');
+///
+/// assert_eq!(volume.depth(), 2);
+/// assert_eq!(volume.build_slices(&[0, 1]), "#.\n\n.>");
+/// assert_eq!(volume.top_down().build(), "#>");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tilemap3 {
+    /// # Description
+    /// Value of empty tiles of new slices.
+    pub empty_tile: char,
+    /// # Description
+    /// Slices from the lowest to the highest.
+    slices: Vec<Tilemap>,
+}
+
+/// # Description
+/// [`TileSource`] that shows [`Tilemap3`] from above, so every position has the tile of the
+/// highest slice that has a tile there. Created by [`Tilemap3::top_down()`].
+#[derive(Debug, Copy, Clone)]
+pub struct TopDown<'a> {
+    /// # Description
+    /// Volume that is shown.
+    volume: &'a Tilemap3,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap3 {
+    /// # Description
+    /// Creates new [`Tilemap3`] without slices.
+    ///
+    /// # Arguments
+    /// * `empty_tile: char` - Value of the empty tile.
+    ///
+    /// # Return
+    /// New instance of the [`Tilemap3`].
+    pub fn new(empty_tile: char) -> Tilemap3 {
+        return Tilemap3 { empty_tile, slices: Vec::new() };
+    }
+
+    /// # Description
+    /// Returns number of slices.
+    pub fn depth(&self) -> usize {
+        return self.slices.len();
+    }
+
+    /// # Description
+    /// Returns size of the largest slice on every axis.
+    pub fn size(&self) -> Vector2 {
+        return self.slices.iter().fold(Vector2::ZERO, |size, slice| {
+            Vector2::new(std::cmp::max(size.x, slice.size().x), std::cmp::max(size.y, slice.size().y))
+        });
+    }
+
+    /// # Description
+    /// Returns slice at the specified Z level.
+    ///
+    /// # Arguments
+    /// * `z: usize` - Z level of the slice.
+    ///
+    /// # Return
+    /// * [`Some`] with the slice.
+    /// * [`None`] if Z level is out of the volume.
+    pub fn slice(&self, z: usize) -> Option<&Tilemap> {
+        return self.slices.get(z);
+    }
+
+    /// # Description
+    /// Returns mutable slice at the specified Z level, so all functions of [`Tilemap`] can be
+    /// used on it.
+    ///
+    /// # Arguments
+    /// * `z: usize` - Z level of the slice.
+    ///
+    /// # Return
+    /// * [`Some`] with the slice.
+    /// * [`None`] if Z level is out of the volume.
+    pub fn slice_mut(&mut self, z: usize) -> Option<&mut Tilemap> {
+        return self.slices.get_mut(z);
+    }
+
+    /// # Description
+    /// Adds slice on top of the volume.
+    ///
+    /// # Arguments
+    /// * `slice: Tilemap` - New highest slice.
+    pub fn push_slice(&mut self, slice: Tilemap) {
+        self.slices.push(slice);
+    }
+
+    /// # Description
+    /// Returns value of the tile at the specified position.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile in the slice.
+    /// * `z: usize` - Z level of the slice.
+    ///
+    /// # Return
+    /// * [`Some`] with value of the tile if it exists.
+    /// * [`None`] if there is no tile at the specified position.
+    pub fn get(&self, position: Vector2, z: usize) -> Option<char> {
+        return self.slices.get(z).and_then(|slice| slice.get(position));
+    }
+
+    /// # Description
+    /// Sets tile at the specified position. Empty slices are added if Z level is out of the volume.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the tile in the slice.
+    /// * `z: usize` - Z level of the slice.
+    /// * `value: char` - Value of the tile.
+    pub fn set(&mut self, position: Vector2, z: usize, value: char) {
+        while self.slices.len() <= z {
+            self.slices.push(Tilemap::new(self.empty_tile));
+        }
+        self.slices[z].set(position, value);
+    }
+
+    /// # Description
+    /// Returns neighbors of the position in the same slice, and positions right above and below
+    /// it. Only positions inside of [`Tilemap3::size()`] and [`Tilemap3::depth()`] are returned.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position in the slice.
+    /// * `z: usize` - Z level of the slice.
+    ///
+    /// # Return
+    /// Positions and Z levels of neighbors in order: up, right, down, left, below, above.
+    pub fn neighbors(&self, position: Vector2, z: usize) -> Vec<(Vector2, usize)> {
+        let size = self.size();
+        let mut result: Vec<(Vector2, usize)> = super::pathfinding::neighbors(position)
+            .filter(|neighbor| neighbor.x < size.x && neighbor.y < size.y)
+            .map(|neighbor| (neighbor, z))
+            .collect();
+
+        if let Some(below) = z.checked_sub(1) {
+            result.push((position, below));
+        }
+        if z + 1 < self.depth() {
+            result.push((position, z + 1));
+        }
+
+        return result;
+    }
+
+    /// # Description
+    /// Builds selected slices and separates them by empty lines. Every slice is built with the
+    /// size of the volume, so all of them are aligned.
+    ///
+    /// # Arguments
+    /// * `levels: &[usize]` - Z levels of slices in order they should be built. Levels out of the
+    ///   volume are skipped.
+    ///
+    /// # Return
+    /// A new [`String`] that contains representation of slices.
+    pub fn build_slices(&self, levels: &[usize]) -> String {
+        let size = self.size();
+        let slices: Vec<String> = levels.iter()
+            .filter_map(|z| self.slices.get(*z))
+            .map(|slice| {
+                let mut slice = slice.clone();
+                // Slices are only enlarged, so no tiles can overflow
+                let _ = slice.resize(size, Default::default());
+                slice.build()
+            })
+            .collect();
+
+        return slices.join("\n\n");
+    }
+
+    /// # Description
+    /// Returns [`TileSource`] that shows the volume from above, so it can be built or passed to
+    /// any [`crate::Renderer`].
+    pub fn top_down(&self) -> TopDown<'_> {
+        return TopDown { volume: self };
+    }
+
+    /// # Description
+    /// Returns the highest slice that has a tile at the specified position.
+    fn top_slice(&self, position: Vector2) -> Option<&Tilemap> {
+        return self.slices.iter().rev().find(|slice| slice.get(position).is_some());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl TileSource for TopDown<'_> {
+    /// # Description
+    /// Returns size of the volume.
+    fn size(&self) -> Vector2 {
+        return self.volume.size();
+    }
+
+    /// # Description
+    /// Returns value of the tile of the highest slice that has a tile at the position.
+    fn get(&self, position: Vector2) -> Option<char> {
+        return self.volume.top_slice(position).and_then(|slice| slice.get(position));
+    }
+
+    /// # Description
+    /// Returns empty tile of the volume.
+    fn empty_tile(&self) -> char {
+        return self.volume.empty_tile;
+    }
+
+    /// # Description
+    /// Returns color of the tile of the highest slice that has a tile at the position.
+    fn color(&self, position: Vector2) -> Option<Color> {
+        return self.volume.top_slice(position).and_then(|slice| slice.color(position));
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{TileSource, Tilemap, Tilemap3, Vector2};
+
+    fn build_test_volume() -> Tilemap3 {
+        let mut volume = Tilemap3::new('.');
+        volume.push_slice(Tilemap::from_string("###\n#.#\n###", '.'));
+        volume.set(Vector2::ONE, 2, '>');
+        volume.set(Vector2::ZERO, 2, '<');
+
+        return volume;
+    }
+
+    #[test]
+    fn slices() {
+        let mut volume = build_test_volume();
+        assert_eq!(volume.depth(), 3);
+        assert_eq!(volume.size(), Vector2::new(3, 3));
+        assert_eq!(volume.get(Vector2::ZERO, 0), Some('#'));
+        assert_eq!(volume.get(Vector2::ZERO, 1), None);
+        assert_eq!(volume.get(Vector2::ONE, 2), Some('>'));
+        assert_eq!(volume.get(Vector2::ONE, 3), None);
+        assert!(volume.slice(3).is_none());
+
+        volume.slice_mut(1).unwrap().set(Vector2::new(2, 0), '=');
+        assert_eq!(volume.slice(1).unwrap().build(), "..=");
+    }
+
+    #[test]
+    fn neighbors() {
+        let volume = build_test_volume();
+        assert_eq!(volume.neighbors(Vector2::ZERO, 0),
+                   vec![(Vector2::new(1, 0), 0), (Vector2::new(0, 1), 0), (Vector2::ZERO, 1)]);
+        assert_eq!(volume.neighbors(Vector2::new(2, 2), 2),
+                   vec![(Vector2::new(2, 1), 2), (Vector2::new(1, 2), 2), (Vector2::new(2, 2), 1)]);
+        assert_eq!(volume.neighbors(Vector2::ONE, 1).len(), 6);
+    }
+
+    #[test]
+    fn build() {
+        let volume = build_test_volume();
+        assert_eq!(volume.build_slices(&[2, 5, 0]), "<..\n.>.\n...\n\n###\n#.#\n###");
+        assert_eq!(volume.top_down().build(), "<##\n#>#\n###");
+    }
+}
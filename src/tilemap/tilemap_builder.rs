@@ -0,0 +1,249 @@
+use crate::tilemap::{Rng, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Builder of [`Tilemap`]s that describes level skeletons with chained calls. Drawing steps are
+/// applied in order of calls by [`TilemapBuilder::build()`], after size and empty tile are
+/// known, and tiles that do not fit into the size are skipped.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::{Tilemap, TilemapBuilder, Vector2};
+///
+/// let pillar = Tilemap::from_string("O", '.');
+/// let tilemap = TilemapBuilder::new()
+///     .size(Vector2::new(6, 4))
+///     .empty_tile('.')
+///     .border('#')
+///     .fill_rect(Vector2::new(3, 1), Vector2::new(1, 2), '~')
+///     .stamp(&pillar, Vector2::new(1, 1))
+///     .build();
+///
+/// assert_eq!(tilemap.build(), "######\n#O.~.#\n#..~.#\n######");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TilemapBuilder {
+    /// # Description
+    /// Size of the built [`Tilemap`].
+    size: Vector2,
+    /// # Description
+    /// Empty tile of the built [`Tilemap`].
+    empty_tile: char,
+    /// # Description
+    /// Drawing steps in order of calls.
+    steps: Vec<BuildStep>,
+}
+
+/// # Description
+/// Drawing step of the [`TilemapBuilder`].
+#[derive(Debug, Clone)]
+enum BuildStep {
+    /// # Description
+    /// Tiles along the edges of the map.
+    Border(char),
+    /// # Description
+    /// Rectangle filled with the value: top left corner, size and value.
+    FillRect(Vector2, Vector2, char),
+    /// # Description
+    /// Tiles of the map copied with their top left corner at the position.
+    Stamp(Box<Tilemap>, Vector2),
+    /// # Description
+    /// Value placed at random positions: value, chance of every position and seed.
+    SeededNoise(char, f64, u64),
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl TilemapBuilder {
+    /// # Description
+    /// Creates new [`TilemapBuilder`] of an empty map with size 0 and space as the empty tile.
+    ///
+    /// # Return
+    /// New instance of the [`TilemapBuilder`].
+    pub fn new() -> TilemapBuilder {
+        return TilemapBuilder { size: Vector2::ZERO, empty_tile: ' ', steps: Vec::new() };
+    }
+
+    /// # Description
+    /// Sets size of the built [`Tilemap`].
+    pub fn size(mut self, size: Vector2) -> TilemapBuilder {
+        self.size = size;
+        return self;
+    }
+
+    /// # Description
+    /// Sets empty tile of the built [`Tilemap`].
+    pub fn empty_tile(mut self, empty_tile: char) -> TilemapBuilder {
+        self.empty_tile = empty_tile;
+        return self;
+    }
+
+    /// # Description
+    /// Draws tiles with the value along all edges of the map.
+    pub fn border(mut self, value: char) -> TilemapBuilder {
+        self.steps.push(BuildStep::Border(value));
+        return self;
+    }
+
+    /// # Description
+    /// Fills the rectangle with the value. Filling with the empty tile clears the rectangle.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Top left corner of the rectangle.
+    /// * `size: Vector2` - Size of the rectangle.
+    /// * `value: char` - Value of tiles.
+    pub fn fill_rect(mut self, position: Vector2, size: Vector2, value: char) -> TilemapBuilder {
+        self.steps.push(BuildStep::FillRect(position, size, value));
+        return self;
+    }
+
+    /// # Description
+    /// Copies tiles of the map, such as a prefab, so its top left corner is at the position.
+    /// Empty positions of the copied map do not change tiles under them.
+    ///
+    /// # Arguments
+    /// * `tilemap: &Tilemap` - Copied map.
+    /// * `position: Vector2` - Position of the top left corner of the copied map.
+    pub fn stamp(mut self, tilemap: &Tilemap, position: Vector2) -> TilemapBuilder {
+        self.steps.push(BuildStep::Stamp(Box::new(tilemap.clone()), position));
+        return self;
+    }
+
+    /// # Description
+    /// Places the value at random positions of the map. The same seed always places it at the
+    /// same positions.
+    ///
+    /// # Arguments
+    /// * `value: char` - Value of tiles.
+    /// * `density: f64` - Chance of every position to get the value, from 0 to 1.
+    /// * `seed: u64` - Seed of the [`Rng`] that picks positions.
+    pub fn seeded_noise(mut self, value: char, density: f64, seed: u64) -> TilemapBuilder {
+        self.steps.push(BuildStep::SeededNoise(value, density, seed));
+        return self;
+    }
+
+    /// # Description
+    /// Creates [`Tilemap`] and applies all drawing steps in order.
+    ///
+    /// # Return
+    /// New instance of the [`Tilemap`] of the specified size.
+    pub fn build(&self) -> Tilemap {
+        let mut tilemap = Tilemap::new(self.empty_tile);
+        tilemap.size = self.size;
+
+        for step in self.steps.iter() {
+            match step {
+                BuildStep::Border(value) => {
+                    self.for_each_position(Vector2::ZERO, self.size, |position| {
+                        let is_edge = position.x == 0 || position.y == 0
+                            || position.x == self.size.x - 1 || position.y == self.size.y - 1;
+                        return is_edge.then_some(*value);
+                    }, &mut tilemap);
+                },
+                BuildStep::FillRect(position, size, value) => {
+                    self.for_each_position(*position, *size, |_| Some(*value), &mut tilemap);
+                },
+                BuildStep::Stamp(stamp, position) => {
+                    for tile in stamp.tiles.iter() {
+                        let target = Vector2::new(position.x.saturating_add(tile.position.x), position.y.saturating_add(tile.position.y));
+                        self.draw(&mut tilemap, target, tile.value);
+                    }
+                },
+                BuildStep::SeededNoise(value, density, seed) => {
+                    let mut rng = Rng::new(*seed);
+                    self.for_each_position(Vector2::ZERO, self.size, |_| rng.chance(*density).then_some(*value), &mut tilemap);
+                },
+            }
+        }
+
+        return tilemap;
+    }
+
+    /// # Description
+    /// Draws values returned by the function at positions of the rectangle in row-major order.
+    /// [`None`] keeps the position unchanged.
+    fn for_each_position<F>(&self, position: Vector2, size: Vector2, mut value_at: F, tilemap: &mut Tilemap)
+        where F: FnMut(Vector2) -> Option<char> {
+        let end = Vector2::new(std::cmp::min(position.x.saturating_add(size.x), self.size.x),
+                               std::cmp::min(position.y.saturating_add(size.y), self.size.y));
+        for y in position.y..end.y {
+            for x in position.x..end.x {
+                if let Some(value) = value_at(Vector2::new(x, y)) {
+                    self.draw(tilemap, Vector2::new(x, y), value);
+                }
+            }
+        }
+    }
+
+    /// # Description
+    /// Sets the value at the position, or removes the tile if it is the empty tile. Positions
+    /// out of the size are skipped.
+    fn draw(&self, tilemap: &mut Tilemap, position: Vector2, value: char) {
+        if position.x >= self.size.x || position.y >= self.size.y {
+            return;
+        }
+
+        if value == self.empty_tile {
+            tilemap.take_tile(position);
+        } else {
+            tilemap.set(position, value);
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl Default for TilemapBuilder {
+    /// # Description
+    /// Creates new [`TilemapBuilder`], see [`TilemapBuilder::new()`].
+    fn default() -> TilemapBuilder {
+        return TilemapBuilder::new();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, TilemapBuilder, Vector2};
+
+    #[test]
+    fn build() {
+        let room = Tilemap::from_string("+.+", '.');
+        let tilemap = TilemapBuilder::new()
+            .border('#')
+            .fill_rect(Vector2::new(1, 1), Vector2::new(10, 1), '~')
+            .stamp(&room, Vector2::new(3, 2))
+            .fill_rect(Vector2::new(2, 1), Vector2::ONE, '.')
+            .empty_tile('.')
+            .size(Vector2::new(5, 4))
+            .build();
+
+        assert_eq!(tilemap.size(), Vector2::new(5, 4));
+        assert_eq!(tilemap.build(), "#####\n#~.~~\n#..+#\n#####");
+        assert_eq!(TilemapBuilder::default().build().size(), Vector2::ZERO);
+        assert_eq!(TilemapBuilder::new().size(Vector2::ONE).border('#').build().build(), "#");
+    }
+
+    #[test]
+    fn seeded_noise() {
+        let builder = TilemapBuilder::new().size(Vector2::new(20, 10)).empty_tile('.').seeded_noise('"', 0.3, 7);
+        let first = builder.build();
+        assert_eq!(first.build(), builder.build().build());
+        let other = TilemapBuilder::new().size(Vector2::new(20, 10)).empty_tile('.').seeded_noise('"', 0.3, 8);
+        assert_ne!(first.build(), other.build().build());
+
+        let count = first.build().matches('"').count();
+        assert!(count > 30 && count < 90);
+        assert_eq!(TilemapBuilder::new().size(Vector2::ONE).seeded_noise('"', 0.0, 7).build().build(), " ");
+    }
+}
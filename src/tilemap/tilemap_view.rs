@@ -0,0 +1,425 @@
+use crate::tilemap::{Color, Tilemap, TileSource, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Read-only rectangular window into the [`Tilemap`]. Has its own local coordinates,
+/// where [`Vector2::ZERO`] is the top left corner of the window.
+///
+/// # Example
+/// ```rust
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::new(2, 2), 'O');
+///
+/// let view = tilemap.view(char_tilemap::Vector2::ONE, char_tilemap::Vector2::new(2, 2)).unwrap();
+/// assert_eq!(view.build(), "--\n-O");
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct TilemapView<'a> {
+    /// # Description
+    /// [`Tilemap`] this view looks into.
+    tilemap: &'a Tilemap,
+    /// # Description
+    /// Position of the top left corner of the window in the [`Tilemap`].
+    position: Vector2,
+    /// # Description
+    /// Size of the window.
+    size: Vector2,
+}
+
+/// # Description
+/// Mutable rectangular window into the [`Tilemap`]. Has its own local coordinates,
+/// where [`Vector2::ZERO`] is the top left corner of the window. All modifications are restricted
+/// to the window.
+///
+/// # Example
+/// ```rust
+/// let mut tilemap = char_tilemap::Tilemap::new('-');
+/// tilemap.set(char_tilemap::Vector2::new(2, 2), '-');
+///
+/// let mut view = tilemap.view_mut(char_tilemap::Vector2::ONE, char_tilemap::Vector2::new(2, 2)).unwrap();
+/// match view.set(char_tilemap::Vector2::ZERO, 'O') {
+///     Ok(_) => (),
+///     Err(msg) => println!("{msg}")
+/// }
+/// assert_eq!(tilemap.get(char_tilemap::Vector2::ONE), Some('O'));
+/// ```
+#[derive(Debug)]
+pub struct TilemapViewMut<'a> {
+    /// # Description
+    /// [`Tilemap`] this view looks into.
+    tilemap: &'a mut Tilemap,
+    /// # Description
+    /// Position of the top left corner of the window in the [`Tilemap`].
+    position: Vector2,
+    /// # Description
+    /// Size of the window.
+    size: Vector2,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl<'a> TilemapView<'a> {
+    /// # Description
+    /// Creates new [`TilemapView`] into the specified region of the [`Tilemap`].
+    ///
+    /// # Arguments
+    /// * `tilemap: &'a Tilemap` - [`Tilemap`] to look into.
+    /// * `position: Vector2` - Top left corner of the region.
+    /// * `size: Vector2` - Size of the region.
+    ///
+    /// # Return
+    /// * [`Ok`] with new [`TilemapView`].
+    /// * [`Err`] if region does not fit into the [`Tilemap`]. Contains error message.
+    pub fn new(tilemap: &'a Tilemap, position: Vector2, size: Vector2) -> Result<TilemapView<'a>, String> {
+        validate_region(tilemap, position, size)?;
+        return Ok(TilemapView { tilemap, position, size });
+    }
+
+    /// # Description
+    /// Returns position of the top left corner of the view in the [`Tilemap`].
+    ///
+    /// # Return
+    /// [`Vector2`] that stores position of the view.
+    pub fn position(&self) -> Vector2 {
+        return self.position;
+    }
+
+    /// # Description
+    /// Returns size of the view.
+    ///
+    /// # Return
+    /// [`Vector2`] that stores size of the view.
+    pub fn size(&self) -> Vector2 {
+        return self.size;
+    }
+
+    /// # Description
+    /// Returns value of the tile at the specified local position if it exists.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Local position of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with value of the tile if it exists.
+    /// * [`None`] if there is no tile at the specified position or it is out of the view.
+    pub fn get(&self, position: Vector2) -> Option<char> {
+        return get_in_region(self.tilemap, self.position, self.size, position);
+    }
+
+    /// # Description
+    /// Builds the view into the string representation using [`Tilemap::empty_tile`] of the
+    /// underlying [`Tilemap`].
+    ///
+    /// # Return
+    /// A new [`String`] that contains representation of the view.
+    pub fn build(&self) -> String {
+        return TileSource::build(self);
+    }
+}
+
+impl<'a> TilemapViewMut<'a> {
+    /// # Description
+    /// Creates new [`TilemapViewMut`] into the specified region of the [`Tilemap`].
+    ///
+    /// # Arguments
+    /// * `tilemap: &'a mut Tilemap` - [`Tilemap`] to look into.
+    /// * `position: Vector2` - Top left corner of the region.
+    /// * `size: Vector2` - Size of the region.
+    ///
+    /// # Return
+    /// * [`Ok`] with new [`TilemapViewMut`].
+    /// * [`Err`] if region does not fit into the [`Tilemap`]. Contains error message.
+    pub fn new(tilemap: &'a mut Tilemap, position: Vector2, size: Vector2) -> Result<TilemapViewMut<'a>, String> {
+        validate_region(tilemap, position, size)?;
+        return Ok(TilemapViewMut { tilemap, position, size });
+    }
+
+    /// # Description
+    /// Returns position of the top left corner of the view in the [`Tilemap`].
+    ///
+    /// # Return
+    /// [`Vector2`] that stores position of the view.
+    pub fn position(&self) -> Vector2 {
+        return self.position;
+    }
+
+    /// # Description
+    /// Returns size of the view.
+    ///
+    /// # Return
+    /// [`Vector2`] that stores size of the view.
+    pub fn size(&self) -> Vector2 {
+        return self.size;
+    }
+
+    /// # Description
+    /// Returns value of the tile at the specified local position if it exists.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Local position of the tile.
+    ///
+    /// # Return
+    /// * [`Some`] with value of the tile if it exists.
+    /// * [`None`] if there is no tile at the specified position or it is out of the view.
+    pub fn get(&self, position: Vector2) -> Option<char> {
+        return get_in_region(self.tilemap, self.position, self.size, position);
+    }
+
+    /// # Description
+    /// Sets value of the tile at the specified local position.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Local position of the tile.
+    /// * `value: char` - New value of the tile.
+    ///
+    /// # Return
+    /// * [`Ok`] if tile was set.
+    /// * [`Err`] if position is out of the view, or tile was not set, see
+    ///   [`Tilemap::try_set()`]. Contains error message.
+    pub fn set(&mut self, position: Vector2, value: char) -> Result<(), String> {
+        if !is_inside(self.size, position) {
+            return Err(format!("Position {position} is out of the view of size {}", self.size));
+        }
+
+        return self.tilemap.try_set(self.position + position, value).map_err(String::from);
+    }
+
+    /// # Description
+    /// Removes tile at the specified local position if it exists.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Local position of the tile.
+    ///
+    /// # Return
+    /// * [`Ok`] if tile did exist and was removed.
+    /// * [`Err`] if position is out of the view or there is no tile. Contains error message.
+    pub fn remove(&mut self, position: Vector2) -> Result<(), String> {
+        if !is_inside(self.size, position) {
+            return Err(format!("Position {position} is out of the view of size {}", self.size));
+        }
+
+        return self.tilemap.remove_tile(self.position + position).map_err(String::from);
+    }
+
+    /// # Description
+    /// Builds the view into the string representation using [`Tilemap::empty_tile`] of the
+    /// underlying [`Tilemap`].
+    ///
+    /// # Return
+    /// A new [`String`] that contains representation of the view.
+    pub fn build(&self) -> String {
+        return TileSource::build(self);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation of traits
+// -------------------------------------------------------------------------------------------------
+
+impl TileSource for TilemapView<'_> {
+    /// # Description
+    /// Returns size of the view.
+    fn size(&self) -> Vector2 {
+        return self.size;
+    }
+
+    /// # Description
+    /// Returns value of the tile at the specified local position if it exists.
+    fn get(&self, position: Vector2) -> Option<char> {
+        return get_in_region(self.tilemap, self.position, self.size, position);
+    }
+
+    /// # Description
+    /// Returns [`Tilemap::empty_tile`] of the underlying [`Tilemap`].
+    fn empty_tile(&self) -> char {
+        return self.tilemap.empty_tile;
+    }
+
+    /// # Description
+    /// Returns color of the tile at the specified local position if it has one.
+    fn color(&self, position: Vector2) -> Option<Color> {
+        if !is_inside(self.size, position) {
+            return None;
+        }
+
+        return self.tilemap.tile_color(self.position + position);
+    }
+}
+
+impl TileSource for TilemapViewMut<'_> {
+    /// # Description
+    /// Returns size of the view.
+    fn size(&self) -> Vector2 {
+        return self.size;
+    }
+
+    /// # Description
+    /// Returns value of the tile at the specified local position if it exists.
+    fn get(&self, position: Vector2) -> Option<char> {
+        return get_in_region(self.tilemap, self.position, self.size, position);
+    }
+
+    /// # Description
+    /// Returns [`Tilemap::empty_tile`] of the underlying [`Tilemap`].
+    fn empty_tile(&self) -> char {
+        return self.tilemap.empty_tile;
+    }
+
+    /// # Description
+    /// Returns color of the tile at the specified local position if it has one.
+    fn color(&self, position: Vector2) -> Option<Color> {
+        if !is_inside(self.size, position) {
+            return None;
+        }
+
+        return self.tilemap.tile_color(self.position + position);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Checks that region fits into the [`Tilemap`].
+///
+/// # Return
+/// * [`Ok`] if region fits.
+/// * [`Err`] otherwise. Contains error message.
+fn validate_region(tilemap: &Tilemap, position: Vector2, size: Vector2) -> Result<(), String> {
+    let fits = match (position.x.checked_add(size.x), position.y.checked_add(size.y)) {
+        (Some(end_x), Some(end_y)) => end_x <= tilemap.size().x && end_y <= tilemap.size().y,
+        _ => false,
+    };
+
+    if !fits {
+        return Err(format!("Region at {position} with size {size} does not fit into tilemap of size {}",
+                           tilemap.size()));
+    }
+
+    return Ok(());
+}
+
+/// # Description
+/// Checks if local `position` is inside the region of the specified `size`.
+fn is_inside(size: Vector2, position: Vector2) -> bool {
+    return position.x < size.x && position.y < size.y;
+}
+
+/// # Description
+/// Returns value of the tile at local `position` of the region, if it exists and inside the region.
+fn get_in_region(tilemap: &Tilemap, origin: Vector2, size: Vector2, position: Vector2) -> Option<char> {
+    if !is_inside(size, position) {
+        return None;
+    }
+
+    return tilemap.get(origin + position);
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Tilemap, Vector2};
+
+    const EMPTY_TILE_CHAR: char = '-';
+    const TILE_VALUE: char = 'O';
+
+    fn build_test_tilemap() -> Tilemap {
+        let mut tilemap = Tilemap::new(EMPTY_TILE_CHAR);
+        for i in 0..4 {
+            tilemap.set(Vector2::new(i, i), TILE_VALUE);
+        }
+
+        return tilemap;
+    }
+
+    #[test]
+    fn new() {
+        let tilemap = build_test_tilemap();
+
+        match tilemap.view(Vector2::ONE, Vector2::new(3, 3)) {
+            Ok(_) => assert!(true),
+            Err(_) => assert!(false)
+        }
+
+        match tilemap.view(Vector2::ONE, Vector2::new(4, 3)) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+
+        match tilemap.view(Vector2::MAX, Vector2::ONE) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+
+    #[test]
+    fn get() {
+        let tilemap = build_test_tilemap();
+        let view = tilemap.view(Vector2::ONE, Vector2::new(2, 2)).unwrap();
+
+        assert_eq!(view.get(Vector2::ZERO), Some(TILE_VALUE));
+        assert_eq!(view.get(Vector2::new(1, 0)), None);
+        assert_eq!(view.get(Vector2::new(2, 2)), None);
+    }
+
+    #[test]
+    fn build() {
+        let tilemap = build_test_tilemap();
+        let view = tilemap.view(Vector2::new(1, 0), Vector2::new(3, 2)).unwrap();
+
+        assert_eq!(view.build(), "---\nO--");
+    }
+
+    #[test]
+    fn set_remove() {
+        let mut tilemap = build_test_tilemap();
+        let mut view = tilemap.view_mut(Vector2::ONE, Vector2::new(2, 2)).unwrap();
+
+        match view.set(Vector2::new(1, 0), 'X') {
+            Ok(_) => assert!(true),
+            Err(_) => assert!(false)
+        }
+
+        match view.set(Vector2::new(2, 0), 'X') {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+
+        match view.remove(Vector2::ZERO) {
+            Ok(_) => assert!(true),
+            Err(_) => assert!(false)
+        }
+
+        assert_eq!(view.build(), "-X\n-O");
+        assert_eq!(tilemap.get(Vector2::new(2, 1)), Some('X'));
+        assert_eq!(tilemap.get(Vector2::ONE), None);
+    }
+
+    #[test]
+    fn set_over_limits() {
+        let mut tilemap = build_test_tilemap();
+        tilemap.limits.max_tiles = Some(4);
+        let mut view = tilemap.view_mut(Vector2::ONE, Vector2::new(2, 2)).unwrap();
+
+        match view.set(Vector2::new(1, 0), 'X') {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+
+        match view.set(Vector2::ONE, 'X') {
+            Ok(_) => assert!(true),
+            Err(_) => assert!(false)
+        }
+
+        assert_eq!(view.build(), "O-\n-X");
+    }
+}
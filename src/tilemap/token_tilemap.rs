@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+use crate::tilemap::Vector2;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Variant of [`crate::Tilemap`] where every cell holds a short string token instead of a single
+/// [`char`], like cells of a spreadsheet. When built, tokens are aligned into columns, width of
+/// every column equals to the length of its longest token.
+///
+/// # Example
+/// ```rust
+/// let mut board = char_tilemap::TokenTilemap::new("-");
+/// board.set(char_tilemap::Vector2::ZERO, "A1").unwrap();
+/// board.set(char_tilemap::Vector2::new(1, 1), "100").unwrap();
+/// assert_eq!(board.build(), "A1 -  \n-  100");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TokenTilemap {
+    /// # Description
+    /// Token that is used for empty cells during the build.
+    empty_token: String,
+    /// # Description
+    /// Size of the tilemap. Always at least equals to the furthest coordinates of tokens.
+    size: Vector2,
+    /// # Description
+    /// Tokens by their positions.
+    tokens: BTreeMap<Vector2, String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl TokenTilemap {
+    /// # Description
+    /// Creates new empty [`TokenTilemap`].
+    ///
+    /// # Arguments
+    /// * `empty_token: &str` - Token that will be used for empty cells during the build.
+    ///
+    /// # Return
+    /// New instance of the [`TokenTilemap`].
+    pub fn new(empty_token: &str) -> TokenTilemap {
+        return TokenTilemap { empty_token: empty_token.to_string(), size: Vector2::ZERO, tokens: BTreeMap::new() };
+    }
+
+    /// # Description
+    /// Returns size of the [`TokenTilemap`].
+    pub fn size(&self) -> Vector2 {
+        return self.size;
+    }
+
+    /// # Description
+    /// Returns token that is used for empty cells.
+    pub fn empty_token(&self) -> &str {
+        return &self.empty_token;
+    }
+
+    /// # Description
+    /// Sets token of the cell at the specified position. Cell will be added if it does not
+    /// exist, or updated otherwise.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the cell.
+    /// * `token: &str` - New token of the cell.
+    ///
+    /// # Return
+    /// * [`Ok`] if token was set.
+    /// * [`Err`] if token is empty or contains control chars, since they break the built rows.
+    ///   Contains error message.
+    pub fn set(&mut self, position: Vector2, token: &str) -> Result<(), String> {
+        if token.is_empty() || token.chars().any(|value| value.is_control()) {
+            return Err(format!("Invalid token \"{}\"", token.escape_debug()));
+        }
+
+        self.tokens.insert(position, token.to_string());
+        self.size.x = std::cmp::max(self.size.x, position.x + 1);
+        self.size.y = std::cmp::max(self.size.y, position.y + 1);
+        return Ok(());
+    }
+
+    /// # Description
+    /// Returns token of the cell at the specified position if it exists.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the cell.
+    ///
+    /// # Return
+    /// * [`Some`] with the token of the cell.
+    /// * [`None`] if there is no token at the specified position.
+    pub fn get(&self, position: Vector2) -> Option<&str> {
+        return self.tokens.get(&position).map(|token| token.as_str());
+    }
+
+    /// # Description
+    /// Removes token of the cell at the specified position if it exists.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Position of the cell.
+    ///
+    /// # Return
+    /// * [`Ok`] with the removed token.
+    /// * [`Err`] if there is no token at the specified position. Contains error message.
+    pub fn remove(&mut self, position: Vector2) -> Result<String, String> {
+        return self.tokens.remove(&position).ok_or(format!("There is no token at the position {position}"));
+    }
+
+    /// # Description
+    /// Returns widths of all columns, each equals to the length of the longest token in the
+    /// column, including the empty token.
+    ///
+    /// # Return
+    /// [`Vec`] with width of every column from left to right.
+    pub fn column_widths(&self) -> Vec<usize> {
+        let mut widths = vec![self.empty_token.chars().count(); self.size.x];
+        for (position, token) in self.tokens.iter() {
+            widths[position.x] = std::cmp::max(widths[position.x], token.chars().count());
+        }
+
+        return widths;
+    }
+
+    /// # Description
+    /// Builds [`TokenTilemap`] into the string representation. Columns are separated with a
+    /// single space, and tokens are left-aligned and padded with spaces to the column width.
+    ///
+    /// # Return
+    /// A new [`String`] that contains representation of the [`TokenTilemap`].
+    pub fn build(&self) -> String {
+        let widths = self.column_widths();
+        let mut rows = Vec::with_capacity(self.size.y);
+
+        for y in 0..self.size.y {
+            let cells: Vec<String> = widths.iter().enumerate().map(|(x, width)| {
+                let token = self.get(Vector2::new(x, y)).unwrap_or(&self.empty_token);
+                return format!("{token:<width$}");
+            }).collect();
+            rows.push(cells.join(" "));
+        }
+
+        return rows.join("\n");
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{TokenTilemap, Vector2};
+
+    #[test]
+    fn set_get_remove() {
+        let mut board = TokenTilemap::new(".");
+        board.set(Vector2::new(2, 1), "GR").unwrap();
+        assert_eq!(board.size(), Vector2::new(3, 2));
+        assert_eq!(board.get(Vector2::new(2, 1)), Some("GR"));
+        assert_eq!(board.get(Vector2::ZERO), None);
+
+        board.set(Vector2::new(2, 1), "WA").unwrap();
+        assert_eq!(board.remove(Vector2::new(2, 1)), Ok(String::from("WA")));
+        for result in [board.remove(Vector2::new(2, 1)).map(|_| ()), board.set(Vector2::ZERO, ""),
+                       board.set(Vector2::ZERO, "a\nb")] {
+            match result {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+
+    #[test]
+    fn build() {
+        let mut board = TokenTilemap::new("..");
+        assert_eq!(board.build(), "");
+
+        board.set(Vector2::ZERO, "Name").unwrap();
+        board.set(Vector2::new(1, 0), "HP").unwrap();
+        board.set(Vector2::new(0, 1), "Orc").unwrap();
+        board.set(Vector2::new(1, 2), "1200").unwrap();
+
+        assert_eq!(board.column_widths(), vec![4, 4]);
+        assert_eq!(board.build(), "Name HP  \nOrc  ..  \n..   1200");
+    }
+}
@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use crate::tilemap::{Direction, TileChange, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Turn that [`Turmite`] makes relative to its current direction.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Turn {
+    /// # Description
+    /// Keeps moving in the same direction.
+    None,
+    /// # Description
+    /// Turns by 90 degrees clockwise.
+    Right,
+    /// # Description
+    /// Turns around.
+    Back,
+    /// # Description
+    /// Turns by 90 degrees counterclockwise.
+    Left,
+}
+
+/// # Description
+/// What [`Turmite`] does when it reads a value in a state.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct TurmiteRule {
+    /// # Description
+    /// Value written at the current position. `None` removes the tile.
+    pub write: Option<char>,
+    /// # Description
+    /// Turn made before moving.
+    pub turn: Turn,
+    /// # Description
+    /// State of the [`Turmite`] after the step.
+    pub next_state: usize,
+}
+
+/// # Description
+/// Two-dimensional Turing machine, such as Langton's ant. On every step it reads the tile at
+/// its position, and by the rule for its state and the read value writes a new value, turns,
+/// changes state and moves forward. [`Turmite`] halts if there is no rule, or if it would leave
+/// the [`Tilemap`] while wrapping is disabled.
+///
+/// # Example
+/// ```rust
+/// let mut tilemap = char_tilemap::Tilemap::from_string("...\n...\n...", '.');
+/// let mut ant = char_tilemap::Turmite::langtons_ant(char_tilemap::Vector2::ONE, char_tilemap::Direction::Up, '#');
+///
+/// ant.run(&mut tilemap, 4);
+/// assert_eq!(tilemap.build(), "...\n.##\n.##");
+/// assert_eq!(ant.position, char_tilemap::Vector2::ONE);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Turmite {
+    /// # Description
+    /// Current position.
+    pub position: Vector2,
+    /// # Description
+    /// Direction of the next move.
+    pub direction: Direction,
+    /// # Description
+    /// Current state.
+    pub state: usize,
+    /// # Description
+    /// Should [`Turmite`] reappear on the opposite side when it leaves the [`Tilemap`].
+    pub wrap: bool,
+    /// # Description
+    /// Rules by the state and the read value.
+    rules: HashMap<(usize, Option<char>), TurmiteRule>,
+    /// # Description
+    /// Is [`Turmite`] halted.
+    halted: bool,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Turmite {
+    /// # Description
+    /// Creates new [`Turmite`] in the state 0 without any rules and with disabled wrapping.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Starting position.
+    /// * `direction: Direction` - Starting direction.
+    ///
+    /// # Return
+    /// New instance of the [`Turmite`].
+    pub fn new(position: Vector2, direction: Direction) -> Turmite {
+        return Turmite { position, direction, state: 0, wrap: false, rules: HashMap::new(), halted: false };
+    }
+
+    /// # Description
+    /// Creates Langton's ant: on an empty tile it writes `value` and turns right, on a tile
+    /// with `value` it removes the tile and turns left.
+    ///
+    /// # Arguments
+    /// * `position: Vector2` - Starting position.
+    /// * `direction: Direction` - Starting direction.
+    /// * `value: char` - Value written by the ant.
+    ///
+    /// # Return
+    /// New instance of the [`Turmite`].
+    pub fn langtons_ant(position: Vector2, direction: Direction, value: char) -> Turmite {
+        let mut ant = Turmite::new(position, direction);
+        ant.add_rule(0, None, TurmiteRule { write: Some(value), turn: Turn::Right, next_state: 0 });
+        ant.add_rule(0, Some(value), TurmiteRule { write: None, turn: Turn::Left, next_state: 0 });
+        return ant;
+    }
+
+    /// # Description
+    /// Adds rule, replacing the previous one for the same state and read value.
+    ///
+    /// # Arguments
+    /// * `state: usize` - State in which the rule is used.
+    /// * `read: Option<char>` - Value for which the rule is used. `None` means an empty tile.
+    /// * `rule: TurmiteRule` - What [`Turmite`] should do.
+    pub fn add_rule(&mut self, state: usize, read: Option<char>, rule: TurmiteRule) {
+        self.rules.insert((state, read), rule);
+    }
+
+    /// # Description
+    /// Checks if [`Turmite`] is halted and does not make steps anymore.
+    pub fn is_halted(&self) -> bool {
+        return self.halted;
+    }
+
+    /// # Description
+    /// Makes a single step over the [`Tilemap`].
+    ///
+    /// # Arguments
+    /// * `tilemap: &mut Tilemap` - [`Tilemap`] the [`Turmite`] walks on.
+    ///
+    /// # Return
+    /// * [`Some`] with the change of the tile at the position where the step was made.
+    /// * [`None`] if [`Turmite`] is halted, or halts because there is no rule or it is out of
+    ///   bounds.
+    pub fn step(&mut self, tilemap: &mut Tilemap) -> Option<TileChange> {
+        let size = tilemap.size();
+        if self.halted || self.position.x >= size.x || self.position.y >= size.y {
+            self.halted = true;
+            return None;
+        }
+
+        let old = tilemap.get(self.position);
+        let Some(rule) = self.rules.get(&(self.state, old)).copied() else {
+            self.halted = true;
+            return None;
+        };
+
+        match rule.write {
+            Some(value) => tilemap.set(self.position, value),
+            None => {
+                tilemap.take_tile(self.position);
+            },
+        }
+        let change = TileChange::new(self.position, old, tilemap.get(self.position));
+
+        self.direction = turned(self.direction, rule.turn);
+        self.state = rule.next_state;
+        match self.direction.step(self.position).filter(|next| next.x < size.x && next.y < size.y) {
+            Some(next) => self.position = next,
+            None if self.wrap => self.position = wrapped(self.position, self.direction, size),
+            None => self.halted = true,
+        }
+
+        return Some(change);
+    }
+
+    /// # Description
+    /// Makes the specified number of steps, or less if [`Turmite`] halts.
+    ///
+    /// # Arguments
+    /// * `tilemap: &mut Tilemap` - [`Tilemap`] the [`Turmite`] walks on.
+    /// * `steps: usize` - Maximal number of steps.
+    ///
+    /// # Return
+    /// [`Vec`] with changes of all steps in order, which can be used to redraw only changed tiles.
+    pub fn run(&mut self, tilemap: &mut Tilemap, steps: usize) -> Vec<TileChange> {
+        let mut changes = Vec::new();
+        for _ in 0..steps {
+            match self.step(tilemap) {
+                Some(change) => changes.push(change),
+                None => break,
+            }
+        }
+
+        return changes;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Returns direction after the turn.
+fn turned(direction: Direction, turn: Turn) -> Direction {
+    let index = Direction::ALL.iter().position(|known| *known == direction).unwrap_or(0);
+    let offset = match turn {
+        Turn::None => 0,
+        Turn::Right => 1,
+        Turn::Back => 2,
+        Turn::Left => 3,
+    };
+
+    return Direction::ALL[(index + offset) % Direction::ALL.len()];
+}
+
+/// # Description
+/// Returns position on the opposite side of the area of the specified size, where position
+/// appears after leaving the area in the direction.
+fn wrapped(position: Vector2, direction: Direction, size: Vector2) -> Vector2 {
+    return match direction {
+        Direction::Up => Vector2::new(position.x, size.y - 1),
+        Direction::Right => Vector2::new(0, position.y),
+        Direction::Down => Vector2::new(position.x, 0),
+        Direction::Left => Vector2::new(size.x - 1, position.y),
+    };
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Direction, TileChange, Tilemap, Turmite, TurmiteRule, Turn, Vector2};
+
+    #[test]
+    fn langtons_ant() {
+        let mut tilemap = Tilemap::from_string("...\n...\n...", '.');
+        let mut ant = Turmite::langtons_ant(Vector2::ONE, Direction::Up, '#');
+
+        let changes = ant.run(&mut tilemap, 5);
+        assert_eq!(changes.len(), 5);
+        assert_eq!(changes[0], TileChange::new(Vector2::ONE, None, Some('#')));
+        assert_eq!(changes[4], TileChange::new(Vector2::ONE, Some('#'), None));
+        assert_eq!(tilemap.build(), "...\n..#\n.##");
+        assert_eq!((ant.position, ant.direction), (Vector2::new(0, 1), Direction::Left));
+
+        assert_eq!(ant.run(&mut tilemap, 10).len(), 6);
+        assert!(ant.is_halted());
+        assert_eq!(ant.step(&mut tilemap), None);
+    }
+
+    #[test]
+    fn wrap() {
+        let mut tilemap = Tilemap::from_string("...", '.');
+        let mut turmite = Turmite::new(Vector2::new(1, 0), Direction::Right);
+        turmite.wrap = true;
+        turmite.add_rule(0, None, TurmiteRule { write: Some('a'), turn: Turn::None, next_state: 1 });
+        turmite.add_rule(1, None, TurmiteRule { write: Some('b'), turn: Turn::None, next_state: 0 });
+
+        assert_eq!(turmite.run(&mut tilemap, 3).len(), 3);
+        assert_eq!(tilemap.build(), "aab");
+        assert_eq!(turmite.position, Vector2::new(1, 0));
+
+        assert_eq!(turmite.run(&mut tilemap, 3).len(), 0);
+        assert!(turmite.is_halted());
+    }
+}
@@ -0,0 +1,487 @@
+use std::collections::BTreeMap;
+use crate::tilemap::{file_format, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Key of the line that starts a map section of the save format.
+const MAP_KEY: &str = "map";
+
+/// # Description
+/// Key of the line that describes a [`Link`] in the save format.
+const LINK_KEY: &str = "link";
+
+/// # Description
+/// Kind of the [`Link`] between two positions.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub enum LinkKind {
+    /// # Description
+    /// Stairs between floors.
+    Stairs,
+    /// # Description
+    /// Portal between any two positions.
+    Portal,
+    /// # Description
+    /// Kind defined by the user.
+    /// # Value
+    /// * `String` - Name of the kind. Can not contain whitespaces.
+    Custom(String),
+}
+
+/// # Description
+/// Position inside of the named map of a [`World`].
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Location {
+    /// # Description
+    /// Name of the map.
+    pub map: String,
+    /// # Description
+    /// Position inside of the map.
+    pub position: Vector2,
+}
+
+/// # Description
+/// One-way connection between two [`Location`]s of a [`World`].
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Link {
+    /// # Description
+    /// Location where the link starts.
+    pub from: Location,
+    /// # Description
+    /// Location where the link leads.
+    pub to: Location,
+    /// # Description
+    /// Kind of the link.
+    pub kind: LinkKind,
+}
+
+/// # Description
+/// Container of several named [`Tilemap`]s, such as floors or zones, and [`Link`]s between
+/// positions of these maps.
+///
+/// # Example
+/// ```rust
+/// use char_tilemap::{Link, LinkKind, Location, Tilemap, Vector2, World};
+///
+/// let mut world = World::new();
+/// world.add_map("ground", Tilemap::from_string("..>", '.')).unwrap();
+/// world.add_map("cellar", Tilemap::from_string("<..", '.')).unwrap();
+/// world.add_link(Link::new(Location::new("ground", Vector2::new(2, 0)),
+///                          Location::new("cellar", Vector2::ZERO), LinkKind::Stairs)).unwrap();
+///
+/// let link = world.links_from(&Location::new("ground", Vector2::new(2, 0))).next().unwrap();
+/// assert_eq!(link.to.map, "cellar");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct World {
+    /// # Description
+    /// Maps sorted by their names.
+    maps: BTreeMap<String, Tilemap>,
+    /// # Description
+    /// Links in order of addition.
+    links: Vec<Link>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl LinkKind {
+    /// # Description
+    /// Returns name of the kind that is used in the save format.
+    ///
+    /// # Return
+    /// `stairs`, `portal` or the name of the [`LinkKind::Custom`] kind.
+    pub fn name(&self) -> &str {
+        return match self {
+            LinkKind::Stairs => "stairs",
+            LinkKind::Portal => "portal",
+            LinkKind::Custom(name) => name,
+        };
+    }
+
+    /// # Description
+    /// Creates [`LinkKind`] from its name. Names that are not `stairs` or `portal` become
+    /// [`LinkKind::Custom`].
+    ///
+    /// # Arguments
+    /// * `name: &str` - Name of the kind.
+    ///
+    /// # Return
+    /// [`LinkKind`] with the specified name.
+    pub fn from_name(name: &str) -> LinkKind {
+        return match name {
+            "stairs" => LinkKind::Stairs,
+            "portal" => LinkKind::Portal,
+            _ => LinkKind::Custom(name.to_string()),
+        };
+    }
+}
+
+impl Location {
+    /// # Description
+    /// Creates new [`Location`] using specified values.
+    ///
+    /// # Arguments
+    /// * `map: &str` - Name of the map.
+    /// * `position: Vector2` - Position inside of the map.
+    ///
+    /// # Return
+    /// Newly created [`Location`] with specified values.
+    pub fn new(map: &str, position: Vector2) -> Location {
+        return Location { map: map.to_string(), position };
+    }
+}
+
+impl Link {
+    /// # Description
+    /// Creates new [`Link`] using specified values.
+    ///
+    /// # Arguments
+    /// * `from: Location` - Location where the link starts.
+    /// * `to: Location` - Location where the link leads.
+    /// * `kind: LinkKind` - Kind of the link.
+    ///
+    /// # Return
+    /// Newly created [`Link`] with specified values.
+    pub fn new(from: Location, to: Location, kind: LinkKind) -> Link {
+        return Link { from, to, kind };
+    }
+}
+
+impl World {
+    /// # Description
+    /// Creates new empty [`World`].
+    ///
+    /// # Return
+    /// New instance of the [`World`] without maps and links.
+    pub fn new() -> World {
+        return World::default();
+    }
+
+    /// # Description
+    /// Adds new named [`Tilemap`].
+    ///
+    /// # Arguments
+    /// * `name: &str` - Name of the map. Can not be empty or contain whitespaces.
+    /// * `tilemap: Tilemap` - Added map.
+    ///
+    /// # Return
+    /// * [`Ok`] if map was added.
+    /// * [`Err`] if name is invalid or map with the same name already exists. Contains error message.
+    pub fn add_map(&mut self, name: &str, tilemap: Tilemap) -> Result<(), String> {
+        validate_name(name)?;
+        if self.maps.contains_key(name) {
+            return Err(format!("Map \"{name}\" already exists"));
+        }
+
+        self.maps.insert(name.to_string(), tilemap);
+        return Ok(());
+    }
+
+    /// # Description
+    /// Removes named [`Tilemap`] and all links that start or end in it.
+    ///
+    /// # Arguments
+    /// * `name: &str` - Name of the map.
+    ///
+    /// # Return
+    /// * [`Some`] with removed [`Tilemap`].
+    /// * [`None`] if there is no map with the specified name.
+    pub fn remove_map(&mut self, name: &str) -> Option<Tilemap> {
+        let tilemap = self.maps.remove(name)?;
+        self.links.retain(|link| link.from.map != name && link.to.map != name);
+        return Some(tilemap);
+    }
+
+    /// # Description
+    /// Returns named [`Tilemap`].
+    ///
+    /// # Arguments
+    /// * `name: &str` - Name of the map.
+    ///
+    /// # Return
+    /// * [`Some`] with reference to the [`Tilemap`].
+    /// * [`None`] if there is no map with the specified name.
+    pub fn map(&self, name: &str) -> Option<&Tilemap> {
+        return self.maps.get(name);
+    }
+
+    /// # Description
+    /// Returns mutable named [`Tilemap`].
+    ///
+    /// # Arguments
+    /// * `name: &str` - Name of the map.
+    ///
+    /// # Return
+    /// * [`Some`] with mutable reference to the [`Tilemap`].
+    /// * [`None`] if there is no map with the specified name.
+    pub fn map_mut(&mut self, name: &str) -> Option<&mut Tilemap> {
+        return self.maps.get_mut(name);
+    }
+
+    /// # Description
+    /// Returns names of all maps in alphabetical order.
+    pub fn map_names(&self) -> impl Iterator<Item = &str> + '_ {
+        return self.maps.keys().map(|name| name.as_str());
+    }
+
+    /// # Description
+    /// Adds new [`Link`].
+    ///
+    /// # Arguments
+    /// * `link: Link` - Added link.
+    ///
+    /// # Return
+    /// * [`Ok`] if link was added.
+    /// * [`Err`] if any of its maps does not exist, or [`LinkKind::Custom`] name is invalid.
+    ///   Contains error message.
+    pub fn add_link(&mut self, link: Link) -> Result<(), String> {
+        for map in [&link.from.map, &link.to.map] {
+            if !self.maps.contains_key(map) {
+                return Err(format!("There is no map \"{map}\""));
+            }
+        }
+        if let LinkKind::Custom(name) = &link.kind {
+            validate_name(name)?;
+        }
+
+        self.links.push(link);
+        return Ok(());
+    }
+
+    /// # Description
+    /// Returns all links in order of addition.
+    pub fn links(&self) -> &[Link] {
+        return &self.links;
+    }
+
+    /// # Description
+    /// Returns links that start at the specified location.
+    ///
+    /// # Arguments
+    /// * `location: &Location` - Start of the links.
+    pub fn links_from(&self, location: &Location) -> impl Iterator<Item = &Link> + '_ {
+        let location = location.clone();
+        return self.links.iter().filter(move |link| link.from == location);
+    }
+
+    /// # Description
+    /// Serializes [`World`] into the text save format. Every map is stored as a `map=<name>` line
+    /// followed by the [`Tilemap`] save format, and every link as a
+    /// `link=<kind> <map> <x> <y> <map> <x> <y>` line.
+    ///
+    /// # Return
+    /// A new [`String`] with serialized [`World`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut world = char_tilemap::World::new();
+    /// world.add_map("hall", char_tilemap::Tilemap::from_string("#.", '.')).unwrap();
+    /// assert_eq!(world.to_file_string(), "map=hall\nversion=3\nempty_tile=.\nsize=2x1\n\n#.");
+    /// ```
+    pub fn to_file_string(&self) -> String {
+        let mut sections: Vec<String> = self.maps.iter()
+            .map(|(name, tilemap)| format!("{MAP_KEY}={name}\n{}", tilemap.to_file_string()))
+            .collect();
+
+        if !self.links.is_empty() {
+            let links: Vec<String> = self.links.iter()
+                .map(|link| format!("{LINK_KEY}={} {} {} {} {} {} {}", link.kind.name(),
+                                    link.from.map, link.from.position.x, link.from.position.y,
+                                    link.to.map, link.to.position.x, link.to.position.y))
+                .collect();
+            sections.push(links.join("\n"));
+        }
+
+        return sections.join("\n\n");
+    }
+
+    /// # Description
+    /// Deserializes [`World`] from the text save format produced by [`World::to_file_string()`].
+    ///
+    /// # Arguments
+    /// * `text: &str` - Serialized [`World`].
+    ///
+    /// # Return
+    /// * [`Ok`] with deserialized [`World`].
+    /// * [`Err`] if text is not in the save format. Contains error message.
+    pub fn from_file_string(text: &str) -> Result<World, String> {
+        let mut world = World::new();
+        let mut lines = text.lines();
+
+        while let Some(line) = lines.next() {
+            if line.is_empty() {
+                continue;
+            }
+
+            match line.split_once('=') {
+                Some((MAP_KEY, name)) => {
+                    let header: Vec<&str> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+                    let height = header.iter()
+                        .find_map(|line| line.strip_prefix(file_format::SIZE_KEY)?.strip_prefix('='))
+                        .map(file_format::parse_size)
+                        .transpose()?
+                        .map_or(0, |size| size.y);
+                    let rows: Vec<&str> = lines.by_ref().take(height).collect();
+
+                    let tilemap = Tilemap::from_file_string(&format!("{}\n\n{}", header.join("\n"), rows.join("\n")))
+                        .map_err(|error| format!("Map \"{name}\": {error}"))?;
+                    world.add_map(name, tilemap)?;
+                },
+                Some((LINK_KEY, value)) => world.add_link(parse_link(value)?)?,
+                _ => return Err(format!("Invalid line \"{line}\"")),
+            }
+        }
+
+        return Ok(world);
+    }
+
+    /// # Description
+    /// Saves [`World`] to the file in the text save format.
+    ///
+    /// # Arguments
+    /// * `path: P` - Path to the file. File will be created or overwritten.
+    ///
+    /// # Return
+    /// * [`Ok`] if [`World`] was saved.
+    /// * [`Err`] if file could not be written. Contains error message.
+    pub fn save_to_file<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), String> {
+        return std::fs::write(&path, self.to_file_string())
+            .map_err(|error| format!("Failed to save world to {}: {error}", path.as_ref().display()));
+    }
+
+    /// # Description
+    /// Loads [`World`] from the file in the text save format.
+    ///
+    /// # Arguments
+    /// * `path: P` - Path to the file.
+    ///
+    /// # Return
+    /// * [`Ok`] with loaded [`World`].
+    /// * [`Err`] if file could not be read or is not in the save format. Contains error message.
+    pub fn load_from_file<P: AsRef<std::path::Path>>(path: P) -> Result<World, String> {
+        let text = std::fs::read_to_string(&path)
+            .map_err(|error| format!("Failed to load world from {}: {error}", path.as_ref().display()))?;
+        return World::from_file_string(&text);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Checks that name of a map or a link kind is not empty and does not contain whitespaces, so
+/// it can be stored in the save format.
+fn validate_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(format!("Invalid name \"{name}\", it can not be empty or contain whitespaces"));
+    }
+
+    return Ok(());
+}
+
+/// # Description
+/// Parses value of the link line in format `<kind> <map> <x> <y> <map> <x> <y>`.
+fn parse_link(value: &str) -> Result<Link, String> {
+    let words: Vec<&str> = value.split_whitespace().collect();
+    let [kind, from_map, from_x, from_y, to_map, to_x, to_y] = words[..] else {
+        return Err(format!("Invalid link \"{value}\""));
+    };
+
+    let parse = |value: &str| value.parse::<usize>().map_err(|_| format!("Invalid number \"{value}\""));
+    let from = Location::new(from_map, Vector2::new(parse(from_x)?, parse(from_y)?));
+    let to = Location::new(to_map, Vector2::new(parse(to_x)?, parse(to_y)?));
+    return Ok(Link::new(from, to, LinkKind::from_name(kind)));
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Link, LinkKind, Location, Tilemap, Vector2, World};
+
+    fn build_test_world() -> World {
+        let mut world = World::new();
+        world.add_map("ground", Tilemap::from_string("#..\n..>", '.')).unwrap();
+        world.add_map("cellar", Tilemap::from_string("<.\n.*", '.')).unwrap();
+        world.add_link(Link::new(Location::new("ground", Vector2::new(2, 1)),
+                                 Location::new("cellar", Vector2::ZERO), LinkKind::Stairs)).unwrap();
+        world.add_link(Link::new(Location::new("cellar", Vector2::ONE),
+                                 Location::new("ground", Vector2::ZERO), LinkKind::Custom(String::from("trapdoor")))).unwrap();
+        return world;
+    }
+
+    #[test]
+    fn maps_and_links() {
+        let mut world = build_test_world();
+        assert_eq!(world.map_names().collect::<Vec<&str>>(), vec!["cellar", "ground"]);
+        assert_eq!(world.map("cellar").unwrap().get(Vector2::ONE), Some('*'));
+
+        let location = Location::new("ground", Vector2::new(2, 1));
+        let links: Vec<&Link> = world.links_from(&location).collect();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].kind, LinkKind::Stairs);
+
+        for result in [world.add_map("ground", Tilemap::new('.')), world.add_map("two words", Tilemap::new('.')),
+                       world.add_link(Link::new(location.clone(), Location::new("attic", Vector2::ZERO), LinkKind::Portal))] {
+            match result {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+
+        world.map_mut("ground").unwrap().set(Vector2::ZERO, '@');
+        assert_eq!(world.map("ground").unwrap().get(Vector2::ZERO), Some('@'));
+
+        assert!(world.remove_map("cellar").is_some());
+        assert!(world.links().is_empty());
+        assert!(world.remove_map("cellar").is_none());
+    }
+
+    #[test]
+    fn file_string() {
+        let world = build_test_world();
+        let text = world.to_file_string();
+        assert_eq!(text, "map=cellar\nversion=3\nempty_tile=.\nsize=2x2\n\n<.\n.*\n\n\
+                          map=ground\nversion=3\nempty_tile=.\nsize=3x2\n\n#..\n..>\n\n\
+                          link=stairs ground 2 1 cellar 0 0\nlink=trapdoor cellar 1 1 ground 0 0");
+
+        let loaded = World::from_file_string(&text).unwrap();
+        assert_eq!(loaded.to_file_string(), text);
+        assert_eq!(loaded.links(), world.links());
+        assert_eq!(World::from_file_string("").unwrap().links().len(), 0);
+    }
+
+    #[test]
+    fn file_string_errors() {
+        for text in ["invalid", "map=a\nempty_tile=.\nsize=1x1\n\n#\n\nmap=a\nempty_tile=.\nsize=1x1\n\n#",
+                     "map=a\nempty_tile=.\nsize=1x1\n\n##", "link=stairs a 0 0 b 0 0",
+                     "map=a\nempty_tile=.\nsize=1x1\n\n#\nlink=stairs a 0 0 a 0"] {
+            match World::from_file_string(text) {
+                Ok(_) => assert!(false),
+                Err(_) => assert!(true)
+            }
+        }
+    }
+
+    #[test]
+    fn save_load() {
+        let path = std::env::temp_dir().join("char_tilemap_world_save_load.world");
+        let world = build_test_world();
+
+        world.save_to_file(&path).unwrap();
+        assert_eq!(World::load_from_file(&path).unwrap().to_file_string(), world.to_file_string());
+
+        std::fs::remove_file(&path).unwrap();
+        match World::load_from_file(&path) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+    }
+}
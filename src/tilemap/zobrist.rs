@@ -0,0 +1,109 @@
+use crate::tilemap::{Rng, Tilemap, Vector2};
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Tilemap {
+    /// # Description
+    /// Returns Zobrist hash of tiles: XOR of random keys of every position and value. Maps with
+    /// the same tiles have the same hash regardless of how they were changed, so it suits
+    /// transposition tables of searches that explore moves on the map. Size and the empty tile
+    /// are not hashed.
+    ///
+    /// Hash is calculated from all tiles, unless it is kept, see
+    /// [`Tilemap::enable_incremental_hash()`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use char_tilemap::Vector2;
+    ///
+    /// let mut tilemap = char_tilemap::Tilemap::from_string("@.\n.o", '.');
+    /// tilemap.enable_incremental_hash();
+    /// let start = tilemap.incremental_hash();
+    ///
+    /// tilemap.try_push(Vector2::ZERO, char_tilemap::Direction::Right, |_| false, |_| true).unwrap();
+    /// assert_ne!(tilemap.incremental_hash(), start);
+    /// tilemap.try_push(Vector2::new(1, 0), char_tilemap::Direction::Left, |_| false, |_| true).unwrap();
+    /// assert_eq!(tilemap.incremental_hash(), start);
+    /// ```
+    pub fn incremental_hash(&self) -> u64 {
+        return match self.zobrist {
+            Some(hash) => hash,
+            None => calculate_hash(self),
+        };
+    }
+
+    /// # Description
+    /// Starts to keep Zobrist hash and update it on every change of a tile, so
+    /// [`Tilemap::incremental_hash()`] takes constant time. Hash is recalculated if it is
+    /// already kept.
+    pub fn enable_incremental_hash(&mut self) {
+        self.zobrist = Some(calculate_hash(self));
+    }
+
+    /// # Description
+    /// Stops to keep Zobrist hash, see [`Tilemap::enable_incremental_hash()`].
+    pub fn disable_incremental_hash(&mut self) {
+        self.zobrist = None;
+    }
+
+    /// # Description
+    /// Updates Zobrist hash after the tile was changed, if hash is kept.
+    pub(crate) fn update_incremental_hash(&mut self, position: Vector2, old: Option<char>, new: Option<char>) {
+        if let Some(hash) = &mut self.zobrist {
+            let key = |value: Option<char>| value.map_or(0, |value| zobrist_key(position, value));
+            *hash ^= key(old) ^ key(new);
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Returns random key of the value at the position. Keys are derived from the position and the
+/// value, so there is no table of keys to store.
+fn zobrist_key(position: Vector2, value: char) -> u64 {
+    let column = Rng::new(Rng::new(value as u64).next_u64() ^ position.x as u64).next_u64();
+    return Rng::new(column ^ (position.y as u64).rotate_left(32)).next_u64();
+}
+
+/// # Description
+/// Calculates Zobrist hash from all tiles.
+fn calculate_hash(tilemap: &Tilemap) -> u64 {
+    return tilemap.tiles.iter().fold(0, |hash, tile| hash ^ zobrist_key(tile.position, tile.value));
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::tilemap::{Orientation, TileOp, Tilemap, Vector2};
+
+    #[test]
+    fn incremental_hash() {
+        let mut tilemap = Tilemap::from_string("ab\nba", '.');
+        assert_ne!(tilemap.incremental_hash(), Tilemap::from_string("ba\nab", '.').incremental_hash());
+        assert_eq!(Tilemap::new('.').incremental_hash(), 0);
+
+        tilemap.enable_incremental_hash();
+        tilemap.set(Vector2::ZERO, 'c');
+        tilemap.remove_tile(Vector2::ONE).unwrap();
+        tilemap.apply_ops(&[TileOp::Add(Vector2::new(3, 3), 'd'), TileOp::Set(Vector2::new(1, 0), 'e')]);
+
+        let kept = tilemap.incremental_hash();
+        tilemap.disable_incremental_hash();
+        assert_eq!(kept, tilemap.incremental_hash());
+
+        tilemap.enable_incremental_hash();
+        let mut rotated = tilemap.oriented(Orientation::Rotate90);
+        rotated.set(Vector2::ZERO, 'f');
+        let kept = rotated.incremental_hash();
+        rotated.disable_incremental_hash();
+        assert_eq!(kept, rotated.incremental_hash());
+    }
+}
@@ -0,0 +1,148 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+use char_tilemap::{commands, Tilemap};
+use crate::cli;
+
+// -------------------------------------------------------------------------------------------------
+// Definition
+// -------------------------------------------------------------------------------------------------
+
+/// # Description
+/// Watches a saved tilemap, or a script of text commands, and renders it every time the file is
+/// modified. Changes since the previous render are highlighted.
+pub struct Watcher {
+    /// # Description
+    /// Watched file.
+    path: PathBuf,
+    /// # Description
+    /// Is watched file a script of commands instead of a saved tilemap.
+    script: bool,
+    /// # Description
+    /// Empty tile of the tilemap built by the script.
+    empty_tile: char,
+    /// # Description
+    /// Modification time and length of the file at the last render.
+    stamp: Option<(SystemTime, u64)>,
+    /// # Description
+    /// Tilemap of the last successful render.
+    previous: Option<Tilemap>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Implementation
+// -------------------------------------------------------------------------------------------------
+
+impl Watcher {
+    /// # Description
+    /// Creates new [`Watcher`] for the specified file.
+    ///
+    /// # Arguments
+    /// * `path: PathBuf` - Path to the watched file.
+    /// * `script: bool` - Is file a script of text commands instead of a saved tilemap.
+    /// * `empty_tile: char` - Empty tile of the tilemap built by the script.
+    pub fn new(path: PathBuf, script: bool, empty_tile: char) -> Watcher {
+        return Watcher { path, script, empty_tile, stamp: None, previous: None };
+    }
+
+    /// # Description
+    /// Renders the file to the output every time it is modified. Runs until interrupted.
+    ///
+    /// # Arguments
+    /// * `output: &mut W` - Output to which renders are written.
+    /// * `interval: Duration` - Delay between checks of the file.
+    ///
+    /// # Return
+    /// [`Err`] if writing to the output failed.
+    pub fn run<W: Write>(&mut self, output: &mut W, interval: Duration) -> std::io::Result<()> {
+        loop {
+            if let Some(frame) = self.poll() {
+                write!(output, "\x1b[2J\x1b[H{frame}\n\nWatching {}, Ctrl-C to quit\n", self.path.display())?;
+                output.flush()?;
+            }
+            std::thread::sleep(interval);
+        }
+    }
+
+    /// # Description
+    /// Checks if the file was modified since the last call and renders it.
+    ///
+    /// # Return
+    /// * [`Some`] with the rendered tilemap, where changes since the previous render are
+    ///   highlighted, or with the error message if file could not be loaded.
+    /// * [`None`] if file was not modified.
+    pub fn poll(&mut self) -> Option<String> {
+        let stamp = std::fs::metadata(&self.path).ok()
+            .and_then(|metadata| Some((metadata.modified().ok()?, metadata.len())));
+        if stamp == self.stamp {
+            return None;
+        }
+        self.stamp = stamp;
+
+        let tilemap = match self.load() {
+            Ok(tilemap) => tilemap,
+            Err(error) => return Some(format!("Error: {error}")),
+        };
+
+        let frame = match &self.previous {
+            Some(previous) => cli::diff_overlay(previous, &tilemap, &previous.diff(&tilemap)),
+            None => tilemap.build(),
+        };
+        self.previous = Some(tilemap);
+        return Some(frame);
+    }
+
+    /// # Description
+    /// Loads the tilemap from the file, or builds it by executing the script.
+    fn load(&self) -> Result<Tilemap, String> {
+        if !self.script {
+            return Tilemap::load_from_file(&self.path);
+        }
+
+        let script = std::fs::read_to_string(&self.path)
+            .map_err(|error| format!("Failed to load script from {}: {error}", self.path.display()))?;
+        let mut tilemap = Tilemap::new(self.empty_tile);
+        commands::execute(&mut tilemap, &script)?;
+        return Ok(tilemap);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Tests
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use char_tilemap::Tilemap;
+    use crate::watch::Watcher;
+
+    #[test]
+    fn poll_tilemap() {
+        let path = std::env::temp_dir().join("char_tilemap_watch.tilemap");
+        Tilemap::from_string("AB", '-').save_to_file(&path).unwrap();
+
+        let mut watcher = Watcher::new(path.clone(), false, '-');
+        assert_eq!(watcher.poll(), Some(String::from("AB")));
+        assert_eq!(watcher.poll(), None);
+
+        Tilemap::from_string("AC\n-D", '-').save_to_file(&path).unwrap();
+        assert_eq!(watcher.poll(), Some(String::from("A\x1b[33mC\x1b[0m\n-\x1b[32mD\x1b[0m\n1 added, 0 removed, 1 changed")));
+
+        std::fs::write(&path, "broken").unwrap();
+        assert!(watcher.poll().unwrap().starts_with("Error: "));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn poll_script() {
+        let path = std::env::temp_dir().join("char_tilemap_watch.script");
+        std::fs::write(&path, "rect 0 0 2 2 #").unwrap();
+
+        let mut watcher = Watcher::new(path.clone(), true, '.');
+        assert_eq!(watcher.poll(), Some(String::from("##\n##")));
+
+        std::fs::write(&path, "rect 0 0 2 2 #\nremove 1 1").unwrap();
+        assert_eq!(watcher.poll(), Some(String::from("##\n#\x1b[31m#\x1b[0m\n0 added, 1 removed, 0 changed")));
+        std::fs::remove_file(path).unwrap();
+    }
+}